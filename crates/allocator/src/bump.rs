@@ -0,0 +1,96 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A simple bump allocator.
+//!
+//! Its goal is to have a much smaller footprint than the `wee_alloc` allocator. It never
+//! frees memory which is fine since contract executions are short lived and do not
+//! allocate a lot of memory.
+
+use core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
+
+/// The size of a Wasm memory page in bytes.
+const PAGE_SIZE: usize = 64 * 1024;
+
+/// A bump allocator for the global Wasm heap memory.
+pub struct BumpAllocator;
+
+/// The region of memory that is currently handed out by the bump allocator.
+struct InnerAlloc {
+    /// Points to the start of the next available allocation.
+    next: usize,
+    /// The address of the upper limit of our heap.
+    upper_limit: usize,
+}
+
+static mut INNER: InnerAlloc = InnerAlloc {
+    next: 0,
+    upper_limit: 0,
+};
+
+impl InnerAlloc {
+    /// Requests `pages` new pages from the Wasm environment and returns the
+    /// address of the first new page or `None` if memory could not be grown.
+    fn request_pages(pages: usize) -> Option<usize> {
+        let prev_page = core::arch::wasm32::memory_grow(0, pages);
+        if prev_page == usize::MAX {
+            return None
+        }
+        prev_page.checked_mul(PAGE_SIZE)
+    }
+
+    /// Tries to allocate enough memory for the given `layout`.
+    fn alloc(&mut self, layout: Layout) -> Option<usize> {
+        let alloc_start = align_up(self.next, layout.align())?;
+        let alloc_end = alloc_start.checked_add(layout.size())?;
+        if alloc_end > self.upper_limit {
+            // Request enough pages to fit the allocation regardless of alignment.
+            let required = layout.size().checked_add(layout.align())?;
+            let pages = (required + PAGE_SIZE - 1) / PAGE_SIZE;
+            let page_start = Self::request_pages(pages)?;
+            if page_start != self.upper_limit {
+                // The new pages are not adjacent to our current heap, e.g. on the
+                // very first allocation, so we continue at the new pages instead.
+                self.next = page_start;
+            }
+            self.upper_limit = page_start.checked_add(pages * PAGE_SIZE)?;
+            return self.alloc(layout)
+        }
+        self.next = alloc_end;
+        Some(alloc_start)
+    }
+}
+
+/// Aligns `addr` upwards to the given power of two `align`.
+fn align_up(addr: usize, align: usize) -> Option<usize> {
+    Some(addr.checked_add(align - 1)? & !(align - 1))
+}
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: Wasm contracts are executed single threaded so there is no
+        //         concurrent access to the inner allocator state.
+        match (*core::ptr::addr_of_mut!(INNER)).alloc(layout) {
+            Some(start) => start as *mut u8,
+            None => core::ptr::null_mut(),
+        }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
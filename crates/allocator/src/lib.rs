@@ -12,19 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Crate providing `WEE_ALLOC` support for all Wasm compilations of ink! smart contract.
+//! Crate providing the global Wasm heap memory allocator of all Wasm compilations
+//! of ink! smart contracts.
 //!
-//! The Wee allocator is an allocator specifically designed to have a low footprint albeit
-//! being less efficient for allocation and deallocation operations.
+//! By default the Wee allocator is used which is an allocator specifically designed
+//! to have a low footprint albeit being less efficient for allocation and
+//! deallocation operations.
+//!
+//! With the `bump` crate feature a bump allocator is used instead which has an even
+//! smaller footprint but never frees memory. This is well suited for contracts that
+//! allocate little memory per call.
+//!
+//! ink! smart contracts state the allocator they expect via the `allocator` config
+//! of the `#[ink::contract]` macro which is checked against [`GlobalAllocator`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "std"), feature(alloc_error_handler, core_intrinsics))]
 
 // We use `wee_alloc` as the global allocator since it is optimized for binary file size
 // so that contracts compiled with it as allocator do not grow too much in size.
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), not(feature = "bump")))]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+#[cfg(all(not(feature = "std"), feature = "bump"))]
+#[global_allocator]
+static ALLOC: bump::BumpAllocator = bump::BumpAllocator;
+
+/// The type of the global allocator registered by this crate.
+#[cfg(all(not(feature = "std"), not(feature = "bump")))]
+pub type GlobalAllocator = wee_alloc::WeeAlloc;
+
+/// The type of the global allocator registered by this crate.
+#[cfg(all(not(feature = "std"), feature = "bump"))]
+pub type GlobalAllocator = bump::BumpAllocator;
+
+#[cfg(not(feature = "std"))]
+mod bump;
 #[cfg(not(feature = "std"))]
 mod handlers;
+
+#[cfg(not(feature = "std"))]
+pub use self::bump::BumpAllocator;
+#[cfg(not(feature = "std"))]
+pub use wee_alloc::WeeAlloc;
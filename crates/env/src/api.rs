@@ -396,6 +396,27 @@ pub fn restore_contract<T>(
     })
 }
 
+/// Replaces the code of the currently executed smart contract with the code
+/// uploaded under the given code hash.
+///
+/// # Note
+///
+/// - The storage of the smart contract is kept as is, so the new code must be
+///   compatible with the storage layout of the replaced code.
+/// - The new code takes effect for calls after the currently executed one.
+///
+/// # Errors
+///
+/// If there is no code uploaded under the given code hash.
+pub fn set_code_hash<T>(code_hash: T::Hash) -> Result<()>
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        TypedEnvBackend::set_code_hash::<T>(instance, code_hash)
+    })
+}
+
 /// Terminates the existence of the currently executed smart contract.
 ///
 /// This removes the calling account and transfers all remaining balance
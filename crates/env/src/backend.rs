@@ -49,6 +49,17 @@ impl ReturnFlags {
         self
     }
 
+    /// Creates the flags from their underlying `u32` representation, e.g. as
+    /// returned by a contract execution.
+    pub fn from_u32(value: u32) -> Self {
+        Self { value }
+    }
+
+    /// Returns `true` if the bit to indicate that the execution has been reverted is set.
+    pub fn is_reverted(&self) -> bool {
+        self.value & 1 != 0
+    }
+
     /// Returns the underlying `u32` representation.
     pub(crate) fn into_u32(self) -> u32 {
         self.value
@@ -316,6 +327,15 @@ pub trait TypedEnvBackend: EnvBackend {
     ) where
         T: Environment;
 
+    /// Replaces the code of the executed contract with the code at the given code hash.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`set_code_hash`][`crate::set_code_hash`]
+    fn set_code_hash<T>(&mut self, code_hash: T::Hash) -> Result<()>
+    where
+        T: Environment;
+
     /// Terminates a smart contract.
     ///
     /// # Note
@@ -19,6 +19,7 @@ use super::{
     },
     OffAccountId,
     OffBalance,
+    OffHash,
 };
 use crate::{
     Environment,
@@ -239,6 +240,32 @@ impl Account {
         })
     }
 
+    /// Returns the code hash of the contract account or an error.
+    ///
+    /// Returns `None` if the code hash of the contract account has never been set.
+    pub fn code_hash<T>(&self) -> Result<Option<T::Hash>>
+    where
+        T: Environment,
+    {
+        self.contract_or_err().and_then(|contract| {
+            contract
+                .code_hash
+                .as_ref()
+                .map(|code_hash| code_hash.decode().map_err(Into::into))
+                .transpose()
+        })
+    }
+
+    /// Sets the code hash of the contract account or returns an error.
+    pub fn set_code_hash<T>(&mut self, new_code_hash: T::Hash) -> Result<()>
+    where
+        T: Environment,
+    {
+        self.contract_or_err_mut().map(|contract| {
+            contract.code_hash = Some(OffHash::new(&new_code_hash));
+        })
+    }
+
     /// Sets the contract storage of key to the new value.
     pub fn set_storage<T>(&mut self, at: Key, new_value: &T) -> Result<()>
     where
@@ -287,6 +314,8 @@ pub enum AccountKind {
 pub struct ContractAccount {
     /// The contract's rent allowance.
     rent_allowance: OffBalance,
+    /// The contract's code hash if it has been set.
+    code_hash: Option<OffHash>,
     /// The contract storage.
     pub storage: ContractStorage,
 }
@@ -299,6 +328,7 @@ impl ContractAccount {
     {
         Self {
             rent_allowance: OffBalance::new(&rent_allowance),
+            code_hash: None,
             storage: ContractStorage::new(),
         }
     }
@@ -14,16 +14,11 @@
 
 use super::super::OffHash;
 use crate::{
-    hash::{
-        Blake2x256,
-        CryptoHash,
-        HashOutput,
-    },
     topics::{
+        topic_hash,
         Topics,
         TopicsBuilderBackend,
     },
-    Clear,
     Environment,
 };
 
@@ -44,18 +39,7 @@ where
     where
         T: scale::Encode,
     {
-        let encoded = topic_value.encode();
-        let len_encoded = encoded.len();
-        let mut result = <E as Environment>::Hash::clear();
-        let len_result = result.as_ref().len();
-        if len_encoded <= len_result {
-            result.as_mut()[..len_encoded].copy_from_slice(&encoded[..]);
-        } else {
-            let mut hash_output = <Blake2x256 as HashOutput>::Type::default();
-            <Blake2x256 as CryptoHash>::hash(&encoded[..], &mut hash_output);
-            let copy_len = core::cmp::min(hash_output.len(), len_result);
-            result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
-        }
+        let result = topic_hash::<E>(&topic_value.encode());
         let off_hash = OffHash::new(&result);
         debug_assert!(
             !self.topics.contains(&off_hash),
@@ -167,7 +167,11 @@ impl EnvBackend for EnvInstance {
         R: scale::Encode,
     {
         let ctx = self.exec_context_mut().expect(UNITIALIZED_EXEC_CONTEXT);
-        ctx.output = Some(return_value.encode());
+        let encoded = return_value.encode();
+        ctx.output = Some(encoded.clone());
+        if crate::test::catches_return_value() {
+            crate::test::throw_return_value(flags, encoded)
+        }
         std::process::exit(flags.into_u32() as i32)
     }
 
@@ -442,6 +446,15 @@ impl TypedEnvBackend for EnvInstance {
         unimplemented!("off-chain environment does not support contract restoration")
     }
 
+    fn set_code_hash<T>(&mut self, code_hash: T::Hash) -> Result<()>
+    where
+        T: Environment,
+    {
+        self.callee_account_mut()
+            .set_code_hash::<T>(code_hash)
+            .map_err(Into::into)
+    }
+
     fn transfer<T>(&mut self, destination: T::AccountId, value: T::Balance) -> Result<()>
     where
         T: Environment,
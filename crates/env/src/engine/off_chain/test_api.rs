@@ -29,7 +29,9 @@ pub use super::{
 use crate::{
     Environment,
     Result,
+    ReturnFlags,
 };
+use core::cell::Cell;
 use ink_prelude::string::String;
 use std::panic::UnwindSafe;
 
@@ -179,6 +181,30 @@ where
     })
 }
 
+/// Returns the code hash of the contract account.
+///
+/// Returns `None` if the code hash of the contract account has never been set
+/// via [`set_code_hash`][`crate::set_code_hash`].
+///
+/// # Errors
+///
+/// - If `account` does not exist.
+/// - If the underlying `account` type does not match.
+/// - If the returned code hash cannot be properly decoded.
+pub fn get_contract_code_hash<T>(account_id: T::AccountId) -> Result<Option<T::Hash>>
+where
+    T: Environment,
+{
+    <EnvInstance as OnInstance>::on_instance(|instance| {
+        instance
+            .accounts
+            .get_account::<T>(&account_id)
+            .ok_or_else(|| AccountError::no_account_for_id::<T>(&account_id))
+            .map_err(Into::into)
+            .and_then(|account| account.code_hash::<T>().map_err(Into::into))
+    })
+}
+
 /// Registers a new chain extension.
 pub fn register_chain_extension<E>(extension: E)
 where
@@ -426,3 +452,68 @@ pub fn assert_contract_termination<T, F>(
     assert_eq!(res.beneficiary, expected_beneficiary);
     assert_eq!(res.transferred, expected_balance);
 }
+
+/// The value returned to the caller of a contract via `ink_env::return_value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReturnedValue {
+    /// `true` if the contract execution has been reverted.
+    pub reverted: bool,
+    /// The encoded returned value.
+    pub data: Vec<u8>,
+}
+
+thread_local! {
+    /// `true` while [`catch_return_value`] runs its closure.
+    static CATCHES_RETURN_VALUE: Cell<bool> = Cell::new(false);
+}
+
+/// Returns `true` if `ink_env::return_value` is supposed to hand the
+/// returned value to [`catch_return_value`] instead of exiting the process.
+pub(crate) fn catches_return_value() -> bool {
+    CATCHES_RETURN_VALUE.with(Cell::get)
+}
+
+/// Hands the returned value to the enclosing [`catch_return_value`].
+pub(crate) fn throw_return_value(flags: ReturnFlags, data: Vec<u8>) -> ! {
+    std::panic::panic_any(ReturnedValue {
+        reverted: flags.is_reverted(),
+        data,
+    })
+}
+
+/// Runs `f` and returns the value it returns to the caller via
+/// `ink_env::return_value`, e.g. when dispatching an ink! message.
+///
+/// Returns `None` if `f` completes without returning a value.
+///
+/// # Note
+///
+/// Outside of this function `ink_env::return_value` exits the process in
+/// the off-chain environment just like it stops the contract execution.
+///
+/// # Usage
+///
+/// ```no_compile
+/// let returned = ink_env::test::catch_return_value(|| {
+///     <MyContract as DispatchUsingMode>::dispatch_using_mode(DispatchMode::Call)
+///         .unwrap()
+/// });
+/// assert_eq!(returned.map(|returned| returned.data), Some(expected_bytes));
+/// ```
+pub fn catch_return_value<F>(f: F) -> Option<ReturnedValue>
+where
+    F: FnOnce() + UnwindSafe,
+{
+    let catches = CATCHES_RETURN_VALUE.with(|catches| catches.replace(true));
+    let result = std::panic::catch_unwind(f);
+    CATCHES_RETURN_VALUE.with(|cell| cell.set(catches));
+    match result {
+        Ok(()) => None,
+        Err(payload) => {
+            match payload.downcast::<ReturnedValue>() {
+                Ok(returned) => Some(*returned),
+                Err(payload) => std::panic::resume_unwind(payload),
+            }
+        }
+    }
+}
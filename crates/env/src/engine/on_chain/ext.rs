@@ -341,6 +341,11 @@ mod sys {
             output_len_ptr: Ptr32Mut<u32>,
         );
     }
+
+    #[link(wasm_import_module = "__unstable__")]
+    extern "C" {
+        pub fn seal_set_code_hash(code_hash_ptr: Ptr32<[u8]>) -> ReturnCode;
+    }
 }
 
 fn extract_from_slice(output: &mut &mut [u8], new_len: usize) {
@@ -494,6 +499,16 @@ pub fn restore_to(
     }
 }
 
+/// Replaces the code of the executed contract with the code at the given code hash.
+///
+/// # Errors
+///
+/// - `CodeNotFound`: If there is no code uploaded for the given `code_hash`.
+pub fn set_code_hash(code_hash: &[u8]) -> Result {
+    let ret_code = unsafe { sys::seal_set_code_hash(Ptr32::from_slice(code_hash)) };
+    ret_code.into()
+}
+
 pub fn terminate(beneficiary: &[u8]) -> ! {
     unsafe {
         sys::seal_terminate(Ptr32::from_slice(beneficiary), beneficiary.len() as u32)
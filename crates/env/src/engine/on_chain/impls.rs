@@ -33,10 +33,10 @@ use crate::{
         Sha2x256,
     },
     topics::{
+        topic_hash,
         Topics,
         TopicsBuilderBackend,
     },
-    Clear,
     EnvBackend,
     Environment,
     Error,
@@ -144,17 +144,7 @@ where
     {
         let mut split = self.scoped_buffer.split();
         let encoded = split.take_encoded(topic_value);
-        let len_encoded = encoded.len();
-        let mut result = <E as Environment>::Hash::clear();
-        let len_result = result.as_ref().len();
-        if len_encoded <= len_result {
-            result.as_mut()[..len_encoded].copy_from_slice(encoded);
-        } else {
-            let mut hash_output = <Blake2x256 as HashOutput>::Type::default();
-            <Blake2x256 as CryptoHash>::hash(encoded, &mut hash_output);
-            let copy_len = core::cmp::min(hash_output.len(), len_result);
-            result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
-        }
+        let result = topic_hash::<E>(encoded);
         self.scoped_buffer.append_encoded(&result);
     }
 
@@ -436,6 +426,14 @@ impl TypedEnvBackend for EnvInstance {
         );
     }
 
+    fn set_code_hash<T>(&mut self, code_hash: T::Hash) -> Result<()>
+    where
+        T: Environment,
+    {
+        let buffer = self.scoped_buffer().take_encoded(&code_hash);
+        ext::set_code_hash(&buffer[..]).map_err(Into::into)
+    }
+
     fn terminate_contract<T>(&mut self, beneficiary: T::AccountId) -> !
     where
         T: Environment,
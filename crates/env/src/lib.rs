@@ -56,8 +56,12 @@ fn panic(_info: &core::panic::PanicInfo) -> ! {
 
 // This extern crate definition is required since otherwise rustc
 // is not recognizing its allocator and panic handler definitions.
+//
+// The allocators are re-exported for the ink! smart contract code generation
+// to check the registered global allocator against the configured one.
 #[cfg(not(feature = "std"))]
-extern crate ink_allocator;
+#[doc(hidden)]
+pub extern crate ink_allocator as allocator;
 
 mod api;
 mod arithmetic;
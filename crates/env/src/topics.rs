@@ -14,7 +14,15 @@
 
 //! Docs
 
-use crate::Environment;
+use crate::{
+    hash::{
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+    },
+    Clear,
+    Environment,
+};
 
 /// The concrete implementation that is guided by the topics builder.
 ///
@@ -202,6 +210,30 @@ pub trait Topics {
         B: TopicsBuilderBackend<E>;
 }
 
+/// Returns the raw topic bytes of the given encoded topic value.
+///
+/// # Note
+///
+/// Encoded values that fit into the hash type of the environment are padded
+/// with zeros, larger ones are hashed using `Blake2x256` first. All ink! event
+/// topics are built this way.
+pub fn topic_hash<E>(encoded: &[u8]) -> <E as Environment>::Hash
+where
+    E: Environment,
+{
+    let mut result = <E as Environment>::Hash::clear();
+    let len_result = result.as_ref().len();
+    if encoded.len() <= len_result {
+        result.as_mut()[..encoded.len()].copy_from_slice(encoded);
+    } else {
+        let mut hash_output = <Blake2x256 as HashOutput>::Type::default();
+        <Blake2x256 as CryptoHash>::hash(encoded, &mut hash_output);
+        let copy_len = core::cmp::min(hash_output.len(), len_result);
+        result.as_mut()[..copy_len].copy_from_slice(&hash_output[..copy_len]);
+    }
+    result
+}
+
 /// For each topic a hash is generated. This hash must be unique
 /// for a field and its value. The `prefix` is concatenated
 /// with the `value`. This result is then hashed.
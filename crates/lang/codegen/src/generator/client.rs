@@ -0,0 +1,178 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::GenerateCode;
+use derive_more::From;
+use heck::SnakeCase as _;
+use ir::Callable as _;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates the client stubs of the ink! smart contract.
+///
+/// # Note
+///
+/// The client struct provides a method per ink! message that encodes the
+/// selector and the inputs of the ink! message and knows how to decode its
+/// result. It does not submit any calls itself and thus is agnostic over the
+/// way calls are submitted to the ink! smart contract, e.g. in integration
+/// tests against a live node.
+#[derive(From)]
+pub struct Client<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl AsRef<ir::Contract> for Client<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
+impl GenerateCode for Client<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let client_ident = self.client_ident();
+        let storage_ident = self.contract.module().storage().ident();
+        let doc = format!(
+            "Client stubs encoding calls to the ink! messages of `{}`.",
+            storage_ident
+        );
+        let messages = self
+            .contract
+            .module()
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .filter(|message| !message.is_test_only())
+            .map(Self::generate_message);
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Copy, Clone, Default)]
+            pub struct #client_ident;
+
+            const _: () = {
+                impl #client_ident {
+                    #( #messages )*
+                }
+            };
+        }
+    }
+}
+
+impl Client<'_> {
+    /// Returns the identifier of the generated client struct.
+    ///
+    /// This is the name of the ink! storage struct suffixed with `Client`.
+    fn client_ident(&self) -> Ident {
+        format_ident!("{}Client", self.contract.module().storage().ident())
+    }
+
+    /// Returns the identifier of the client method of the ink! message.
+    ///
+    /// Methods of ink! messages defined in trait implementation blocks are
+    /// prefixed with the snake case name of the trait to avoid name clashes.
+    fn method_ident(message: &ir::CallableWithSelector<ir::Message>) -> Ident {
+        let ident = message.ident();
+        let trait_ident = message
+            .item_impl()
+            .trait_path()
+            .and_then(|path| path.segments.last())
+            .map(|segment| &segment.ident);
+        match trait_ident {
+            Some(trait_ident) => {
+                format_ident!("{}_{}", trait_ident.to_string().to_snake_case(), ident)
+            }
+            None => ident.clone(),
+        }
+    }
+
+    /// Generates the client method of the ink! message.
+    fn generate_message(message: ir::CallableWithSelector<ir::Message>) -> TokenStream2 {
+        let span = message.span();
+        let attrs = message.attrs();
+        let method_ident = Self::method_ident(&message);
+        let selector_bytes = message.composed_selector().as_bytes().to_owned();
+        let (input_idents, input_types): (Vec<_>, Vec<_>) = message
+            .inputs()
+            .iter()
+            .map(|(ident, ty)| (ident, ty))
+            .unzip();
+        let output = if message.is_raw_return() {
+            quote! { ::ink_lang::RawReturn }
+        } else {
+            message
+                .output()
+                .map(quote::ToTokens::to_token_stream)
+                .unwrap_or_else(|| quote! { () })
+        };
+        #[cfg(feature = "dry-run")]
+        let dry_run = Self::generate_dry_run_message(&message, &method_ident, &output);
+        #[cfg(not(feature = "dry-run"))]
+        let dry_run = TokenStream2::new();
+        quote_spanned!(span=>
+            #( #attrs )*
+            pub fn #method_ident(
+                &self #(, #input_idents : #input_types )*
+            ) -> ::ink_lang::ClientCall<#output> {
+                ::ink_lang::ClientCall::new(
+                    [ #( #selector_bytes ),* ],
+                    &( #( #input_idents, )* ),
+                )
+            }
+
+            #dry_run
+        )
+    }
+
+    /// Generates the dry-run client method of the ink! message.
+    ///
+    /// The dry-run method has the same signature as the client method of the
+    /// ink! message and is suffixed with `_dry_run`.
+    #[cfg(feature = "dry-run")]
+    fn generate_dry_run_message(
+        message: &ir::CallableWithSelector<ir::Message>,
+        method_ident: &Ident,
+        output: &TokenStream2,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let dry_run_ident = format_ident!("{}_dry_run", method_ident);
+        let doc = format!(
+            "Dry-run variant of [`Self::{}`] that also decodes whether the call would be reverted.",
+            method_ident
+        );
+        let must_use = message.must_use();
+        let (input_idents, input_types): (Vec<_>, Vec<_>) = message
+            .inputs()
+            .iter()
+            .map(|(ident, ty)| (ident, ty))
+            .unzip();
+        quote_spanned!(span=>
+            #[doc = #doc]
+            #must_use
+            pub fn #dry_run_ident(
+                &self #(, #input_idents : #input_types )*
+            ) -> ::ink_lang::DryRunCall<#output> {
+                ::ink_lang::DryRunCall::new(
+                    self.#method_ident( #( #input_idents ),* )
+                )
+            }
+        )
+    }
+}
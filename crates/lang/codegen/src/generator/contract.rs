@@ -39,33 +39,180 @@ impl GenerateCode for Contract<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let module = self.contract.module();
         let ident = module.ident();
-        let attrs = module.attrs();
+        let outer_attrs = module
+            .attrs()
+            .iter()
+            .filter(|attr| matches!(attr.style, syn::AttrStyle::Outer));
+        let inner_attrs = module
+            .attrs()
+            .iter()
+            .filter(|attr| matches!(attr.style, syn::AttrStyle::Inner(_)));
         let vis = module.vis();
         let env = self.generate_code_using::<generator::Env>();
         let storage = self.generate_code_using::<generator::Storage>();
         let events = self.generate_code_using::<generator::Events>();
         let dispatch = self.generate_code_using::<generator::Dispatch>();
         let item_impls = self.generate_code_using::<generator::ItemImpls>();
+        let interface = self.generate_code_using::<generator::Interface>();
+        #[cfg(feature = "client")]
+        let client = self.generate_code_using::<generator::Client>();
+        #[cfg(not(feature = "client"))]
+        let client = TokenStream2::new();
+        #[cfg(feature = "event-filters")]
+        let event_filters = self.generate_code_using::<generator::EventFilters>();
+        #[cfg(not(feature = "event-filters"))]
+        let event_filters = TokenStream2::new();
         let cross_calling = self.generate_code_using::<generator::CrossCalling>();
         let metadata = self.generate_code_using::<generator::Metadata>();
-        let non_ink_items = self
+        let cross_calling_ref = self.generate_code_using::<generator::CrossCallingRef>();
+        // `macro_rules!` definitions are textually scoped and thus have to
+        // precede all generated items which might invoke them.
+        let (macro_rules, non_ink_items): (Vec<_>, Vec<_>) = self
             .contract
             .module()
             .items()
             .iter()
-            .filter_map(ir::Item::map_rust_item);
+            .filter_map(ir::Item::map_rust_item)
+            .partition(|item| Self::is_macro_rules(item));
         quote! {
-            #( #attrs )*
+            #( #outer_attrs )*
             #vis mod #ident {
+                #( #inner_attrs )*
+                #( #macro_rules )*
                 #env
                 #storage
                 #events
+                #event_filters
                 #dispatch
                 #item_impls
+                #interface
+                #client
                 #cross_calling
                 #metadata
                 #( #non_ink_items )*
             }
+
+            #cross_calling_ref
         }
     }
 }
+
+impl Contract<'_> {
+    /// Returns `true` if the item is a `macro_rules!` definition.
+    fn is_macro_rules(item: &syn::Item) -> bool {
+        matches!(item, syn::Item::Macro(item_macro) if item_macro.mac.path.is_ident("macro_rules"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimal_contract() -> ir::Contract {
+        ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod flipper {
+                    #[ink(storage)]
+                    pub struct Flipper {
+                        value: bool,
+                    }
+
+                    impl Flipper {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self { value: false } }
+
+                        #[ink(message)]
+                        pub fn flip(&mut self) { self.value = !self.value; }
+                    }
+                }
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn minimal_contract_expansion_works() {
+        let contract = minimal_contract();
+        let expansion = crate::generate_code(&contract);
+        assert_eq!(
+            expansion.to_string(),
+            crate::generate_code(&minimal_contract()).to_string(),
+            "the contract expansion must be deterministic",
+        );
+        let file = syn::parse2::<syn::File>(expansion.clone()).unwrap();
+        let module_idents = file
+            .items
+            .iter()
+            .filter_map(|item| {
+                match item {
+                    syn::Item::Mod(module) => Some(module.ident.to_string()),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(module_idents, vec!["flipper".to_string()]);
+        let expansion = expansion.to_string();
+        for expected in &[
+            "impl :: ink_lang :: ContractEnv for Flipper",
+            "pub struct Flipper",
+            "pub enum __ink_MessageDispatchEnum",
+            "pub enum __ink_ConstructorDispatchEnum",
+            "pub fn flip (& mut self)",
+            "pub fn __ink_generate_metadata",
+            "pub use flipper :: Flipper as FlipperRef",
+        ] {
+            assert!(
+                expansion.contains(expected),
+                "missing `{}` in the contract expansion",
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn macro_rules_precede_generated_items() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod counter {
+                    #[ink(storage)]
+                    pub struct Counter {}
+
+                    impl Counter {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { double!(Self {}) }
+
+                        #[ink(message)]
+                        pub fn get(&self) {}
+                    }
+
+                    macro_rules! double {
+                        ( $value:expr ) => { $value };
+                    }
+
+                    double!(struct Helper;);
+                }
+            },
+        )
+        .unwrap();
+        let file = syn::parse2::<syn::File>(crate::generate_code(&contract)).unwrap();
+        let items = file
+            .items
+            .iter()
+            .find_map(|item| {
+                match item {
+                    syn::Item::Mod(module) => module.content.as_ref(),
+                    _ => None,
+                }
+            })
+            .map(|(_, items)| items)
+            .unwrap();
+        assert!(Contract::is_macro_rules(&items[0]));
+        // Macro invocations are emitted together with the other Rust items.
+        assert!(matches!(
+            items.last().unwrap(),
+            syn::Item::Macro(item_macro) if item_macro.mac.path.is_ident("double")
+        ));
+    }
+}
@@ -104,6 +104,37 @@ impl GenerateCode for CrossCalling<'_> {
     }
 }
 
+/// Generates the re-export of the ink! smart contract reference type.
+///
+/// # Note
+///
+/// When compiled as dependency the ink! storage struct becomes a reference to a
+/// live smart contract instance. It is re-exported next to the ink! module under
+/// the configured `ref_name` or otherwise under the name of the ink! storage
+/// struct suffixed with `Ref`, e.g. `use token::TokenRef;`.
+#[derive(From)]
+pub struct CrossCallingRef<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl GenerateCode for CrossCallingRef<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let cfg = CrossCalling::from(self.contract).generate_cfg();
+        let module_ident = self.contract.module().ident();
+        let storage_ident = self.contract.module().storage().ident();
+        let ref_ident = self
+            .contract
+            .config()
+            .ref_name()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("{}Ref", storage_ident));
+        quote! {
+            #cfg
+            pub use #module_ident::#storage_ident as #ref_ident;
+        }
+    }
+}
+
 impl CrossCalling<'_> {
     /// Generates code for conditionally compiling code only if the contract
     /// is compiled as dependency.
@@ -181,7 +212,7 @@ impl CrossCalling<'_> {
     }
 
     /// Builds up the `ink_env::call::utils::ArgumentList` type structure for the given types.
-    fn generate_arg_list<'a, Args>(args: Args) -> TokenStream2
+    pub(super) fn generate_arg_list<'a, Args>(args: Args) -> TokenStream2
     where
         Args: IntoIterator<Item = &'a syn::Type>,
         <Args as IntoIterator>::IntoIter: DoubleEndedIterator,
@@ -223,12 +254,12 @@ impl CrossCalling<'_> {
         );
         let attrs = message.attrs();
         let input_bindings = message
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = message
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         let output_ty = message
@@ -270,12 +301,12 @@ impl CrossCalling<'_> {
         let composed_selector = message.composed_selector().as_bytes().to_owned();
         let attrs = message.attrs();
         let input_bindings = message
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = message
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         let arg_list = Self::generate_arg_list(input_types.iter().cloned());
@@ -369,12 +400,12 @@ impl CrossCalling<'_> {
             serde_hex::to_hex(&scale::Encode::encode(&linker_error), false)
         );
         let input_bindings = constructor
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = constructor
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         quote_spanned!(span =>
@@ -394,6 +425,25 @@ impl CrossCalling<'_> {
         )
     }
 
+    /// Returns the associated types and constants of the ink! trait
+    /// implementation block.
+    ///
+    /// # Note
+    ///
+    /// These need to be repeated for every implementor of the ink! trait
+    /// since associated items are not forwarded like ink! messages.
+    fn trait_impl_assoc_items(
+        item_impl: &ir::ItemImpl,
+    ) -> impl Iterator<Item = &syn::ImplItem> {
+        item_impl
+            .items()
+            .iter()
+            .filter_map(ir::ImplItem::filter_map_other_item)
+            .filter(|item| {
+                matches!(item, syn::ImplItem::Type(_) | syn::ImplItem::Const(_))
+            })
+    }
+
     /// Generates code for a single call forwarder trait implementation block.
     ///
     /// The `mutable` parameter indicates whether only read-only (`false`) or
@@ -415,6 +465,7 @@ impl CrossCalling<'_> {
         let messages = item_impl
             .iter_messages()
             .map(|message| Self::generate_call_forwarder_trait_message(mutable, message));
+        let assoc_items = Self::trait_impl_assoc_items(item_impl);
         let trait_path = item_impl
             .trait_path()
             .expect("encountered missing trait path for trait impl block");
@@ -425,12 +476,12 @@ impl CrossCalling<'_> {
             trait_ident,
             item_impl.iter_constructors().map(|constructor| {
                 let ident = constructor.ident().clone();
-                let len_inputs = constructor.inputs().count();
+                let len_inputs = constructor.inputs_iter().count();
                 (ident, len_inputs)
             }),
             item_impl.iter_messages().map(|message| {
                 let ident = message.ident().clone();
-                let len_inputs = message.inputs().count() + 1;
+                let len_inputs = message.inputs_iter().count() + 1;
                 let is_mut = message.receiver().is_ref_mut();
                 (ident, len_inputs, is_mut)
             }),
@@ -443,6 +494,7 @@ impl CrossCalling<'_> {
             impl<'a> #trait_path for #forwarder_ident<&'a #mut_tok #storage_ident> {
                 type __ink_Checksum = [(); #checksum];
 
+                #( #assoc_items )*
                 #( #constructors )*
                 #( #messages )*
             }
@@ -457,12 +509,12 @@ impl CrossCalling<'_> {
         let composed_selector = message.composed_selector().as_bytes().to_owned();
         let attrs = message.attrs();
         let input_bindings = message
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = message
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         let arg_list = Self::generate_arg_list(input_types.iter().cloned());
@@ -613,8 +665,8 @@ impl CrossCalling<'_> {
             "encountered error while calling <{} as {}>::{}",
             storage_ident_str, trait_path, ident_str
         );
-        let inputs_sig = message.inputs();
-        let inputs_params = message.inputs().map(|pat_type| &pat_type.pat);
+        let inputs_sig = message.inputs_iter();
+        let inputs_params = message.inputs_iter().map(|pat_type| &pat_type.pat);
         let output_ty = message
             .output()
             .cloned()
@@ -659,12 +711,12 @@ impl CrossCalling<'_> {
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
         let composed_selector = constructor.composed_selector().as_bytes().to_owned();
         let input_bindings = constructor
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = constructor
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         let arg_list = Self::generate_arg_list(input_types.iter().cloned());
@@ -716,16 +768,17 @@ impl CrossCalling<'_> {
         let constructors = impl_block
             .iter_constructors()
             .map(Self::generate_trait_impl_block_constructor);
+        let assoc_items = Self::trait_impl_assoc_items(impl_block);
         let hash = ir::InkTrait::compute_verify_hash(
             trait_ident,
             impl_block.iter_constructors().map(|constructor| {
                 let ident = constructor.ident().clone();
-                let len_inputs = constructor.inputs().count();
+                let len_inputs = constructor.inputs_iter().count();
                 (ident, len_inputs)
             }),
             impl_block.iter_messages().map(|message| {
                 let ident = message.ident().clone();
-                let len_inputs = message.inputs().count() + 1;
+                let len_inputs = message.inputs_iter().count() + 1;
                 let is_mut = message.receiver().is_ref_mut();
                 (ident, len_inputs, is_mut)
             }),
@@ -740,6 +793,7 @@ impl CrossCalling<'_> {
             impl #trait_path for #self_type {
                 type __ink_Checksum = [(); #checksum];
 
+                #( #assoc_items )*
                 #( #messages )*
                 #( #constructors )*
             }
@@ -759,12 +813,12 @@ impl CrossCalling<'_> {
         let ident = constructor.ident();
         let composed_selector = constructor.composed_selector().as_bytes().to_owned();
         let input_bindings = constructor
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
         let input_types = constructor
-            .inputs()
+            .inputs_iter()
             .map(|pat_type| &*pat_type.ty)
             .collect::<Vec<_>>();
         let arg_list = Self::generate_arg_list(input_types.iter().cloned());
@@ -809,8 +863,8 @@ impl CrossCalling<'_> {
             "encountered error while calling {}::{}",
             storage_ident_str, ident_str
         );
-        let inputs_sig = message.inputs();
-        let inputs_params = message.inputs().map(|pat_type| &pat_type.pat);
+        let inputs_sig = message.inputs_iter();
+        let inputs_params = message.inputs_iter().map(|pat_type| &pat_type.pat);
         let output_sig = message.output().map(|output| quote! { -> #output });
         let receiver = message.receiver();
         let forward_ident = match receiver {
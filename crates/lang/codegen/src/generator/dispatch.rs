@@ -54,6 +54,27 @@ impl AsRef<ir::Contract> for Dispatch<'_> {
     }
 }
 
+/// Generates the `#[inline]` attribute for small generated helper functions.
+///
+/// # Note
+///
+/// Only generates the attribute for ink! smart contracts configured with
+/// `inline_helpers = true`. Inlining the helpers may save the overhead of
+/// function calls at the cost of a potentially larger Wasm binary.
+#[derive(From)]
+pub struct InlineHelperAttr<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl GenerateCode for InlineHelperAttr<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        if self.contract.config().is_inline_helpers_enabled() {
+            return quote! { #[inline] }
+        }
+        quote! {}
+    }
+}
+
 impl GenerateCode for Dispatch<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let no_cross_calling_cfg =
@@ -64,11 +85,14 @@ impl GenerateCode for Dispatch<'_> {
         let dispatch_trait_impls = self.generate_dispatch_trait_impls();
         let message_dispatch_enum = self.generate_message_dispatch_enum();
         let constructor_dispatch_enum = self.generate_constructor_dispatch_enum();
+        let no_panic_dispatch_guard = self.generate_no_panic_dispatch_guard();
         quote! {
+            #no_panic_dispatch_guard
+
             // We do not generate contract dispatch code while the contract
-            // is being tested or the contract is a dependency of another
-            // since both resulting compilations do not require dispatching.
-            #[cfg(not(test))]
+            // is a dependency of another since the resulting compilation does
+            // not require dispatching. Test builds only omit the entry points
+            // so that tests are able to dispatch, e.g. to `test_only` messages.
             #no_cross_calling_cfg
             const _: () = {
                 #entry_points
@@ -83,19 +107,47 @@ impl GenerateCode for Dispatch<'_> {
 }
 
 impl Dispatch<'_> {
+    /// Generates a compile error for Wasm builds of ink! smart contracts configured
+    /// with `no_panic_dispatch = true`.
+    ///
+    /// # Note
+    ///
+    /// Panics can only be caught with the `std` feature. Wasm builds abort on panics
+    /// so that the config would silently have no effect on-chain.
+    fn generate_no_panic_dispatch_guard(&self) -> Option<TokenStream2> {
+        if !self.contract.config().is_no_panic_dispatch_enabled() {
+            return None
+        }
+        let no_cross_calling_cfg =
+            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        Some(quote! {
+            #[cfg(not(feature = "std"))]
+            #no_cross_calling_cfg
+            ::core::compile_error!(
+                "the `no_panic_dispatch` ink! config requires the `std` feature \
+                 since panics cannot be caught in Wasm builds which abort on panics"
+            );
+        })
+    }
+
     /// Generates the static ink! contract entry points.
     ///
     /// # Note
     ///
     /// Those are expected to exist by the smart contracts host module.
     /// They guide the dispatch, set-up and tear-down of a smart contract.
+    ///
+    /// They are exported as `deploy` and `call` unless configured otherwise
+    /// via `entry_deploy` and `entry_call`.
     fn generate_entry_points(&self) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
         let all_messages_deny_payment = self.all_messages_deny_payment();
+        let entry_deploy = self.contract.config().entry_deploy();
+        let entry_call = self.contract.config().entry_call();
         quote! {
             #[cfg(not(test))]
             #[no_mangle]
-            fn deploy() -> u32 {
+            fn #entry_deploy() -> u32 {
                 ::ink_lang::DispatchRetCode::from(
                     <#storage_ident as ::ink_lang::DispatchUsingMode>::dispatch_using_mode(
                         ::ink_lang::DispatchMode::Instantiate,
@@ -106,7 +158,7 @@ impl Dispatch<'_> {
 
             #[cfg(not(test))]
             #[no_mangle]
-            fn call() -> u32 {
+            fn #entry_call() -> u32 {
                 if #all_messages_deny_payment {
                     ::ink_lang::deny_payment::<<#storage_ident as ::ink_lang::ContractEnv>::Env>()
                         .expect("caller transferred value even though all ink! message deny payments")
@@ -121,25 +173,55 @@ impl Dispatch<'_> {
         }
     }
 
+    /// Generates the decoding of the contract input into the given dispatch enum type.
+    ///
+    /// # Note
+    ///
+    /// If a maximum input size has been configured inputs exceeding it are
+    /// rejected before being decoded.
+    fn generate_decode_input(&self, dispatch_type: TokenStream2) -> TokenStream2 {
+        match self.contract.config().max_input_size() {
+            Some(max_input_size) => {
+                quote! {
+                    ::ink_env::decode_input::<::ink_lang::BoundedInput<#dispatch_type, #max_input_size>>()
+                        .map(::ink_lang::BoundedInput::into_inner)
+                }
+            }
+            None => {
+                quote! {
+                    ::ink_env::decode_input::<#dispatch_type>()
+                }
+            }
+        }
+    }
+
     /// Generates the `DispatchUsingMode` trait implementation to guide contract dispatch.
     fn generate_dispatch_using_mode(&self) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
+        let decode_constructor = self.generate_decode_input(quote! {
+            <#storage_ident as ::ink_lang::ConstructorDispatcher>::Type
+        });
+        let decode_message = self.generate_decode_input(quote! {
+            <#storage_ident as ::ink_lang::MessageDispatcher>::Type
+        });
+        let inline = self.generate_code_using::<generator::InlineHelperAttr>();
         quote! {
             impl ::ink_lang::DispatchUsingMode for #storage_ident {
                 #[allow(unused_parens)]
+                #inline
                 fn dispatch_using_mode(
                     mode: ::ink_lang::DispatchMode
                 ) -> core::result::Result<(), ::ink_lang::DispatchError> {
                     match mode {
                         ::ink_lang::DispatchMode::Instantiate => {
                             <<#storage_ident as ::ink_lang::ConstructorDispatcher>::Type as ::ink_lang::Execute>::execute(
-                                ::ink_env::decode_input::<<#storage_ident as ::ink_lang::ConstructorDispatcher>::Type>()
+                                #decode_constructor
                                     .map_err(|_| ::ink_lang::DispatchError::CouldNotReadInput)?
                             )
                         }
                         ::ink_lang::DispatchMode::Call => {
                             <<#storage_ident as ::ink_lang::MessageDispatcher>::Type as ::ink_lang::Execute>::execute(
-                                ::ink_env::decode_input::<<#storage_ident as ::ink_lang::MessageDispatcher>::Type>()
+                                #decode_message
                                     .map_err(|_| ::ink_lang::DispatchError::CouldNotReadInput)?
                             )
                         }
@@ -208,7 +290,8 @@ impl Dispatch<'_> {
         let (selector_bytes, selector_id) = (selector.as_bytes(), selector.unique_id());
         let input_types = callable
             .inputs()
-            .map(|pat_type| &pat_type.ty)
+            .iter()
+            .map(|(_, ty)| Self::generate_input_type(ty))
             .collect::<Vec<_>>();
         let storage_ident = self.contract.module().storage().ident();
         let namespace = Self::dispatch_trait_impl_namespace(cws.kind());
@@ -285,7 +368,7 @@ impl Dispatch<'_> {
         C: ir::Callable,
     {
         let input_bindings = callable
-            .inputs()
+            .inputs_iter()
             .enumerate()
             .map(|(n, _pat_type)| format_ident!("__ink_binding_{}", n))
             .collect::<Vec<_>>();
@@ -297,6 +380,53 @@ impl Dispatch<'_> {
         (input_bindings, inputs_as_tuple_or_wildcard)
     }
 
+    /// Returns the element type `T` if the given input type is a slice `&[T]`.
+    fn slice_input_elem(ty: &syn::Type) -> Option<&syn::Type> {
+        match ty {
+            syn::Type::Reference(reference) if reference.mutability.is_none() => {
+                match &*reference.elem {
+                    syn::Type::Slice(slice) => Some(&*slice.elem),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Generates the type that is decoded for an input of the given type.
+    ///
+    /// # Note
+    ///
+    /// Slice inputs `&[T]` cannot be decoded and are instead decoded as owned
+    /// `Vec<T>` that is borrowed when calling the ink! message or constructor.
+    fn generate_input_type(ty: &syn::Type) -> TokenStream2 {
+        match Self::slice_input_elem(ty) {
+            Some(elem) => quote_spanned!(ty.span() => ::ink_lang::SliceInput<#elem>),
+            None => quote! { #ty },
+        }
+    }
+
+    /// Generates the arguments with which the ink! callable is called given
+    /// the identifier bindings of its decoded inputs.
+    ///
+    /// Decoded slice inputs are passed as borrows.
+    fn generate_input_args<C>(callable: &C, input_bindings: &[Ident]) -> Vec<TokenStream2>
+    where
+        C: ir::Callable,
+    {
+        callable
+            .inputs()
+            .iter()
+            .zip(input_bindings)
+            .map(|((_, ty), binding)| {
+                match Self::slice_input_elem(ty) {
+                    Some(_) => quote! { &#binding },
+                    None => quote! { #binding },
+                }
+            })
+            .collect()
+    }
+
     /// Generates all the dispatch trait implementations for the given ink! message.
     fn generate_trait_impls_for_message(
         &self,
@@ -306,10 +436,14 @@ impl Dispatch<'_> {
         let message_span = message.span();
         let selector = cws.composed_selector();
         let selector_id = selector.unique_id();
-        let output_tokens = message
-            .output()
-            .map(quote::ToTokens::to_token_stream)
-            .unwrap_or_else(|| quote! { () });
+        let output_tokens = if message.is_raw_return() {
+            quote! { ::ink_lang::RawReturn }
+        } else {
+            message
+                .output()
+                .map(quote::ToTokens::to_token_stream)
+                .unwrap_or_else(|| quote! { () })
+        };
         let is_mut = message.receiver().is_ref_mut();
         let storage_ident = self.contract.module().storage().ident();
         let message_ident = message.ident();
@@ -331,25 +465,37 @@ impl Dispatch<'_> {
         };
         let (input_bindings, inputs_as_tuple_or_wildcard) =
             Self::generate_input_bindings(message);
+        let input_args = Self::generate_input_args(message, &input_bindings);
         let as_trait = cws.item_impl().trait_path().map(|trait_path| {
             quote_spanned!(message_span =>
                 as #trait_path
             )
         });
+        let mut call = quote_spanned!(message_span =>
+            <#storage_ident #as_trait>::#message_ident(state, #( #input_args ),* )
+        );
+        if message.is_raw_return() {
+            // The bytes of `raw_return` messages are returned without length prefix.
+            call = quote_spanned!(message_span => ::ink_lang::RawReturn(#call));
+        }
         let message_impl = quote_spanned!(message_span =>
             impl ::ink_lang::#message_trait_ident for #namespace<[(); #selector_id]> {
                 const CALLABLE: fn(
                     &#mut_token <Self as ::ink_lang::FnState>::State,
                     <Self as ::ink_lang::FnInput>::Input
                 ) -> <Self as ::ink_lang::FnOutput>::Output = |state, #inputs_as_tuple_or_wildcard| {
-                    <#storage_ident #as_trait>::#message_ident(state, #( #input_bindings ),* )
+                    #call
                 };
             }
         );
+        let test_only_cfg = Self::generate_test_only_cfg(message);
         quote_spanned!(message_span =>
-            #callable_impl
-            #fn_output_impl
-            #message_impl
+            #test_only_cfg
+            const _: () = {
+                #callable_impl
+                #fn_output_impl
+                #message_impl
+            };
         )
     }
 
@@ -369,17 +515,28 @@ impl Dispatch<'_> {
         let callable_impl = self.generate_trait_impls_for_callable(cws);
         let (input_bindings, inputs_as_tuple_or_wildcard) =
             Self::generate_input_bindings(constructor);
+        let input_args = Self::generate_input_args(constructor, &input_bindings);
         let as_trait = cws.item_impl().trait_path().map(|trait_path| {
             quote_spanned!(constructor_span =>
                 as #trait_path
             )
         });
+        let output = match constructor.error_type() {
+            Some(error_type) => {
+                quote_spanned!(constructor_span =>
+                    ::core::result::Result<#storage_ident, #error_type>
+                )
+            }
+            None => quote_spanned!(constructor_span => #storage_ident),
+        };
         let constructor_impl = quote_spanned!(constructor_span =>
             impl ::ink_lang::Constructor for #namespace<[(); #selector_id]> {
+                type Output = #output;
+
                 const CALLABLE: fn(
                     <Self as ::ink_lang::FnInput>::Input
-                ) -> <Self as ::ink_lang::FnState>::State = |#inputs_as_tuple_or_wildcard| {
-                    <#storage_ident #as_trait>::#constructor_ident(#( #input_bindings ),* )
+                ) -> <Self as ::ink_lang::Constructor>::Output = |#inputs_as_tuple_or_wildcard| {
+                    <#storage_ident #as_trait>::#constructor_ident(#( #input_args ),* )
                 };
             }
         );
@@ -437,11 +594,12 @@ impl Dispatch<'_> {
         )
     }
 
-    /// Generates one match arm of the dispatch `scale::Decode` implementation.
+    /// Generates the decoding of the inputs of one dispatch variant for the
+    /// dispatch `scale::Decode` implementation.
     ///
     /// # Note
     ///
-    /// There is one match arm per ink! constructor or message for the dispatch
+    /// There is one such decoding per ink! constructor or message for the dispatch
     /// `scale::Decode` implementation.
     fn generate_dispatch_variant_decode<C>(
         &self,
@@ -450,16 +608,104 @@ impl Dispatch<'_> {
     where
         C: ir::Callable,
     {
-        let selector_bytes = cws.composed_selector().as_bytes().to_owned();
         let variant_ident = self.generate_dispatch_variant_ident(cws);
-        let variant_types = cws.callable().inputs().map(|arg| &arg.ty);
+        let variant_types = cws
+            .callable()
+            .inputs()
+            .iter()
+            .map(|(_, ty)| Self::generate_input_type(ty));
         quote! {
-            [ #( #selector_bytes ),* ] => {
-                Ok(Self::#variant_ident(
-                    #(
-                        <#variant_types as ::scale::Decode>::decode(input)?
-                    ),*
-                ))
+            Ok(Self::#variant_ident(
+                #(
+                    <#variant_types as ::scale::Decode>::decode(input)?
+                ),*
+            ))
+        }
+    }
+
+    /// Generates the selector dispatch of the `scale::Decode` implementation
+    /// of a dispatch enum using the configured dispatch strategy.
+    ///
+    /// Every decoder is given as the selector it dispatches on, an optional
+    /// `cfg` attribute guarding it and the decoding of its dispatch variant.
+    ///
+    /// # Note
+    ///
+    /// For the binary search strategy the selectors are sorted at compile time
+    /// and the position of the decoded selector within them is matched against
+    /// which allows the compiler to generate a jump table.
+    ///
+    /// If a `fallback` decoding is given it is used for all unknown selectors
+    /// instead of returning an error.
+    fn generate_decode_dispatch(
+        &self,
+        decoders: Vec<(ir::Selector, Option<TokenStream2>, TokenStream2)>,
+        fallback: Option<TokenStream2>,
+        error: &str,
+    ) -> TokenStream2 {
+        let fallback = fallback.unwrap_or_else(|| {
+            quote! { Err(::scale::Error::from(#error)) }
+        });
+        match self.contract.config().dispatch() {
+            ir::DispatchStrategy::Match => {
+                let arms = decoders.into_iter().map(|(selector, cfg, decode)| {
+                    let selector_bytes = selector.as_bytes();
+                    quote! {
+                        #cfg
+                        [ #( #selector_bytes ),* ] => { #decode }
+                    }
+                });
+                quote! {
+                    match <[u8; 4] as ::scale::Decode>::decode(input)? {
+                        #( #arms )*
+                        _invalid => { #fallback }
+                    }
+                }
+            }
+            ir::DispatchStrategy::BinarySearch => {
+                let mut decoders = decoders;
+                decoders.sort_by_key(|(selector, _, _)| *selector.as_bytes());
+                let selectors = decoders.iter().map(|(selector, cfg, _)| {
+                    let selector_bytes = selector.as_bytes();
+                    quote! { #cfg [ #( #selector_bytes ),* ] }
+                });
+                let (index_consts, arms): (Vec<_>, Vec<_>) = decoders
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (_, cfg, decode))| {
+                        let preceding_cfgs = decoders[..index]
+                            .iter()
+                            .map(|(_, cfg, _)| cfg)
+                            .collect::<Vec<_>>();
+                        if preceding_cfgs.iter().all(|cfg| cfg.is_none()) {
+                            return (None, quote! { #cfg Ok(#index) => { #decode } })
+                        }
+                        // The index of a selector depends on the preceding selectors
+                        // that are enabled by their `cfg` attribute.
+                        let index_ident = format_ident!("__INK_SELECTOR_INDEX_{}", index);
+                        let index_const = quote! {
+                            #cfg
+                            const #index_ident: usize = <[()]>::len(&[
+                                #( #preceding_cfgs () ),*
+                            ]);
+                        };
+                        (
+                            Some(index_const),
+                            quote! { #cfg Ok(#index_ident) => { #decode } },
+                        )
+                    })
+                    .unzip();
+                quote! {
+                    const __INK_SORTED_SELECTORS: &[[u8; 4]] = &[
+                        #( #selectors ),*
+                    ];
+                    #( #index_consts )*
+                    let selector = <[u8; 4] as ::scale::Decode>::decode(input)?;
+                    match __INK_SORTED_SELECTORS.binary_search(&selector) {
+                        #( #arms )*
+                        _invalid => { #fallback }
+                    }
+                }
             }
         }
     }
@@ -477,7 +723,11 @@ impl Dispatch<'_> {
     where
         C: ir::Callable,
     {
-        let input_types = cws.callable().inputs().map(|arg| &arg.ty);
+        let input_types = cws
+            .callable()
+            .inputs()
+            .iter()
+            .map(|(_, ty)| Self::generate_input_type(ty));
         let variant_ident = self.generate_dispatch_variant_ident(cws);
         quote! {
             #variant_ident(#(#input_types),*)
@@ -510,7 +760,11 @@ impl Dispatch<'_> {
         let storage_ident = self.contract.module().storage().ident();
         let ident = self.generate_dispatch_variant_ident(cws);
         let message = cws.callable();
-        let arg_pats = message.inputs().map(|arg| &arg.pat).collect::<Vec<_>>();
+        let arg_pats = message
+            .inputs()
+            .iter()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
         let arg_inputs = if arg_pats.len() == 1 {
             quote! { #(#arg_pats),* }
         } else {
@@ -537,11 +791,14 @@ impl Dispatch<'_> {
             .contract
             .config()
             .is_dynamic_storage_allocator_enabled();
+        let is_no_panic_dispatch_enabled =
+            self.contract.config().is_no_panic_dispatch_enabled();
         quote! {
             Self::#ident(#(#arg_pats),*) => {
                 ::ink_lang::#exec_fn::<<#storage_ident as ::ink_lang::ContractEnv>::Env, #namespace<[(); #selector_id]>, _>(
                     ::ink_lang::AcceptsPayments(#accepts_payments),
                     ::ink_lang::EnablesDynamicStorageAllocator(#is_dynamic_storage_allocation_enabled),
+                    ::ink_lang::CatchesPanics(#is_no_panic_dispatch_enabled),
                     move |state: &#mut_mod #storage_ident| {
                         <#namespace<[(); #selector_id]> as ::ink_lang::#msg_trait>::CALLABLE(
                             state, #arg_inputs
@@ -552,6 +809,20 @@ impl Dispatch<'_> {
         }
     }
 
+    /// Generates the `#[cfg(test)]` attribute for test-only ink! messages.
+    ///
+    /// # Note
+    ///
+    /// All dispatch code of test-only ink! messages, including their selectors
+    /// for the `binary_search` dispatch, is guarded by this attribute so that
+    /// they are never dispatched outside of test builds.
+    fn generate_test_only_cfg(message: &ir::Message) -> Option<TokenStream2> {
+        if message.is_test_only() {
+            return Some(quote! { #[cfg(test)] })
+        }
+        None
+    }
+
     /// Returns an iterator over all ink! messages of the ink! contract.
     fn contract_messages(
         &self,
@@ -566,15 +837,30 @@ impl Dispatch<'_> {
     /// Generates the entire dispatch variant enum for all ink! messages.
     fn generate_message_dispatch_enum(&self) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
-        let message_variants = self
-            .contract_messages()
-            .map(|message| self.generate_dispatch_variant_arm(message));
-        let decode_message = self
-            .contract_messages()
-            .map(|message| self.generate_dispatch_variant_decode(message));
-        let execute_variants = self
-            .contract_messages()
-            .map(|message| self.generate_dispatch_execute_message_arm(message));
+        let message_variants = self.contract_messages().map(|message| {
+            let test_only_cfg = Self::generate_test_only_cfg(message.callable());
+            let variant = self.generate_dispatch_variant_arm(message);
+            quote! { #test_only_cfg #variant }
+        });
+        let decode_message = self.generate_decode_dispatch(
+            self.contract_messages()
+                .map(|message| {
+                    (
+                        message.composed_selector(),
+                        Self::generate_test_only_cfg(message.callable()),
+                        self.generate_dispatch_variant_decode(message),
+                    )
+                })
+                .collect(),
+            None,
+            "encountered unknown ink! message selector",
+        );
+        let execute_variants = self.contract_messages().map(|message| {
+            let test_only_cfg = Self::generate_test_only_cfg(message.callable());
+            let execute = self.generate_dispatch_execute_message_arm(message);
+            quote! { #test_only_cfg #execute }
+        });
+        let inline = self.generate_code_using::<generator::InlineHelperAttr>();
         quote! {
             const _: () = {
                 #[doc(hidden)]
@@ -587,11 +873,9 @@ impl Dispatch<'_> {
                 }
 
                 impl ::scale::Decode for __ink_MessageDispatchEnum {
+                    #inline
                     fn decode<I: ::scale::Input>(input: &mut I) -> ::core::result::Result<Self, ::scale::Error> {
-                        match <[u8; 4] as ::scale::Decode>::decode(input)? {
-                            #( #decode_message )*
-                            _invalid => Err(::scale::Error::from("encountered unknown ink! message selector"))
-                        }
+                        #decode_message
                     }
                 }
 
@@ -618,7 +902,11 @@ impl Dispatch<'_> {
     ) -> TokenStream2 {
         let ident = self.generate_dispatch_variant_ident(cws);
         let constructor = cws.callable();
-        let arg_pats = constructor.inputs().map(|arg| &arg.pat).collect::<Vec<_>>();
+        let arg_pats = constructor
+            .inputs()
+            .iter()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
         let arg_inputs = if arg_pats.len() == 1 {
             quote! { #(#arg_pats),* }
         } else {
@@ -662,12 +950,25 @@ impl Dispatch<'_> {
         let message_variants = self
             .contract_constructors()
             .map(|message| self.generate_dispatch_variant_arm(message));
-        let decode_message = self
-            .contract_constructors()
-            .map(|message| self.generate_dispatch_variant_decode(message));
+        let decode_message = self.generate_decode_dispatch(
+            self.contract_constructors()
+                .map(|constructor| {
+                    (
+                        constructor.composed_selector(),
+                        None,
+                        self.generate_dispatch_variant_decode(constructor),
+                    )
+                })
+                .collect(),
+            self.contract_constructors()
+                .find(|constructor| constructor.callable().has_wildcard_selector())
+                .map(|constructor| self.generate_dispatch_variant_decode(constructor)),
+            "encountered unknown ink! constructor selector",
+        );
         let execute_variants = self
             .contract_constructors()
             .map(|cws| self.generate_dispatch_execute_constructor_arm(cws));
+        let inline = self.generate_code_using::<generator::InlineHelperAttr>();
         quote! {
             const _: () = {
                 #[doc(hidden)]
@@ -680,11 +981,9 @@ impl Dispatch<'_> {
                 }
 
                 impl ::scale::Decode for __ink_ConstructorDispatchEnum {
+                    #inline
                     fn decode<I: ::scale::Input>(input: &mut I) -> ::core::result::Result<Self, ::scale::Error> {
-                        match <[u8; 4] as ::scale::Decode>::decode(input)? {
-                            #( #decode_message )*
-                            _invalid => Err(::scale::Error::from("encountered unknown ink! constructor selector"))
-                        }
+                        #decode_message
                     }
                 }
 
@@ -699,3 +998,601 @@ impl Dispatch<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates the ink! contract with the given config and ink! constructors and
+    /// messages of its ink! storage struct `MyStorage`.
+    fn new_contract(config: TokenStream2, callables: TokenStream2) -> ir::Contract {
+        ir::Contract::new(
+            config,
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: bool,
+                    }
+
+                    impl MyStorage {
+                        #callables
+                    }
+                }
+            },
+        )
+        .unwrap()
+    }
+
+    /// Creates the ink! contract with the given config and an ink! getter message.
+    fn contract_with_getter(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(constructor)]
+                pub fn new() -> Self { Self { value: false } }
+
+                #[ink(message, getter = "value")]
+                pub fn get(&self) -> bool {}
+
+                #[ink(message)]
+                pub fn flip(&mut self) { self.value = !self.value; }
+            },
+        )
+    }
+
+    /// Creates the ink! contract with the given config and many ink! constructors
+    /// and messages with computed as well as explicit selectors.
+    fn contract_with_many_messages(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(constructor)]
+                pub fn new() -> Self { Self { value: false } }
+
+                #[ink(constructor, selector = "0x00000001")]
+                pub fn with_seed(seed: u32) -> Self { Self { value: seed % 2 == 0 } }
+
+                #[ink(message)]
+                pub fn m0(&self) {}
+                #[ink(message)]
+                pub fn m1(&self, a: bool) {}
+                #[ink(message)]
+                pub fn m2(&self, a: u16) {}
+                #[ink(message)]
+                pub fn m3(&mut self, a: u32) {}
+                #[ink(message)]
+                pub fn m4(&mut self, a: u64) {}
+                #[ink(message)]
+                pub fn m5(&self, a: u8, b: u8) {}
+                #[ink(message)]
+                pub fn m6(&self, a: i32) {}
+                #[ink(message)]
+                pub fn m7(&mut self, a: u128) {}
+                #[ink(message, selector = "0x00000000")]
+                pub fn m8(&self) {}
+                #[ink(message, selector = "0xFFFFFFFF")]
+                pub fn m9(&self) {}
+                #[ink(message, selector = "0x80000000")]
+                pub fn m10(&self) {}
+                #[ink(message, selector = "0x7FFFFFFF")]
+                pub fn m11(&self) {}
+            },
+        )
+    }
+
+    /// Returns the body of the `scale::Decode::decode` implementation of the
+    /// dispatch enum with the given name.
+    fn decode_fn_body(contract: &ir::Contract, enum_name: &str) -> syn::Block {
+        fn find(items: &[syn::Item], enum_name: &str) -> Option<syn::Block> {
+            items.iter().find_map(|item| {
+                match item {
+                    syn::Item::Const(item_const) => {
+                        match &*item_const.expr {
+                            syn::Expr::Block(block) => {
+                                let items = block
+                                    .block
+                                    .stmts
+                                    .iter()
+                                    .filter_map(|stmt| {
+                                        match stmt {
+                                            syn::Stmt::Item(item) => Some(item.clone()),
+                                            _ => None,
+                                        }
+                                    })
+                                    .collect::<Vec<_>>();
+                                find(&items, enum_name)
+                            }
+                            _ => None,
+                        }
+                    }
+                    syn::Item::Impl(item_impl) => {
+                        let self_ty = &item_impl.self_ty;
+                        let trait_path = &item_impl.trait_.as_ref()?.1;
+                        if quote!(#self_ty).to_string() != enum_name
+                            || trait_path.segments.last()?.ident != "Decode"
+                        {
+                            return None
+                        }
+                        match item_impl.items.first()? {
+                            syn::ImplItem::Method(method) => Some(method.block.clone()),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            })
+        }
+        let generated =
+            syn::parse2::<syn::File>(Dispatch::from(contract).generate_code()).unwrap();
+        find(&generated.items, enum_name).expect("missing dispatch decode function")
+    }
+
+    /// Returns the dispatch variant constructed by the given match arm.
+    fn arm_variant(arm: &syn::Arm) -> String {
+        let body = &arm.body;
+        quote!(#body)
+            .to_string()
+            .split("Self :: ")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .expect("missing dispatch variant")
+            .to_string()
+    }
+
+    fn lit_int<N>(expr: &syn::Expr) -> N
+    where
+        N: core::str::FromStr,
+        N::Err: core::fmt::Display,
+    {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => lit_int.base10_parse().unwrap(),
+            _ => panic!("expected an integer literal"),
+        }
+    }
+
+    fn pat_lit_int<N>(pat: &syn::Pat) -> N
+    where
+        N: core::str::FromStr,
+        N::Err: core::fmt::Display,
+    {
+        match pat {
+            syn::Pat::Lit(pat_lit) => lit_int(&pat_lit.expr),
+            _ => panic!("expected an integer literal pattern"),
+        }
+    }
+
+    fn match_expr(stmt: &syn::Stmt) -> &syn::ExprMatch {
+        match stmt {
+            syn::Stmt::Expr(syn::Expr::Match(expr_match)) => expr_match,
+            _ => panic!("expected a match expression"),
+        }
+    }
+
+    /// Returns `true` if the item with the given attributes is compiled in test
+    /// builds if `is_test` is `true` or in non-test builds otherwise.
+    fn is_compiled(attrs: &[syn::Attribute], is_test: bool) -> bool {
+        is_test
+            || !attrs
+                .iter()
+                .any(|attr| quote!(#attr).to_string() == "# [cfg (test)]")
+    }
+
+    /// Simulates the generated `match` dispatch for the given selector and
+    /// returns the dispatch variant it decodes or `None` if it is unknown.
+    fn simulate_match_dispatch(
+        body: &syn::Block,
+        selector: [u8; 4],
+        is_test: bool,
+    ) -> Option<String> {
+        match_expr(body.stmts.last().unwrap())
+            .arms
+            .iter()
+            .filter(|arm| is_compiled(&arm.attrs, is_test))
+            .find(|arm| {
+                match &arm.pat {
+                    syn::Pat::Slice(pat_slice) => {
+                        pat_slice.elems.iter().map(pat_lit_int::<u8>).eq(selector)
+                    }
+                    _ => false,
+                }
+            })
+            .map(arm_variant)
+    }
+
+    /// Returns the elements of the array referenced by the given expression
+    /// that are compiled in test builds if `is_test` is `true` or in non-test
+    /// builds otherwise.
+    fn compiled_array_elems(expr: &syn::Expr, is_test: bool) -> Vec<&syn::Expr> {
+        match expr {
+            syn::Expr::Reference(reference) => {
+                match &*reference.expr {
+                    syn::Expr::Array(array) => {
+                        array
+                            .elems
+                            .iter()
+                            .filter(|elem| {
+                                let attrs = match elem {
+                                    syn::Expr::Array(elem) => &elem.attrs,
+                                    syn::Expr::Tuple(elem) => &elem.attrs,
+                                    _ => panic!("unexpected array element"),
+                                };
+                                is_compiled(attrs, is_test)
+                            })
+                            .collect()
+                    }
+                    _ => panic!("expected a reference to an array"),
+                }
+            }
+            _ => panic!("expected a reference to an array"),
+        }
+    }
+
+    /// Simulates the generated binary search dispatch for the given selector
+    /// and returns the dispatch variant it decodes or `None` if it is unknown.
+    fn simulate_binary_search_dispatch(
+        body: &syn::Block,
+        selector: [u8; 4],
+        is_test: bool,
+    ) -> Option<String> {
+        let sorted_selectors = match &body.stmts[0] {
+            syn::Stmt::Item(syn::Item::Const(item_const)) => {
+                compiled_array_elems(&item_const.expr, is_test)
+                    .into_iter()
+                    .map(|elem| {
+                        match elem {
+                            syn::Expr::Array(bytes) => {
+                                let bytes = bytes
+                                    .elems
+                                    .iter()
+                                    .map(lit_int::<u8>)
+                                    .collect::<Vec<_>>();
+                                [bytes[0], bytes[1], bytes[2], bytes[3]]
+                            }
+                            _ => panic!("expected a selector array"),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            }
+            _ => panic!("expected the sorted selectors"),
+        };
+        let mut expected_sorted = sorted_selectors.clone();
+        expected_sorted.sort_unstable();
+        assert_eq!(sorted_selectors, expected_sorted);
+        // The selector indices that depend on `cfg` attributes of preceding selectors.
+        let index_consts = body.stmts[1..]
+            .iter()
+            .filter_map(|stmt| {
+                match stmt {
+                    syn::Stmt::Item(syn::Item::Const(item_const))
+                        if is_compiled(&item_const.attrs, is_test) =>
+                    {
+                        match &*item_const.expr {
+                            syn::Expr::Call(call) => {
+                                let len =
+                                    compiled_array_elems(&call.args[0], is_test).len();
+                                Some((item_const.ident.to_string(), len))
+                            }
+                            _ => panic!("expected a selector index computation"),
+                        }
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let index = sorted_selectors.binary_search(&selector).ok()?;
+        match_expr(body.stmts.last().unwrap())
+            .arms
+            .iter()
+            .filter(|arm| is_compiled(&arm.attrs, is_test))
+            .find(|arm| {
+                match &arm.pat {
+                    syn::Pat::TupleStruct(pat) => {
+                        match &pat.pat.elems[0] {
+                            syn::Pat::Ident(pat_ident) => {
+                                index_consts.iter().any(|(ident, len)| {
+                                    pat_ident.ident == ident && *len == index
+                                })
+                            }
+                            pat => pat_lit_int::<usize>(pat) == index,
+                        }
+                    }
+                    _ => false,
+                }
+            })
+            .map(arm_variant)
+    }
+
+    #[test]
+    fn binary_search_dispatches_to_same_handlers_as_match() {
+        let match_contract = contract_with_many_messages(TokenStream2::new());
+        let binary_search_contract =
+            contract_with_many_messages(quote! { dispatch = "binary_search" });
+        for enum_name in &["__ink_MessageDispatchEnum", "__ink_ConstructorDispatchEnum"] {
+            let match_body = decode_fn_body(&match_contract, enum_name);
+            let binary_search_body = decode_fn_body(&binary_search_contract, enum_name);
+            let known_selectors = match_expr(match_body.stmts.last().unwrap())
+                .arms
+                .iter()
+                .filter_map(|arm| {
+                    match &arm.pat {
+                        syn::Pat::Slice(pat_slice) => {
+                            let bytes = pat_slice
+                                .elems
+                                .iter()
+                                .map(pat_lit_int::<u8>)
+                                .collect::<Vec<_>>();
+                            Some([bytes[0], bytes[1], bytes[2], bytes[3]])
+                        }
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let unknown_selectors = vec![[0x00, 0x00, 0x00, 0x02], [0xFF; 4], [0x55; 4]]
+                .into_iter()
+                .filter(|selector| !known_selectors.contains(selector))
+                .collect::<Vec<_>>();
+            assert!(known_selectors.len() > 1);
+            for selector in &known_selectors {
+                let expected = simulate_match_dispatch(&match_body, *selector, false);
+                assert!(expected.is_some());
+                assert_eq!(
+                    simulate_binary_search_dispatch(
+                        &binary_search_body,
+                        *selector,
+                        false
+                    ),
+                    expected,
+                );
+            }
+            for selector in unknown_selectors {
+                assert_eq!(simulate_match_dispatch(&match_body, selector, false), None);
+                assert_eq!(
+                    simulate_binary_search_dispatch(&binary_search_body, selector, false),
+                    None
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_messages_are_dispatched_only_in_test_builds() {
+        for config in &[TokenStream2::new(), quote! { dispatch = "binary_search" }] {
+            let is_binary_search = !config.is_empty();
+            let contract = new_contract(
+                config.clone(),
+                quote! {
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self { value: false } }
+                    #[ink(message, selector = "0x00000001", test_only)]
+                    pub fn seed(&mut self, value: bool) { self.value = value; }
+                    #[ink(message, selector = "0x00000002")]
+                    pub fn get(&self) -> bool { self.value }
+                    #[ink(message, selector = "0x00000003", test_only)]
+                    pub fn inspect(&self) -> bool { self.value }
+                    #[ink(message, selector = "0x00000004")]
+                    pub fn flip(&mut self) { self.value = !self.value; }
+                },
+            );
+            let body = decode_fn_body(&contract, "__ink_MessageDispatchEnum");
+            let simulate = |selector: u8, is_test: bool| {
+                let selector = [0x00, 0x00, 0x00, selector];
+                if is_binary_search {
+                    simulate_binary_search_dispatch(&body, selector, is_test)
+                } else {
+                    simulate_match_dispatch(&body, selector, is_test)
+                }
+            };
+            let dispatched = |is_test: bool| {
+                (1..=4)
+                    .map(|selector| simulate(selector, is_test).is_some())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(dispatched(true), vec![true, true, true, true]);
+            assert_eq!(dispatched(false), vec![false, true, false, true]);
+            for selector in 1..=4 {
+                if let Some(variant) = simulate(selector, false) {
+                    assert_eq!(simulate(selector, true), Some(variant));
+                }
+            }
+        }
+    }
+
+    /// Returns the dispatch variant decoded for unknown selectors or `None`
+    /// if unknown selectors are rejected.
+    fn simulate_fallback_dispatch(body: &syn::Block) -> Option<String> {
+        let fallback = match_expr(body.stmts.last().unwrap()).arms.last().unwrap();
+        assert!(matches!(&fallback.pat, syn::Pat::Ident(pat) if pat.ident == "_invalid"));
+        let fallback_body = &fallback.body;
+        if quote!(#fallback_body).to_string().contains("Self ::") {
+            Some(arm_variant(fallback))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the ink! contract with the given config and a wildcard ink! constructor.
+    fn contract_with_wildcard_constructor(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(constructor)]
+                pub fn new() -> Self { Self { value: false } }
+                #[ink(constructor, selector = _)]
+                pub fn fallback(value: u32) -> Self { Self { value: value != 0 } }
+                #[ink(message)]
+                pub fn get(&self) {}
+            },
+        )
+    }
+
+    #[test]
+    fn unknown_constructor_selectors_dispatch_to_wildcard_constructor() {
+        for config in vec![TokenStream2::new(), quote! { dispatch = "binary_search" }] {
+            let contract = contract_with_wildcard_constructor(config);
+            let constructors = decode_fn_body(&contract, "__ink_ConstructorDispatchEnum");
+            let fallback = simulate_fallback_dispatch(&constructors)
+                .expect("missing fallback constructor dispatch");
+            let wildcard = contract
+                .module()
+                .impls()
+                .flat_map(|item_impl| item_impl.iter_constructors())
+                .find(|constructor| constructor.callable().has_wildcard_selector())
+                .unwrap();
+            assert_eq!(
+                fallback,
+                Dispatch::from(&contract)
+                    .generate_dispatch_variant_ident(wildcard)
+                    .to_string()
+            );
+            let messages = decode_fn_body(&contract, "__ink_MessageDispatchEnum");
+            assert_eq!(simulate_fallback_dispatch(&messages), None);
+        }
+        let contract = contract_with_getter(TokenStream2::new());
+        let constructors = decode_fn_body(&contract, "__ink_ConstructorDispatchEnum");
+        assert_eq!(simulate_fallback_dispatch(&constructors), None);
+    }
+
+    #[test]
+    fn fallible_constructors_output_result() {
+        let contract = new_contract(
+            TokenStream2::new(),
+            quote! {
+                #[ink(constructor)]
+                pub fn new() -> Self { Self { value: false } }
+                #[ink(constructor)]
+                pub fn try_new(fail: bool) -> Result<MyStorage, Error> {
+                    if fail { return Err(Error) }
+                    Ok(Self { value: false })
+                }
+                #[ink(message)]
+                pub fn get(&self) {}
+            },
+        );
+        let generated = Dispatch::from(&contract).generate_code().to_string();
+        assert!(generated.contains("type Output = MyStorage ;"));
+        assert!(generated.contains(
+            "type Output = :: core :: result :: Result < MyStorage , Error > ;"
+        ));
+    }
+
+    /// Returns the number of `#[inline]` attributes in the generated code.
+    fn count_inline_attrs(contract: &ir::Contract) -> usize {
+        let generated = [
+            Dispatch::from(contract).generate_code().to_string(),
+            generator::ItemImpls::from(contract)
+                .generate_code()
+                .to_string(),
+        ]
+        .concat();
+        generated.matches("# [inline]").count()
+    }
+
+    #[test]
+    fn inline_helpers_are_emitted_if_enabled() {
+        // `dispatch_using_mode`, both `decode` functions and the getter message.
+        assert_eq!(
+            count_inline_attrs(&contract_with_getter(quote! { inline_helpers = true })),
+            4
+        );
+    }
+
+    #[test]
+    fn no_inline_helpers_by_default() {
+        assert_eq!(
+            count_inline_attrs(&contract_with_getter(TokenStream2::new())),
+            0
+        );
+        assert_eq!(
+            count_inline_attrs(&contract_with_getter(quote! { inline_helpers = false })),
+            0
+        );
+    }
+
+    /// Returns the `CatchesPanics` argument of the dispatch of every ink! message.
+    fn catches_panics_args(contract: &ir::Contract) -> Vec<syn::Expr> {
+        let dispatch = Dispatch::from(contract);
+        dispatch
+            .contract_messages()
+            .map(|message| {
+                let arm = syn::parse2::<syn::Arm>(
+                    dispatch.generate_dispatch_execute_message_arm(message),
+                )
+                .unwrap();
+                match *arm.body {
+                    syn::Expr::Block(block) => {
+                        match &block.block.stmts[..] {
+                            [syn::Stmt::Expr(syn::Expr::Call(call))] => {
+                                call.args[2].clone()
+                            }
+                            stmts => panic!("unexpected dispatch arm: {:?}", stmts),
+                        }
+                    }
+                    body => panic!("unexpected dispatch arm: {:?}", body),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn panics_are_caught_if_no_panic_dispatch_is_enabled() {
+        let contract = contract_with_getter(quote! { no_panic_dispatch = true });
+        let expected: syn::Expr = syn::parse_quote! { ::ink_lang::CatchesPanics(true) };
+        assert_eq!(
+            catches_panics_args(&contract),
+            vec![expected.clone(), expected]
+        );
+        let guard = Dispatch::from(&contract)
+            .generate_no_panic_dispatch_guard()
+            .unwrap()
+            .to_string();
+        assert!(guard.starts_with(&quote! { #[cfg(not(feature = "std"))] }.to_string()));
+        assert!(guard.contains("compile_error"));
+    }
+
+    #[test]
+    fn panics_are_not_caught_by_default() {
+        let contract = contract_with_getter(TokenStream2::new());
+        let expected: syn::Expr = syn::parse_quote! { ::ink_lang::CatchesPanics(false) };
+        assert_eq!(
+            catches_panics_args(&contract),
+            vec![expected.clone(), expected]
+        );
+        assert!(Dispatch::from(&contract)
+            .generate_no_panic_dispatch_guard()
+            .is_none());
+    }
+
+    /// Returns the names of the exported entry point functions.
+    fn entry_point_names(contract: &ir::Contract) -> Vec<String> {
+        let entry_points = Dispatch::from(contract).generate_entry_points();
+        syn::parse2::<syn::File>(entry_points)
+            .unwrap()
+            .items
+            .into_iter()
+            .filter_map(|item| {
+                match item {
+                    syn::Item::Fn(item_fn) => Some(item_fn.sig.ident.to_string()),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn entry_points_are_renamed_if_configured() {
+        assert_eq!(
+            entry_point_names(&contract_with_getter(TokenStream2::new())),
+            vec!["deploy", "call"]
+        );
+        assert_eq!(
+            entry_point_names(&contract_with_getter(
+                quote! { entry_call = "exec", entry_deploy = "init" }
+            )),
+            vec!["init", "exec"]
+        );
+    }
+}
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+    GenerateCodeUsing as _,
+};
 use derive_more::From;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
@@ -23,10 +27,17 @@ pub struct Env<'a> {
     contract: &'a ir::Contract,
 }
 
+impl AsRef<ir::Contract> for Env<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
 impl GenerateCode for Env<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let env = self.contract.config().env();
         let storage_ident = self.contract.module().storage().ident();
+        let global_allocator_check = self.generate_global_allocator_check();
         quote! {
             impl ::ink_lang::ContractEnv for #storage_ident {
                 type Env = #env;
@@ -39,6 +50,88 @@ impl GenerateCode for Env<'_> {
             type Hash = <<#storage_ident as ::ink_lang::ContractEnv>::Env as ::ink_env::Environment>::Hash;
             type Timestamp = <<#storage_ident as ::ink_lang::ContractEnv>::Env as ::ink_env::Environment>::Timestamp;
             type BlockNumber = <<#storage_ident as ::ink_lang::ContractEnv>::Env as ::ink_env::Environment>::BlockNumber;
+
+            #global_allocator_check
+        }
+    }
+}
+
+impl Env<'_> {
+    /// Generates a check that the global Wasm heap memory allocator registered by
+    /// `ink_allocator` is the allocator configured for the ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// The global allocator is registered by `ink_allocator` so that it is also
+    /// available to no_std users that are not ink! smart contracts. Its `bump`
+    /// allocator is selected by the `bump-allocator` feature of `ink_env`, so this
+    /// check fails to compile with a type mismatch if the feature does not match
+    /// the configured allocator.
+    ///
+    /// The check is only generated for the root contract since dependencies share
+    /// the global allocator of the contract that depends on them.
+    fn generate_global_allocator_check(&self) -> TokenStream2 {
+        let no_cross_calling_cfg =
+            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let allocator = match self.contract.config().allocator() {
+            ir::Allocator::WeeAlloc => quote! { ::ink_env::allocator::WeeAlloc },
+            ir::Allocator::Bump => quote! { ::ink_env::allocator::BumpAllocator },
+        };
+        quote! {
+            #[cfg(not(feature = "std"))]
+            #no_cross_calling_cfg
+            const _: fn(::ink_env::allocator::GlobalAllocator) -> #allocator =
+                |global_allocator| global_allocator;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the generated global allocator check of the ink! smart contract
+    /// with the given config.
+    fn global_allocator_check(config: TokenStream2) -> String {
+        let contract = ir::Contract::new(
+            config,
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        Env::from(&contract)
+            .generate_global_allocator_check()
+            .to_string()
+    }
+
+    #[test]
+    fn wee_alloc_is_the_default_global_allocator() {
+        let expected = global_allocator_check(quote! { allocator = "wee_alloc" });
+        assert_eq!(global_allocator_check(TokenStream2::new()), expected);
+        assert!(expected.contains(
+            "fn (:: ink_env :: allocator :: GlobalAllocator) -> :: ink_env :: allocator :: WeeAlloc"
+        ));
+    }
+
+    #[test]
+    fn bump_allocator_is_required_as_global_allocator() {
+        let generated = global_allocator_check(quote! { allocator = "bump" });
+        assert!(generated.contains("# [cfg (not (feature = \"std\"))]"));
+        assert!(generated.contains(
+            "fn (:: ink_env :: allocator :: GlobalAllocator) -> :: ink_env :: allocator :: BumpAllocator"
+        ));
+        assert!(!generated.contains("WeeAlloc"));
+    }
+}
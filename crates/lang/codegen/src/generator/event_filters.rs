@@ -0,0 +1,172 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+};
+use derive_more::From;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates the event filter builders of the ink! smart contract.
+///
+/// # Note
+///
+/// There is one filter builder per ink! event instance with a method per topic
+/// field that restricts the emitted events to the given topic value. The raw
+/// topics are computed the same way as upon emitting the ink! event so that
+/// off-chain indexers can directly subscribe with them.
+#[derive(From)]
+pub struct EventFilters<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl AsRef<ir::Contract> for EventFilters<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
+impl GenerateCode for EventFilters<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        let filters = self
+            .contract
+            .module()
+            .events()
+            .flat_map(|event| event.instances())
+            .map(|instance| self.generate_event_filter(instance));
+        quote! {
+            #( #filters )*
+        }
+    }
+}
+
+impl EventFilters<'_> {
+    /// Generates the filter builder of the ink! event instance.
+    ///
+    /// The filter builder is named after the event suffixed with `Filter`.
+    fn generate_event_filter(&self, instance: ir::EventInstance<'_>) -> TokenStream2 {
+        let event = instance.event();
+        let span = event.span();
+        let contract_ident = self.contract.module().storage().ident();
+        let event_name = instance.name();
+        let filter_ident = format_ident!("{}Filter", instance.variant_ident());
+        let doc = format!(
+            "Filter builder matching emitted `{}` events by their topics.",
+            event_name
+        );
+        // Anonymous events do not include their signature topic.
+        let (signature_topic, topics_offset) = if event.anonymous {
+            (None, 0)
+        } else {
+            let event_signature = generator::generate_topic_signature(
+                span,
+                contract_ident,
+                &event_name,
+                None,
+            );
+            let signature_topic = quote_spanned!(span=>
+                .with_topic(0, &::ink_env::topics::PrefixedValue {
+                    value: #event_signature,
+                    prefix: b"",
+                })
+            );
+            (Some(signature_topic), 1)
+        };
+        let len_topics =
+            event.fields().filter(|field| field.is_topic).count() + topics_offset;
+        let topic_methods = event
+            .fields()
+            .enumerate()
+            .filter(|(_, field)| field.is_topic)
+            .enumerate()
+            .map(|(index, (n, topic_field))| {
+                let span = topic_field.span();
+                let field_ident = topic_field
+                    .ident()
+                    .map(quote::ToTokens::into_token_stream)
+                    .unwrap_or_else(|| quote_spanned!(span=> #n));
+                let method_ident = topic_field
+                    .ident()
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("topic_{}", n));
+                let field_type = instance.monomorphize(topic_field.ty());
+                let signature = generator::generate_topic_signature(
+                    span,
+                    contract_ident,
+                    &event_name,
+                    Some(&field_ident),
+                );
+                let index = index + topics_offset;
+                let doc = format!(
+                    "Only matches `{}` events with the given `{}` topic.",
+                    event_name, field_ident
+                );
+                quote_spanned!(span=>
+                    #[doc = #doc]
+                    pub fn #method_ident(self, value: &#field_type) -> Self {
+                        Self {
+                            filter: self.filter.with_topic(
+                                #index,
+                                &::ink_env::topics::PrefixedValue { value, prefix: #signature },
+                            ),
+                        }
+                    }
+                )
+            });
+        quote_spanned!(span=>
+            #[doc = #doc]
+            #[derive(Debug, Clone)]
+            pub struct #filter_ident {
+                filter: ::ink_lang::EventFilter<Environment>,
+            }
+
+            const _: () = {
+                impl #filter_ident {
+                    /// Creates a new filter matching all emitted events of this type.
+                    pub fn new() -> Self {
+                        Self {
+                            filter: ::ink_lang::EventFilter::new(#len_topics)
+                                #signature_topic,
+                        }
+                    }
+
+                    #( #topic_methods )*
+
+                    /// Returns the raw topics to subscribe with where `None` matches any value.
+                    pub fn topics(&self) -> &[::core::option::Option<Hash>] {
+                        self.filter.topics()
+                    }
+
+                    /// Returns the underlying event filter.
+                    pub fn into_filter(self) -> ::ink_lang::EventFilter<Environment> {
+                        self.filter
+                    }
+                }
+
+                impl ::core::default::Default for #filter_ident {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+            };
+        )
+    }
+}
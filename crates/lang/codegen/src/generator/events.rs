@@ -46,22 +46,65 @@ impl GenerateCode for Events<'_> {
             // Generate no code in case there are no event definitions.
             return TokenStream2::new()
         }
+        let event_structs = self.generate_event_structs();
+        if self.contract.config().is_compile_as_dependency_enabled() {
+            // Dependencies only need the event types for decoding and never
+            // emit events themselves so we skip all the emission machinery.
+            return quote! {
+                #( #event_structs )*
+            }
+        }
         let emit_event_trait_impl = self.generate_emit_event_trait_impl();
         let event_base = self.generate_event_base();
         let topic_guards = self.generate_topic_guards();
         let topics_impls = self.generate_topics_impls();
-        let event_structs = self.generate_event_structs();
+        let field_count_warnings = self.generate_field_count_warnings();
         quote! {
             #emit_event_trait_impl
             #event_base
             #( #topic_guards )*
             #( #event_structs )*
             #( #topics_impls )*
+            #( #field_count_warnings )*
         }
     }
 }
 
 impl<'a> Events<'a> {
+    /// Generates advisory warnings for all ink! events that have more fields
+    /// than configured via `max_event_fields`.
+    ///
+    /// # Note
+    ///
+    /// The warnings point at the oversized ink! events.
+    fn generate_field_count_warnings(
+        &'a self,
+    ) -> impl Iterator<Item = TokenStream2> + 'a {
+        let max_event_fields = self.contract.config().max_event_fields();
+        self.contract
+            .module()
+            .events()
+            .filter(move |event| {
+                max_event_fields
+                    .map(|max_event_fields| event.field_count() > max_event_fields)
+                    .unwrap_or(false)
+            })
+            .map(move |event| {
+                let note = format!(
+                    "ink! event `{}` has {} fields which exceeds the configured `max_event_fields = {}`, \
+                     consider splitting it up into multiple events",
+                    event.ident(),
+                    event.field_count(),
+                    max_event_fields.unwrap_or_default(),
+                );
+                generator::generate_warning(
+                    event.span(),
+                    "__ink_event_exceeds_max_fields",
+                    &note,
+                )
+            })
+    }
+
     /// Used to allow emitting user defined events directly instead of converting
     /// them first into the automatically generated base trait of the contract.
     fn generate_emit_event_trait_impl(&self) -> TokenStream2 {
@@ -89,23 +132,35 @@ impl<'a> Events<'a> {
     /// Generates the base event enum that comprises all user defined events.
     /// All emitted events are converted into a variant of this enum before being
     /// serialized and emitted to apply their unique event discriminant (ID).
+    ///
+    /// # Note
+    ///
+    /// ink! events annotated with `#[doc(hidden)]` are excluded from the metadata
+    /// and therefore placed after all other events so that the discriminants of
+    /// the remaining events match their position in the metadata.
     fn generate_event_base(&self) -> TokenStream2 {
         let storage_ident = &self.contract.module().storage().ident();
         let no_cross_calling_cfg =
             self.generate_code_using::<generator::CrossCallingConflictCfg>();
-        let event_idents = self
+        let (visible_events, hidden_events): (Vec<_>, Vec<_>) = self
             .contract
             .module()
             .events()
-            .map(|event| event.ident())
-            .collect::<Vec<_>>();
+            .partition(|event| !event.is_doc_hidden());
+        let (event_variants, event_types): (Vec<_>, Vec<_>) = visible_events
+            .into_iter()
+            .chain(hidden_events)
+            .flat_map(|event| event.instances())
+            .map(|instance| (instance.variant_ident(), instance.ty()))
+            .unzip();
         let base_event_ident =
             proc_macro2::Ident::new("__ink_EventBase", Span::call_site());
         quote! {
             #no_cross_calling_cfg
             #[derive(::scale::Encode, ::scale::Decode)]
+            #[allow(non_camel_case_types)]
             pub enum #base_event_ident {
-                #( #event_idents(#event_idents), )*
+                #( #event_variants(#event_types), )*
             }
 
             #no_cross_calling_cfg
@@ -118,14 +173,15 @@ impl<'a> Events<'a> {
             #(
                 #no_cross_calling_cfg
                 const _: () = {
-                    impl From<#event_idents> for #base_event_ident {
-                        fn from(event: #event_idents) -> Self {
-                            Self::#event_idents(event)
+                    impl From<#event_types> for #base_event_ident {
+                        fn from(event: #event_types) -> Self {
+                            Self::#event_variants(event)
                         }
                     }
                 };
             )*
 
+            #no_cross_calling_cfg
             const _: () = {
                 pub enum __ink_UndefinedAmountOfTopics {}
                 impl ::ink_env::topics::EventTopicsAmount for __ink_UndefinedAmountOfTopics {
@@ -146,8 +202,8 @@ impl<'a> Events<'a> {
                     {
                         match self {
                             #(
-                                Self::#event_idents(event) => {
-                                    <#event_idents as ::ink_env::Topics>::topics::<E, B>(event, builder)
+                                Self::#event_variants(event) => {
+                                    <#event_types as ::ink_env::Topics>::topics::<E, B>(event, builder)
                                 }
                             )*
                         }
@@ -158,9 +214,10 @@ impl<'a> Events<'a> {
     }
 
     /// Generate checks to guard against too many topics in event definitions.
-    fn generate_topics_guard(&self, event: &ir::Event) -> TokenStream2 {
+    fn generate_topics_guard(&self, instance: ir::EventInstance<'_>) -> TokenStream2 {
         let storage_ident = self.contract.module().storage().ident();
-        let event_ident = event.ident();
+        let event = instance.event();
+        let event_ty = instance.ty();
         let len_topics = event.fields().filter(|event| event.is_topic).count();
         let span = event.span();
         quote_spanned!(span=>
@@ -181,7 +238,7 @@ impl<'a> Events<'a> {
                     type Type = __ink_CheckSatisfied;
                 }
                 impl __ink_RenameBool for [(); false as usize] {
-                    type Type = #event_ident;
+                    type Type = #event_ty;
                 }
 
                 #[allow(non_upper_case_globals)]
@@ -203,14 +260,18 @@ impl<'a> Events<'a> {
     fn generate_topic_guards(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
         let no_cross_calling_cfg =
             self.generate_code_using::<generator::CrossCallingConflictCfg>();
-        self.contract.module().events().map(move |event| {
-            let span = event.span();
-            let topics_guard = self.generate_topics_guard(event);
-            quote_spanned!(span =>
-                #no_cross_calling_cfg
-                #topics_guard
-            )
-        })
+        self.contract
+            .module()
+            .events()
+            .flat_map(|event| event.instances())
+            .map(move |instance| {
+                let span = instance.event().span();
+                let topics_guard = self.generate_topics_guard(instance);
+                quote_spanned!(span =>
+                    #no_cross_calling_cfg
+                    #topics_guard
+                )
+            })
     }
 
     /// Generates the `Topics` trait implementations for the user defined events.
@@ -218,86 +279,99 @@ impl<'a> Events<'a> {
         let no_cross_calling_cfg =
             self.generate_code_using::<generator::CrossCallingConflictCfg>();
         let contract_ident = self.contract.module().storage().ident();
-        self.contract.module().events().map(move |event| {
-            let span = event.span();
-            let event_ident = event.ident();
-            let event_signature = syn::LitByteStr::new(
-                format!("{}::{}", contract_ident, event_ident
-            ).as_bytes(), span);
-            let len_event_signature = event_signature.value().len();
-            let len_topics = event.fields().filter(|field| field.is_topic).count();
-            let topic_impls = event
-                .fields()
-                .enumerate()
-                .filter(|(_, field)| field.is_topic)
-                .map(|(n, topic_field)| {
-                    let span = topic_field.span();
-                    let field_ident = topic_field
-                        .ident()
-                        .map(quote::ToTokens::into_token_stream)
-                        .unwrap_or_else(|| quote_spanned!(span => #n));
-                    let field_type = topic_field.ty();
-                    let signature = syn::LitByteStr::new(
-                        format!("{}::{}::{}", contract_ident, event_ident,
-                            field_ident
-                        ).as_bytes(), span);
-                    quote_spanned!(span =>
-                        .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
-                            &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
+        self.contract
+            .module()
+            .events()
+            .flat_map(|event| event.instances())
+            .map(move |instance| {
+                let event = instance.event();
+                let span = event.span();
+                let event_ty = instance.ty();
+                let event_name = instance.name();
+                let event_signature = generator::generate_topic_signature(
+                    span, contract_ident, &event_name, None,
+                );
+                let len_event_signature = event_signature.value().len();
+                let len_topics = event.fields().filter(|field| field.is_topic).count();
+                let topic_impls = event
+                    .fields()
+                    .enumerate()
+                    .filter(|(_, field)| field.is_topic)
+                    .map(|(n, topic_field)| {
+                        let span = topic_field.span();
+                        let field_ident = topic_field
+                            .ident()
+                            .map(quote::ToTokens::into_token_stream)
+                            .unwrap_or_else(|| quote_spanned!(span => #n));
+                        let field_type = instance.monomorphize(topic_field.ty());
+                        let signature = generator::generate_topic_signature(
+                            span, contract_ident, &event_name, Some(&field_ident),
+                        );
+                        quote_spanned!(span =>
+                            .push_topic::<::ink_env::topics::PrefixedValue<#field_type>>(
+                                &::ink_env::topics::PrefixedValue { value: &self.#field_ident, prefix: #signature }
+                            )
                         )
-                    )
-                });
-            // Only include topic for event signature in case of non-anonymous event.
-            let event_signature_topic = match event.anonymous {
-                true => None,
-                false => Some(quote_spanned!(span=>
-                    .push_topic::<::ink_env::topics::PrefixedValue<[u8; #len_event_signature]>>(
-                        &::ink_env::topics::PrefixedValue { value: #event_signature, prefix: b"" }
-                    )
-                ))
-            };
-            // Anonymous events require 1 fewer topics since they do not include their signature.
-            let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
-            let remaining_topics_ty = match len_topics + anonymous_topics_offset {
-                0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
-                n => quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n]),
-            };
-            quote_spanned!(span =>
-                #no_cross_calling_cfg
-                const _: () = {
-                    impl ::ink_env::Topics for #event_ident {
-                        type RemainingTopics = #remaining_topics_ty;
-
-                        fn topics<E, B>(
-                            &self,
-                            builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
-                        ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
-                        where
-                            E: ::ink_env::Environment,
-                            B: ::ink_env::topics::TopicsBuilderBackend<E>,
-                        {
-                            builder
-                                .build::<Self>()
-                                #event_signature_topic
-                                #(
-                                    #topic_impls
-                                )*
-                                .finish()
-                        }
-                    }
+                    });
+                // Only include topic for event signature in case of non-anonymous event.
+                let event_signature_topic = match event.anonymous {
+                    true => None,
+                    false => Some(quote_spanned!(span=>
+                        .push_topic::<::ink_env::topics::PrefixedValue<[u8; #len_event_signature]>>(
+                            &::ink_env::topics::PrefixedValue { value: #event_signature, prefix: b"" }
+                        )
+                    ))
                 };
-            )
-        })
+                // Anonymous events require 1 fewer topics since they do not include their signature.
+                let anonymous_topics_offset = if event.anonymous { 0 } else { 1 };
+                let remaining_topics_ty = match len_topics + anonymous_topics_offset {
+                    0 => quote_spanned!(span=> ::ink_env::topics::state::NoRemainingTopics),
+                    n => quote_spanned!(span=> [::ink_env::topics::state::HasRemainingTopics; #n]),
+                };
+                quote_spanned!(span =>
+                    #no_cross_calling_cfg
+                    const _: () = {
+                        impl ::ink_env::Topics for #event_ty {
+                            type RemainingTopics = #remaining_topics_ty;
+
+                            fn topics<E, B>(
+                                &self,
+                                builder: ::ink_env::topics::TopicsBuilder<::ink_env::topics::state::Uninit, E, B>,
+                            ) -> <B as ::ink_env::topics::TopicsBuilderBackend<E>>::Output
+                            where
+                                E: ::ink_env::Environment,
+                                B: ::ink_env::topics::TopicsBuilderBackend<E>,
+                            {
+                                builder
+                                    .build::<Self>()
+                                    #event_signature_topic
+                                    #(
+                                        #topic_impls
+                                    )*
+                                    .finish()
+                            }
+                        }
+                    };
+                )
+            })
     }
 
     /// Generates all the user defined event struct definitions.
+    ///
+    /// # Note
+    ///
+    /// The event structs are generated regardless of the `ink-as-dependency`
+    /// mode so that dependencies are still able to decode emitted events.
     fn generate_event_structs(&'a self) -> impl Iterator<Item = TokenStream2> + 'a {
-        let no_cross_calling_cfg =
-            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let derive_debug = self.contract.config().is_derive_debug_enabled().then(|| {
+            quote! { #[cfg_attr(any(test, feature = "std"), derive(::core::fmt::Debug))] }
+        });
         self.contract.module().events().map(move |event| {
             let span = event.span();
             let ident = event.ident();
             let attrs = event.attrs();
+            let generics = event.generics();
+            let where_clause = &generics.where_clause;
             let fields = event.fields().map(|event_field| {
                 let span = event_field.span();
                 let attrs = event_field.attrs();
@@ -310,13 +384,182 @@ impl<'a> Events<'a> {
                 )
             });
             quote_spanned!(span =>
-                #no_cross_calling_cfg
                 #( #attrs )*
                 #[derive(scale::Encode, scale::Decode)]
-                pub struct #ident {
+                #derive_debug
+                pub struct #ident #generics #where_clause {
                     #( #fields ),*
                 }
             )
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_dependency_strips_event_emission() {
+        let contract = ir::Contract::new(
+            quote! { compile_as_dependency = true },
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    pub struct MyEvent {
+                        #[ink(topic)]
+                        value: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated = Events::from(&contract).generate_code().to_string();
+        assert!(generated.contains("pub struct MyEvent"));
+        assert!(!generated.contains("EmitEvent"));
+        assert!(!generated.contains("__ink_EventBase"));
+        assert!(!generated.contains("Topics"));
+    }
+
+    fn max_event_fields_contract(config: TokenStream2) -> ir::Contract {
+        ir::Contract::new(
+            config,
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    pub struct MyEvent {
+                        #[ink(topic)]
+                        a: i32,
+                        b: bool,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn max_event_fields_flags_oversized_events() {
+        let contract = max_event_fields_contract(quote! { max_event_fields = 1 });
+        let generated = Events::from(&contract).generate_code().to_string();
+        assert!(generated.contains("__ink_event_exceeds_max_fields"));
+        assert!(generated.contains("ink! event `MyEvent` has 2 fields"));
+    }
+
+    #[test]
+    fn max_event_fields_ignores_events_within_limit() {
+        for config in [quote! {}, quote! { max_event_fields = 2 }] {
+            let contract = max_event_fields_contract(config);
+            let generated = Events::from(&contract).generate_code().to_string();
+            assert!(!generated.contains("__ink_event_exceeds_max_fields"));
+        }
+    }
+
+    #[test]
+    fn hidden_events_are_placed_last_in_event_base() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    #[doc(hidden)]
+                    pub struct Internal {}
+
+                    #[ink(event)]
+                    pub struct First {}
+
+                    #[ink(event)]
+                    pub struct Second {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated = Events::from(&contract).generate_event_base().to_string();
+        let expected = quote! {
+            pub enum __ink_EventBase {
+                First(First),
+                Second(Second),
+                Internal(Internal),
+            }
+        }
+        .to_string();
+        assert!(generated.contains(&expected));
+    }
+
+    #[test]
+    fn debug_is_derived_for_events_if_enabled() {
+        let contract = |config: TokenStream2| {
+            ir::Contract::new(
+                config,
+                quote! {
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyStorage {}
+
+                        #[ink(event)]
+                        pub struct MyEvent {
+                            value: i32,
+                        }
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn new() -> Self { Self {} }
+
+                            #[ink(message)]
+                            pub fn message(&self) {}
+                        }
+                    }
+                },
+            )
+            .unwrap()
+        };
+        let derive_debug = quote! {
+            #[cfg_attr(any(test, feature = "std"), derive(::core::fmt::Debug))]
+            pub struct MyEvent
+        }
+        .to_string();
+        let enabled = contract(quote! { derive_debug = true });
+        assert!(Events::from(&enabled)
+            .generate_code()
+            .to_string()
+            .contains(&derive_debug));
+        let disabled = contract(TokenStream2::new());
+        assert!(!Events::from(&disabled)
+            .generate_code()
+            .to_string()
+            .contains("Debug"));
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    generator,
+    GenerateCode,
+    GenerateCodeUsing as _,
+};
+use derive_more::From;
+use heck::SnakeCase as _;
+use ir::Callable as _;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Generates the interface trait comprising the signatures of all ink! messages.
+///
+/// # Note
+///
+/// The interface trait is only generated if the ink! smart contract has been
+/// configured via `emit_interface = true`. It allows external code to be generic
+/// over anything that provides the same set of messages as the ink! smart contract.
+#[derive(From)]
+pub struct Interface<'a> {
+    contract: &'a ir::Contract,
+}
+
+impl AsRef<ir::Contract> for Interface<'_> {
+    fn as_ref(&self) -> &ir::Contract {
+        self.contract
+    }
+}
+
+impl GenerateCode for Interface<'_> {
+    fn generate_code(&self) -> TokenStream2 {
+        if !self.contract.config().is_emit_interface_enabled() {
+            return TokenStream2::new()
+        }
+        let storage_ident = self.contract.module().storage().ident();
+        let interface_ident = self.interface_ident();
+        let no_cross_calling_cfg =
+            self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let message_decls = self
+            .contract_messages()
+            .map(|message| self.generate_message_decl(message));
+        let message_impls = self
+            .contract_messages()
+            .map(|message| self.generate_message_impl(message));
+        quote! {
+            /// The interface comprising all ink! messages of the ink! smart contract.
+            pub trait #interface_ident {
+                #( #message_decls )*
+            }
+
+            #no_cross_calling_cfg
+            const _: () = {
+                impl #interface_ident for #storage_ident {
+                    #( #message_impls )*
+                }
+            };
+        }
+    }
+}
+
+impl Interface<'_> {
+    /// Returns the identifier of the generated interface trait.
+    ///
+    /// This is the name of the ink! storage struct suffixed with `Interface`.
+    fn interface_ident(&self) -> Ident {
+        format_ident!("{}Interface", self.contract.module().storage().ident())
+    }
+
+    /// Returns an iterator over all ink! messages of the ink! contract.
+    fn contract_messages(
+        &self,
+    ) -> impl Iterator<Item = ir::CallableWithSelector<'_, ir::Message>> {
+        self.contract
+            .module()
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+    }
+
+    /// Returns the identifier of the interface trait method of the ink! message.
+    ///
+    /// Trait messages whose name clashes with another ink! message of the contract
+    /// are prefixed with the snake case name of their trait, e.g. `flip` of trait
+    /// `Flip` becomes `flip_flip`.
+    fn method_ident(&self, message: &ir::CallableWithSelector<ir::Message>) -> Ident {
+        let ident = message.ident();
+        let is_ambiguous = self
+            .contract_messages()
+            .filter(|other| other.ident() == ident)
+            .count()
+            > 1;
+        let trait_ident = message
+            .item_impl()
+            .trait_path()
+            .and_then(|trait_path| trait_path.segments.last())
+            .map(|segment| &segment.ident);
+        match trait_ident {
+            Some(trait_ident) if is_ambiguous => {
+                format_ident!("{}_{}", trait_ident.to_string().to_snake_case(), ident)
+            }
+            _ => ident.clone(),
+        }
+    }
+
+    /// Returns the identifiers used to bind the inputs of the ink! message.
+    fn input_bindings(message: &ir::CallableWithSelector<ir::Message>) -> Vec<Ident> {
+        message
+            .inputs_iter()
+            .enumerate()
+            .map(|(n, _)| format_ident!("__ink_binding_{}", n))
+            .collect::<Vec<_>>()
+    }
+
+    /// Generates the interface trait method declaration of the ink! message.
+    fn generate_message_decl(
+        &self,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let attrs = message.attrs();
+        let method_ident = self.method_ident(&message);
+        let receiver = message.receiver();
+        let input_bindings = Self::input_bindings(&message);
+        let input_types = message.inputs_iter().map(|pat_type| &*pat_type.ty);
+        let output = message.output().map(|output| quote! { -> #output });
+        quote_spanned!(span=>
+            #( #attrs )*
+            fn #method_ident(#receiver #(, #input_bindings : #input_types )* ) #output;
+        )
+    }
+
+    /// Generates the implementation of the interface trait method of the ink!
+    /// message that forwards to the ink! message of the ink! storage struct.
+    fn generate_message_impl(
+        &self,
+        message: ir::CallableWithSelector<ir::Message>,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let storage_ident = self.contract.module().storage().ident();
+        let ident = message.ident();
+        let method_ident = self.method_ident(&message);
+        let receiver = message.receiver();
+        let input_bindings = Self::input_bindings(&message);
+        let input_types = message.inputs_iter().map(|pat_type| &*pat_type.ty);
+        let output = message.output().map(|output| quote! { -> #output });
+        let callee = match message.item_impl().trait_path() {
+            Some(trait_path) => quote! { <#storage_ident as #trait_path>::#ident },
+            None => quote! { #storage_ident::#ident },
+        };
+        quote_spanned!(span=>
+            fn #method_ident(#receiver #(, #input_bindings : #input_types )* ) #output {
+                #callee(self #(, #input_bindings )* )
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens as _;
+
+    #[test]
+    fn interface_has_method_per_message() {
+        let contract = ir::Contract::new(
+            quote! { emit_interface = true },
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: bool,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self { value: false } }
+
+                        #[ink(message)]
+                        pub fn get(&self) -> bool { self.value }
+
+                        #[ink(message)]
+                        pub fn set(&mut self, value: bool) { self.value = value; }
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated =
+            syn::parse2::<syn::File>(Interface::from(&contract).generate_code()).unwrap();
+        let item_trait = generated
+            .items
+            .iter()
+            .find_map(|item| {
+                match item {
+                    syn::Item::Trait(item_trait) => Some(item_trait),
+                    _ => None,
+                }
+            })
+            .expect("missing interface trait");
+        assert_eq!(item_trait.ident, "MyStorageInterface");
+        let methods = item_trait
+            .items
+            .iter()
+            .map(|item| {
+                match item {
+                    syn::TraitItem::Method(method) => {
+                        let receiver = match method.sig.inputs.first() {
+                            Some(syn::FnArg::Receiver(receiver)) => {
+                                receiver.mutability.is_some()
+                            }
+                            _ => panic!("missing receiver"),
+                        };
+                        (method.sig.ident.to_string(), receiver)
+                    }
+                    _ => panic!("unexpected interface trait item"),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            methods,
+            vec![("get".to_string(), false), ("set".to_string(), true)],
+        );
+    }
+
+    #[test]
+    fn interface_retains_must_use() {
+        let contract = ir::Contract::new(
+            quote! { emit_interface = true },
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: bool,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self { value: false } }
+
+                        #[ink(message)]
+                        #[must_use]
+                        pub fn get(&self) -> bool { self.value }
+
+                        #[ink(message)]
+                        pub fn set(&mut self, value: bool) { self.value = value; }
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated =
+            syn::parse2::<syn::File>(Interface::from(&contract).generate_code()).unwrap();
+        let is_must_use = |attrs: &[syn::Attribute]| {
+            attrs.iter().any(|attr| attr.path.is_ident("must_use"))
+        };
+        let mut decls = Vec::new();
+        let mut impls = Vec::new();
+        for item in &generated.items {
+            match item {
+                syn::Item::Trait(item_trait) => {
+                    for item in &item_trait.items {
+                        if let syn::TraitItem::Method(method) = item {
+                            decls.push((
+                                method.sig.ident.to_string(),
+                                is_must_use(&method.attrs),
+                            ));
+                        }
+                    }
+                }
+                syn::Item::Const(item_const) => {
+                    if let syn::Expr::Block(block) = &*item_const.expr {
+                        for stmt in &block.block.stmts {
+                            if let syn::Stmt::Item(syn::Item::Impl(item_impl)) = stmt {
+                                for item in &item_impl.items {
+                                    if let syn::ImplItem::Method(method) = item {
+                                        impls.push(is_must_use(&method.attrs));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        assert_eq!(
+            decls,
+            vec![("get".to_string(), true), ("set".to_string(), false)],
+        );
+        // `#[must_use]` is not applicable to trait methods in impl blocks.
+        assert_eq!(impls, vec![false, false]);
+    }
+
+    #[test]
+    fn same_named_trait_messages_are_disambiguated() {
+        let contract = ir::Contract::new(
+            quote! { emit_interface = true },
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: bool,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self { value: false } }
+
+                        #[ink(message)]
+                        pub fn get(&self) -> bool { self.value }
+                    }
+
+                    impl Getter for MyStorage {
+                        #[ink(message)]
+                        fn get(&self) -> bool { self.value }
+                    }
+
+                    impl Flip for MyStorage {
+                        #[ink(message)]
+                        fn flip(&mut self) { self.value = !self.value; }
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated =
+            syn::parse2::<syn::File>(Interface::from(&contract).generate_code()).unwrap();
+        let item_trait = generated
+            .items
+            .iter()
+            .find_map(|item| {
+                match item {
+                    syn::Item::Trait(item_trait) => Some(item_trait),
+                    _ => None,
+                }
+            })
+            .expect("missing interface trait");
+        let methods = item_trait
+            .items
+            .iter()
+            .map(|item| {
+                match item {
+                    syn::TraitItem::Method(method) => method.sig.ident.to_string(),
+                    _ => panic!("unexpected interface trait item"),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(methods, vec!["get", "getter_get", "flip"]);
+        let generated = generated.into_token_stream().to_string();
+        assert!(generated.contains("< MyStorage as Getter > :: get"));
+        assert!(!generated.contains("inline"));
+    }
+
+    #[test]
+    fn no_interface_by_default() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        assert!(Interface::from(&contract).generate_code().is_empty());
+    }
+}
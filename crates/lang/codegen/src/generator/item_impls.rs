@@ -72,20 +72,51 @@ impl ItemImpls<'_> {
         };
         let ident = constructor.ident();
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
-        let inputs = constructor.inputs();
+        let inputs = constructor.inputs_iter();
+        let where_clause = constructor.where_clause();
         let statements = constructor.statements();
         quote_spanned!(span =>
             type #output_ident = Self;
 
             #( #attrs )*
-            #vis fn #ident( #( #inputs ),* ) -> Self::#output_ident {
+            #vis fn #ident( #( #inputs ),* ) -> Self::#output_ident #where_clause {
                 #( #statements )*
             }
         )
     }
 
+    /// Generates the statements of the given ink! message.
+    ///
+    /// # Note
+    ///
+    /// The statements of ink! getter messages are generated from their storage field.
+    fn generate_message_statements(message: &ir::Message) -> TokenStream2 {
+        let span = message.span();
+        match message.getter() {
+            Some(field) => {
+                quote_spanned!(span =>
+                    ::core::clone::Clone::clone(&self.#field)
+                )
+            }
+            None => {
+                let statements = message.statements();
+                quote_spanned!(span =>
+                    #( #statements )*
+                )
+            }
+        }
+    }
+
+    /// Generates the `#[inline]` attribute for the given ink! message if it is
+    /// an ink! getter message and inlining of helpers has been enabled.
+    fn generate_getter_inline_attr(&self, message: &ir::Message) -> Option<TokenStream2> {
+        message
+            .getter()
+            .map(|_| self.generate_code_using::<generator::InlineHelperAttr>())
+    }
+
     /// Generates the code for the given ink! message within a trait implementation block.
-    fn generate_trait_message(message: &ir::Message) -> TokenStream2 {
+    fn generate_trait_message(&self, message: &ir::Message) -> TokenStream2 {
         let span = message.span();
         let attrs = message.attrs();
         let vis = match message.visibility() {
@@ -98,29 +129,32 @@ impl ItemImpls<'_> {
         };
         let ident = message.ident();
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
-        let inputs = message.inputs();
+        let inputs = message.inputs_iter();
         let output = message
             .output()
             .cloned()
             .unwrap_or_else(|| syn::parse_quote! { () });
-        let statements = message.statements();
+        let where_clause = message.where_clause();
+        let statements = Self::generate_message_statements(message);
+        let inline = self.generate_getter_inline_attr(message);
         quote_spanned!(span =>
             type #output_ident = #output;
 
             #( #attrs )*
-            #vis fn #ident(#receiver #(, #inputs )* ) -> Self::#output_ident {
-                #( #statements )*
+            #inline
+            #vis fn #ident(#receiver #(, #inputs )* ) -> Self::#output_ident #where_clause {
+                #statements
             }
         )
     }
 
-    fn generate_trait_item_impl(item_impl: &ir::ItemImpl) -> TokenStream2 {
+    fn generate_trait_item_impl(&self, item_impl: &ir::ItemImpl) -> TokenStream2 {
         assert!(item_impl.trait_path().is_some());
         let span = item_impl.span();
         let attrs = item_impl.attrs();
         let messages = item_impl
             .iter_messages()
-            .map(|cws| Self::generate_trait_message(cws.callable()));
+            .map(|cws| self.generate_trait_message(cws.callable()));
         let constructors = item_impl
             .iter_constructors()
             .map(|cws| Self::generate_trait_constructor(cws.callable()));
@@ -140,12 +174,12 @@ impl ItemImpls<'_> {
             trait_ident,
             item_impl.iter_constructors().map(|constructor| {
                 let ident = constructor.ident().clone();
-                let len_inputs = constructor.inputs().count();
+                let len_inputs = constructor.inputs_iter().count();
                 (ident, len_inputs)
             }),
             item_impl.iter_messages().map(|message| {
                 let ident = message.ident().clone();
-                let len_inputs = message.inputs().count() + 1;
+                let len_inputs = message.inputs_iter().count() + 1;
                 let is_mut = message.receiver().is_ref_mut();
                 (ident, len_inputs, is_mut)
             }),
@@ -174,18 +208,19 @@ impl ItemImpls<'_> {
             ir::Visibility::Public(vis_public) => Some(vis_public),
         };
         let ident = constructor.ident();
-        let inputs = constructor.inputs();
+        let inputs = constructor.inputs_iter();
+        let where_clause = constructor.where_clause();
         let statements = constructor.statements();
         quote_spanned!(span =>
             #( #attrs )*
-            #vis fn #ident( #( #inputs ),* ) -> Self {
+            #vis fn #ident( #( #inputs ),* ) -> Self #where_clause {
                 #( #statements )*
             }
         )
     }
 
     /// Generates the code for the given ink! message within an inherent implementation block.
-    fn generate_inherent_message(message: &ir::Message) -> TokenStream2 {
+    fn generate_inherent_message(&self, message: &ir::Message) -> TokenStream2 {
         let span = message.span();
         let attrs = message.attrs();
         let vis = match message.visibility() {
@@ -197,25 +232,28 @@ impl ItemImpls<'_> {
             ir::Receiver::Ref => quote! { &self },
         };
         let ident = message.ident();
-        let inputs = message.inputs();
+        let inputs = message.inputs_iter();
         let output_arrow = message.output().map(|_| quote! { -> });
         let output = message.output();
-        let statements = message.statements();
+        let where_clause = message.where_clause();
+        let statements = Self::generate_message_statements(message);
+        let inline = self.generate_getter_inline_attr(message);
         quote_spanned!(span =>
             #( #attrs )*
-            #vis fn #ident(#receiver, #( #inputs ),* ) #output_arrow #output {
-                #( #statements )*
+            #inline
+            #vis fn #ident(#receiver, #( #inputs ),* ) #output_arrow #output #where_clause {
+                #statements
             }
         )
     }
 
-    fn generate_inherent_item_impl(item_impl: &ir::ItemImpl) -> TokenStream2 {
+    fn generate_inherent_item_impl(&self, item_impl: &ir::ItemImpl) -> TokenStream2 {
         assert!(item_impl.trait_path().is_none());
         let span = item_impl.span();
         let attrs = item_impl.attrs();
         let messages = item_impl
             .iter_messages()
-            .map(|cws| Self::generate_inherent_message(cws.callable()));
+            .map(|cws| self.generate_inherent_message(cws.callable()));
         let constructors = item_impl
             .iter_constructors()
             .map(|cws| Self::generate_inherent_constructor(cws.callable()));
@@ -249,16 +287,84 @@ impl ItemImpls<'_> {
         )
     }
 
+    /// Generates deprecation warnings for all payable ink! messages of the
+    /// implementation block that take `&self`.
+    ///
+    /// # Note
+    ///
+    /// Such ink! messages cannot record anything about the transferred value
+    /// in the contract storage and will be rejected in a future version.
+    fn generate_payable_ref_receiver_warnings(
+        &self,
+        item_impl: &ir::ItemImpl,
+    ) -> Vec<TokenStream2> {
+        item_impl
+            .iter_messages()
+            .filter(|message| {
+                message.is_payable() && message.callable().receiver() == ir::Receiver::Ref
+            })
+            .map(|message| {
+                let note = format!(
+                    "payable ink! message `{}` takes `&self`, this is deprecated and \
+                     payable ink! messages should take `&mut self` instead",
+                    message.ident(),
+                );
+                generator::generate_warning(
+                    message.callable().span(),
+                    "__ink_payable_ref_receiver",
+                    &note,
+                )
+            })
+            .collect()
+    }
+
     /// Generates code for the given ink! implementation block.
     fn generate_item_impl(&self, item_impl: &ir::ItemImpl) -> TokenStream2 {
         let self_ty_guard = self.generate_item_impl_self_ty_guard(item_impl);
+        let payable_ref_receiver_warnings =
+            self.generate_payable_ref_receiver_warnings(item_impl);
         let impl_block = match item_impl.trait_path() {
-            Some(_) => Self::generate_trait_item_impl(item_impl),
-            None => Self::generate_inherent_item_impl(item_impl),
+            Some(_) => self.generate_trait_item_impl(item_impl),
+            None => self.generate_inherent_item_impl(item_impl),
         };
         quote! {
             #self_ty_guard
+            #( #payable_ref_receiver_warnings )*
             #impl_block
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payable_ref_receiver_messages_are_deprecated() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message, payable)]
+                        pub fn deposit(&self) {}
+
+                        #[ink(message, payable)]
+                        pub fn fund(&mut self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated = ItemImpls::from(&contract).generate_code().to_string();
+        assert_eq!(generated.matches("__ink_payable_ref_receiver").count(), 2);
+        assert!(generated.contains("payable ink! message `deposit` takes `&self`"));
+        assert!(!generated.contains("`fund`"));
+    }
+}
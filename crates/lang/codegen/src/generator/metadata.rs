@@ -15,12 +15,19 @@
 use crate::GenerateCode;
 use derive_more::From;
 use ir::Callable as _;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
 use quote::{
+    format_ident,
     quote,
     quote_spanned,
 };
-use syn::spanned::Spanned as _;
+use syn::{
+    ext::IdentExt as _,
+    spanned::Spanned as _,
+};
 
 /// Generates code to generate the metadata of the contract.
 #[derive(From)]
@@ -33,6 +40,7 @@ impl GenerateCode for Metadata<'_> {
     fn generate_code(&self) -> TokenStream2 {
         let contract = self.generate_contract();
         let layout = self.generate_layout();
+        let version = self.generate_version();
 
         quote! {
             #[cfg(feature = "std")]
@@ -47,6 +55,7 @@ impl GenerateCode for Metadata<'_> {
                         #layout
                     };
                     ::ink_metadata::InkProject::new(layout, contract)
+                        .with_version(#version)
                 }
             };
         }
@@ -54,6 +63,12 @@ impl GenerateCode for Metadata<'_> {
 }
 
 impl Metadata<'_> {
+    /// Generates the version of the metadata schema configured for the contract.
+    fn generate_version(&self) -> TokenStream2 {
+        let variant = format_ident!("V{}", self.contract.config().metadata_version());
+        quote! { ::ink_metadata::MetadataVersion::#variant }
+    }
+
     fn generate_layout(&self) -> TokenStream2 {
         let contract_ident = self.contract.module().storage().ident();
         quote! {
@@ -65,28 +80,113 @@ impl Metadata<'_> {
 
     fn generate_contract(&self) -> TokenStream2 {
         let constructors = self.generate_constructors();
-        let messages = self.generate_messages();
+        let messages = self.generate_messages(false);
+        let test_only_messages = self.generate_messages(true);
         let events = self.generate_events();
         let docs = self.generate_docs();
+        let source_hash = self.generate_source_hash();
+        let license = self.generate_license();
+        let environment = self.generate_environment();
+        let build_info = self.generate_build_info();
 
         quote! {
             ::ink_metadata::ContractSpec::new()
                 .constructors(vec![
                     #(#constructors ,)*
                 ])
-                .messages(vec![
-                    #(#messages ,)*
-                ])
+                .messages({
+                    #[allow(unused_mut)]
+                    let mut messages = vec![
+                        #(#messages ,)*
+                    ];
+                    #(
+                        #[cfg(test)]
+                        messages.push(#test_only_messages);
+                    )*
+                    messages
+                })
                 .events(vec![
                     #(#events ,)*
                 ])
                 .docs(vec![
                     #(#docs ,)*
                 ])
+                #source_hash
+                #license
+                .environment(#environment)
+                #build_info
                 .done()
         }
     }
 
+    /// Generates the source hash of the contract if enabled in the configuration.
+    fn generate_source_hash(&self) -> Option<TokenStream2> {
+        if !self.contract.config().is_source_hash_enabled() {
+            return None
+        }
+        let source_hash = self.contract.source_hash();
+        Some(quote! {
+            .source_hash([ #( #source_hash ),* ])
+        })
+    }
+
+    /// Generates the license of the contract if specified in the configuration.
+    fn generate_license(&self) -> Option<TokenStream2> {
+        let license = self.contract.config().license()?;
+        Some(quote! {
+            .license(#license)
+        })
+    }
+
+    /// Generates the versions of the Rust compiler and of ink! that build the
+    /// contract if enabled in the configuration.
+    ///
+    /// Both are captured at the time the contract is compiled.
+    fn generate_build_info(&self) -> Option<TokenStream2> {
+        if !self.contract.config().is_embed_build_info_enabled() {
+            return None
+        }
+        let rustc_version = env!("INK_RUSTC_VERSION");
+        let ink_version = env!("CARGO_PKG_VERSION");
+        Some(quote! {
+            .build_info(#rustc_version, #ink_version)
+        })
+    }
+
+    /// Generates the ink! metadata for the environmental types of the contract.
+    ///
+    /// The width of each type is the SCALE encoded size of the value decoded from
+    /// zeroed bytes. This is the encoded width of the fixed-width integer and
+    /// byte array types that environments use. The width is left out if no such
+    /// value can be decoded.
+    ///
+    /// No trait bounds beyond those of `ink_env::Environment` are required from
+    /// the environmental types.
+    fn generate_environment(&self) -> TokenStream2 {
+        let storage_ident = self.contract.module().storage().ident();
+        let env_types = ["AccountId", "Balance", "Hash", "BlockNumber", "Timestamp"]
+            .iter()
+            .map(|name| {
+                let ident = format_ident!("{}", name);
+                let ty = quote! {
+                    <<#storage_ident as ::ink_lang::ContractEnv>::Env as ::ink_env::Environment>::#ident
+                };
+                quote! {
+                    ::ink_metadata::EnvTypeSpec::new(
+                        ::core::any::type_name::<#ty>(),
+                        <#ty as ::scale::Decode>::decode(&mut &[0x00_u8; 128][..])
+                            .ok()
+                            .map(|value| ::scale::Encode::encoded_size(&value) as u32),
+                    )
+                }
+            });
+        quote! {
+            ::ink_metadata::EnvironmentSpec::new(
+                #( #env_types ),*
+            )
+        }
+    }
+
     /// Extracts the doc strings from the given slice of attributes.
     fn extract_doc_comments(
         attributes: &[syn::Attribute],
@@ -130,10 +230,14 @@ impl Metadata<'_> {
                 let selector_bytes = selector.as_bytes();
                 let constructor = constructor.callable();
                 let ident = constructor.ident();
-                let ident_lit = ident.to_string();
+                let ident_lit = ident.unraw().to_string();
                 let args = constructor
                     .inputs()
-                    .map(|arg| Self::generate_message_param(arg));
+                    .iter()
+                    .zip(constructor.input_defaults())
+                    .map(|((ident, ty), default)| {
+                        Self::generate_message_param(ident, ty, default.as_deref())
+                    });
                 let constr = match trait_ident {
                     Some(trait_ident) => {
                         let trait_ident_lit = trait_ident.to_string();
@@ -157,17 +261,20 @@ impl Metadata<'_> {
             })
     }
 
-    /// Generates the ink! metadata for the given parameter and parameter type.
-    fn generate_message_param(pat_type: &syn::PatType) -> TokenStream2 {
-        let ident = match &*pat_type.pat {
-            syn::Pat::Ident(ident) => &ident.ident,
-            _ => unreachable!("encountered unexpected non identifier in ink! parameter"),
-        };
-        let ident_lit = ident.to_string();
-        let type_spec = Self::generate_type_spec(&pat_type.ty);
+    /// Generates the ink! metadata for the given parameter, parameter type and
+    /// optional default value.
+    fn generate_message_param(
+        ident: &Ident,
+        ty: &syn::Type,
+        default: Option<&str>,
+    ) -> TokenStream2 {
+        let ident_lit = ident.unraw().to_string();
+        let type_spec = Self::generate_type_spec(ty);
+        let default = default.map(|default| quote! { .default(#default) });
         quote! {
             ::ink_metadata::MessageParamSpec::new(#ident_lit)
                 .of_type(#type_spec)
+                #default
                 .done()
         }
     }
@@ -200,7 +307,25 @@ impl Metadata<'_> {
         }
     }
 
-    fn generate_messages(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+    /// Generates ink! metadata for all contract messages.
+    ///
+    /// Yields either only the test-only ink! messages or only the other ink! messages
+    /// depending on `test_only`. ink! messages annotated with `#[doc(hidden)]` are
+    /// skipped.
+    fn generate_messages(
+        &self,
+        test_only: bool,
+    ) -> impl Iterator<Item = TokenStream2> + '_ {
+        let storage_fields = self
+            .contract
+            .module()
+            .storage()
+            .fields()
+            .filter_map(|field| field.ident.as_ref())
+            .map(|ident| ident.unraw().to_string())
+            .collect::<Vec<_>>();
+        let storage_access_hints =
+            self.contract.config().is_storage_access_hints_enabled();
         self.contract
             .module()
             .impls()
@@ -213,7 +338,9 @@ impl Metadata<'_> {
                     .iter_messages()
                     .map(move |message| (trait_ident, message))
             })
-            .map(|(trait_ident, message)| {
+            .filter(move |(_, message)| message.is_test_only() == test_only)
+            .filter(|(_, message)| !message.is_doc_hidden())
+            .map(move |(trait_ident, message)| {
                 let span = message.span();
                 let attrs = message.attrs();
                 let docs = Self::extract_doc_comments(&attrs);
@@ -221,12 +348,34 @@ impl Metadata<'_> {
                 let selector_bytes = selector.as_bytes();
                 let is_payable = message.is_payable();
                 let message = message.callable();
-                let mutates = message.receiver().is_ref_mut();
+                let weight = message
+                    .weight()
+                    .map(|weight| quote_spanned!(span => .weight(#weight)));
+                let standard = message
+                    .standard()
+                    .map(|standard| quote_spanned!(span => .standard(#standard)));
+                let storage_access = storage_access_hints.then(|| {
+                    let access = message.storage_access();
+                    let is_field = |name: &&str| storage_fields.iter().any(|field| field == name);
+                    let reads = access.reads().filter(is_field);
+                    let writes = access.writes().filter(is_field);
+                    quote_spanned!(span =>
+                        .storage_access(
+                            vec![#(#reads ,)*],
+                            vec![#(#writes ,)*],
+                        )
+                    )
+                });
+                let mutates = message.mutates();
                 let ident = message.ident();
-                let ident_lit = ident.to_string();
+                let ident_lit = ident.unraw().to_string();
                 let args = message
                     .inputs()
-                    .map(|arg| Self::generate_message_param(arg));
+                    .iter()
+                    .zip(message.input_defaults())
+                    .map(|((ident, ty), default)| {
+                        Self::generate_message_param(ident, ty, default.as_deref())
+                    });
                 let ret_ty = Self::generate_return_type(message.output());
                 let constr = match trait_ident {
                     Some(trait_ident) => {
@@ -249,6 +398,9 @@ impl Metadata<'_> {
                         .docs(vec![
                             #(#docs ,)*
                         ])
+                        #weight
+                        #standard
+                        #storage_access
                         .done()
                 )
             })
@@ -272,36 +424,47 @@ impl Metadata<'_> {
     }
 
     /// Generates ink! metadata for all user provided ink! event definitions.
+    ///
+    /// ink! events annotated with `#[doc(hidden)]` are skipped. They are encoded
+    /// with discriminants following all other events so that the position of an
+    /// event in the metadata equals its discriminant.
     fn generate_events(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.contract.module().events().map(|event| {
-            let span = event.span();
-            let ident = event.ident();
-            let ident_lit = ident.to_string();
-            let docs = Self::extract_doc_comments(event.attrs());
-            let args = Self::generate_event_args(event);
-            quote_spanned!(span =>
-                ::ink_metadata::EventSpec::new(#ident_lit)
-                    .args(vec![
-                        #( #args, )*
-                    ])
-                    .docs(vec![
-                        #( #docs, )*
-                    ])
-                    .done()
-            )
-        })
+        self.contract
+            .module()
+            .events()
+            .filter(|event| !event.is_doc_hidden())
+            .flat_map(|event| event.instances())
+            .map(|instance| {
+                let event = instance.event();
+                let span = event.span();
+                let ident_lit = instance.name();
+                let docs = Self::extract_doc_comments(event.attrs());
+                let args = Self::generate_event_args(instance);
+                quote_spanned!(span =>
+                    ::ink_metadata::EventSpec::new(#ident_lit)
+                        .args(vec![
+                            #( #args, )*
+                        ])
+                        .docs(vec![
+                            #( #docs, )*
+                        ])
+                        .done()
+                )
+            })
     }
 
     /// Generate ink! metadata for a single argument of an ink! event definition.
-    fn generate_event_args(event: &ir::Event) -> impl Iterator<Item = TokenStream2> + '_ {
-        event.fields().map(|event_field| {
+    fn generate_event_args(
+        instance: ir::EventInstance<'_>,
+    ) -> impl Iterator<Item = TokenStream2> + '_ {
+        instance.event().fields().map(move |event_field| {
             let span = event_field.span();
             let ident = event_field.ident();
-            let ident_lit = ident.map(ToString::to_string);
+            let ident_lit = ident.map(|ident| ident.unraw().to_string());
             let is_topic = event_field.is_topic;
             let attrs = event_field.attrs();
             let docs = Self::extract_doc_comments(&attrs);
-            let ty = Self::generate_type_spec(event_field.ty());
+            let ty = Self::generate_type_spec(&instance.monomorphize(event_field.ty()));
             quote_spanned!(span =>
                 ::ink_metadata::EventParamSpec::new(#ident_lit)
                     .of_type(#ty)
@@ -315,6 +478,10 @@ impl Metadata<'_> {
     }
 
     /// Generates the documentation for the contract module.
+    ///
+    /// # Note
+    ///
+    /// This includes both outer `///` and inner `//!` doc comments of the module.
     fn generate_docs(&self) -> impl Iterator<Item = String> + '_ {
         Self::extract_doc_comments(self.contract.module().attrs())
     }
@@ -397,4 +564,416 @@ mod tests {
             ],
         )
     }
+
+    #[test]
+    fn generate_docs_includes_inner_doc_comments() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                /// Outer documentation.
+                mod my_contract {
+                    //! Inner documentation.
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            Metadata::from(&contract)
+                .generate_docs()
+                .collect::<Vec<_>>(),
+            vec![
+                " Outer documentation.".to_string(),
+                " Inner documentation.".to_string(),
+            ],
+        );
+    }
+
+    #[test]
+    fn no_constructor_contract_has_empty_constructors() {
+        let contract = ir::Contract::new(
+            quote! { allow_no_constructor = true },
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let generated = Metadata::from(&contract).generate_code().to_string();
+        assert!(generated.contains("__ink_generate_metadata"));
+        assert!(generated.contains(". constructors (vec ! [])"));
+    }
+
+    #[test]
+    fn generate_version_works() {
+        let new_contract = |config| {
+            ir::Contract::new(
+                config,
+                quote! {
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyStorage {}
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn new() -> Self { Self {} }
+
+                            #[ink(message)]
+                            pub fn message(&self) {}
+                        }
+                    }
+                },
+            )
+            .unwrap()
+        };
+        let default_contract = new_contract(TokenStream2::new());
+        let v1_contract = new_contract(quote! { metadata_version = 1 });
+        assert_eq!(
+            Metadata::from(&default_contract)
+                .generate_version()
+                .to_string(),
+            quote! { ::ink_metadata::MetadataVersion::V0 }.to_string(),
+        );
+        assert_eq!(
+            Metadata::from(&v1_contract).generate_version().to_string(),
+            quote! { ::ink_metadata::MetadataVersion::V1 }.to_string(),
+        );
+        assert!(Metadata::from(&v1_contract)
+            .generate_code()
+            .to_string()
+            .contains(". with_version (:: ink_metadata :: MetadataVersion :: V1)"));
+    }
+
+    #[test]
+    fn raw_identifiers_are_unraw_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn r#move() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn r#type(&self, r#in: bool) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let metadata = Metadata::from(&contract);
+        let constructors = metadata
+            .generate_constructors()
+            .map(|constructor| constructor.to_string())
+            .collect::<Vec<_>>();
+        let messages = metadata
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        assert!(constructors[0].contains("from_name (\"move\")"));
+        assert!(messages[0].contains("from_name (\"type\")"));
+        assert!(messages[0].contains("MessageParamSpec :: new (\"in\")"));
+        assert!(!messages[0].contains("r#"));
+    }
+
+    #[test]
+    fn message_weight_is_emitted_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message, weight = 1_000_000)]
+                        pub fn weighted(&self) {}
+
+                        #[ink(message)]
+                        pub fn unweighted(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let messages = Metadata::from(&contract)
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        assert!(messages[0].contains(". weight (1000000u64)"));
+        assert!(!messages[1].contains(". weight ("));
+    }
+
+    #[test]
+    fn message_standard_is_emitted_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message, standard = "erc20::transfer")]
+                        pub fn transfer(&mut self) {}
+
+                        #[ink(message)]
+                        pub fn other(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let messages = Metadata::from(&contract)
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        assert!(messages[0].contains(". standard (\"erc20::transfer\")"));
+        assert!(!messages[1].contains(". standard ("));
+    }
+
+    #[test]
+    fn storage_access_is_emitted_in_metadata() {
+        let new_contract = |config| {
+            ir::Contract::new(
+                config,
+                quote! {
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyStorage {
+                            value: i32,
+                            step: i32,
+                        }
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn new() -> Self { Self { value: 0, step: 1 } }
+
+                            #[ink(message)]
+                            pub fn inc(&mut self) {
+                                let local = Local { step: 0 };
+                                self.value = self.step + local.step;
+                            }
+                        }
+                    }
+                },
+            )
+            .unwrap()
+        };
+        let generate_messages = |contract: &ir::Contract| {
+            Metadata::from(contract)
+                .generate_messages(false)
+                .map(|message| message.to_string())
+                .collect::<Vec<_>>()
+        };
+        let with_hints = new_contract(quote! { storage_access_hints = true });
+        let without_hints = new_contract(TokenStream2::new());
+        assert!(generate_messages(&with_hints)[0]
+            .contains(". storage_access (vec ! [\"step\" ,] , vec ! [\"value\" ,] ,)"));
+        assert!(!generate_messages(&without_hints)[0].contains(". storage_access ("));
+    }
+
+    #[test]
+    fn param_default_is_emitted_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn set(&self, #[ink(default = 0)] a: i32, b: bool) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let messages = Metadata::from(&contract)
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(messages[0].matches(". default (\"0\")").count(), 1);
+    }
+
+    #[test]
+    fn message_mutates_override_wins_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message, mutates = false)]
+                        pub fn overridden(&mut self) {}
+
+                        #[ink(message)]
+                        pub fn mutating(&mut self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let messages = Metadata::from(&contract)
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        assert!(messages[0].contains(". mutates (false)"));
+        assert!(messages[1].contains(". mutates (true)"));
+    }
+
+    #[test]
+    fn constructor_selectors_are_emitted_in_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(constructor, selector = "0xCAFEBABE")]
+                        pub fn default() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let constructors = Metadata::from(&contract)
+            .generate_constructors()
+            .map(|constructor| constructor.to_string())
+            .collect::<Vec<_>>();
+        let selector = |bytes: &[u8; 4]| quote! { .selector([#(#bytes),*]) }.to_string();
+        assert_eq!(constructors.len(), 2);
+        assert!(constructors[0].contains(&selector(ir::Selector::new(b"new").as_bytes())));
+        assert!(constructors[1].contains(&selector(&[0xCA, 0xFE, 0xBA, 0xBE])));
+    }
+
+    #[test]
+    fn license_is_emitted_in_metadata() {
+        let contract = |config: TokenStream2| {
+            ir::Contract::new(
+                config,
+                quote! {
+                    mod my_contract {
+                        #[ink(storage)]
+                        pub struct MyStorage {}
+
+                        impl MyStorage {
+                            #[ink(constructor)]
+                            pub fn new() -> Self { Self {} }
+
+                            #[ink(message)]
+                            pub fn message(&self) {}
+                        }
+                    }
+                },
+            )
+            .unwrap()
+        };
+        let licensed = contract(quote! { license = "Apache-2.0" });
+        let unlicensed = contract(TokenStream2::new());
+        assert!(Metadata::from(&licensed)
+            .generate_contract()
+            .to_string()
+            .contains(". license (\"Apache-2.0\")"));
+        assert!(!Metadata::from(&unlicensed)
+            .generate_contract()
+            .to_string()
+            .contains(". license ("));
+    }
+
+    #[test]
+    fn doc_hidden_items_are_excluded_from_metadata() {
+        let contract = ir::Contract::new(
+            TokenStream2::new(),
+            quote! {
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    #[ink(event)]
+                    pub struct Visible {}
+
+                    #[ink(event)]
+                    #[doc(hidden)]
+                    pub struct Internal {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn visible(&self) {}
+
+                        #[ink(message)]
+                        #[doc(hidden)]
+                        pub fn internal(&self) {}
+                    }
+                }
+            },
+        )
+        .unwrap();
+        let metadata = Metadata::from(&contract);
+        let messages = metadata
+            .generate_messages(false)
+            .map(|message| message.to_string())
+            .collect::<Vec<_>>();
+        let events = metadata
+            .generate_events()
+            .map(|event| event.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("from_name (\"visible\")"));
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains("EventSpec :: new (\"Visible\")"));
+        // Hidden ink! messages are still dispatched.
+        let [b0, b1, b2, b3] = *ir::Selector::new(b"internal").as_bytes();
+        let variant =
+            format_ident!("__ink_Message_0x{:02x}{:02x}{:02x}{:02x}", b0, b1, b2, b3);
+        let dispatch = crate::generator::Dispatch::from(&contract)
+            .generate_code()
+            .to_string();
+        assert!(dispatch.contains(&variant.to_string()));
+    }
 }
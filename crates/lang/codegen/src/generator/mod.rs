@@ -13,30 +13,93 @@
 // limitations under the License.
 
 mod chain_extension;
+#[cfg(feature = "client")]
+mod client;
 mod contract;
 mod cross_calling;
 mod dispatch;
 mod env;
+#[cfg(feature = "event-filters")]
+mod event_filters;
 mod events;
 mod ink_test;
+mod interface;
 mod item_impls;
 mod metadata;
 mod storage;
 mod trait_def;
 
+#[cfg(feature = "client")]
+pub use self::client::Client;
+#[cfg(feature = "event-filters")]
+pub use self::event_filters::EventFilters;
 pub use self::{
     chain_extension::ChainExtension,
     contract::Contract,
     cross_calling::{
         CrossCalling,
         CrossCallingConflictCfg,
+        CrossCallingRef,
+    },
+    dispatch::{
+        Dispatch,
+        InlineHelperAttr,
     },
-    dispatch::Dispatch,
     env::Env,
     events::Events,
     ink_test::InkTest,
+    interface::Interface,
     item_impls::ItemImpls,
     metadata::Metadata,
     storage::Storage,
     trait_def::TraitDefinition,
 };
+use proc_macro2::{
+    Ident,
+    Span,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+
+/// Generates code that makes the compiler emit a warning with the given note
+/// pointing at the given span.
+///
+/// # Note
+///
+/// Since procedural macros cannot emit warnings on stable Rust we use a
+/// deprecated item named `ident` that is spanned to the offending code so that
+/// the compiler reports the deprecation note as a warning pointing at it.
+pub fn generate_warning(span: Span, ident: &str, note: &str) -> TokenStream2 {
+    let ident = format_ident!("{}", ident, span = span);
+    quote_spanned!(span =>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #ident: () = ();
+            #ident
+        };
+    )
+}
+
+/// Returns the signature used as the topic prefix of an ink! event or, if
+/// `field` is given, of one of its topic fields.
+///
+/// # Note
+///
+/// The signature is the path of the event or field within the contract,
+/// e.g. `Erc20::Transfer` or `Erc20::Transfer::from`.
+pub fn generate_topic_signature(
+    span: Span,
+    contract_ident: &Ident,
+    event_name: &str,
+    field: Option<&TokenStream2>,
+) -> syn::LitByteStr {
+    let signature = match field {
+        Some(field) => format!("{}::{}::{}", contract_ident, event_name, field),
+        None => format!("{}::{}", contract_ident, event_name),
+    };
+    syn::LitByteStr::new(signature.as_bytes(), span)
+}
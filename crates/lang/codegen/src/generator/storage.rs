@@ -49,9 +49,19 @@ impl GenerateCode for Storage<'_> {
             None
         };
         let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let pub_field_warnings = self.generate_pub_field_warnings();
+        let interior_mutability_warnings = self.generate_interior_mutability_warnings();
+        let new_zeroed = self.generate_new_zeroed();
+        let selectors = self.generate_selectors_const();
+        let call_info = self.generate_call_info_const();
         quote_spanned!(storage_span =>
             #access_env_impls
             #storage_struct
+            #new_zeroed
+            #selectors
+            #call_info
+            #( #pub_field_warnings )*
+            #( #interior_mutability_warnings )*
 
             #cfg
             const _: () = {
@@ -68,6 +78,203 @@ impl GenerateCode for Storage<'_> {
 }
 
 impl Storage<'_> {
+    /// Generates advisory warnings for all `pub` fields of the ink! storage
+    /// struct if enabled via `warn_pub_storage`.
+    ///
+    /// # Note
+    ///
+    /// The warnings point at the `pub` storage fields.
+    fn generate_pub_field_warnings(&self) -> Vec<TokenStream2> {
+        if !self.contract.config().is_warn_pub_storage_enabled() {
+            return Vec::new()
+        }
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let storage = self.contract.module().storage();
+        storage
+            .pub_fields()
+            .map(|field| {
+                let name = field
+                    .ident
+                    .as_ref()
+                    .map(|ident| format!(" `{}`", ident))
+                    .unwrap_or_default();
+                let note = format!(
+                    "ink! storage field{} of `{}` is declared `pub`, \
+                     consider keeping it private and exposing it via accessor ink! messages instead",
+                    name,
+                    storage.ident(),
+                );
+                let warning = generator::generate_warning(
+                    field.span(),
+                    "__ink_pub_storage_field",
+                    &note,
+                );
+                quote! {
+                    #cfg
+                    #warning
+                }
+            })
+            .collect()
+    }
+
+    /// Generates advisory warnings for all fields of the ink! storage struct
+    /// of interior mutability types if enabled via `warn_interior_mutability`.
+    ///
+    /// # Note
+    ///
+    /// The warnings point at the storage fields of interior mutability types.
+    fn generate_interior_mutability_warnings(&self) -> Vec<TokenStream2> {
+        if !self.contract.config().is_warn_interior_mutability_enabled() {
+            return Vec::new()
+        }
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let storage = self.contract.module().storage();
+        storage
+            .interior_mutability_fields()
+            .map(|(field, ty)| {
+                let name = field
+                    .ident
+                    .as_ref()
+                    .map(|ident| format!(" `{}`", ident))
+                    .unwrap_or_default();
+                let note = format!(
+                    "ink! storage field{} of `{}` uses the interior mutability type `{}` \
+                     which does not persist meaningfully in the contract storage, \
+                     consider storing the value directly and mutating it in `&mut self` ink! messages instead",
+                    name,
+                    storage.ident(),
+                    ty,
+                );
+                let warning = generator::generate_warning(
+                    field.span(),
+                    "__ink_interior_mutability_storage_field",
+                    &note,
+                );
+                quote! {
+                    #cfg
+                    #warning
+                }
+            })
+            .collect()
+    }
+
+    /// Generates the `new_zeroed` associated function of the ink! storage struct
+    /// if it has been flagged with `#[ink(storage, zeroed)]`.
+    fn generate_new_zeroed(&self) -> Option<TokenStream2> {
+        let storage = self.contract.module().storage();
+        let zero_values = storage.zero_values()?;
+        let span = storage.span();
+        let storage_ident = storage.ident();
+        let members = storage.fields().enumerate().map(|(n, field)| {
+            match field.ident.as_ref() {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(n)),
+            }
+        });
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        Some(quote_spanned!(span =>
+            #cfg
+            const _: () = {
+                impl #storage_ident {
+                    /// Returns the ink! storage with all of its fields set to zero.
+                    #[allow(dead_code)]
+                    pub fn new_zeroed() -> Self {
+                        Self {
+                            #( #members: #zero_values, )*
+                        }
+                    }
+                }
+            };
+        ))
+    }
+
+    /// Returns the ink! messages exposed via `expose_selectors` and
+    /// `expose_call_info` sorted by their selectors in ascending order.
+    ///
+    /// # Note
+    ///
+    /// Test-only ink! messages are never dispatched on-chain and are therefore
+    /// not exposed.
+    fn exposed_messages(&self) -> Vec<ir::CallableWithSelector<ir::Message>> {
+        let mut messages = self
+            .contract
+            .module()
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .filter(|message| !message.callable().is_test_only())
+            .collect::<Vec<_>>();
+        messages.sort_unstable_by_key(|message| *message.composed_selector().as_bytes());
+        messages
+    }
+
+    /// Generates the `SELECTORS` associated constant of the ink! storage struct
+    /// if enabled via `expose_selectors`.
+    fn generate_selectors_const(&self) -> Option<TokenStream2> {
+        if !self.contract.config().is_expose_selectors_enabled() {
+            return None
+        }
+        let storage = self.contract.module().storage();
+        let span = storage.span();
+        let storage_ident = storage.ident();
+        let selectors = self.exposed_messages().into_iter().map(|message| {
+            let [b0, b1, b2, b3] = *message.composed_selector().as_bytes();
+            quote! { [#b0, #b1, #b2, #b3] }
+        });
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        Some(quote_spanned!(span =>
+            #cfg
+            const _: () = {
+                impl #storage_ident {
+                    /// The selectors of all ink! messages of the contract sorted in ascending order.
+                    pub const SELECTORS: &'static [[::core::primitive::u8; 4]] = &[
+                        #( #selectors ),*
+                    ];
+                }
+            };
+        ))
+    }
+
+    /// Generates the `CALL_INFO` associated constant of the ink! storage struct
+    /// if enabled via `expose_call_info`.
+    ///
+    /// # Note
+    ///
+    /// Every entry is made of the selector of an ink! message as big-endian `u32`,
+    /// its signature, e.g. `"transfer(AccountId,Balance)"`, and its return type,
+    /// e.g. `"bool"` or `"()"`. The entries are in the same order as the
+    /// `SELECTORS` exposed via `expose_selectors`.
+    fn generate_call_info_const(&self) -> Option<TokenStream2> {
+        if !self.contract.config().is_expose_call_info_enabled() {
+            return None
+        }
+        let storage = self.contract.module().storage();
+        let span = storage.span();
+        let storage_ident = storage.ident();
+        let entries = self.exposed_messages().into_iter().map(|message| {
+            let selector = u32::from_be_bytes(*message.composed_selector().as_bytes());
+            let abi_signature = ir::AbiSignature::from_message(&message);
+            let signature = abi_signature.signature();
+            let output = abi_signature.output().unwrap_or("()");
+            quote! { (#selector, #signature, #output) }
+        });
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        Some(quote_spanned!(span =>
+            #cfg
+            const _: () = {
+                impl #storage_ident {
+                    /// The selectors, signatures and return types of all ink! messages of the contract.
+                    pub const CALL_INFO: &'static [(
+                        ::core::primitive::u32,
+                        &'static ::core::primitive::str,
+                        &'static ::core::primitive::str,
+                    )] = &[
+                        #( #entries ),*
+                    ];
+                }
+            };
+        ))
+    }
+
     fn generate_access_env_trait_impls(&self) -> TokenStream2 {
         let storage_ident = &self.contract.module().storage().ident();
         let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
@@ -101,18 +308,541 @@ impl Storage<'_> {
         let attrs = &storage.attrs();
         let fields = storage.fields();
         let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        let derive_debug = if self.contract.config().is_derive_debug_enabled() {
+            quote! { #[cfg_attr(any(test, feature = "std"), derive(::core::fmt::Debug))] }
+        } else {
+            quote! { #[cfg_attr(test, derive(Debug))] }
+        };
+        if storage.has_explicit_storage_keys() || storage.has_hidden_fields() {
+            let layout_impls = self.generate_layout_impls();
+            return quote_spanned!( span =>
+                #cfg
+                #(#attrs)*
+                #derive_debug
+                pub struct #ident {
+                    #( #fields ),*
+                }
+
+                #layout_impls
+            )
+        }
+        let derive_layout = (!storage.has_manual_layout()).then(|| {
+            quote! {
+                #[cfg_attr(
+                    feature = "std",
+                    derive(::ink_storage::traits::StorageLayout)
+                )]
+            }
+        });
         quote_spanned!( span =>
             #cfg
             #(#attrs)*
-            #[cfg_attr(
-                feature = "std",
-                derive(::ink_storage::traits::StorageLayout)
-            )]
+            #derive_layout
             #[derive(::ink_storage::traits::SpreadLayout)]
-            #[cfg_attr(test, derive(Debug))]
+            #derive_debug
             pub struct #ident {
                 #( #fields ),*
             }
         )
     }
+
+    /// Generates the `SpreadLayout` and `StorageLayout` implementations of the
+    /// storage struct if some of its fields have explicit storage keys or are
+    /// hidden from the metadata.
+    ///
+    /// # Note
+    ///
+    /// Fields with explicit storage keys are pulled, pushed and cleared using
+    /// their own key pointer starting at their explicit storage key. They do
+    /// not contribute to the footprint of the storage struct so that all other
+    /// fields keep their positional storage keys.
+    ///
+    /// The explicit storage keys are asserted at compile time to not lie within
+    /// the range of keys used by the fields without explicit storage key which
+    /// starts at the root key and spans their accumulated footprint.
+    ///
+    /// Hidden fields are skipped in the `StorageLayout` while still advancing
+    /// the key pointer so that the layout of all other fields is unaffected.
+    ///
+    /// The `StorageLayout` implementation is not generated for storage structs
+    /// flagged with `#[ink(storage, manual_layout)]`.
+    fn generate_layout_impls(&self) -> TokenStream2 {
+        let storage = self.contract.module().storage();
+        let span = storage.span();
+        let storage_ident = storage.ident();
+        let fields = storage
+            .fields()
+            .zip(storage.storage_keys())
+            .zip(storage.hidden_fields())
+            .enumerate()
+            .map(|(n, ((field, storage_key), hidden))| {
+                let member = match field.ident.as_ref() {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(syn::Index::from(n)),
+                };
+                let name = match field.ident.as_ref() {
+                    Some(ident) => {
+                        let ident_str = ident.to_string();
+                        quote! { Some(#ident_str) }
+                    }
+                    None => quote! { None },
+                };
+                let key_ptr = storage_key.as_ref().map(|storage_key| {
+                    let bytes = storage_key.as_bytes();
+                    quote! {
+                        &mut ::ink_storage::traits::KeyPtr::from(
+                            ::ink_primitives::Key::from([ #( #bytes ),* ])
+                        )
+                    }
+                });
+                let ty = &field.ty;
+                let layout = match (*hidden, key_ptr.as_ref()) {
+                    (false, key_ptr) => {
+                        let key_ptr = key_ptr.cloned().unwrap_or_else(|| quote! { __key_ptr });
+                        quote! {
+                            __fields.push(::ink_metadata::layout::FieldLayout::new(
+                                #name,
+                                <#ty as ::ink_storage::traits::StorageLayout>::layout(#key_ptr),
+                            ));
+                        }
+                    }
+                    (true, None) => {
+                        quote! {
+                            __key_ptr.advance_by(<#ty as ::ink_storage::traits::SpreadLayout>::FOOTPRINT);
+                        }
+                    }
+                    (true, Some(_)) => quote! {},
+                };
+                (member, layout, ty, key_ptr)
+            })
+            .collect::<Vec<_>>();
+        let footprint = fields
+            .iter()
+            .filter(|(_, _, _, key_ptr)| key_ptr.is_none())
+            .map(|(_, _, ty, _)| {
+                quote! { <#ty as ::ink_storage::traits::SpreadLayout>::FOOTPRINT }
+            })
+            .fold(quote! { 0u64 }, |lhs, rhs| quote! { (#lhs + #rhs) });
+        let requires_deep_clean_up = fields
+            .iter()
+            .map(|(_, _, ty, _)| {
+                quote! { <#ty as ::ink_storage::traits::SpreadLayout>::REQUIRES_DEEP_CLEAN_UP }
+            })
+            .fold(quote! { false }, |lhs, rhs| quote! { (#lhs || #rhs) });
+        let key_ptrs = fields
+            .iter()
+            .map(|(_, _, _, key_ptr)| {
+                key_ptr.clone().unwrap_or_else(|| quote! { __key_ptr })
+            })
+            .collect::<Vec<_>>();
+        let members = fields
+            .iter()
+            .map(|(member, _, _, _)| member)
+            .collect::<Vec<_>>();
+        let layouts = fields.iter().map(|(_, layout, _, _)| layout);
+        let types = fields.iter().map(|(_, _, ty, _)| ty).collect::<Vec<_>>();
+        let storage_layout_impl = (!storage.has_manual_layout()).then(|| {
+            quote! {
+                #[cfg(feature = "std")]
+                impl ::ink_storage::traits::StorageLayout for #storage_ident {
+                    fn layout(__key_ptr: &mut ::ink_storage::traits::KeyPtr) -> ::ink_metadata::layout::Layout {
+                        #[allow(unused_mut)]
+                        let mut __fields: Vec<::ink_metadata::layout::FieldLayout> = vec![];
+                        #( #layouts )*
+                        ::ink_metadata::layout::Layout::Struct(
+                            ::ink_metadata::layout::StructLayout::new(__fields)
+                        )
+                    }
+                }
+            }
+        });
+        let storage_key_guards = storage
+            .fields()
+            .zip(storage.storage_keys())
+            .filter_map(|(field, storage_key)| {
+                let bytes = storage_key.as_ref()?.as_bytes();
+                // Storage keys beyond `u64::MAX` can never collide since the
+                // footprint of the storage struct is a `u64`.
+                if bytes[8..].iter().any(|byte| *byte != 0x00) {
+                    return None
+                }
+                let mut low_bytes = [0x00; 8];
+                low_bytes.copy_from_slice(&bytes[..8]);
+                let low = u64::from_le_bytes(low_bytes);
+                let span = field.span();
+                Some(quote_spanned!(span=>
+                    ::ink_lang::static_assertions::const_assert!(
+                        <#storage_ident as ::ink_storage::traits::SpreadLayout>::FOOTPRINT <= #low
+                    );
+                ))
+            });
+        let cfg = self.generate_code_using::<generator::CrossCallingConflictCfg>();
+        quote_spanned!(span =>
+            #cfg
+            const _: () = {
+                #( #storage_key_guards )*
+
+                impl ::ink_storage::traits::SpreadLayout for #storage_ident {
+                    const FOOTPRINT: u64 = #footprint;
+                    const REQUIRES_DEEP_CLEAN_UP: bool = #requires_deep_clean_up;
+
+                    fn pull_spread(__key_ptr: &mut ::ink_storage::traits::KeyPtr) -> Self {
+                        Self {
+                            #(
+                                #members: <#types as ::ink_storage::traits::SpreadLayout>::pull_spread(#key_ptrs),
+                            )*
+                        }
+                    }
+
+                    fn push_spread(&self, __key_ptr: &mut ::ink_storage::traits::KeyPtr) {
+                        #(
+                            ::ink_storage::traits::SpreadLayout::push_spread(&self.#members, #key_ptrs);
+                        )*
+                    }
+
+                    fn clear_spread(&self, __key_ptr: &mut ::ink_storage::traits::KeyPtr) {
+                        #(
+                            ::ink_storage::traits::SpreadLayout::clear_spread(&self.#members, #key_ptrs);
+                        )*
+                    }
+                }
+
+                #storage_layout_impl
+            };
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates the ink! contract with the given config, ink! storage struct and
+    /// ink! messages.
+    ///
+    /// The ink! storage struct is named `MyStorage` and must implement `Default`
+    /// which is used by the single ink! constructor of the contract.
+    fn new_contract(
+        config: TokenStream2,
+        storage: TokenStream2,
+        messages: TokenStream2,
+    ) -> ir::Contract {
+        ir::Contract::new(
+            config,
+            quote! {
+                mod my_contract {
+                    #storage
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self::default() }
+
+                        #messages
+                    }
+                }
+            },
+        )
+        .unwrap()
+    }
+
+    /// Creates the ink! contract with the given config and a `pub` ink! storage field.
+    fn pub_field_contract(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(storage)]
+                #[derive(Default)]
+                pub struct MyStorage {
+                    pub value: bool,
+                    owner: AccountId,
+                }
+            },
+            quote! {
+                #[ink(message)]
+                pub fn message(&self) {}
+            },
+        )
+    }
+
+    #[test]
+    fn pub_storage_fields_are_flagged_if_enabled() {
+        let contract = pub_field_contract(quote! { warn_pub_storage = true });
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert_eq!(generated.matches("__ink_pub_storage_field").count(), 2);
+        assert!(generated
+            .contains("ink! storage field `value` of `MyStorage` is declared `pub`"));
+        assert!(!generated.contains("`owner`"));
+    }
+
+    /// Creates the ink! contract with the given config and an ink! storage field
+    /// of an interior mutability type.
+    fn interior_mutability_contract(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(storage)]
+                #[derive(Default)]
+                pub struct MyStorage {
+                    cache: RefCell<u32>,
+                    value: u32,
+                }
+            },
+            quote! {
+                #[ink(message)]
+                pub fn message(&self) {}
+            },
+        )
+    }
+
+    #[test]
+    fn interior_mutability_fields_are_flagged_if_enabled() {
+        let contract =
+            interior_mutability_contract(quote! { warn_interior_mutability = true });
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert_eq!(
+            generated
+                .matches("__ink_interior_mutability_storage_field")
+                .count(),
+            2
+        );
+        assert!(generated.contains(
+            "ink! storage field `cache` of `MyStorage` uses the interior mutability type `RefCell`"
+        ));
+        assert!(!generated.contains("`value`"));
+    }
+
+    #[test]
+    fn interior_mutability_fields_are_not_flagged_by_default() {
+        let contract = interior_mutability_contract(TokenStream2::new());
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("__ink_interior_mutability_storage_field"));
+    }
+
+    #[test]
+    fn pub_storage_fields_are_not_flagged_by_default() {
+        let contract = pub_field_contract(TokenStream2::new());
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("__ink_pub_storage_field"));
+    }
+
+    /// Creates the ink! contract with the given config and ink! messages with
+    /// explicit selectors.
+    fn selectors_contract(config: TokenStream2) -> ir::Contract {
+        new_contract(
+            config,
+            quote! {
+                #[ink(storage)]
+                #[derive(Default)]
+                pub struct MyStorage {}
+            },
+            quote! {
+                #[ink(message, selector = 0xFF000000)]
+                pub fn last(&self) {}
+
+                #[ink(message, selector = 0x00000002)]
+                pub fn second(&self) {}
+
+                #[ink(message, selector = 0x00000001)]
+                pub fn first(&self) {}
+
+                #[ink(message, selector = 0x00000003, test_only)]
+                pub fn test_helper(&self) {}
+            },
+        )
+    }
+
+    #[test]
+    fn selectors_are_exposed_sorted_if_enabled() {
+        let contract = selectors_contract(quote! { expose_selectors = true });
+        let generated = Storage::from(&contract).generate_code().to_string();
+        let expected = quote! {
+            pub const SELECTORS: &'static [[::core::primitive::u8; 4]] = &[
+                [0u8, 0u8, 0u8, 1u8],
+                [0u8, 0u8, 0u8, 2u8],
+                [255u8, 0u8, 0u8, 0u8]
+            ];
+        }
+        .to_string();
+        assert!(generated.contains(&expected));
+    }
+
+    #[test]
+    fn call_info_is_exposed_if_enabled() {
+        let contract = new_contract(
+            quote! { expose_call_info = true },
+            quote! {
+                #[ink(storage)]
+                #[derive(Default)]
+                pub struct MyStorage {}
+            },
+            quote! {
+                #[ink(message, selector = 0xFF000000)]
+                pub fn flip(&mut self) {}
+
+                #[ink(message, selector = 0x00000002)]
+                pub fn name(&self) -> Option<&'static str> { None }
+
+                #[ink(message, selector = 0x00000001)]
+                pub fn transfer(&mut self, to: AccountId, values: Vec<u8>) -> bool {
+                    !values.is_empty()
+                }
+
+                #[ink(message, selector = 0x00000003, test_only)]
+                pub fn test_helper(&self) {}
+            },
+        );
+        let generated = Storage::from(&contract).generate_code().to_string();
+        let expected = quote! {
+            = &[
+                (1u32, "transfer(AccountId,Vec<u8>)", "bool"),
+                (2u32, "name()", "Option<&'static str>"),
+                (4278190080u32, "flip()", "()")
+            ];
+        }
+        .to_string();
+        assert!(generated.contains(&expected));
+    }
+
+    #[test]
+    fn call_info_is_not_exposed_by_default() {
+        let contract = selectors_contract(TokenStream2::new());
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("CALL_INFO"));
+    }
+
+    #[test]
+    fn selectors_are_not_exposed_by_default() {
+        let contract = selectors_contract(TokenStream2::new());
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("SELECTORS"));
+    }
+
+    /// Creates the ink! contract with a manually laid out ink! storage struct
+    /// with the given fields.
+    fn manual_layout_contract(fields: TokenStream2) -> ir::Contract {
+        new_contract(
+            TokenStream2::new(),
+            quote! {
+                #[ink(storage, manual_layout)]
+                #[derive(Default)]
+                pub struct MyStorage {
+                    #fields
+                }
+
+                #[cfg(feature = "std")]
+                impl ::ink_storage::traits::StorageLayout for MyStorage {
+                    fn layout(
+                        _key_ptr: &mut ::ink_storage::traits::KeyPtr,
+                    ) -> ::ink_metadata::layout::Layout {
+                        ::ink_metadata::layout::Layout::Struct(
+                            ::ink_metadata::layout::StructLayout::new(
+                                ::ink_prelude::vec::Vec::new(),
+                            ),
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[ink(message)]
+                pub fn message(&self) {}
+            },
+        )
+    }
+
+    #[test]
+    fn manual_layout_generates_no_storage_layout() {
+        let contract = manual_layout_contract(quote! { value: u32 });
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("StorageLayout"));
+        assert!(generated.contains("SpreadLayout"));
+    }
+
+    #[test]
+    fn manual_layout_with_storage_keys_generates_no_storage_layout() {
+        let contract = manual_layout_contract(quote! {
+            value: u32,
+            #[ink(storage_key = 0x1234)]
+            pinned: bool,
+        });
+        let generated = Storage::from(&contract).generate_code().to_string();
+        assert!(!generated.contains("StorageLayout"));
+        assert!(generated
+            .contains("impl :: ink_storage :: traits :: SpreadLayout for MyStorage"));
+    }
+
+    #[test]
+    fn explicit_storage_keys_are_guarded_against_positional_keys() {
+        let contract = new_contract(
+            TokenStream2::new(),
+            quote! {
+                #[ink(storage)]
+                #[derive(Default)]
+                pub struct MyStorage {
+                    value: u32,
+                    #[ink(storage_key = 0x1234)]
+                    pinned: bool,
+                    #[ink(storage_key = 0x0100000000000000000000000000000000)]
+                    far: bool,
+                }
+            },
+            quote! {
+                #[ink(message)]
+                pub fn message(&self) {}
+            },
+        );
+        let generated = Storage::from(&contract).generate_code().to_string();
+        let guard = quote! {
+            ::ink_lang::static_assertions::const_assert!(
+                <MyStorage as ::ink_storage::traits::SpreadLayout>::FOOTPRINT <= 4660u64
+            );
+        }
+        .to_string();
+        assert!(generated.contains(&guard));
+        assert_eq!(generated.matches("const_assert").count(), 1);
+    }
+
+    #[test]
+    fn new_zeroed_works_for_tuple_storage_structs() {
+        let contract = new_contract(
+            TokenStream2::new(),
+            quote! {
+                #[ink(storage, zeroed)]
+                #[derive(Default)]
+                pub struct MyStorage(u32, (bool, [i8; 2]));
+            },
+            quote! {
+                #[ink(message)]
+                pub fn message(&self) {}
+            },
+        );
+        let generated = Storage::from(&contract)
+            .generate_new_zeroed()
+            .unwrap()
+            .to_string();
+        let expected = quote! {
+            Self {
+                0: 0,
+                1: (false, [0; 2],),
+            }
+        }
+        .to_string();
+        assert!(generated.contains(&expected), "{}", generated);
+    }
+
+    #[test]
+    fn debug_is_derived_for_std_if_enabled() {
+        let std_debug = quote! {
+            #[cfg_attr(any(test, feature = "std"), derive(::core::fmt::Debug))]
+        }
+        .to_string();
+        let test_debug = quote! { #[cfg_attr(test, derive(Debug))] }.to_string();
+        let enabled = pub_field_contract(quote! { derive_debug = true });
+        let generated = Storage::from(&enabled).generate_code().to_string();
+        assert!(generated.contains(&std_debug));
+        assert!(!generated.contains(&test_debug));
+        let disabled = pub_field_contract(TokenStream2::new());
+        let generated = Storage::from(&disabled).generate_code().to_string();
+        assert!(!generated.contains(&std_debug));
+        assert!(generated.contains(&test_debug));
+    }
 }
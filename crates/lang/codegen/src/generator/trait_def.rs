@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::GenerateCode;
+use crate::{
+    generator,
+    GenerateCode,
+};
 use derive_more::From;
 use heck::CamelCase as _;
 use proc_macro2::TokenStream as TokenStream2;
@@ -38,12 +41,13 @@ impl<'a> TraitDefinition<'a> {
         let ident = &sig.ident;
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
         let inputs = &sig.inputs;
+        let where_clause = &sig.generics.where_clause;
         quote_spanned!(span =>
             /// Output type of the respective trait constructor.
             type #output_ident;
 
             #(#attrs)*
-            fn #ident(#inputs) -> Self::#output_ident;
+            fn #ident(#inputs) -> Self::#output_ident #where_clause;
         )
     }
 
@@ -57,15 +61,129 @@ impl<'a> TraitDefinition<'a> {
             syn::ReturnType::Default => quote! { () },
             syn::ReturnType::Type(_, ty) => quote! { #ty },
         };
+        let where_clause = &sig.generics.where_clause;
         let output_ident = format_ident!("{}Out", ident.to_string().to_camel_case());
         quote_spanned!(span =>
             /// Output type of the respective trait message.
             type #output_ident: ::ink_lang::ImpliesReturn<#output>;
 
             #(#attrs)*
-            fn #ident(#inputs) -> Self::#output_ident;
+            fn #ident(#inputs) -> Self::#output_ident #where_clause;
         )
     }
+
+    /// Generates the call builder method of the given ink! trait message.
+    ///
+    /// The call is encoded using the selector of the trait message so that
+    /// any contract implementing the ink! trait can be called.
+    fn generate_call_builder_message(
+        message: ir::InkTraitMessage<'a>,
+        selector: ir::Selector,
+    ) -> TokenStream2 {
+        let span = message.span();
+        let attrs = message.attrs();
+        let sig = message.sig();
+        let ident = &sig.ident;
+        let selector_bytes = selector.as_bytes().to_owned();
+        let (input_bindings, input_types): (Vec<_>, Vec<_>) = sig
+            .inputs
+            .iter()
+            .filter_map(|fn_arg| {
+                match fn_arg {
+                    syn::FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+                    syn::FnArg::Receiver(_) => None,
+                }
+            })
+            .enumerate()
+            .map(|(n, ty)| (format_ident!("__ink_binding_{}", n), ty))
+            .unzip();
+        let arg_list =
+            generator::CrossCalling::generate_arg_list(input_types.iter().cloned());
+        let output_sig = match &sig.output {
+            syn::ReturnType::Default => quote! { () },
+            syn::ReturnType::Type(_, ty) => {
+                quote! { ::ink_env::call::utils::ReturnType<#ty> }
+            }
+        };
+        quote_spanned!(span =>
+            #( #attrs )*
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn #ident(
+                &self,
+                #( #input_bindings : #input_types ),*
+            ) -> ::ink_env::call::CallBuilder<
+                E,
+                ::ink_env::call::utils::Set<E::AccountId>,
+                ::ink_env::call::utils::Unset<u64>,
+                ::ink_env::call::utils::Unset<E::Balance>,
+                ::ink_env::call::utils::Set<::ink_env::call::ExecutionInput<#arg_list>>,
+                ::ink_env::call::utils::Set<#output_sig>,
+            > {
+                ::ink_env::call::build_call::<E>()
+                    .callee(::core::clone::Clone::clone(&self.account_id))
+                    .exec_input(
+                        ::ink_env::call::ExecutionInput::new(
+                            ::ink_env::call::Selector::new([ #( #selector_bytes ),* ])
+                        )
+                        #(
+                            .push_arg(#input_bindings)
+                        )*
+                    )
+                    .returns::<#output_sig>()
+            }
+        )
+    }
+
+    /// Generates the call builder of the ink! trait definition.
+    ///
+    /// # Note
+    ///
+    /// The call builder is named after the trait suffixed with `CallBuilder`,
+    /// e.g. `Erc20CallBuilder`, and builds calls to the ink! trait messages of
+    /// any contract implementing the ink! trait. It is only generated for builds
+    /// with the `ink-as-dependency` crate feature, i.e. for contracts that depend
+    /// on the crate defining the ink! trait. Calls to ink! trait constructors
+    /// are not supported.
+    fn generate_call_builder(&self) -> Option<TokenStream2> {
+        if !self.trait_def.generics().params.is_empty() {
+            return None
+        }
+        let span = self.trait_def.span();
+        let trait_ident = self.trait_def.ident();
+        let builder_ident = format_ident!("{}CallBuilder", trait_ident);
+        let doc = format!(
+            "Builds calls to the ink! messages of any contract implementing [`{}`].",
+            trait_ident
+        );
+        let messages = self.trait_def.call_builder_messages().into_iter().map(
+            |(message, selector)| Self::generate_call_builder_message(message, selector),
+        );
+        Some(quote_spanned!(span =>
+            #[doc = #doc]
+            #[cfg(feature = "ink-as-dependency")]
+            pub struct #builder_ident<E: ::ink_env::Environment = ::ink_env::DefaultEnvironment> {
+                account_id: E::AccountId,
+            }
+
+            #[cfg(feature = "ink-as-dependency")]
+            impl<E: ::ink_env::Environment> #builder_ident<E> {
+                /// Creates a call builder for the contract with the given account ID.
+                #[inline]
+                pub fn new(account_id: E::AccountId) -> Self {
+                    Self { account_id }
+                }
+
+                /// Returns the account ID of the called contract.
+                #[inline]
+                pub fn account_id(&self) -> &E::AccountId {
+                    &self.account_id
+                }
+
+                #( #messages )*
+            }
+        ))
+    }
 }
 
 impl GenerateCode for TraitDefinition<'_> {
@@ -94,13 +212,22 @@ impl GenerateCode for TraitDefinition<'_> {
             .iter_items()
             .flat_map(ir::InkTraitItem::filter_map_message)
             .map(Self::generate_for_message);
+        let consts = self.trait_def.iter_consts();
+        let types = self.trait_def.iter_types();
+        let generics = self.trait_def.generics();
+        let where_clause = &generics.where_clause;
+        let call_builder = self.generate_call_builder();
         quote_spanned!(span =>
             #(#attrs)*
-            pub trait #ident: ::ink_lang::CheckedInkTrait<[(); #verify_hash_id]> {
+            pub trait #ident #generics: ::ink_lang::CheckedInkTrait<[(); #verify_hash_id]>
+            #where_clause
+            {
                 #[doc(hidden)]
                 #[allow(non_camel_case_types)]
                 type __ink_Checksum: #helper_ident;
 
+                #(#types)*
+                #(#consts)*
                 #(#constructors)*
                 #(#messages)*
             }
@@ -112,6 +239,8 @@ impl GenerateCode for TraitDefinition<'_> {
             const _: () = {
                 unsafe impl #helper_ident for [(); #verify_hash_id] {}
             };
+
+            #call_builder
         )
     }
 }
@@ -31,7 +31,7 @@ use syn::{
 ///
 /// These are the segments `env = ::my::env::Environment` and `compile_as_dependency = true`
 /// in `#[ink::contract(env = ::my::env::Environment, compile_as_dependency = true`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct AttributeArgs {
     args: Punctuated<MetaNameValue, Token![,]>,
 }
@@ -40,7 +40,7 @@ pub struct AttributeArgs {
 ///
 /// The only difference from `syn::MetaNameValue` is that this additionally
 /// allows the `value` to be a plain identifier or path.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MetaNameValue {
     pub name: syn::Path,
     pub eq_token: syn::token::Eq,
@@ -48,12 +48,19 @@ pub struct MetaNameValue {
 }
 
 /// Either a path or a literal.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PathOrLit {
     Path(syn::Path),
     Lit(syn::Lit),
 }
 
+impl AttributeArgs {
+    /// Returns an iterator over all name-value pairs of the attribute arguments.
+    pub fn iter(&self) -> syn::punctuated::Iter<'_, MetaNameValue> {
+        self.args.iter()
+    }
+}
+
 impl IntoIterator for AttributeArgs {
     type Item = MetaNameValue;
     type IntoIter = syn::punctuated::IntoIter<MetaNameValue>;
@@ -0,0 +1,345 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ir,
+    ir::utils,
+};
+use std::collections::BTreeMap;
+
+/// The kind of an ink! item that is part of the ABI of an ink! smart contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AbiItemKind {
+    /// An ink! constructor.
+    Constructor,
+    /// An ink! message.
+    Message,
+    /// An ink! event.
+    Event,
+}
+
+/// The ABI relevant signature of an ink! constructor, message or event.
+///
+/// # Note
+///
+/// Types are stored in their compact textual form and are compared
+/// syntactically, e.g. `u32` and `core::primitive::u32` are considered to be
+/// different types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiSignature {
+    /// The kind of the ink! item.
+    kind: AbiItemKind,
+    /// The composed selector of ink! constructors and messages.
+    selector: Option<ir::Selector>,
+    /// The name of the ink! item.
+    name: String,
+    /// The types of the inputs of ink! constructors and messages or the fields
+    /// of ink! events, in order.
+    ///
+    /// Topic fields of ink! events are prefixed with `#[topic]`.
+    inputs: Vec<String>,
+    /// The return type of ink! messages if any.
+    output: Option<String>,
+    /// `true` if the ink! message mutates the storage.
+    mutates: bool,
+    /// `true` if the ink! constructor or message is payable.
+    payable: bool,
+}
+
+impl AbiSignature {
+    /// Returns the ABI signature of the given ink! constructor.
+    pub fn from_constructor(
+        constructor: &ir::CallableWithSelector<ir::Constructor>,
+    ) -> Self {
+        Self::from_callable(AbiItemKind::Constructor, constructor, None, false)
+    }
+
+    /// Returns the ABI signature of the given ink! message.
+    pub fn from_message(message: &ir::CallableWithSelector<ir::Message>) -> Self {
+        Self::from_callable(
+            AbiItemKind::Message,
+            message,
+            message.output(),
+            message.mutates(),
+        )
+    }
+
+    /// Returns the ABI signature of the given ink! constructor or message.
+    fn from_callable<C>(
+        kind: AbiItemKind,
+        callable: &ir::CallableWithSelector<C>,
+        output: Option<&syn::Type>,
+        mutates: bool,
+    ) -> Self
+    where
+        C: ir::Callable,
+    {
+        use ir::Callable as _;
+        Self {
+            kind,
+            selector: Some(callable.composed_selector()),
+            name: callable.ident().to_string(),
+            inputs: callable
+                .inputs_iter()
+                .map(|input| utils::type_to_string(&input.ty))
+                .collect(),
+            output: output.map(utils::type_to_string),
+            mutates,
+            payable: callable.is_payable(),
+        }
+    }
+
+    /// Returns the kind of the ink! item.
+    pub fn kind(&self) -> AbiItemKind {
+        self.kind
+    }
+
+    /// Returns the composed selector of ink! constructors and messages.
+    ///
+    /// Returns `None` for ink! events.
+    pub fn selector(&self) -> Option<ir::Selector> {
+        self.selector
+    }
+
+    /// Returns the name of the ink! item.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the input types of ink! constructors and messages or the field
+    /// types of ink! events.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the return type of ink! messages if any.
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+
+    /// Returns the name of the ink! item followed by its comma separated input
+    /// types in parentheses, e.g. `"transfer(AccountId,Balance)"`.
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.inputs.join(","))
+    }
+
+    /// Returns `true` if the ink! message mutates the storage.
+    pub fn mutates(&self) -> bool {
+        self.mutates
+    }
+
+    /// Returns `true` if the ink! constructor or message is payable.
+    pub fn is_payable(&self) -> bool {
+        self.payable
+    }
+
+    /// Returns the key under which two signatures are matched up.
+    ///
+    /// ink! constructors and messages are matched by selector while ink!
+    /// events are matched by name.
+    fn key(&self) -> (AbiItemKind, String) {
+        let key = match self.selector {
+            Some(selector) => {
+                let [b0, b1, b2, b3] = *selector.as_bytes();
+                format!("0x{:02X}{:02X}{:02X}{:02X}", b0, b1, b2, b3)
+            }
+            None => self.name.clone(),
+        };
+        (self.kind, key)
+    }
+}
+
+/// A single way in which the signature of an ink! item has changed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AbiChange {
+    /// The ink! item has been renamed while its selector was preserved.
+    Renamed,
+    /// The input types of an ink! constructor or message or the fields of an
+    /// ink! event changed.
+    InputsChanged,
+    /// The return type of an ink! message changed.
+    OutputChanged,
+    /// An ink! message changed between `&self` and `&mut self`.
+    MutabilityChanged,
+    /// An ink! constructor or message changed its payability.
+    PayabilityChanged,
+}
+
+/// An ink! item present in both ABIs with a differing signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiModification {
+    /// The signature in the original ABI.
+    old: AbiSignature,
+    /// The signature in the new ABI.
+    new: AbiSignature,
+    /// The classified changes in the order of [`AbiChange`].
+    changes: Vec<AbiChange>,
+}
+
+impl AbiModification {
+    /// Returns the signature in the original ABI.
+    pub fn before(&self) -> &AbiSignature {
+        &self.old
+    }
+
+    /// Returns the signature in the new ABI.
+    pub fn after(&self) -> &AbiSignature {
+        &self.new
+    }
+
+    /// Returns the classified changes between both signatures.
+    pub fn changes(&self) -> &[AbiChange] {
+        &self.changes
+    }
+}
+
+/// The difference between the ABIs of two ink! smart contracts.
+///
+/// Computed by [`ir::Contract::abi_diff`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AbiDiff {
+    /// The ink! items only present in the new ABI.
+    added: Vec<AbiSignature>,
+    /// The ink! items only present in the original ABI.
+    removed: Vec<AbiSignature>,
+    /// The ink! items present in both ABIs with differing signatures.
+    changed: Vec<AbiModification>,
+}
+
+impl AbiDiff {
+    /// Computes the difference from the `old` to the `new` ink! smart contract.
+    pub(super) fn new(old: &ir::Contract, new: &ir::Contract) -> Self {
+        let mut old = abi_signatures(old);
+        let mut diff = Self::default();
+        for (key, new) in abi_signatures(new) {
+            match old.remove(&key) {
+                Some(old) => {
+                    let changes = classify_changes(&old, &new);
+                    if !changes.is_empty() {
+                        diff.changed.push(AbiModification { old, new, changes })
+                    }
+                }
+                None => diff.added.push(new),
+            }
+        }
+        diff.removed.extend(old.into_iter().map(|entry| entry.1));
+        diff
+    }
+
+    /// Returns the ink! items only present in the new ABI.
+    pub fn added(&self) -> &[AbiSignature] {
+        &self.added
+    }
+
+    /// Returns the ink! items only present in the original ABI.
+    pub fn removed(&self) -> &[AbiSignature] {
+        &self.removed
+    }
+
+    /// Returns the ink! items present in both ABIs with differing signatures.
+    pub fn changed(&self) -> &[AbiModification] {
+        &self.changed
+    }
+
+    /// Returns `true` if both ABIs are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Returns `true` if callers of the original ABI can use the new ABI.
+    ///
+    /// # Note
+    ///
+    /// This is the case if no ink! items have been removed or changed while
+    /// additions are allowed.
+    pub fn is_compatible(&self) -> bool {
+        self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Returns the ABI signatures of all ink! constructors and messages of the
+/// ink! smart contract in the order of their definition.
+///
+/// Test-only ink! messages are not part of the ABI.
+pub(super) fn callable_signatures(
+    contract: &ir::Contract,
+) -> impl Iterator<Item = AbiSignature> + '_ {
+    contract.module().impls().flat_map(|item_impl| {
+        let constructors = item_impl
+            .iter_constructors()
+            .map(|constructor| AbiSignature::from_constructor(&constructor));
+        let messages = item_impl
+            .iter_messages()
+            .filter(|message| !message.is_test_only())
+            .map(|message| AbiSignature::from_message(&message));
+        constructors.chain(messages)
+    })
+}
+
+/// Returns the ABI signatures of all ink! constructors, messages and events
+/// of the ink! smart contract.
+///
+/// Test-only ink! messages are not part of the ABI.
+fn abi_signatures(
+    contract: &ir::Contract,
+) -> BTreeMap<(AbiItemKind, String), AbiSignature> {
+    let mut signatures = callable_signatures(contract).collect::<Vec<_>>();
+    for event in contract.module().events() {
+        let inputs = event
+            .fields()
+            .map(|field| {
+                let ty = utils::type_to_string(field.ty());
+                if field.is_topic {
+                    format!("#[topic] {}", ty)
+                } else {
+                    ty
+                }
+            })
+            .collect();
+        signatures.push(AbiSignature {
+            kind: AbiItemKind::Event,
+            selector: None,
+            name: event.ident().to_string(),
+            inputs,
+            output: None,
+            mutates: false,
+            payable: false,
+        });
+    }
+    signatures
+        .into_iter()
+        .map(|signature| (signature.key(), signature))
+        .collect()
+}
+
+/// Classifies the changes between two signatures of the same ink! item.
+fn classify_changes(old: &AbiSignature, new: &AbiSignature) -> Vec<AbiChange> {
+    let mut changes = Vec::new();
+    if old.name != new.name {
+        changes.push(AbiChange::Renamed)
+    }
+    if old.inputs != new.inputs {
+        changes.push(AbiChange::InputsChanged)
+    }
+    if old.output != new.output {
+        changes.push(AbiChange::OutputChanged)
+    }
+    if old.mutates != new.mutates {
+        changes.push(AbiChange::MutabilityChanged)
+    }
+    if old.payable != new.payable {
+        changes.push(AbiChange::PayabilityChanged)
+    }
+    changes
+}
@@ -0,0 +1,340 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error::ExtError as _,
+    ir,
+    ir::AttributeArgKind,
+};
+use syn::spanned::Spanned as _;
+
+/// The kinds of items within the ink! module that ink! attributes can be applied to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttributeTarget {
+    /// A `struct` item.
+    Struct,
+    /// An `enum` item.
+    Enum,
+    /// A `union` item.
+    Union,
+    /// A free `fn` item.
+    Function,
+    /// A `const` item.
+    Const,
+    /// A `static` item.
+    Static,
+    /// A `type` alias item.
+    TypeAlias,
+    /// A `trait` or trait alias item.
+    Trait,
+    /// A `mod` item.
+    Module,
+    /// A `use` declaration.
+    Use,
+    /// An `extern crate` item.
+    ExternCrate,
+    /// An `extern` block.
+    ForeignMod,
+    /// A macro invocation or definition.
+    Macro,
+    /// An `impl` block.
+    Impl,
+    /// A method within an `impl` block.
+    Method,
+    /// An associated constant within an `impl` block.
+    AssocConst,
+    /// An associated type within an `impl` block.
+    AssocType,
+    /// A field of the ink! storage struct.
+    StorageField,
+    /// A field of an ink! event struct.
+    EventField,
+    /// An input of an ink! message or constructor.
+    Input,
+    /// Any other kind of item.
+    Other,
+}
+
+impl core::fmt::Display for AttributeTarget {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let name = match self {
+            Self::Struct => "a struct",
+            Self::Enum => "an enum",
+            Self::Union => "a union",
+            Self::Function => "a function",
+            Self::Const => "a constant",
+            Self::Static => "a static",
+            Self::TypeAlias => "a type alias",
+            Self::Trait => "a trait",
+            Self::Module => "a module",
+            Self::Use => "a use declaration",
+            Self::ExternCrate => "an extern crate",
+            Self::ForeignMod => "an extern block",
+            Self::Macro => "a macro",
+            Self::Impl => "an impl block",
+            Self::Method => "a method",
+            Self::AssocConst => "an associated constant",
+            Self::AssocType => "an associated type",
+            Self::StorageField => "a storage field",
+            Self::EventField => "an event field",
+            Self::Input => "an input",
+            Self::Other => "this item",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl AttributeTarget {
+    /// Returns the kind of the given item of the ink! module.
+    pub fn of_item(item: &syn::Item) -> Self {
+        match item {
+            syn::Item::Struct(_) => Self::Struct,
+            syn::Item::Enum(_) => Self::Enum,
+            syn::Item::Union(_) => Self::Union,
+            syn::Item::Fn(_) => Self::Function,
+            syn::Item::Const(_) => Self::Const,
+            syn::Item::Static(_) => Self::Static,
+            syn::Item::Type(_) => Self::TypeAlias,
+            syn::Item::Trait(_) | syn::Item::TraitAlias(_) => Self::Trait,
+            syn::Item::Mod(_) => Self::Module,
+            syn::Item::Use(_) => Self::Use,
+            syn::Item::ExternCrate(_) => Self::ExternCrate,
+            syn::Item::ForeignMod(_) => Self::ForeignMod,
+            syn::Item::Macro(_) | syn::Item::Macro2(_) => Self::Macro,
+            syn::Item::Impl(_) => Self::Impl,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns the kind of the given item of an `impl` block.
+    pub fn of_impl_item(impl_item: &syn::ImplItem) -> Self {
+        match impl_item {
+            syn::ImplItem::Method(_) => Self::Method,
+            syn::ImplItem::Const(_) => Self::AssocConst,
+            syn::ImplItem::Type(_) => Self::AssocType,
+            syn::ImplItem::Macro(_) => Self::Macro,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Ensures that all arguments of the given ink! attributes can be applied to
+/// the given kind of item.
+///
+/// # Errors
+///
+/// Returns a combined error for every ink! attribute argument that cannot be
+/// applied to the kind of item, e.g. `#[ink(constructor)] cannot be applied
+/// to a struct`.
+pub fn ensure_applicable(
+    ink_attrs: &[ir::InkAttribute],
+    target: AttributeTarget,
+) -> Result<(), syn::Error> {
+    ink_attrs
+        .iter()
+        .flat_map(ir::InkAttribute::args)
+        .filter_map(|arg| {
+            let kind = arg.kind().kind();
+            if kind.targets().contains(&target) {
+                return None
+            }
+            Some(format_err!(
+                arg.span(),
+                "#[ink({})] cannot be applied to {}",
+                kind.name(),
+                target,
+            ))
+        })
+        .reduce(syn::Error::into_combine)
+        .map_or(Ok(()), Err)
+}
+
+/// Returns the reason why the given ink! attribute arguments are mutually
+/// exclusive or `None` if they can be combined.
+///
+/// # Note
+///
+/// This is the single source of truth for invalid combinations of ink! attribute
+/// arguments. The order of both arguments does not matter.
+fn exclusion_reason(
+    first: &AttributeArgKind,
+    second: &AttributeArgKind,
+) -> Option<&'static str> {
+    use AttributeArgKind::*;
+    let reason = |first: &AttributeArgKind, second: &AttributeArgKind| {
+        match (first, second) {
+            (Storage, Event) => {
+                Some("the ink! storage struct cannot be an ink! event at the same time")
+            }
+            (Storage, Anonymous) => Some("only ink! events can be anonymous"),
+            (Message, Constructor) => {
+                Some("an ink! message cannot be an ink! constructor at the same time")
+            }
+            (Getter, RawReturn) => {
+                Some("ink! getter messages return the SCALE encoded storage field")
+            }
+            (TestOnly, Standard) => {
+                Some("test-only ink! messages are not part of any standard interface")
+            }
+            _ => None,
+        }
+    };
+    reason(first, second).or_else(|| reason(second, first))
+}
+
+/// Ensures that the given ink! attribute contains no mutually exclusive
+/// arguments.
+///
+/// # Errors
+///
+/// Returns a combined error for every invalid combination, e.g. `#[ink(anonymous)]
+/// cannot be combined with #[ink(storage)]: only ink! events can be anonymous`.
+pub fn ensure_valid_combinations(ink_attr: &ir::InkAttribute) -> Result<(), syn::Error> {
+    let args = ink_attr.args().collect::<Vec<_>>();
+    args.iter()
+        .enumerate()
+        .flat_map(|(n, second)| {
+            args[..n].iter().filter_map(move |first| {
+                let first_kind = first.kind().kind();
+                let second_kind = second.kind().kind();
+                exclusion_reason(&first_kind, &second_kind).map(|reason| {
+                    format_err!(
+                        second.span(),
+                        "#[ink({})] cannot be combined with #[ink({})]: {}",
+                        second_kind.name(),
+                        first_kind.name(),
+                        reason,
+                    )
+                })
+            })
+        })
+        .reduce(syn::Error::into_combine)
+        .map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    fn assert_applicable(
+        attrs: Vec<syn::Attribute>,
+        target: AttributeTarget,
+        expected: Result<(), &str>,
+    ) {
+        let (ink_attrs, _) = ir::partition_attributes(attrs).unwrap();
+        assert_eq!(
+            ensure_applicable(&ink_attrs, target).map_err(|err| err.to_string()),
+            expected.map_err(ToString::to_string),
+        );
+    }
+
+    #[test]
+    fn applicable_attributes_work() {
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(storage, zeroed)] }],
+            AttributeTarget::Struct,
+            Ok(()),
+        );
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(message, payable, selector = "0xCAFEBABE")] }],
+            AttributeTarget::Method,
+            Ok(()),
+        );
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(topic)] }],
+            AttributeTarget::EventField,
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn constructor_on_struct_fails() {
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(constructor)] }],
+            AttributeTarget::Struct,
+            Err("#[ink(constructor)] cannot be applied to a struct"),
+        );
+    }
+
+    #[test]
+    fn topic_on_storage_field_fails() {
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(topic)] }],
+            AttributeTarget::StorageField,
+            Err("#[ink(topic)] cannot be applied to a storage field"),
+        );
+    }
+
+    #[test]
+    fn payable_on_function_fails() {
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(payable)] }],
+            AttributeTarget::of_item(&syn::parse_quote! { fn free_function() {} }),
+            Err("#[ink(payable)] cannot be applied to a function"),
+        );
+    }
+
+    #[test]
+    fn extension_in_ink_module_fails() {
+        assert_applicable(
+            vec![syn::parse_quote! { #[ink(extension = 1)] }],
+            AttributeTarget::Method,
+            Err("#[ink(extension)] cannot be applied to a method"),
+        );
+    }
+
+    fn assert_combinations(attr: syn::Attribute, expected: Result<(), &str>) {
+        let ink_attr = ir::InkAttribute::try_from(attr).unwrap();
+        assert_eq!(
+            ensure_valid_combinations(&ink_attr).map_err(|err| err.to_string()),
+            expected.map_err(ToString::to_string),
+        );
+    }
+
+    #[test]
+    fn valid_combinations_work() {
+        assert_combinations(syn::parse_quote! { #[ink(event, anonymous)] }, Ok(()));
+        assert_combinations(
+            syn::parse_quote! { #[ink(message, payable, selector = "0xCAFEBABE")] },
+            Ok(()),
+        );
+        assert_combinations(
+            syn::parse_quote! { #[ink(message, getter = "value")] },
+            Ok(()),
+        );
+    }
+
+    #[test]
+    fn anonymous_storage_fails() {
+        assert_combinations(
+            syn::parse_quote! { #[ink(storage, anonymous)] },
+            Err(
+                "#[ink(anonymous)] cannot be combined with #[ink(storage)]: \
+                 only ink! events can be anonymous",
+            ),
+        );
+    }
+
+    #[test]
+    fn getter_with_raw_return_fails() {
+        assert_combinations(
+            syn::parse_quote! { #[ink(message, raw_return, getter = "value")] },
+            Err(
+                "#[ink(getter)] cannot be combined with #[ink(raw_return)]: \
+                 ink! getter messages return the SCALE encoded storage field",
+            ),
+        );
+    }
+}
@@ -246,12 +246,130 @@ impl InkAttribute {
         })
     }
 
+    /// Returns the name of the constant given as selector, e.g. via
+    /// `selector = MY_SELECTOR`, if any.
+    pub fn selector_const(&self) -> Option<&Ident> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::SelectorConst(ident) = arg.kind() {
+                return Some(ident)
+            }
+            None
+        })
+    }
+
+    /// Returns `true` if the ink! attribute contains the wildcard selector
+    /// argument `selector = _`.
+    pub fn has_wildcard_selector(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::WildcardSelector))
+    }
+
     /// Returns `true` if the ink! attribute contains the `payable` argument.
     pub fn is_payable(&self) -> bool {
         self.args()
             .any(|arg| matches!(arg.kind(), AttributeArg::Payable))
     }
 
+    /// Returns the name of the storage field of an ink! getter if any.
+    pub fn getter(&self) -> Option<Ident> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Getter(field) = arg.kind() {
+                return Some(field.clone())
+            }
+            None
+        })
+    }
+
+    /// Returns `true` if the ink! attribute contains the `test_only` argument.
+    pub fn is_test_only(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::TestOnly))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `hidden` argument.
+    pub fn is_hidden(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::Hidden))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `zeroed` argument.
+    pub fn is_zeroed(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::Zeroed))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `manual_layout` argument.
+    pub fn is_manual_layout(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::ManualLayout))
+    }
+
+    /// Returns `true` if the ink! attribute contains the `raw_return` argument.
+    pub fn is_raw_return(&self) -> bool {
+        self.args()
+            .any(|arg| matches!(arg.kind(), AttributeArg::RawReturn))
+    }
+
+    /// Returns the explicit storage key of the ink! attribute if any.
+    pub fn storage_key(&self) -> Option<ir::StorageKey> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::StorageKey(storage_key) = arg.kind() {
+                return Some(*storage_key)
+            }
+            None
+        })
+    }
+
+    /// Returns the weight hint of the ink! attribute if any.
+    pub fn weight(&self) -> Option<u64> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Weight(weight) = arg.kind() {
+                return Some(*weight)
+            }
+            None
+        })
+    }
+
+    /// Returns the storage version of the ink! attribute if any.
+    pub fn version(&self) -> Option<u32> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Version(version) = arg.kind() {
+                return Some(*version)
+            }
+            None
+        })
+    }
+
+    /// Returns the standard interface identifier of the ink! attribute if any.
+    pub fn standard(&self) -> Option<&str> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Standard(standard) = arg.kind() {
+                return Some(standard.as_str())
+            }
+            None
+        })
+    }
+
+    /// Returns the mutability override of the ink! attribute if any.
+    pub fn mutates(&self) -> Option<bool> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Mutates(mutates) = arg.kind() {
+                return Some(*mutates)
+            }
+            None
+        })
+    }
+
+    /// Returns the advisory default value of the ink! attribute if any.
+    pub fn default_value(&self) -> Option<&str> {
+        self.args().find_map(|arg| {
+            if let ir::AttributeArg::Default(value) = arg.kind() {
+                return Some(value.as_str())
+            }
+            None
+        })
+    }
+
     /// Returns `true` if the ink! attribute contains the `anonymous` argument.
     pub fn is_anonymous(&self) -> bool {
         self.args()
@@ -302,6 +420,12 @@ impl Spanned for AttributeFrag {
 pub enum AttributeArgKind {
     /// `#[ink(storage)]`
     Storage,
+    /// `#[ink(zeroed)]`
+    Zeroed,
+    /// `#[ink(manual_layout)]`
+    ManualLayout,
+    /// `#[ink(version = N: u32)]`
+    Version,
     /// `#[ink(event)]`
     Event,
     /// `#[ink(anonymous)]`
@@ -314,12 +438,30 @@ pub enum AttributeArgKind {
     Constructor,
     /// `#[ink(payable)]`
     Payable,
-    /// `#[ink(selector = "0xDEADBEEF")]`
+    /// `#[ink(test_only)]`
+    TestOnly,
+    /// `#[ink(raw_return)]`
+    RawReturn,
+    /// `#[ink(selector = "0xDEADBEEF")]` or `#[ink(selector = N: u32)]`
     Selector,
     /// `#[ink(extension = N: u32)]`
     Extension,
     /// `#[ink(namespace = "my_namespace")]`
     Namespace,
+    /// `#[ink(getter = "my_field")]`
+    Getter,
+    /// `#[ink(weight = N: u64)]`
+    Weight,
+    /// `#[ink(standard = "erc20::transfer")]`
+    Standard,
+    /// `#[ink(mutates = flag: bool)]`
+    Mutates,
+    /// `#[ink(default = value)]`
+    Default,
+    /// `#[ink(storage_key = N)]`
+    StorageKey,
+    /// `#[ink(hidden)]`
+    Hidden,
     /// `#[ink(impl)]`
     Implementation,
     /// `#[ink(handle_status = flag: bool)]`
@@ -336,6 +478,21 @@ pub enum AttributeArg {
     /// Applied on `struct` types in order to flag them for being the
     /// contract's storage definition.
     Storage,
+    /// `#[ink(zeroed)]`
+    ///
+    /// Applied on the ink! storage struct in order to generate a `new_zeroed`
+    /// associated function returning the storage with all fields set to zero.
+    Zeroed,
+    /// `#[ink(manual_layout)]`
+    ///
+    /// Applied on the ink! storage struct in order to skip the generation of
+    /// its `StorageLayout` implementation in favor of a hand-written one.
+    ManualLayout,
+    /// `#[ink(version = N: u32)]`
+    ///
+    /// Applied on the ink! storage struct in order to declare the version of
+    /// its storage layout. Storage structs without a version have version `0`.
+    Version(u32),
     /// `#[ink(event)]`
     ///
     /// Applied on `struct` types in order to flag them for being an ink! event.
@@ -367,16 +524,80 @@ pub enum AttributeArg {
     /// Applied on ink! constructors or messages in order to specify that they
     /// can receive funds from callers.
     Payable,
-    /// `#[ink(selector = "0xDEADBEEF")]`
+    /// `#[ink(test_only)]`
+    ///
+    /// Applied on ink! messages in order to only expose them in test builds.
+    TestOnly,
+    /// `#[ink(raw_return)]`
+    ///
+    /// Applied on ink! messages returning `Vec<u8>` in order to return their
+    /// bytes as they are instead of SCALE encoding them with a length prefix.
+    RawReturn,
+    /// `#[ink(selector = "0xDEADBEEF")]` or `#[ink(selector = N: u32)]`
     ///
     /// Applied on ink! constructors or messages to manually control their
-    /// selectors.
+    /// selectors. Integer selectors can be given in decimal, hexadecimal or
+    /// binary form and are interpreted as big-endian bytes, e.g. `0x0000000A`
+    /// and `10` both result in the selector `[0x00, 0x00, 0x00, 0x0A]`.
     Selector(Selector),
+    /// `#[ink(selector = _)]`
+    ///
+    /// Applied on at most one ink! constructor in order to designate it as the
+    /// fallback that is run when no other ink! constructor selector matches.
+    WildcardSelector,
+    /// `#[ink(selector = MY_SELECTOR)]`
+    ///
+    /// Applied on ink! constructors or messages in order to take their selector
+    /// from a constant of type `u32` or `[u8; 4]` defined at the root of the
+    /// ink! module. The constant is resolved by the ink! module.
+    SelectorConst(Ident),
     /// `#[ink(namespace = "my_namespace")]`
     ///
     /// Applied on ink! trait implementation blocks to disambiguate other trait
     /// implementation blocks with equal names.
     Namespace(Namespace),
+    /// `#[ink(getter = "my_field")]`
+    ///
+    /// Applied on ink! messages with an empty body in order to have their
+    /// body generated from the storage field with the given name.
+    Getter(Ident),
+    /// `#[ink(weight = N: u64)]`
+    ///
+    /// Applied on ink! messages in order to provide front-ends with a static
+    /// hint of their weight for fee estimation. The hint is only emitted into
+    /// the metadata and does not affect the behavior of the ink! message.
+    Weight(u64),
+    /// `#[ink(standard = "erc20::transfer")]`
+    ///
+    /// Applied on ink! messages in order to tag them as the entry of a standard
+    /// interface so that wallets are able to recognize it. The tag is only
+    /// emitted into the metadata and does not affect the ink! message.
+    Standard(String),
+    /// `#[ink(mutates = flag: bool)]`
+    ///
+    /// Applied on ink! messages in order to override whether they are advertised
+    /// as mutating the contract storage in the metadata regardless of their
+    /// receiver. This does not affect the behavior of the ink! message.
+    Mutates(bool),
+    /// `#[ink(default = value)]`
+    ///
+    /// Applied on inputs of ink! messages and constructors in order to provide
+    /// front-ends with a default value to prefill them with. The default value
+    /// is only emitted into the metadata and callers still have to provide all
+    /// inputs.
+    Default(String),
+    /// `#[ink(storage_key = N)]`
+    ///
+    /// Applied on fields of the ink! storage struct in order to pin their
+    /// storage cells to the given key instead of deriving it from their
+    /// position within the ink! storage struct.
+    StorageKey(StorageKey),
+    /// `#[ink(hidden)]`
+    ///
+    /// Applied on fields of the ink! storage struct in order to exclude them
+    /// from the generated metadata. Hidden fields are still part of the
+    /// on-chain storage layout of the ink! storage struct.
+    Hidden,
     /// `#[ink(impl)]`
     ///
     /// This attribute supports a niche case that is rarely needed.
@@ -410,28 +631,83 @@ pub enum AttributeArg {
     ReturnsResult(bool),
 }
 
+impl AttributeArgKind {
+    /// Returns the name of the ink! attribute argument kind, its parameter if any
+    /// and the kinds of items within the ink! module it can be applied to.
+    ///
+    /// # Note
+    ///
+    /// This is the single source of truth for the properties of ink! attribute
+    /// arguments. The `match` is exhaustive so that new ink! attribute arguments
+    /// cannot be added without deciding where they apply.
+    ///
+    /// Only the kind of the item is taken into account. Whether an applicable
+    /// argument is valid for the particular item, e.g. `#[ink(payable)]` on an
+    /// ink! constructor, is still checked by the respective ink! item. The methods
+    /// of ink! trait definitions and chain extensions are validated by those on
+    /// their own.
+    fn spec(
+        &self,
+    ) -> (
+        &'static str,
+        Option<&'static str>,
+        &'static [ir::AttributeTarget],
+    ) {
+        use ir::AttributeTarget::*;
+        match self {
+            Self::Storage => ("storage", None, &[Struct]),
+            Self::Zeroed => ("zeroed", None, &[Struct]),
+            Self::ManualLayout => ("manual_layout", None, &[Struct]),
+            Self::Version => ("version", Some("N:u32"), &[Struct]),
+            Self::Event => ("event", None, &[Struct]),
+            Self::Anonymous => ("anonymous", None, &[Struct]),
+            Self::Topic => ("topic", None, &[EventField]),
+            Self::Message => ("message", None, &[Method]),
+            Self::Constructor => ("constructor", None, &[Method]),
+            Self::Payable => ("payable", None, &[Method]),
+            Self::TestOnly => ("test_only", None, &[Method]),
+            Self::RawReturn => ("raw_return", None, &[Method]),
+            Self::Selector => ("selector", Some("S:[u8; 4]"), &[Method]),
+            Self::Extension => ("extension", Some("N:u32"), &[]),
+            Self::Namespace => ("namespace", Some("N:string"), &[Impl]),
+            Self::Getter => ("getter", Some("F:string"), &[Method]),
+            Self::Weight => ("weight", Some("N:u64"), &[Method]),
+            Self::Standard => ("standard", Some("S:string"), &[Method]),
+            Self::Mutates => ("mutates", Some("flag:bool"), &[Method]),
+            Self::Default => ("default", Some("value"), &[Input]),
+            Self::StorageKey => ("storage_key", Some("N"), &[StorageField]),
+            Self::Hidden => ("hidden", None, &[StorageField]),
+            Self::Implementation => ("impl", None, &[Impl]),
+            Self::HandleStatus => ("handle_status", Some("flag:bool"), &[]),
+            Self::ReturnsResult => ("returns_result", Some("flag:bool"), &[]),
+        }
+    }
+
+    /// Returns the name of the ink! attribute argument kind, e.g. `selector`.
+    pub fn name(&self) -> &'static str {
+        self.spec().0
+    }
+
+    /// Returns `true` if the ink! attribute argument has no parameter.
+    ///
+    /// Only such ink! attribute arguments can be written in the path form
+    /// `#[ink::<kind>]`.
+    pub fn is_flag(&self) -> bool {
+        self.spec().1.is_none()
+    }
+
+    /// Returns the kinds of items within the ink! module the ink! attribute
+    /// argument can be applied to.
+    pub fn targets(&self) -> &'static [ir::AttributeTarget] {
+        self.spec().2
+    }
+}
+
 impl core::fmt::Display for AttributeArgKind {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
-        match self {
-            Self::Storage => write!(f, "storage"),
-            Self::Event => write!(f, "event"),
-            Self::Anonymous => write!(f, "anonymous"),
-            Self::Topic => write!(f, "topic"),
-            Self::Message => write!(f, "message"),
-            Self::Constructor => write!(f, "constructor"),
-            Self::Payable => write!(f, "payable"),
-            Self::Selector => {
-                write!(f, "selector = S:[u8; 4]")
-            }
-            Self::Extension => {
-                write!(f, "extension = N:u32)")
-            }
-            Self::Namespace => {
-                write!(f, "namespace = N:string")
-            }
-            Self::Implementation => write!(f, "impl"),
-            Self::HandleStatus => write!(f, "handle_status"),
-            Self::ReturnsResult => write!(f, "returns_result"),
+        match self.spec() {
+            (name, None, _) => write!(f, "{}", name),
+            (name, Some(param), _) => write!(f, "{} = {}", name, param),
         }
     }
 }
@@ -441,15 +717,29 @@ impl AttributeArg {
     pub fn kind(&self) -> AttributeArgKind {
         match self {
             Self::Storage => AttributeArgKind::Storage,
+            Self::Zeroed => AttributeArgKind::Zeroed,
+            Self::ManualLayout => AttributeArgKind::ManualLayout,
+            Self::Version(_) => AttributeArgKind::Version,
             Self::Event => AttributeArgKind::Event,
             Self::Anonymous => AttributeArgKind::Anonymous,
             Self::Topic => AttributeArgKind::Topic,
             Self::Message => AttributeArgKind::Message,
             Self::Constructor => AttributeArgKind::Constructor,
             Self::Payable => AttributeArgKind::Payable,
-            Self::Selector(_) => AttributeArgKind::Selector,
+            Self::TestOnly => AttributeArgKind::TestOnly,
+            Self::RawReturn => AttributeArgKind::RawReturn,
+            Self::Selector(_) | Self::WildcardSelector | Self::SelectorConst(_) => {
+                AttributeArgKind::Selector
+            }
             Self::Extension(_) => AttributeArgKind::Extension,
             Self::Namespace(_) => AttributeArgKind::Namespace,
+            Self::Getter(_) => AttributeArgKind::Getter,
+            Self::Weight(_) => AttributeArgKind::Weight,
+            Self::Standard(_) => AttributeArgKind::Standard,
+            Self::Mutates(_) => AttributeArgKind::Mutates,
+            Self::Default(_) => AttributeArgKind::Default,
+            Self::StorageKey(_) => AttributeArgKind::StorageKey,
+            Self::Hidden => AttributeArgKind::Hidden,
             Self::Implementation => AttributeArgKind::Implementation,
             Self::HandleStatus(_) => AttributeArgKind::HandleStatus,
             Self::ReturnsResult(_) => AttributeArgKind::ReturnsResult,
@@ -461,21 +751,37 @@ impl core::fmt::Display for AttributeArg {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         match self {
             Self::Storage => write!(f, "storage"),
+            Self::Zeroed => write!(f, "zeroed"),
+            Self::ManualLayout => write!(f, "manual_layout"),
+            Self::Version(version) => write!(f, "version = {:?}", version),
             Self::Event => write!(f, "event"),
             Self::Anonymous => write!(f, "anonymous"),
             Self::Topic => write!(f, "topic"),
             Self::Message => write!(f, "message"),
             Self::Constructor => write!(f, "constructor"),
             Self::Payable => write!(f, "payable"),
+            Self::TestOnly => write!(f, "test_only"),
+            Self::RawReturn => write!(f, "raw_return"),
             Self::Selector(selector) => {
                 write!(f, "selector = {:?}", selector.as_bytes())
             }
+            Self::WildcardSelector => write!(f, "selector = _"),
+            Self::SelectorConst(ident) => write!(f, "selector = {}", ident),
             Self::Extension(extension) => {
                 write!(f, "extension = {:?}", extension.into_u32())
             }
             Self::Namespace(namespace) => {
                 write!(f, "namespace = {:?}", namespace.as_bytes())
             }
+            Self::Getter(field) => write!(f, "getter = {:?}", field.to_string()),
+            Self::Weight(weight) => write!(f, "weight = {:?}", weight),
+            Self::Standard(standard) => write!(f, "standard = {:?}", standard),
+            Self::Mutates(value) => write!(f, "mutates = {:?}", value),
+            Self::Default(value) => write!(f, "default = {:?}", value),
+            Self::StorageKey(storage_key) => {
+                write!(f, "storage_key = {:?}", storage_key.as_bytes())
+            }
+            Self::Hidden => write!(f, "hidden"),
             Self::Implementation => write!(f, "impl"),
             Self::HandleStatus(value) => write!(f, "handle_status = {:?}", value),
             Self::ReturnsResult(value) => write!(f, "returns_result = {:?}", value),
@@ -503,8 +809,74 @@ impl Namespace {
     }
 }
 
+/// An explicit storage key of an ink! storage field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StorageKey {
+    /// The underlying bytes in little-endian byte order.
+    bytes: [u8; 32],
+}
+
+impl StorageKey {
+    /// Creates a storage key from the given decimal digits.
+    ///
+    /// Returns `None` if the number exceeds the 256-bit width of storage keys.
+    fn from_base10_digits(digits: &str) -> Option<Self> {
+        let mut bytes = [0x00_u8; 32];
+        for digit in digits.chars() {
+            let mut carry = digit.to_digit(10)?;
+            for byte in bytes.iter_mut() {
+                let value = u32::from(*byte) * 10 + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+            if carry != 0 {
+                return None
+            }
+        }
+        Some(Self { bytes })
+    }
+
+    /// Returns the storage key as bytes in little-endian byte order.
+    ///
+    /// # Note
+    ///
+    /// This is the byte order used by `ink_primitives::Key`.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.bytes
+    }
+}
+
+/// Returns the ink! attribute argument of the given attribute path if it is
+/// of the path form, e.g. `ink::message` for `#[ink::message]`.
+///
+/// # Note
+///
+/// Only ink! attribute arguments without a parameter can be written in the
+/// path form. Other paths under `ink`, e.g. `ink::test`, are not ink! attributes
+/// but refer to the procedural macros of ink! and are therefore not recognized.
+fn ink_path_attribute_kind(path: &syn::Path) -> Option<&Ident> {
+    if path.leading_colon.is_some() || path.segments.len() != 2 {
+        return None
+    }
+    let (ink, kind) = (&path.segments[0], &path.segments[1]);
+    if ink.ident != "ink" || !ink.arguments.is_empty() || !kind.arguments.is_empty() {
+        return None
+    }
+    let meta = syn::Meta::Path(syn::Path::from(kind.ident.clone()));
+    AttributeFrag::try_from(syn::NestedMeta::Meta(meta))
+        .ok()
+        .filter(|frag| frag.kind().kind().is_flag())
+        .map(|_| &kind.ident)
+}
+
+/// Returns `true` if the given attribute is an ink! attribute of the form
+/// `#[ink(..)]`, `#[ink]` or `#[ink::<kind>]`.
+fn is_ink_attribute(attr: &syn::Attribute) -> bool {
+    attr.path.is_ident("ink") || ink_path_attribute_kind(&attr.path).is_some()
+}
+
 /// Returns `true` if the given iterator yields at least one attribute of the form
-/// `#[ink(..)]` or `#[ink]`.
+/// `#[ink(..)]`, `#[ink]` or `#[ink::<kind>]`.
 ///
 /// # Note
 ///
@@ -514,7 +886,11 @@ pub fn contains_ink_attributes<'a, I>(attrs: I) -> bool
 where
     I: IntoIterator<Item = &'a syn::Attribute>,
 {
-    attrs.into_iter().any(|attr| attr.path.is_ident("ink"))
+    attrs.into_iter().any(|attr| {
+        is_ink_attribute(attr)
+            || (attr.path.is_ident("cfg_attr")
+                && !unwrap_cfg_attr(attr.clone()).0.is_empty())
+    })
 }
 
 /// Returns the first valid ink! attribute, if any.
@@ -530,15 +906,111 @@ pub fn first_ink_attribute<'a, I>(
 where
     I: IntoIterator<Item = &'a syn::Attribute>,
 {
-    let first = attrs.into_iter().find(|attr| attr.path.is_ident("ink"));
+    let first = expand_cfg_attrs(attrs.into_iter().cloned())
+        .into_iter()
+        .find(is_ink_attribute);
     match first {
         None => Ok(None),
-        Some(ink_attr) => InkAttribute::try_from(ink_attr.clone()).map(Some),
+        Some(ink_attr) => InkAttribute::try_from(ink_attr).map(Some),
+    }
+}
+
+/// Unwraps the ink! attributes wrapped in the given `cfg_attr` attribute.
+///
+/// Returns the unwrapped ink! attributes as well as the `cfg_attr` attribute
+/// without them if it still wraps other attributes. Nested `cfg_attr` attributes
+/// are unwrapped recursively.
+///
+/// # Note
+///
+/// Procedural macros cannot evaluate `cfg` predicates. Therefore ink! attributes
+/// wrapped in `cfg_attr` are applied as if the `cfg` predicate holds.
+fn unwrap_cfg_attr(
+    attr: syn::Attribute,
+) -> (Vec<syn::Attribute>, Option<syn::Attribute>) {
+    let meta_list = match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) if meta_list.path.is_ident("cfg_attr") => {
+            meta_list
+        }
+        _ => return (Vec::new(), Some(attr)),
+    };
+    let mut nested = meta_list.nested.iter();
+    let predicate = match nested.next() {
+        Some(predicate) => predicate,
+        None => return (Vec::new(), Some(attr)),
+    };
+    let to_attr = |meta: &syn::Meta| {
+        let mut attr_meta: syn::Attribute = syn::parse_quote! { #[#meta] };
+        attr_meta.style = attr.style;
+        attr_meta
+    };
+    let mut ink_attrs = Vec::new();
+    let mut others = Vec::new();
+    for meta in nested {
+        match meta {
+            syn::NestedMeta::Meta(meta)
+                if meta.path().is_ident("ink")
+                    || ink_path_attribute_kind(meta.path()).is_some() =>
+            {
+                ink_attrs.push(to_attr(meta))
+            }
+            syn::NestedMeta::Meta(meta) if meta.path().is_ident("cfg_attr") => {
+                let (nested_ink_attrs, nested_other) = unwrap_cfg_attr(to_attr(meta));
+                ink_attrs.extend(nested_ink_attrs);
+                others.extend(nested_other.map(|nested_other| {
+                    nested_other
+                        .parse_meta()
+                        .map(syn::NestedMeta::Meta)
+                        .expect("encountered invalid unwrapped `cfg_attr` attribute")
+                }));
+            }
+            other => others.push(other.clone()),
+        }
+    }
+    if ink_attrs.is_empty() {
+        return (Vec::new(), Some(attr))
+    }
+    let other = if others.is_empty() {
+        None
+    } else {
+        let mut other: syn::Attribute = syn::parse_quote! {
+            #[cfg_attr(#predicate, #( #others ),*)]
+        };
+        other.style = attr.style;
+        Some(other)
+    };
+    (ink_attrs, other)
+}
+
+/// Expands all `cfg_attr` attributes wrapping ink! attributes.
+///
+/// The wrapped ink! attributes take the place of their `cfg_attr` attribute
+/// which is preserved for all its other wrapped attributes.
+fn expand_cfg_attrs<I>(attrs: I) -> Vec<syn::Attribute>
+where
+    I: IntoIterator<Item = syn::Attribute>,
+{
+    let mut expanded = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("cfg_attr") {
+            expanded.push(attr);
+            continue
+        }
+        let (ink_attrs, other) = unwrap_cfg_attr(attr);
+        expanded.extend(ink_attrs);
+        expanded.extend(other);
     }
+    expanded
 }
 
 /// Partitions the given attributes into ink! specific and non-ink! specific attributes.
 ///
+/// # Note
+///
+/// ink! attributes wrapped in `cfg_attr` attributes are unwrapped and applied as if
+/// the `cfg` predicate holds since procedural macros cannot evaluate `cfg` predicates.
+/// The `cfg_attr` attributes are preserved for all their other wrapped attributes.
+///
 /// # Error
 ///
 /// Returns an error if some ink! specific attributes could not be successfully parsed.
@@ -550,6 +1022,8 @@ where
 {
     use either::Either;
     use itertools::Itertools as _;
+    let attrs = expand_cfg_attrs(attrs);
+    Attribute::ensure_consistent_ink_attribute_forms(&attrs)?;
     let (ink_attrs, others) = attrs
         .into_iter()
         .map(<Attribute as TryFrom<_>>::try_from)
@@ -569,7 +1043,8 @@ where
 ///
 /// This partitions the attributes into ink! and non-ink! attributes.
 /// All ink! attributes are normalized, they are checked to have a valid first
-/// ink! attribute argument and no conflicts given the conflict predicate.
+/// ink! attribute argument, no mutually exclusive arguments and no conflicts
+/// given the conflict predicate.
 ///
 /// Returns the partitioned ink! and non-ink! attributes.
 ///
@@ -584,6 +1059,7 @@ where
 /// - If there are invalid ink! attributes.
 /// - If there are duplicate ink! attributes.
 /// - If the first ink! attribute is not matching the expected.
+/// - If there are mutually exclusive ink! attribute arguments.
 /// - If there are conflicting ink! attributes.
 pub fn sanitize_attributes<I, C>(
     parent_span: Span,
@@ -606,6 +1082,7 @@ where
             is_valid_first,
         ))
     })?;
+    ir::ensure_valid_combinations(&normalized)?;
     normalized.ensure_no_conflicts(|arg| is_conflicting_attr(arg))?;
     Ok((normalized, other_attrs))
 }
@@ -636,13 +1113,45 @@ impl Attribute {
         }
         Ok(())
     }
+
+    /// Returns `Ok` if the given ink! attributes are either all written in the
+    /// `#[ink(..)]` form or all in the `#[ink::<kind>]` path form.
+    ///
+    /// # Errors
+    ///
+    /// If the two forms of ink! attributes are mixed.
+    fn ensure_consistent_ink_attribute_forms(
+        attrs: &[syn::Attribute],
+    ) -> Result<(), syn::Error> {
+        let mut ink_attrs = attrs.iter().filter(|attr| is_ink_attribute(attr));
+        let first = match ink_attrs.next() {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+        let is_path_form = |attr: &syn::Attribute| !attr.path.is_ident("ink");
+        if let Some(mixed) =
+            ink_attrs.find(|attr| is_path_form(attr) != is_path_form(first))
+        {
+            use crate::error::ExtError as _;
+            return Err(format_err_spanned!(
+                mixed,
+                "encountered inconsistently mixed `#[ink(..)]` and `#[ink::..]` ink! attributes, \
+                 use either form consistently"
+            )
+            .into_combine(format_err_spanned!(
+                first,
+                "first ink! attribute in the other form here"
+            )))
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<syn::Attribute> for Attribute {
     type Error = syn::Error;
 
     fn try_from(attr: syn::Attribute) -> Result<Self, Self::Error> {
-        if attr.path.is_ident("ink") {
+        if is_ink_attribute(&attr) {
             return <InkAttribute as TryFrom<_>>::try_from(attr).map(Into::into)
         }
         Ok(Attribute::Other(attr))
@@ -659,18 +1168,35 @@ impl TryFrom<syn::Attribute> for InkAttribute {
     type Error = syn::Error;
 
     fn try_from(attr: syn::Attribute) -> Result<Self, Self::Error> {
+        if let Some(kind) = ink_path_attribute_kind(&attr.path) {
+            if !attr.tokens.is_empty() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "unexpected ink! attribute structure, the `#[ink::{}]` form does not take arguments",
+                    kind,
+                ))
+            }
+            let arg = <AttributeFrag as TryFrom<_>>::try_from(syn::NestedMeta::Meta(
+                syn::Meta::Path(syn::Path::from(kind.clone())),
+            ))?;
+            return Ok(InkAttribute { args: vec![arg] })
+        }
         if !attr.path.is_ident("ink") {
             return Err(format_err_spanned!(attr, "unexpected non-ink! attribute"))
         }
+        let (attr, wildcard_selector) = extract_ident_selector(attr);
         match attr.parse_meta().map_err(|_| {
             format_err_spanned!(attr, "unexpected ink! attribute structure")
         })? {
             syn::Meta::List(meta_list) => {
-                let args = meta_list
+                let mut args = meta_list
                     .nested
                     .into_iter()
                     .map(<AttributeFrag as TryFrom<_>>::try_from)
                     .collect::<Result<Vec<_>, syn::Error>>()?;
+                if let Some((index, frag)) = wildcard_selector {
+                    args.insert(index, frag);
+                }
                 Self::ensure_no_duplicate_args(&args)?;
                 if args.is_empty() {
                     return Err(format_err_spanned!(
@@ -732,6 +1258,109 @@ impl InkAttribute {
     }
 }
 
+/// Extracts the wildcard selector argument `selector = _` or a selector given
+/// by a constant, e.g. `selector = MY_SELECTOR` or `selector = self::MY_SELECTOR`,
+/// from the given `#[ink(..)]` attribute.
+///
+/// Returns the attribute without the extracted selector argument and, if it
+/// has been found, its position among the attribute arguments together with
+/// its ink! attribute argument.
+///
+/// # Note
+///
+/// This is required since `_` and paths are no literals and therefore cannot
+/// be parsed as [`syn::Meta`] like all other ink! attribute arguments.
+fn extract_ident_selector(
+    attr: syn::Attribute,
+) -> (syn::Attribute, Option<(usize, AttributeFrag)>) {
+    use proc_macro2::{
+        Delimiter,
+        TokenStream,
+        TokenTree,
+    };
+    let group = match attr
+        .tokens
+        .clone()
+        .into_iter()
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+            group.clone()
+        }
+        _ => return (attr, None),
+    };
+    let mut segments: Vec<Vec<TokenTree>> = vec![Vec::new()];
+    for tt in group.stream() {
+        match &tt {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                segments.push(Vec::new())
+            }
+            _ => {
+                segments
+                    .last_mut()
+                    .expect("there is always at least one segment")
+                    .push(tt)
+            }
+        }
+    }
+    let ident_selector = |segment: &[TokenTree]| {
+        let value = match segment {
+            [TokenTree::Ident(name), TokenTree::Punct(eq), value @ ..]
+                if name == "selector" && eq.as_char() == '=' =>
+            {
+                value
+            }
+            _ => return None,
+        };
+        let ident = match value {
+            [TokenTree::Ident(ident)] => ident,
+            [TokenTree::Ident(module), TokenTree::Punct(fst), TokenTree::Punct(snd), TokenTree::Ident(ident)]
+                if module == "self" && fst.as_char() == ':' && snd.as_char() == ':' =>
+            {
+                ident
+            }
+            _ => return None,
+        };
+        match ident.to_string().as_str() {
+            "_" => Some(AttributeArg::WildcardSelector),
+            "true" | "false" | "self" => None,
+            _ => Some(AttributeArg::SelectorConst(ident.clone())),
+        }
+    };
+    let (index, arg) = match segments
+        .iter()
+        .enumerate()
+        .find_map(|(index, segment)| ident_selector(segment).map(|arg| (index, arg)))
+    {
+        Some(found) => found,
+        None => return (attr, None),
+    };
+    let segment = segments.remove(index);
+    let name = match &segment[0] {
+        TokenTree::Ident(name) => name.clone(),
+        _ => unreachable!("checked that the segment starts with an identifier"),
+    };
+    let frag = AttributeFrag {
+        ast: syn::Meta::Path(syn::Path::from(name)),
+        arg,
+    };
+    let remaining = segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.into_iter().collect::<TokenStream>());
+    let mut stripped = proc_macro2::Group::new(
+        Delimiter::Parenthesis,
+        quote::quote! { #( #remaining ),* },
+    );
+    stripped.set_span(group.span());
+    let attr = syn::Attribute {
+        tokens: TokenTree::Group(stripped).into(),
+        ..attr
+    };
+    (attr, Some((index, frag)))
+}
+
 /// Returns an error to notify about non-hex digits at a position.
 fn err_non_hex(meta: &syn::Meta, pos: usize) -> syn::Error {
     format_err_spanned!(meta, "encountered non-hex digit at position {}", pos)
@@ -794,7 +1423,21 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                                     )),
                                 })
                             }
-                            return Err(format_err!(name_value, "expecteded 4-digit hexcode for `selector` argument, e.g. #[ink(selector = 0xC0FEBABE]"))
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let selector = lit_int.base10_parse::<u32>().map_err(|parse_err| {
+                                    format_err!(
+                                        name_value,
+                                        "could not parse `N` in `#[ink(selector = N)]` into a 4 byte `u32` integer",
+                                    ).into_combine(parse_err)
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Selector(Selector::from_bytes(
+                                        selector.to_be_bytes(),
+                                    )),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expecteded 4-digit hexcode or `u32` integer for `selector` argument, e.g. #[ink(selector = 0xC0FEBABE]"))
                         }
                         if name_value.path.is_ident("namespace") {
                             if let syn::Lit::Str(lit_str) = &name_value.lit {
@@ -808,67 +1451,216 @@ impl TryFrom<syn::NestedMeta> for AttributeFrag {
                             }
                             return Err(format_err!(name_value, "expecteded string type for `namespace` argument, e.g. #[ink(namespace = \"hello\")]"))
                         }
-                        if name_value.path.is_ident("extension") {
+                        if name_value.path.is_ident("getter") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let field = lit_str.parse::<Ident>().map_err(|_| {
+                                    format_err!(
+                                        name_value,
+                                        "expected a storage field identifier for `getter` argument, found {:?}",
+                                        lit_str.value(),
+                                    )
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Getter(field),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected string type for `getter` argument, e.g. #[ink(getter = \"my_field\")]"))
+                        }
+                        if name_value.path.is_ident("weight") {
                             if let syn::Lit::Int(lit_int) = &name_value.lit {
-                                let id = lit_int.base10_parse::<u32>().map_err(|parse_err| {
+                                let weight = lit_int
+                                    .base10_parse::<u64>()
+                                    .ok()
+                                    .filter(|weight| *weight > 0)
+                                    .ok_or_else(|| {
+                                        format_err!(
+                                            name_value,
+                                            "expected a positive `u64` integer for `weight` argument, e.g. #[ink(weight = 1_000_000)]",
+                                        )
+                                    })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Weight(weight),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected a positive `u64` integer for `weight` argument, e.g. #[ink(weight = 1_000_000)]"))
+                        }
+                        if name_value.path.is_ident("version") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let version = lit_int.base10_parse::<u32>().map_err(|_| {
                                     format_err!(
                                         name_value,
-                                        "could not parse `N` in `#[ink(extension = N)]` into a `u32` integer",
-                                    ).into_combine(parse_err)
+                                        "expected `u32` integer for `version` argument, e.g. #[ink(storage, version = 1)]",
+                                    )
                                 })?;
                                 return Ok(AttributeFrag {
                                     ast: meta,
-                                    arg: AttributeArg::Extension(
-                                        ExtensionId::from_u32(id),
-                                    ),
+                                    arg: AttributeArg::Version(version),
                                 })
                             }
-                            return Err(format_err!(name_value, "expecteded `u32` integer type for `N` in #[ink(extension = N)]"))
+                            return Err(format_err!(name_value, "expected `u32` integer for `version` argument, e.g. #[ink(storage, version = 1)]"))
                         }
-                        if name_value.path.is_ident("handle_status") {
-                            if let syn::Lit::Bool(lit_bool) = &name_value.lit {
-                                let value = lit_bool.value;
+                        if name_value.path.is_ident("standard") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                let standard = lit_str.value();
+                                if standard.trim().is_empty() {
+                                    return Err(format_err!(
+                                        name_value,
+                                        "expected a non-empty identifier for `standard` argument, e.g. #[ink(standard = \"erc20::transfer\")]",
+                                    ))
+                                }
                                 return Ok(AttributeFrag {
                                     ast: meta,
-                                    arg: AttributeArg::HandleStatus(value),
+                                    arg: AttributeArg::Standard(standard),
                                 })
                             }
-                            return Err(format_err!(name_value, "expecteded `bool` value type for `flag` in #[ink(handle_status = flag)]"))
+                            return Err(format_err!(name_value, "expected string type for `standard` argument, e.g. #[ink(standard = \"erc20::transfer\")]"))
                         }
-                        if name_value.path.is_ident("returns_result") {
+                        if name_value.path.is_ident("mutates") {
                             if let syn::Lit::Bool(lit_bool) = &name_value.lit {
                                 let value = lit_bool.value;
                                 return Ok(AttributeFrag {
                                     ast: meta,
-                                    arg: AttributeArg::ReturnsResult(value),
+                                    arg: AttributeArg::Mutates(value),
                                 })
                             }
-                            return Err(format_err!(name_value, "expecteded `bool` value type for `flag` in #[ink(returns_result = flag)]"))
+                            return Err(format_err!(name_value, "expected `bool` value type for `flag` in #[ink(mutates = flag)]"))
                         }
-                        Err(format_err_spanned!(
-                            meta,
-                            "unknown ink! attribute argument (name = value)",
-                        ))
-                    }
-                    syn::Meta::Path(path) => {
-                        path
-                            .get_ident()
-                            .map(Ident::to_string)
-                            .ok_or_else(|| format_err_spanned!(meta, "unknown ink! attribute (path)"))
-                            .and_then(|ident| match ident.as_str() {
-                                "storage" => Ok(AttributeArg::Storage),
-                                "message" => Ok(AttributeArg::Message),
-                                "constructor" => Ok(AttributeArg::Constructor),
+                        if name_value.path.is_ident("default") {
+                            let value = match &name_value.lit {
+                                syn::Lit::Str(lit_str) => Some(lit_str.value()),
+                                syn::Lit::Int(lit_int) => Some(lit_int.base10_digits().to_string()),
+                                syn::Lit::Float(lit_float) => Some(lit_float.base10_digits().to_string()),
+                                syn::Lit::Bool(lit_bool) => Some(lit_bool.value.to_string()),
+                                _ => None,
+                            };
+                            if let Some(value) = value {
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Default(value),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected a string, integer, float or bool literal for `default` argument, e.g. #[ink(default = 0)]"))
+                        }
+                        if name_value.path.is_ident("storage_key") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let storage_key = StorageKey::from_base10_digits(lit_int.base10_digits())
+                                    .ok_or_else(|| {
+                                        format_err!(
+                                            name_value,
+                                            "storage key `{}` exceeds the 256-bit width of storage keys",
+                                            lit_int,
+                                        )
+                                    })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::StorageKey(storage_key),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expected an unsigned integer for `storage_key` argument, e.g. #[ink(storage_key = 0x1234)]"))
+                        }
+                        if name_value.path.is_ident("extension") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                let id = lit_int.base10_parse::<u32>().map_err(|parse_err| {
+                                    format_err!(
+                                        name_value,
+                                        "could not parse `N` in `#[ink(extension = N)]` into a `u32` integer",
+                                    ).into_combine(parse_err)
+                                })?;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::Extension(
+                                        ExtensionId::from_u32(id),
+                                    ),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expecteded `u32` integer type for `N` in #[ink(extension = N)]"))
+                        }
+                        if name_value.path.is_ident("handle_status") {
+                            if let syn::Lit::Bool(lit_bool) = &name_value.lit {
+                                let value = lit_bool.value;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::HandleStatus(value),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expecteded `bool` value type for `flag` in #[ink(handle_status = flag)]"))
+                        }
+                        if name_value.path.is_ident("returns_result") {
+                            if let syn::Lit::Bool(lit_bool) = &name_value.lit {
+                                let value = lit_bool.value;
+                                return Ok(AttributeFrag {
+                                    ast: meta,
+                                    arg: AttributeArg::ReturnsResult(value),
+                                })
+                            }
+                            return Err(format_err!(name_value, "expecteded `bool` value type for `flag` in #[ink(returns_result = flag)]"))
+                        }
+                        Err(format_err_spanned!(
+                            meta,
+                            "unknown ink! attribute argument (name = value)",
+                        ))
+                    }
+                    syn::Meta::Path(path) => {
+                        path
+                            .get_ident()
+                            .map(Ident::to_string)
+                            .ok_or_else(|| format_err_spanned!(meta, "unknown ink! attribute (path)"))
+                            .and_then(|ident| match ident.as_str() {
+                                "storage" => Ok(AttributeArg::Storage),
+                                "zeroed" => Ok(AttributeArg::Zeroed),
+                                "manual_layout" => Ok(AttributeArg::ManualLayout),
+                                "message" => Ok(AttributeArg::Message),
+                                "constructor" => Ok(AttributeArg::Constructor),
                                 "event" => Ok(AttributeArg::Event),
                                 "anonymous" => Ok(AttributeArg::Anonymous),
                                 "topic" => Ok(AttributeArg::Topic),
                                 "payable" => Ok(AttributeArg::Payable),
+                                "test_only" => Ok(AttributeArg::TestOnly),
+                                "raw_return" => Ok(AttributeArg::RawReturn),
+                                "hidden" => Ok(AttributeArg::Hidden),
                                 "impl" => Ok(AttributeArg::Implementation),
                                 "namespace" => Err(format_err!(
                                     meta,
                                     "encountered #[ink(namespace)] that is missing its string parameter. \
                                     Did you mean #[ink(namespace = name: str)] ?"
                                 )),
+                                "getter" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(getter)] that is missing its field parameter. \
+                                    Did you mean #[ink(getter = field: str)] ?"
+                                )),
+                                "storage_key" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(storage_key)] that is missing its N parameter. \
+                                    Did you mean #[ink(storage_key = N)] ?"
+                                )),
+                                "weight" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(weight)] that is missing its N parameter. \
+                                    Did you mean #[ink(weight = N: u64)] ?"
+                                )),
+                                "version" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(version)] that is missing its N parameter. \
+                                    Did you mean #[ink(version = N: u32)] ?"
+                                )),
+                                "standard" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(standard)] that is missing its identifier parameter. \
+                                    Did you mean #[ink(standard = identifier: str)] ?"
+                                )),
+                                "mutates" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(mutates)] that is missing its `flag: bool` parameter. \
+                                    Did you mean #[ink(mutates = flag: bool)] ?"
+                                )),
+                                "default" => Err(format_err!(
+                                    meta,
+                                    "encountered #[ink(default)] that is missing its value parameter. \
+                                    Did you mean #[ink(default = value)] ?"
+                                )),
                                 "extension" => Err(format_err!(
                                     meta,
                                     "encountered #[ink(extension)] that is missing its N parameter. \
@@ -922,6 +1714,9 @@ mod tests {
             syn::parse_quote! { #[likely] },
             syn::parse_quote! { #[ink(storage)] },
         ]));
+        assert!(contains_ink_attributes(&[
+            syn::parse_quote! { #[ink::storage] }
+        ]));
         assert!(!contains_ink_attributes(&[
             syn::parse_quote! { #[inline] },
             syn::parse_quote! { #[likely] },
@@ -1050,6 +1845,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn path_form_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::storage]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Storage])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::message]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Message])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::hidden]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Hidden])),
+        );
+    }
+
+    #[test]
+    fn path_form_of_arguments_with_parameter_is_no_ink_attribute() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::namespace]
+            },
+            Ok(test::Attribute::Other(syn::parse_quote! {
+                #[ink::namespace]
+            })),
+        );
+    }
+
+    #[test]
+    fn path_form_with_arguments_fails() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::message(payable)]
+            },
+            Err("unexpected ink! attribute structure, the `#[ink::message]` form does not take arguments"),
+        );
+    }
+
+    #[test]
+    fn path_form_of_ink_macros_is_no_ink_attribute() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink::test]
+            },
+            Ok(test::Attribute::Other(syn::parse_quote! {
+                #[ink::test]
+            })),
+        );
+    }
+
     /// This tests that `#[ink(impl)]` works which can be non-trivial since
     /// `impl` is also a Rust keyword.
     #[test]
@@ -1062,6 +1913,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_only_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(test_only)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::TestOnly])),
+        );
+    }
+
+    #[test]
+    fn hidden_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(hidden)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Hidden])),
+        );
+    }
+
+    #[test]
+    fn zeroed_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage, zeroed)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Storage,
+                AttributeArg::Zeroed,
+            ])),
+        );
+    }
+
+    #[test]
+    fn manual_layout_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage, manual_layout)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Storage,
+                AttributeArg::ManualLayout,
+            ])),
+        );
+    }
+
+    #[test]
+    fn version_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage, version = 2)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Storage,
+                AttributeArg::Version(2),
+            ])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(version = "2")]
+            },
+            Err("expected `u32` integer for `version` argument, e.g. #[ink(storage, version = 1)]"),
+        );
+    }
+
+    #[test]
+    fn cfg_attr_without_ink_attribute_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[cfg_attr(feature = "std", derive(Debug), allow(dead_code))]
+            },
+            Ok(test::Attribute::Other(syn::parse_quote! {
+                #[cfg_attr(feature = "std", derive(Debug), allow(dead_code))]
+            })),
+        );
+    }
+
+    #[test]
+    fn raw_return_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(raw_return)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::RawReturn])),
+        );
+    }
+
     #[test]
     fn selector_works() {
         assert_attribute_try_from(
@@ -1074,6 +2012,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wildcard_selector_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = _)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::WildcardSelector])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(constructor, selector = _)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Constructor,
+                AttributeArg::WildcardSelector,
+            ])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = _, constructor,)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::WildcardSelector,
+                AttributeArg::Constructor,
+            ])),
+        );
+    }
+
+    #[test]
+    fn selector_const_works() {
+        let my_selector = || Ident::new("MY_SELECTOR", Span::call_site());
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(message, selector = MY_SELECTOR)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::Message,
+                AttributeArg::SelectorConst(my_selector()),
+            ])),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = self::MY_SELECTOR, payable)]
+            },
+            Ok(test::Attribute::Ink(vec![
+                AttributeArg::SelectorConst(my_selector()),
+                AttributeArg::Payable,
+            ])),
+        );
+    }
+
+    #[test]
+    fn wildcard_and_explicit_selector_fails() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(constructor, selector = _, selector = 1)]
+            },
+            Err("encountered ink! attribute arguments with equal kinds"),
+        );
+    }
+
     #[test]
     fn selector_non_hexcode() {
         assert_attribute_try_from(
@@ -1094,13 +2093,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selector_integer_works() {
+        fn assert_selector(attr: syn::Attribute, expected: [u8; 4]) {
+            assert_attribute_try_from(
+                attr,
+                Ok(test::Attribute::Ink(vec![AttributeArg::Selector(
+                    Selector::from_bytes(expected),
+                )])),
+            );
+        }
+        let expected = [0x00, 0x00, 0x00, 0x0A];
+        assert_selector(syn::parse_quote! { #[ink(selector = 10)] }, expected);
+        assert_selector(
+            syn::parse_quote! { #[ink(selector = 0x0000000A)] },
+            expected,
+        );
+        assert_selector(syn::parse_quote! { #[ink(selector = 0xA)] }, expected);
+        assert_selector(syn::parse_quote! { #[ink(selector = 0b1010)] }, expected);
+        assert_selector(syn::parse_quote! { #[ink(selector = 0o12)] }, expected);
+        assert_selector(
+            syn::parse_quote! { #[ink(selector = 0xFFFFFFFF)] },
+            [0xFF, 0xFF, 0xFF, 0xFF],
+        );
+        assert_selector(
+            syn::parse_quote! { #[ink(selector = 0xDEADBEEF)] },
+            [0xDE, 0xAD, 0xBE, 0xEF],
+        );
+    }
+
+    #[test]
+    fn selector_integer_overflow_fails() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = 0x1_0000_0000)]
+            },
+            Err("could not parse `N` in `#[ink(selector = N)]` into a 4 byte `u32` integer"),
+        );
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(selector = 4294967296)]
+            },
+            Err("could not parse `N` in `#[ink(selector = N)]` into a 4 byte `u32` integer"),
+        );
+    }
+
     #[test]
     fn selector_invalid_type() {
         assert_attribute_try_from(
             syn::parse_quote! {
-                #[ink(selector = 42)]
+                #[ink(selector = true)]
             },
-            Err("expecteded 4-digit hexcode for `selector` argument, e.g. #[ink(selector = 0xC0FEBABE]"),
+            Err("expecteded 4-digit hexcode or `u32` integer for `selector` argument, e.g. #[ink(selector = 0xC0FEBABE]"),
         );
     }
 
@@ -1139,6 +2183,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn storage_key_works() {
+        let mut expected = [0x00; 32];
+        expected[0] = 0x34;
+        expected[1] = 0x12;
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage_key = 0x1234)]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::StorageKey(
+                StorageKey { bytes: expected },
+            )])),
+        );
+        assert_eq!(
+            StorageKey::from_base10_digits(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            ),
+            Some(StorageKey { bytes: [0xFF; 32] }),
+        );
+    }
+
+    #[test]
+    fn storage_key_too_large_fails() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage_key = 115792089237316195423570985008687907853269984665640564039457584007913129639936)]
+            },
+            Err("storage key `115792089237316195423570985008687907853269984665640564039457584007913129639936` \
+                 exceeds the 256-bit width of storage keys"),
+        );
+    }
+
+    #[test]
+    fn storage_key_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(storage_key = "0x1234")]
+            },
+            Err("expected an unsigned integer for `storage_key` argument, e.g. #[ink(storage_key = 0x1234)]"),
+        );
+    }
+
+    #[test]
+    fn getter_works() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(getter = "my_field")]
+            },
+            Ok(test::Attribute::Ink(vec![AttributeArg::Getter(
+                Ident::new("my_field", Span::call_site()),
+            )])),
+        );
+    }
+
+    #[test]
+    fn getter_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(getter = 42)]
+            },
+            Err("expected string type for `getter` argument, e.g. #[ink(getter = \"my_field\")]"),
+        );
+    }
+
+    #[test]
+    fn getter_invalid_identifier() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(getter = "my field")]
+            },
+            Err("expected a storage field identifier for `getter` argument, found \"my field\""),
+        );
+    }
+
+    #[test]
+    fn getter_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(getter)]
+            },
+            Err(
+                "encountered #[ink(getter)] that is missing its field parameter. \
+                Did you mean #[ink(getter = field: str)] ?",
+            ),
+        );
+    }
+
+    #[test]
+    fn default_works() {
+        let test_inputs: Vec<(syn::Attribute, &str)> = vec![
+            (syn::parse_quote! { #[ink(default = 0)] }, "0"),
+            (syn::parse_quote! { #[ink(default = 1_000)] }, "1000"),
+            (syn::parse_quote! { #[ink(default = 0.5)] }, "0.5"),
+            (syn::parse_quote! { #[ink(default = true)] }, "true"),
+            (syn::parse_quote! { #[ink(default = "Alice")] }, "Alice"),
+        ];
+        for (input, expected) in test_inputs {
+            assert_attribute_try_from(
+                input,
+                Ok(test::Attribute::Ink(vec![AttributeArg::Default(
+                    expected.to_string(),
+                )])),
+            );
+        }
+    }
+
+    #[test]
+    fn default_invalid_type() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(default = b'a')]
+            },
+            Err("expected a string, integer, float or bool literal for `default` argument, e.g. #[ink(default = 0)]"),
+        );
+    }
+
+    #[test]
+    fn default_missing_parameter() {
+        assert_attribute_try_from(
+            syn::parse_quote! {
+                #[ink(default)]
+            },
+            Err(
+                "encountered #[ink(default)] that is missing its value parameter. \
+                Did you mean #[ink(default = value)] ?",
+            ),
+        );
+    }
+
     #[test]
     fn extension_works() {
         assert_attribute_try_from(
@@ -1397,6 +2570,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parition_path_form_attributes_works() {
+        assert_parition_attributes(
+            vec![
+                syn::parse_quote! { #[ink::message] },
+                syn::parse_quote! { #[ink::payable] },
+                syn::parse_quote! { #[non_ink_attribute] },
+            ],
+            Ok((
+                vec![
+                    test::InkAttribute::from(vec![AttributeArg::Message]),
+                    test::InkAttribute::from(vec![AttributeArg::Payable]),
+                ],
+                vec![syn::parse_quote! { #[non_ink_attribute] }],
+            )),
+        )
+    }
+
+    #[test]
+    fn parition_mixed_forms_fails() {
+        assert_parition_attributes(
+            vec![
+                syn::parse_quote! { #[ink(message)] },
+                syn::parse_quote! { #[ink::payable] },
+            ],
+            Err("encountered inconsistently mixed `#[ink(..)]` and `#[ink::..]` ink! attributes, \
+                 use either form consistently"),
+        )
+    }
+
     #[test]
     fn parition_duplicates_fails() {
         assert_parition_attributes(
@@ -1407,4 +2610,55 @@ mod tests {
             Err("encountered duplicate ink! attribute"),
         )
     }
+
+    #[test]
+    fn parition_cfg_attr_wrapped_attributes_works() {
+        assert_parition_attributes(
+            vec![
+                syn::parse_quote! { #[ink(message)] },
+                syn::parse_quote! { #[cfg_attr(feature = "x", ink(payable))] },
+            ],
+            Ok((
+                vec![
+                    test::InkAttribute::from(vec![AttributeArg::Message]),
+                    test::InkAttribute::from(vec![AttributeArg::Payable]),
+                ],
+                vec![],
+            )),
+        );
+        assert_parition_attributes(
+            vec![
+                syn::parse_quote! { #[ink::message] },
+                syn::parse_quote! { #[cfg_attr(feature = "x", ink::payable, allow(dead_code))] },
+            ],
+            Ok((
+                vec![
+                    test::InkAttribute::from(vec![AttributeArg::Message]),
+                    test::InkAttribute::from(vec![AttributeArg::Payable]),
+                ],
+                vec![syn::parse_quote! { #[cfg_attr(feature = "x", allow(dead_code))] }],
+            )),
+        );
+    }
+
+    #[test]
+    fn parition_nested_cfg_attr_wrapped_attributes_works() {
+        assert_parition_attributes(
+            vec![
+                syn::parse_quote! { #[ink(message)] },
+                syn::parse_quote! {
+                    #[cfg_attr(feature = "x", cfg_attr(test, ink(payable), inline))]
+                },
+            ],
+            Ok((
+                vec![
+                    test::InkAttribute::from(vec![AttributeArg::Message]),
+                    test::InkAttribute::from(vec![AttributeArg::Payable]),
+                ],
+                vec![syn::parse_quote! {
+                    #[cfg_attr(feature = "x", cfg_attr(test, inline))]
+                }],
+            )),
+        )
+    }
 }
@@ -15,8 +15,11 @@
 use crate::{
     ast,
     error::ExtError as _,
+    ir,
 };
 use core::convert::TryFrom;
+use proc_macro2::Ident;
+use quote::format_ident;
 use syn::spanned::Spanned;
 
 /// The ink! configuration.
@@ -38,8 +41,132 @@ pub struct Config {
     /// be used to change the underlying environmental types of an ink! smart
     /// contract.
     env: Option<Environment>,
+    /// If `true` includes the hash of the original contract source module
+    /// in the generated metadata. The default is `false`.
+    source_hash: Option<bool>,
+    /// If `true` allows the ink! smart contract to have no constructors.
+    ///
+    /// This is mainly useful for library contracts that are only ever used
+    /// as dependencies. The default is `false`.
+    allow_no_constructor: Option<bool>,
+    /// The maximum size of the contract input in bytes.
+    ///
+    /// If set, inputs larger than this are rejected before decoding.
+    /// The default is unbounded.
+    max_input_size: Option<usize>,
+    /// The name under which the ink! smart contract reference type is
+    /// re-exported when the contract is compiled as dependency.
+    ///
+    /// The default is the name of the storage struct suffixed with `Ref`.
+    ref_name: Option<Ident>,
+    /// If `true` generates a `set_code` ink! message that allows to replace
+    /// the code of the ink! smart contract. The default is `false`.
+    upgradeable: Option<bool>,
+    /// If `true` generates a trait comprising the signatures of all ink!
+    /// messages of the ink! smart contract. The default is `false`.
+    emit_interface: Option<bool>,
+    /// The version of the metadata schema generated for the ink! smart contract.
+    ///
+    /// The default is `0` which is the original metadata schema.
+    metadata_version: Option<u32>,
+    /// If `true` requires all ink! messages and constructors to specify their
+    /// selectors explicitly instead of deriving them from their names.
+    /// The default is `false`.
+    explicit_selectors: Option<bool>,
+    /// The namespace of the ink! smart contract that seeds the composed
+    /// selectors of all of its ink! messages and constructors.
+    ///
+    /// This is useful to disambiguate the selectors of multiple ink! smart
+    /// contracts that are generated into the same artifact.
+    /// The default is no namespace.
+    namespace: Option<ir::Namespace>,
+    /// If `true` marks small generated helper functions such as the bodies of
+    /// ink! getter messages and the dispatch helpers with `#[inline]`.
+    /// The default is `false`.
+    inline_helpers: Option<bool>,
+    /// The SPDX license identifier of the ink! smart contract that is
+    /// included in the generated metadata, e.g. `"Apache-2.0"`.
+    /// The default is no license.
+    license: Option<String>,
+    /// The number of fields above which ink! events are flagged with an
+    /// advisory warning since they likely exceed practical log data sizes.
+    /// The default is no limit.
+    max_event_fields: Option<usize>,
+    /// The global Wasm heap memory allocator of the ink! smart contract.
+    ///
+    /// The default is the `wee_alloc` allocator.
+    allocator: Option<Allocator>,
+    /// If `true` emits advisory warnings for `pub` fields of the ink! storage
+    /// struct which should rather be exposed via accessor ink! messages.
+    /// The default is `false`.
+    warn_pub_storage: Option<bool>,
+    /// The hash primitive used to compute the selectors of all ink! messages
+    /// and constructors, e.g. to match the hashing of a runtime.
+    ///
+    /// The default is BLAKE-2 256-bit.
+    selector_hash: Option<ir::SelectorHash>,
+    /// The strategy used to dispatch on the selectors of incoming calls.
+    ///
+    /// The default is a `match` over all selectors.
+    dispatch: Option<DispatchStrategy>,
+    /// If `true` emits the storage fields read and written by every ink!
+    /// message into the metadata as hints for static analysis tools.
+    /// The default is `false`.
+    storage_access_hints: Option<bool>,
+    /// If `true` embeds the version of the Rust compiler and of ink! that
+    /// built the ink! smart contract into the metadata.
+    /// The default is `false`.
+    embed_build_info: Option<bool>,
+    /// If `true` emits advisory warnings for fields of the ink! storage struct
+    /// of interior mutability types such as `RefCell` which do not persist
+    /// meaningfully in the contract storage.
+    /// The default is `false`.
+    warn_interior_mutability: Option<bool>,
+    /// If `true` generates a `SELECTORS` constant on the ink! storage struct
+    /// listing the selectors of all ink! messages sorted in ascending order.
+    ///
+    /// This is useful for contracts that want to expose their own set of
+    /// selectors on-chain for introspection.
+    expose_selectors: Option<bool>,
+    /// If `true` rejects types of ink! messages, constructors, events and the
+    /// ink! storage struct that can never be registered in the metadata type
+    /// registry, e.g. function pointers or trait objects.
+    /// The default is `false`.
+    validate_metadata_types: Option<bool>,
+    /// The name of the exported Wasm entry point that dispatches calls to the
+    /// ink! messages of the ink! smart contract.
+    ///
+    /// The default is `call`.
+    entry_call: Option<Ident>,
+    /// The name of the exported Wasm entry point that dispatches calls to the
+    /// ink! constructors of the ink! smart contract.
+    ///
+    /// The default is `deploy`.
+    entry_deploy: Option<Ident>,
+    /// If `true` derives `Debug` for the ink! storage struct and all ink! event
+    /// structs in test and `std` builds.
+    /// The default is `false`.
+    derive_debug: Option<bool>,
+    /// If `true` generates a `migrate` ink! message stub for ink! storage structs
+    /// with a version greater than `0`. The default is `false`.
+    migration_stubs: Option<bool>,
+    /// If `true` panics of ink! messages are caught during dispatch and returned
+    /// as an error instead of trapping the contract execution. Requires the `std`
+    /// feature since Wasm builds abort on panics.
+    no_panic_dispatch: Option<bool>,
+    /// If `true` generates the `CALL_INFO` associated constant of the ink! storage
+    /// struct mapping the selectors of all ink! messages to their signature and
+    /// return type.
+    ///
+    /// This is a lightweight companion to the full metadata, e.g. for block explorers.
+    expose_call_info: Option<bool>,
+    /// The original attribute arguments the configuration has been parsed from.
+    raw_args: ast::AttributeArgs,
 }
 
+/// The versions of the metadata schema that are supported by ink!.
+const SUPPORTED_METADATA_VERSIONS: &[u32] = &[0, 1];
+
 /// Return an error to notify about duplicate ink! config arguments.
 fn duplicate_config_err<F, S>(fst: F, snd: S, name: &str) -> syn::Error
 where
@@ -58,65 +185,431 @@ where
     ))
 }
 
+/// Parses the bool literal of the ink! config argument `name` into `slot`.
+///
+/// # Errors
+///
+/// If the argument has already been given or its value is not a bool literal.
+fn parse_bool_arg(
+    name: &str,
+    arg: ast::MetaNameValue,
+    slot: &mut Option<(bool, ast::MetaNameValue)>,
+) -> Result<(), syn::Error> {
+    if let Some((_, ast)) = slot.take() {
+        return Err(duplicate_config_err(ast, arg, name))
+    }
+    if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
+        *slot = Some((lit_bool.value, arg));
+        Ok(())
+    } else {
+        Err(format_err_spanned!(
+            arg,
+            "expected a bool literal for `{}` ink! config argument",
+            name,
+        ))
+    }
+}
+
 impl TryFrom<ast::AttributeArgs> for Config {
     type Error = syn::Error;
 
     fn try_from(args: ast::AttributeArgs) -> Result<Self, Self::Error> {
+        Self::parse(args, false)
+    }
+}
+
+impl Config {
+    /// Parses the ink! configuration from the given attribute arguments
+    /// while ignoring unknown config arguments.
+    ///
+    /// # Note
+    ///
+    /// In contrast to the strict [`TryFrom`] implementation this does not
+    /// return an error for unknown config arguments. This allows tooling built
+    /// on top of ink! to read its own config arguments via [`Config::raw_args`].
+    ///
+    /// # Errors
+    ///
+    /// If a known config argument is malformed or duplicated.
+    pub fn try_from_lenient(args: ast::AttributeArgs) -> Result<Self, syn::Error> {
+        Self::parse(args, true)
+    }
+
+    /// Parses the ink! configuration from the given attribute arguments.
+    ///
+    /// Unknown config arguments are only ignored if `lenient` is `true`.
+    fn parse(args: ast::AttributeArgs, lenient: bool) -> Result<Self, syn::Error> {
+        let raw_args = args.clone();
         let mut dynamic_storage_allocator: Option<(bool, ast::MetaNameValue)> = None;
         let mut as_dependency: Option<(bool, ast::MetaNameValue)> = None;
         let mut env: Option<(Environment, ast::MetaNameValue)> = None;
+        let mut source_hash: Option<(bool, ast::MetaNameValue)> = None;
+        let mut allow_no_constructor: Option<(bool, ast::MetaNameValue)> = None;
+        let mut max_input_size: Option<(usize, ast::MetaNameValue)> = None;
+        let mut ref_name: Option<(Ident, ast::MetaNameValue)> = None;
+        let mut upgradeable: Option<(bool, ast::MetaNameValue)> = None;
+        let mut emit_interface: Option<(bool, ast::MetaNameValue)> = None;
+        let mut metadata_version: Option<(u32, ast::MetaNameValue)> = None;
+        let mut explicit_selectors: Option<(bool, ast::MetaNameValue)> = None;
+        let mut namespace: Option<(ir::Namespace, ast::MetaNameValue)> = None;
+        let mut inline_helpers: Option<(bool, ast::MetaNameValue)> = None;
+        let mut license: Option<(String, ast::MetaNameValue)> = None;
+        let mut max_event_fields: Option<(usize, ast::MetaNameValue)> = None;
+        let mut allocator: Option<(Allocator, ast::MetaNameValue)> = None;
+        let mut warn_pub_storage: Option<(bool, ast::MetaNameValue)> = None;
+        let mut storage_access_hints: Option<(bool, ast::MetaNameValue)> = None;
+        let mut embed_build_info: Option<(bool, ast::MetaNameValue)> = None;
+        let mut warn_interior_mutability: Option<(bool, ast::MetaNameValue)> = None;
+        let mut expose_selectors: Option<(bool, ast::MetaNameValue)> = None;
+        let mut validate_metadata_types: Option<(bool, ast::MetaNameValue)> = None;
+        let mut entry_call: Option<(Ident, ast::MetaNameValue)> = None;
+        let mut entry_deploy: Option<(Ident, ast::MetaNameValue)> = None;
+        let mut derive_debug: Option<(bool, ast::MetaNameValue)> = None;
+        let mut migration_stubs: Option<(bool, ast::MetaNameValue)> = None;
+        let mut no_panic_dispatch: Option<(bool, ast::MetaNameValue)> = None;
+        let mut expose_call_info: Option<(bool, ast::MetaNameValue)> = None;
+        let mut selector_hash: Option<(ir::SelectorHash, ast::MetaNameValue)> = None;
+        let mut dispatch: Option<(DispatchStrategy, ast::MetaNameValue)> = None;
         for arg in args.into_iter() {
             if arg.name.is_ident("dynamic_storage_allocator") {
-                if let Some((_, ast)) = dynamic_storage_allocator {
-                    return Err(duplicate_config_err(
-                        ast,
+                parse_bool_arg(
+                    "dynamic_storage_allocator",
+                    arg,
+                    &mut dynamic_storage_allocator,
+                )?;
+            } else if arg.name.is_ident("compile_as_dependency") {
+                parse_bool_arg("compile_as_dependency", arg, &mut as_dependency)?;
+            } else if arg.name.is_ident("env") {
+                if let Some((_, ast)) = env {
+                    return Err(duplicate_config_err(ast, arg, "env"))
+                }
+                if let ast::PathOrLit::Path(path) = &arg.value {
+                    env = Some((Environment { path: path.clone() }, arg))
+                } else {
+                    return Err(format_err_spanned!(
                         arg,
-                        "dynamic_storage_allocator",
+                        "expected a path for `env` ink! config argument",
                     ))
                 }
-                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
-                    dynamic_storage_allocator = Some((lit_bool.value, arg))
+            } else if arg.name.is_ident("source_hash") {
+                parse_bool_arg("source_hash", arg, &mut source_hash)?;
+            } else if arg.name.is_ident("allow_no_constructor") {
+                parse_bool_arg("allow_no_constructor", arg, &mut allow_no_constructor)?;
+            } else if arg.name.is_ident("max_input_size") {
+                if let Some((_, ast)) = max_input_size {
+                    return Err(duplicate_config_err(ast, arg, "max_input_size"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) = &arg.value {
+                    let value = lit_int.base10_parse::<usize>().map_err(|_| {
+                        format_err_spanned!(
+                            lit_int,
+                            "expected an unsigned integer literal for `max_input_size` ink! config argument",
+                        )
+                    })?;
+                    max_input_size = Some((value, arg))
                 } else {
                     return Err(format_err_spanned!(
                         arg,
-                        "expected a bool literal for `dynamic_storage_allocator` ink! config argument",
+                        "expected an unsigned integer literal for `max_input_size` ink! config argument",
                     ))
                 }
-            } else if arg.name.is_ident("compile_as_dependency") {
-                if let Some((_, ast)) = as_dependency {
-                    return Err(duplicate_config_err(ast, arg, "compile_as_dependency"))
+            } else if arg.name.is_ident("ref_name") {
+                if let Some((_, ast)) = ref_name {
+                    return Err(duplicate_config_err(ast, arg, "ref_name"))
                 }
-                if let ast::PathOrLit::Lit(syn::Lit::Bool(lit_bool)) = &arg.value {
-                    as_dependency = Some((lit_bool.value, arg))
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let ident = lit_str.parse::<Ident>().map_err(|_| {
+                        format_err_spanned!(
+                            lit_str,
+                            "expected an identifier for `ref_name` ink! config argument, found {:?}",
+                            lit_str.value(),
+                        )
+                    })?;
+                    ref_name = Some((ident, arg))
                 } else {
                     return Err(format_err_spanned!(
                         arg,
-                        "expected a bool literal for `compile_as_dependency` ink! config argument",
+                        "expected a string literal for `ref_name` ink! config argument",
                     ))
                 }
-            } else if arg.name.is_ident("env") {
-                if let Some((_, ast)) = env {
-                    return Err(duplicate_config_err(ast, arg, "env"))
+            } else if arg.name.is_ident("upgradeable") {
+                parse_bool_arg("upgradeable", arg, &mut upgradeable)?;
+            } else if arg.name.is_ident("emit_interface") {
+                parse_bool_arg("emit_interface", arg, &mut emit_interface)?;
+            } else if arg.name.is_ident("explicit_selectors") {
+                parse_bool_arg("explicit_selectors", arg, &mut explicit_selectors)?;
+            } else if arg.name.is_ident("max_event_fields") {
+                if let Some((_, ast)) = max_event_fields {
+                    return Err(duplicate_config_err(ast, arg, "max_event_fields"))
                 }
-                if let ast::PathOrLit::Path(path) = &arg.value {
-                    env = Some((Environment { path: path.clone() }, arg))
+                if let ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) = &arg.value {
+                    let value = lit_int.base10_parse::<usize>().map_err(|_| {
+                        format_err_spanned!(
+                            lit_int,
+                            "expected an unsigned integer literal for `max_event_fields` ink! config argument",
+                        )
+                    })?;
+                    max_event_fields = Some((value, arg))
                 } else {
                     return Err(format_err_spanned!(
                         arg,
-                        "expected a path for `env` ink! config argument",
+                        "expected an unsigned integer literal for `max_event_fields` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("hash") {
+                if let Some((_, ast)) = selector_hash {
+                    return Err(duplicate_config_err(ast, arg, "hash"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let value =
+                        ir::SelectorHash::from_name(&lit_str.value()).ok_or_else(|| {
+                            format_err_spanned!(
+                                lit_str,
+                                "unsupported ink! selector hash {:?}, supported hashes are: {}",
+                                lit_str.value(),
+                                ir::SelectorHash::NAMES.join(", "),
+                            )
+                        })?;
+                    selector_hash = Some((value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `hash` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("dispatch") {
+                if let Some((_, ast)) = dispatch {
+                    return Err(duplicate_config_err(ast, arg, "dispatch"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let value =
+                        DispatchStrategy::from_name(&lit_str.value()).ok_or_else(|| {
+                            format_err_spanned!(
+                                lit_str,
+                                "unsupported ink! dispatch strategy {:?}, supported strategies are: {}",
+                                lit_str.value(),
+                                DispatchStrategy::NAMES.join(", "),
+                            )
+                        })?;
+                    dispatch = Some((value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `dispatch` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("warn_pub_storage") {
+                parse_bool_arg("warn_pub_storage", arg, &mut warn_pub_storage)?;
+            } else if arg.name.is_ident("storage_access_hints") {
+                parse_bool_arg("storage_access_hints", arg, &mut storage_access_hints)?;
+            } else if arg.name.is_ident("embed_build_info") {
+                parse_bool_arg("embed_build_info", arg, &mut embed_build_info)?;
+            } else if arg.name.is_ident("allocator") {
+                if let Some((_, ast)) = allocator {
+                    return Err(duplicate_config_err(ast, arg, "allocator"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let value = Allocator::from_name(&lit_str.value()).ok_or_else(|| {
+                        format_err_spanned!(
+                            lit_str,
+                            "unsupported ink! allocator {:?}, supported allocators are: {}",
+                            lit_str.value(),
+                            Allocator::NAMES.join(", "),
+                        )
+                    })?;
+                    allocator = Some((value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `allocator` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("license") {
+                if let Some((_, ast)) = license {
+                    return Err(duplicate_config_err(ast, arg, "license"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let value = lit_str.value();
+                    if value.trim().is_empty() {
+                        return Err(format_err_spanned!(
+                            lit_str,
+                            "expected a non-empty string literal for `license` ink! config argument",
+                        ))
+                    }
+                    license = Some((value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `license` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("inline_helpers") {
+                parse_bool_arg("inline_helpers", arg, &mut inline_helpers)?;
+            } else if arg.name.is_ident("namespace") {
+                if let Some((_, ast)) = namespace {
+                    return Err(duplicate_config_err(ast, arg, "namespace"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let bytes = lit_str.value().into_bytes();
+                    if bytes.is_empty() {
+                        return Err(format_err_spanned!(
+                            lit_str,
+                            "expected a non-empty string literal for `namespace` ink! config argument",
+                        ))
+                    }
+                    namespace = Some((ir::Namespace::from(bytes), arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `namespace` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("metadata_version") {
+                if let Some((_, ast)) = metadata_version {
+                    return Err(duplicate_config_err(ast, arg, "metadata_version"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Int(lit_int)) = &arg.value {
+                    let value = lit_int
+                        .base10_parse::<u32>()
+                        .ok()
+                        .filter(|version| SUPPORTED_METADATA_VERSIONS.contains(version))
+                        .ok_or_else(|| {
+                            format_err_spanned!(
+                                lit_int,
+                                "unsupported ink! metadata version `{}`, supported versions are: {}",
+                                lit_int,
+                                SUPPORTED_METADATA_VERSIONS
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            )
+                        })?;
+                    metadata_version = Some((value, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected an integer literal for `metadata_version` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("warn_interior_mutability") {
+                parse_bool_arg(
+                    "warn_interior_mutability",
+                    arg,
+                    &mut warn_interior_mutability,
+                )?;
+            } else if arg.name.is_ident("expose_selectors") {
+                parse_bool_arg("expose_selectors", arg, &mut expose_selectors)?;
+            } else if arg.name.is_ident("validate_metadata_types") {
+                parse_bool_arg(
+                    "validate_metadata_types",
+                    arg,
+                    &mut validate_metadata_types,
+                )?;
+            } else if arg.name.is_ident("entry_call") {
+                if let Some((_, ast)) = entry_call {
+                    return Err(duplicate_config_err(ast, arg, "entry_call"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let ident = lit_str.parse::<Ident>().map_err(|_| {
+                        format_err_spanned!(
+                            lit_str,
+                            "expected a valid symbol identifier for `entry_call` ink! config argument, found {:?}",
+                            lit_str.value(),
+                        )
+                    })?;
+                    entry_call = Some((ident, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `entry_call` ink! config argument",
+                    ))
+                }
+            } else if arg.name.is_ident("entry_deploy") {
+                if let Some((_, ast)) = entry_deploy {
+                    return Err(duplicate_config_err(ast, arg, "entry_deploy"))
+                }
+                if let ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) = &arg.value {
+                    let ident = lit_str.parse::<Ident>().map_err(|_| {
+                        format_err_spanned!(
+                            lit_str,
+                            "expected a valid symbol identifier for `entry_deploy` ink! config argument, found {:?}",
+                            lit_str.value(),
+                        )
+                    })?;
+                    entry_deploy = Some((ident, arg))
+                } else {
+                    return Err(format_err_spanned!(
+                        arg,
+                        "expected a string literal for `entry_deploy` ink! config argument",
                     ))
                 }
-            } else {
+            } else if arg.name.is_ident("derive_debug") {
+                parse_bool_arg("derive_debug", arg, &mut derive_debug)?;
+            } else if arg.name.is_ident("migration_stubs") {
+                parse_bool_arg("migration_stubs", arg, &mut migration_stubs)?;
+            } else if arg.name.is_ident("no_panic_dispatch") {
+                parse_bool_arg("no_panic_dispatch", arg, &mut no_panic_dispatch)?;
+            } else if arg.name.is_ident("expose_call_info") {
+                parse_bool_arg("expose_call_info", arg, &mut expose_call_info)?;
+            } else if !lenient {
                 return Err(format_err_spanned!(
                     arg,
                     "encountered unknown or unsupported ink! config argument",
                 ))
             }
         }
+        let entry_name = |entry: &Option<(Ident, ast::MetaNameValue)>, default: &str| {
+            entry
+                .as_ref()
+                .map(|(ident, _)| ident.to_string())
+                .unwrap_or_else(|| default.to_string())
+        };
+        if entry_name(&entry_call, "call") == entry_name(&entry_deploy, "deploy") {
+            let arg = entry_deploy
+                .as_ref()
+                .or(entry_call.as_ref())
+                .map(|(_, arg)| arg)
+                .expect("the default entry point names are distinct");
+            return Err(format_err_spanned!(
+                arg,
+                "the `call` and `deploy` entry points must be exported under different names, \
+                 found `{}` for both",
+                entry_name(&entry_call, "call"),
+            ))
+        }
         Ok(Config {
             dynamic_storage_allocator: dynamic_storage_allocator.map(|(value, _)| value),
             as_dependency: as_dependency.map(|(value, _)| value),
             env: env.map(|(value, _)| value),
+            source_hash: source_hash.map(|(value, _)| value),
+            allow_no_constructor: allow_no_constructor.map(|(value, _)| value),
+            max_input_size: max_input_size.map(|(value, _)| value),
+            ref_name: ref_name.map(|(value, _)| value),
+            upgradeable: upgradeable.map(|(value, _)| value),
+            emit_interface: emit_interface.map(|(value, _)| value),
+            metadata_version: metadata_version.map(|(value, _)| value),
+            explicit_selectors: explicit_selectors.map(|(value, _)| value),
+            namespace: namespace.map(|(value, _)| value),
+            inline_helpers: inline_helpers.map(|(value, _)| value),
+            license: license.map(|(value, _)| value),
+            max_event_fields: max_event_fields.map(|(value, _)| value),
+            allocator: allocator.map(|(value, _)| value),
+            warn_pub_storage: warn_pub_storage.map(|(value, _)| value),
+            selector_hash: selector_hash.map(|(value, _)| value),
+            dispatch: dispatch.map(|(value, _)| value),
+            storage_access_hints: storage_access_hints.map(|(value, _)| value),
+            embed_build_info: embed_build_info.map(|(value, _)| value),
+            warn_interior_mutability: warn_interior_mutability.map(|(value, _)| value),
+            expose_selectors: expose_selectors.map(|(value, _)| value),
+            validate_metadata_types: validate_metadata_types.map(|(value, _)| value),
+            entry_call: entry_call.map(|(value, _)| value),
+            entry_deploy: entry_deploy.map(|(value, _)| value),
+            derive_debug: derive_debug.map(|(value, _)| value),
+            migration_stubs: migration_stubs.map(|(value, _)| value),
+            no_panic_dispatch: no_panic_dispatch.map(|(value, _)| value),
+            expose_call_info: expose_call_info.map(|(value, _)| value),
+            raw_args,
         })
     }
 }
@@ -149,6 +642,235 @@ impl Config {
     pub fn is_compile_as_dependency_enabled(&self) -> bool {
         self.as_dependency.unwrap_or(false)
     }
+
+    /// Returns `true` if the hash of the original contract source module shall
+    /// be included in the generated metadata, returns `false` otherwise.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_source_hash_enabled(&self) -> bool {
+        self.source_hash.unwrap_or(false)
+    }
+
+    /// Returns `true` if the ink! smart contract is allowed to have no
+    /// constructors, returns `false` otherwise.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_no_constructor_allowed(&self) -> bool {
+        self.allow_no_constructor.unwrap_or(false)
+    }
+
+    /// Returns the maximum size of the contract input in bytes if specified.
+    ///
+    /// If nothing has been specified returns the default which is `None`,
+    /// meaning that the contract input is unbounded.
+    pub fn max_input_size(&self) -> Option<usize> {
+        self.max_input_size
+    }
+
+    /// Returns the name under which the ink! smart contract reference type is
+    /// re-exported when the contract is compiled as dependency if specified.
+    ///
+    /// If nothing has been specified returns `None` in which case the name of
+    /// the storage struct suffixed with `Ref` is used.
+    pub fn ref_name(&self) -> Option<&Ident> {
+        self.ref_name.as_ref()
+    }
+
+    /// Returns `true` if a `set_code` ink! message shall be generated for the
+    /// ink! smart contract, returns `false` otherwise.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_upgradeable(&self) -> bool {
+        self.upgradeable.unwrap_or(false)
+    }
+
+    /// Returns `true` if a trait comprising the signatures of all ink! messages
+    /// shall be generated for the ink! smart contract, returns `false` otherwise.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_emit_interface_enabled(&self) -> bool {
+        self.emit_interface.unwrap_or(false)
+    }
+
+    /// Returns the version of the metadata schema generated for the ink!
+    /// smart contract.
+    ///
+    /// If nothing has been specified returns the default which is `0`.
+    pub fn metadata_version(&self) -> u32 {
+        self.metadata_version.unwrap_or(0)
+    }
+
+    /// Returns `true` if all ink! messages and constructors are required to
+    /// specify their selectors explicitly.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_explicit_selectors_enabled(&self) -> bool {
+        self.explicit_selectors.unwrap_or(false)
+    }
+
+    /// Returns the namespace of the ink! smart contract if specified.
+    ///
+    /// If specified the namespace seeds the composed selectors of all ink!
+    /// messages and constructors of the ink! smart contract.
+    pub fn namespace(&self) -> Option<&ir::Namespace> {
+        self.namespace.as_ref()
+    }
+
+    /// Returns `true` if small generated helper functions shall be marked
+    /// with `#[inline]`, returns `false` otherwise.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_inline_helpers_enabled(&self) -> bool {
+        self.inline_helpers.unwrap_or(false)
+    }
+
+    /// Returns the SPDX license identifier of the ink! smart contract if specified.
+    pub fn license(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+
+    /// Returns the number of fields above which ink! events are flagged with
+    /// an advisory warning if specified.
+    ///
+    /// If nothing has been specified returns `None` in which case ink! events
+    /// with any number of fields are accepted silently.
+    pub fn max_event_fields(&self) -> Option<usize> {
+        self.max_event_fields
+    }
+
+    /// Returns the global Wasm heap memory allocator of the ink! smart contract.
+    ///
+    /// If nothing has been specified returns [`Allocator::WeeAlloc`].
+    pub fn allocator(&self) -> Allocator {
+        self.allocator.unwrap_or(Allocator::WeeAlloc)
+    }
+
+    /// Returns `true` if advisory warnings for `pub` fields of the ink! storage
+    /// struct are enabled.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_warn_pub_storage_enabled(&self) -> bool {
+        self.warn_pub_storage.unwrap_or(false)
+    }
+
+    /// Returns the hash primitive used to compute the selectors of all ink!
+    /// messages and constructors.
+    ///
+    /// If nothing has been specified returns [`ir::SelectorHash::Blake2x256`].
+    pub fn selector_hash(&self) -> ir::SelectorHash {
+        self.selector_hash.unwrap_or(ir::SelectorHash::Blake2x256)
+    }
+
+    /// Returns the strategy used to dispatch on the selectors of incoming calls.
+    ///
+    /// If nothing has been specified returns [`DispatchStrategy::Match`].
+    pub fn dispatch(&self) -> DispatchStrategy {
+        self.dispatch.unwrap_or(DispatchStrategy::Match)
+    }
+
+    /// Returns `true` if the storage fields read and written by every ink!
+    /// message are emitted into the metadata.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_storage_access_hints_enabled(&self) -> bool {
+        self.storage_access_hints.unwrap_or(false)
+    }
+
+    /// Returns `true` if the versions of the Rust compiler and of ink! that
+    /// built the ink! smart contract are embedded into the metadata.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_embed_build_info_enabled(&self) -> bool {
+        self.embed_build_info.unwrap_or(false)
+    }
+
+    /// Returns `true` if fields of the ink! storage struct of interior mutability
+    /// types are flagged with advisory warnings.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_warn_interior_mutability_enabled(&self) -> bool {
+        self.warn_interior_mutability.unwrap_or(false)
+    }
+
+    /// Returns `true` if the `SELECTORS` constant listing all ink! message
+    /// selectors shall be generated for the ink! storage struct.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_expose_selectors_enabled(&self) -> bool {
+        self.expose_selectors.unwrap_or(false)
+    }
+
+    /// Returns `true` if the types of the ink! smart contract are checked to be
+    /// registrable in the metadata type registry.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_validate_metadata_types_enabled(&self) -> bool {
+        self.validate_metadata_types.unwrap_or(false)
+    }
+
+    /// Returns the name of the exported Wasm entry point dispatching calls to
+    /// the ink! messages of the ink! smart contract.
+    ///
+    /// If nothing has been specified returns the default which is `call`.
+    pub fn entry_call(&self) -> Ident {
+        self.entry_call
+            .clone()
+            .unwrap_or_else(|| format_ident!("call"))
+    }
+
+    /// Returns the name of the exported Wasm entry point dispatching calls to
+    /// the ink! constructors of the ink! smart contract.
+    ///
+    /// If nothing has been specified returns the default which is `deploy`.
+    pub fn entry_deploy(&self) -> Ident {
+        self.entry_deploy
+            .clone()
+            .unwrap_or_else(|| format_ident!("deploy"))
+    }
+
+    /// Returns `true` if `Debug` shall be derived for the ink! storage struct
+    /// and the ink! event structs in test and `std` builds.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_derive_debug_enabled(&self) -> bool {
+        self.derive_debug.unwrap_or(false)
+    }
+
+    /// Returns `true` if a `migrate` ink! message stub is generated for versioned
+    /// ink! storage structs.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_migration_stubs_enabled(&self) -> bool {
+        self.migration_stubs.unwrap_or(false)
+    }
+
+    /// Returns `true` if panics of ink! messages are caught during dispatch.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_no_panic_dispatch_enabled(&self) -> bool {
+        self.no_panic_dispatch.unwrap_or(false)
+    }
+
+    /// Returns `true` if the `CALL_INFO` table of the ink! messages is generated.
+    ///
+    /// If nothing has been specified returns the default which is `false`.
+    pub fn is_expose_call_info_enabled(&self) -> bool {
+        self.expose_call_info.unwrap_or(false)
+    }
+
+    /// Returns the original attribute arguments the configuration has been
+    /// parsed from.
+    ///
+    /// # Note
+    ///
+    /// This is mainly useful for tooling built on top of ink! that wants to
+    /// read config arguments that are not interpreted by ink! itself.
+    /// Unknown config arguments are rejected by the strict [`TryFrom`]
+    /// implementation but are readable here when the configuration has been
+    /// parsed via [`Config::try_from_lenient`].
+    pub fn raw_args(&self) -> &ast::AttributeArgs {
+        &self.raw_args
+    }
 }
 
 /// The environmental types definition.
@@ -166,20 +888,82 @@ impl Default for Environment {
     }
 }
 
+/// The global Wasm heap memory allocators supported by ink!.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Allocator {
+    /// The `wee_alloc` allocator which is optimized for a small code size.
+    WeeAlloc,
+    /// A bump allocator that never frees memory.
+    ///
+    /// It has an even smaller code size than `wee_alloc` and is well suited
+    /// for short lived contract executions that allocate little memory.
+    Bump,
+}
+
+impl Allocator {
+    /// The names of all supported allocators as used in the ink! config.
+    const NAMES: &'static [&'static str] = &["wee_alloc", "bump"];
+
+    /// Returns the allocator for the given name if it is supported.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "wee_alloc" => Some(Self::WeeAlloc),
+            "bump" => Some(Self::Bump),
+            _ => None,
+        }
+    }
+}
+
+/// The strategies to dispatch on the selectors of incoming calls.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DispatchStrategy {
+    /// A `match` over all selectors.
+    Match,
+    /// A binary search over all selectors sorted at compile time.
+    ///
+    /// This scales better than a `match` for contracts with many ink! messages
+    /// or constructors.
+    BinarySearch,
+}
+
+impl DispatchStrategy {
+    /// The names of all supported dispatch strategies as used in the ink! config.
+    const NAMES: &'static [&'static str] = &["match", "binary_search"];
+
+    /// Returns the dispatch strategy for the given name if it is supported.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "match" => Some(Self::Match),
+            "binary_search" => Some(Self::BinarySearch),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     /// Asserts that the given input config attribute argument are converted
     /// into the expected ink! configuration or yields the expected error message.
+    ///
+    /// The raw attribute arguments of the expected ink! configuration are set
+    /// to the given input.
     fn assert_try_from(
         input: ast::AttributeArgs,
         expected: Result<Config, &'static str>,
     ) {
         assert_eq!(
-            <Config as TryFrom<ast::AttributeArgs>>::try_from(input)
+            <Config as TryFrom<ast::AttributeArgs>>::try_from(input.clone())
                 .map_err(|err| err.to_string()),
-            expected.map_err(ToString::to_string),
+            expected
+                .map(|config| {
+                    Config {
+                        raw_args: input,
+                        ..config
+                    }
+                })
+                .map_err(ToString::to_string),
         );
     }
 
@@ -198,6 +982,34 @@ mod tests {
                 dynamic_storage_allocator: Some(true),
                 as_dependency: None,
                 env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
             }),
         )
     }
@@ -220,6 +1032,34 @@ mod tests {
                 dynamic_storage_allocator: None,
                 as_dependency: Some(false),
                 env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
             }),
         )
     }
@@ -246,6 +1086,34 @@ mod tests {
                 env: Some(Environment {
                     path: syn::parse_quote! { ::my::env::Types },
                 }),
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
             }),
         )
     }
@@ -259,21 +1127,1454 @@ mod tests {
     }
 
     #[test]
-    fn unknown_arg_fails() {
+    fn source_hash_works() {
         assert_try_from(
-            syn::parse_quote! { unknown = argument },
-            Err("encountered unknown or unsupported ink! config argument"),
-        );
+            syn::parse_quote! {
+                source_hash = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: Some(true),
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
     }
 
     #[test]
-    fn duplicate_args_fails() {
+    fn source_hash_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { source_hash = "invalid" },
+            Err("expected a bool literal for `source_hash` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn allow_no_constructor_works() {
         assert_try_from(
             syn::parse_quote! {
-                env = ::my::env::Types,
-                env = ::my::other::env::Types,
+                allow_no_constructor = true
             },
-            Err("encountered duplicate ink! `env` config argument"),
-        );
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: Some(true),
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn allow_no_constructor_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { allow_no_constructor = "invalid" },
+            Err(
+                "expected a bool literal for `allow_no_constructor` ink! config argument",
+            ),
+        )
+    }
+
+    #[test]
+    fn max_input_size_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_input_size = 1024
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: Some(1024),
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn max_input_size_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { max_input_size = "invalid" },
+            Err("expected an unsigned integer literal for `max_input_size` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { max_input_size = -1 },
+            Err("expected an unsigned integer literal for `max_input_size` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn ref_name_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                ref_name = "TokenRef"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: Some(syn::parse_quote! { TokenRef }),
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn ref_name_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { ref_name = TokenRef },
+            Err("expected a string literal for `ref_name` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { ref_name = "Token Ref" },
+            Err("expected an identifier for `ref_name` ink! config argument, found \"Token Ref\""),
+        );
+    }
+
+    #[test]
+    fn max_event_fields_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                max_event_fields = 16
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: Some(16),
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn max_event_fields_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { max_event_fields = "16" },
+            Err("expected an unsigned integer literal for `max_event_fields` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { max_event_fields = -1 },
+            Err("expected an unsigned integer literal for `max_event_fields` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn allocator_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                allocator = "bump"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: Some(Allocator::Bump),
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn allocator_defaults_to_wee_alloc() {
+        assert_eq!(Config::default().allocator(), Allocator::WeeAlloc);
+    }
+
+    #[test]
+    fn allocator_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { allocator = "dlmalloc" },
+            Err("unsupported ink! allocator \"dlmalloc\", supported allocators are: wee_alloc, bump"),
+        );
+        assert_try_from(
+            syn::parse_quote! { allocator = bump },
+            Err("expected a string literal for `allocator` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn warn_pub_storage_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                warn_pub_storage = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: Some(true),
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn warn_pub_storage_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { warn_pub_storage = "true" },
+            Err("expected a bool literal for `warn_pub_storage` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn storage_access_hints_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                storage_access_hints = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: Some(true),
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn storage_access_hints_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { storage_access_hints = 1 },
+            Err(
+                "expected a bool literal for `storage_access_hints` ink! config argument",
+            ),
+        );
+    }
+
+    #[test]
+    fn embed_build_info_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                embed_build_info = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: Some(true),
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn embed_build_info_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { embed_build_info = "yes" },
+            Err("expected a bool literal for `embed_build_info` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn warn_interior_mutability_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                warn_interior_mutability = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: Some(true),
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn warn_interior_mutability_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { warn_interior_mutability = "true" },
+            Err("expected a bool literal for `warn_interior_mutability` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn expose_selectors_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                expose_selectors = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: Some(true),
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn expose_selectors_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { expose_selectors = 1 },
+            Err("expected a bool literal for `expose_selectors` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn validate_metadata_types_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                validate_metadata_types = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: Some(true),
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn validate_metadata_types_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { validate_metadata_types = 1 },
+            Err("expected a bool literal for `validate_metadata_types` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn entry_points_work() {
+        assert_try_from(
+            syn::parse_quote! {
+                entry_call = "exec", entry_deploy = "init"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: Some(format_ident!("exec")),
+                entry_deploy: Some(format_ident!("init")),
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn entry_points_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { entry_call = exec },
+            Err("expected a string literal for `entry_call` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { entry_deploy = "0init" },
+            Err("expected a valid symbol identifier for `entry_deploy` ink! config argument, found \"0init\""),
+        );
+    }
+
+    #[test]
+    fn entry_points_with_equal_names_fail() {
+        assert_try_from(
+            syn::parse_quote! { entry_call = "exec", entry_deploy = "exec" },
+            Err("the `call` and `deploy` entry points must be exported under different names, \
+                 found `exec` for both"),
+        );
+        assert_try_from(
+            syn::parse_quote! { entry_call = "deploy" },
+            Err("the `call` and `deploy` entry points must be exported under different names, \
+                 found `deploy` for both"),
+        );
+    }
+
+    #[test]
+    fn derive_debug_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                derive_debug = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: Some(true),
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn derive_debug_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { derive_debug = 1 },
+            Err("expected a bool literal for `derive_debug` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn migration_stubs_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                migration_stubs = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: Some(true),
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn migration_stubs_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { migration_stubs = 1 },
+            Err("expected a bool literal for `migration_stubs` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn no_panic_dispatch_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                no_panic_dispatch = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: Some(true),
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn no_panic_dispatch_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { no_panic_dispatch = 1 },
+            Err("expected a bool literal for `no_panic_dispatch` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn expose_call_info_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                expose_call_info = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: Some(true),
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn expose_call_info_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { expose_call_info = 1 },
+            Err("expected a bool literal for `expose_call_info` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn selector_hash_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                hash = "keccak_256"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: Some(ir::SelectorHash::Keccak256),
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn selector_hash_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { hash = "sha2_256" },
+            Err("unsupported ink! selector hash \"sha2_256\", supported hashes are: blake2_256, keccak_256"),
+        );
+        assert_try_from(
+            syn::parse_quote! { hash = keccak_256 },
+            Err("expected a string literal for `hash` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn dispatch_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                dispatch = "binary_search"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: Some(DispatchStrategy::BinarySearch),
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn dispatch_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { dispatch = "perfect_hash" },
+            Err("unsupported ink! dispatch strategy \"perfect_hash\", supported strategies are: match, binary_search"),
+        );
+        assert_try_from(
+            syn::parse_quote! { dispatch = binary_search },
+            Err("expected a string literal for `dispatch` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn license_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                license = "Apache-2.0"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: Some("Apache-2.0".to_string()),
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn license_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { license = 42 },
+            Err("expected a string literal for `license` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { license = " " },
+            Err("expected a non-empty string literal for `license` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn inline_helpers_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                inline_helpers = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: Some(true),
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn inline_helpers_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { inline_helpers = "yes" },
+            Err("expected a bool literal for `inline_helpers` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn namespace_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                namespace = "vault"
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: Some(ir::Namespace::from(b"vault".to_vec())),
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn namespace_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { namespace = vault },
+            Err("expected a string literal for `namespace` ink! config argument"),
+        );
+        assert_try_from(
+            syn::parse_quote! { namespace = "" },
+            Err("expected a non-empty string literal for `namespace` ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn namespace_duplicate_fails() {
+        assert_try_from(
+            syn::parse_quote! {
+                namespace = "vault",
+                namespace = "bank",
+            },
+            Err("encountered duplicate ink! `namespace` config argument"),
+        );
+    }
+
+    #[test]
+    fn upgradeable_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                upgradeable = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: Some(true),
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn upgradeable_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { upgradeable = "invalid" },
+            Err("expected a bool literal for `upgradeable` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn emit_interface_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                emit_interface = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: Some(true),
+                metadata_version: None,
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn emit_interface_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { emit_interface = "invalid" },
+            Err("expected a bool literal for `emit_interface` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn metadata_version_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                metadata_version = 1
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: Some(1),
+                explicit_selectors: None,
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn explicit_selectors_works() {
+        assert_try_from(
+            syn::parse_quote! {
+                explicit_selectors = true
+            },
+            Ok(Config {
+                dynamic_storage_allocator: None,
+                as_dependency: None,
+                env: None,
+                source_hash: None,
+                allow_no_constructor: None,
+                max_input_size: None,
+                ref_name: None,
+                upgradeable: None,
+                emit_interface: None,
+                metadata_version: None,
+                explicit_selectors: Some(true),
+                namespace: None,
+                inline_helpers: None,
+                license: None,
+                max_event_fields: None,
+                allocator: None,
+                warn_pub_storage: None,
+                selector_hash: None,
+                dispatch: None,
+                storage_access_hints: None,
+                embed_build_info: None,
+                warn_interior_mutability: None,
+                expose_selectors: None,
+                validate_metadata_types: None,
+                entry_call: None,
+                entry_deploy: None,
+                derive_debug: None,
+                migration_stubs: None,
+                no_panic_dispatch: None,
+                expose_call_info: None,
+                raw_args: Default::default(),
+            }),
+        )
+    }
+
+    #[test]
+    fn explicit_selectors_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { explicit_selectors = "invalid" },
+            Err("expected a bool literal for `explicit_selectors` ink! config argument"),
+        )
+    }
+
+    #[test]
+    fn metadata_version_unsupported_fails() {
+        assert_try_from(
+            syn::parse_quote! { metadata_version = 2 },
+            Err("unsupported ink! metadata version `2`, supported versions are: 0, 1"),
+        )
+    }
+
+    #[test]
+    fn metadata_version_invalid_value_fails() {
+        assert_try_from(
+            syn::parse_quote! { metadata_version = true },
+            Err(
+                "expected an integer literal for `metadata_version` ink! config argument",
+            ),
+        )
+    }
+
+    #[test]
+    fn unknown_arg_fails() {
+        assert_try_from(
+            syn::parse_quote! { unknown = argument },
+            Err("encountered unknown or unsupported ink! config argument"),
+        );
+    }
+
+    #[test]
+    fn lenient_unknown_arg_works() {
+        let args: ast::AttributeArgs = syn::parse_quote! {
+            unknown = argument,
+            upgradeable = true,
+        };
+        let config = Config::try_from_lenient(args.clone()).unwrap();
+        assert!(config.is_upgradeable());
+        assert_eq!(config.raw_args(), &args);
+        let names = config
+            .raw_args()
+            .iter()
+            .map(|arg| arg.name.clone())
+            .collect::<Vec<syn::Path>>();
+        assert_eq!(
+            names,
+            vec![
+                syn::parse_quote! { unknown },
+                syn::parse_quote! { upgradeable }
+            ]
+        );
+    }
+
+    #[test]
+    fn lenient_invalid_known_arg_fails() {
+        assert_eq!(
+            Config::try_from_lenient(
+                syn::parse_quote! { unknown = argument, upgradeable = 1 }
+            )
+            .map_err(|err| err.to_string()),
+            Err(
+                "expected a bool literal for `upgradeable` ink! config argument"
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn duplicate_args_fails() {
+        assert_try_from(
+            syn::parse_quote! {
+                env = ::my::env::Types,
+                env = ::my::other::env::Types,
+            },
+            Err("encountered duplicate ink! `env` config argument"),
+        );
+    }
+
+    #[test]
+    fn duplicate_args_error_points_to_second_arg() {
+        let args = syn::parse_str::<ast::AttributeArgs>("env = A, env = A").unwrap();
+        let error = <Config as TryFrom<ast::AttributeArgs>>::try_from(args).unwrap_err();
+        let spans = error
+            .into_iter()
+            .map(|error| {
+                let span = error.span();
+                (span.start().column, span.end().column)
+            })
+            .collect::<Vec<_>>();
+        // The first error points to the duplicate argument while the
+        // second error points to the first occurrence of the argument.
+        assert_eq!(spans, vec![(9, 16), (0, 7)]);
     }
 }
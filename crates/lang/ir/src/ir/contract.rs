@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::blake2::blake2b_256;
 use crate::{
     ast,
     ir,
 };
 use core::convert::TryFrom;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+    TokenTree as TokenTree2,
+};
+use std::collections::BTreeSet;
 
 /// An ink! contract definition consisting of the ink! configuration and module.
 ///
@@ -42,6 +48,10 @@ pub struct Contract {
     item: ir::ItemMod,
     /// The specified ink! configuration.
     config: ir::Config,
+    /// The hash of the original ink! module token stream.
+    source_hash: [u8; 32],
+    /// The ink! trait definitions declared within the ink! module.
+    trait_definitions: Vec<ir::InkTrait>,
 }
 
 impl Contract {
@@ -59,20 +69,121 @@ impl Contract {
     ///
     /// Returns an error if the provided token stream cannot be decoded properly
     /// into a valid ink! configuration or ink! module respectively.
+    ///
+    /// Also returns an error if the ink! module does not contain any ink!
+    /// constructor unless `allow_no_constructor = true` has been configured.
+    ///
+    /// For `validate_metadata_types = true` also returns an error if any type
+    /// of the ink! smart contract cannot be registered in the metadata.
+    ///
+    /// For `derive_debug = true` also returns an error if the ink! storage struct
+    /// or any ink! event struct already implements `Debug`.
+    ///
+    /// For `upgradeable = true` also returns an error if a user defined ink!
+    /// message has the same selector as the generated `set_code` ink! message.
+    ///
+    /// For `migration_stubs = true` also returns an error if a user defined ink!
+    /// message has the same selector as the generated `migrate` ink! message.
     pub fn new(
         ink_config: TokenStream2,
         ink_module: TokenStream2,
     ) -> Result<Self, syn::Error> {
+        let source_hash = compute_source_hash(&ink_module);
         let config = syn::parse2::<ast::AttributeArgs>(ink_config)?;
         let module = syn::parse2::<syn::ItemMod>(ink_module)?;
         let ink_config = ir::Config::try_from(config)?;
-        let ink_module = ir::ItemMod::try_from(module)?;
+        let mut ink_module = ir::ItemMod::try_from(module)?;
+        if !ink_config.is_no_constructor_allowed() {
+            ink_module.ensure_contains_constructor()?;
+        }
+        if ink_config.is_explicit_selectors_enabled() {
+            ink_module.ensure_explicit_selectors()?;
+        }
+        if ink_config.is_validate_metadata_types_enabled() {
+            ink_module.ensure_registrable_types()?;
+        }
+        if ink_config.is_derive_debug_enabled() {
+            ink_module.ensure_no_manual_debug_impls()?;
+        }
+        if ink_config.is_upgradeable() {
+            ink_module.add_set_code_message()?;
+        }
+        if ink_config.is_migration_stubs_enabled() {
+            ink_module.add_migrate_message()?;
+        }
+        if let Some(namespace) = ink_config.namespace() {
+            ink_module.set_contract_namespace(namespace)?;
+        }
+        if ink_config.selector_hash() != ir::SelectorHash::Blake2x256 {
+            ink_module.set_selector_hash(ink_config.selector_hash())?;
+        }
+        let trait_definitions = collect_trait_definitions(&ink_module);
         Ok(Self {
             item: ink_module,
             config: ink_config,
+            source_hash,
+            trait_definitions,
         })
     }
 
+    /// Creates a new ink! contract from the single `#[ink::contract]` module
+    /// found within the given Rust source file.
+    ///
+    /// This is useful for tooling, e.g. formatters or analyzers, that operate
+    /// on whole source files instead of the input of the ink! macro.
+    ///
+    /// # Note
+    ///
+    /// The `#[ink::contract]` module may be nested within other inline modules
+    /// of the file. Both `#[ink::contract]` and `#[ink_lang::contract]` are
+    /// recognized, also if they are conditionally applied via `cfg_attr`, e.g.
+    /// `#[cfg_attr(feature = "contract", ink::contract)]`. The conditions of
+    /// `cfg_attr` are not evaluated and only the `ink::contract` annotation is
+    /// removed from it, other conditionally applied attributes are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file contains no or more than one
+    /// `#[ink::contract]` module or if that module is not a valid ink!
+    /// smart contract as described in [`Contract::new`].
+    pub fn from_file(file: syn::File) -> Result<Self, syn::Error> {
+        use quote::ToTokens as _;
+        let mut modules = Vec::new();
+        collect_contract_modules(file.items, &mut modules);
+        let mut modules = modules.into_iter();
+        let mut module = match modules.next() {
+            Some(module) => module,
+            None => {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "encountered no #[ink::contract] module in the file",
+                ))
+            }
+        };
+        if let Some(duplicate) = modules.next() {
+            return Err(format_err_spanned!(
+                duplicate,
+                "encountered multiple #[ink::contract] modules in the file",
+            ))
+        }
+        let (position, (ink_config, remaining)) = module
+            .attrs
+            .iter()
+            .enumerate()
+            .find_map(|(position, attr)| {
+                contract_attr_config(attr).map(|config| (position, config))
+            })
+            .expect("collected modules must have an #[ink::contract] attribute");
+        let ink_config = ink_config?;
+        match remaining {
+            Some(remaining) => module.attrs[position] = remaining,
+            None => {
+                module.attrs.remove(position);
+            }
+        }
+        Self::new(ink_config, module.into_token_stream())
+    }
+
     /// Returns the ink! inline module definition.
     ///
     /// # Note
@@ -90,6 +201,16 @@ impl Contract {
         &self.item
     }
 
+    /// Returns the identifier of the ink! module as declared in the source.
+    ///
+    /// # Note
+    ///
+    /// The ink! module is never renamed by ink! so this is the same identifier
+    /// under which the generated module is available.
+    pub fn module_ident(&self) -> &Ident {
+        self.module().ident()
+    }
+
     /// Returns the configuration of the ink! smart contract.
     ///
     /// # Note
@@ -108,10 +229,1385 @@ impl Contract {
     ///                    if it was a dependency of another smart contract.
     ///                    This configuration is mainly needed for testing and
     ///                    the default is `false`.
+    /// - `source_hash`: If `true` includes the hash of the original ink! module
+    ///                  in the generated metadata. The default is `false`.
+    /// - `allow_no_constructor`: If `true` allows the ink! smart contract to
+    ///                           have no constructors, e.g. for library
+    ///                           contracts. The default is `false`.
+    /// - `max_input_size`: If set rejects contract inputs larger than the given
+    ///                     amount of bytes. The default is unbounded.
+    /// - `ref_name`: The name under which the ink! smart contract reference type
+    ///               is re-exported when compiled as dependency. The default is
+    ///               the name of the storage struct suffixed with `Ref`.
+    /// - `upgradeable`: If `true` generates a `set_code` ink! message that allows
+    ///                  to replace the code of the ink! smart contract. The
+    ///                  default is `false`.
+    /// - `emit_interface`: If `true` generates a trait comprising the signatures
+    ///                     of all ink! messages. The default is `false`.
+    /// - `metadata_version`: The version of the generated metadata schema.
+    ///                       The default is `0`.
     ///
-    /// Note that we might add more configuration fields in the future if
-    /// necessary.
+    /// See the documentation of the `ink::contract` attribute macro for the
+    /// complete list of configuration fields.
     pub fn config(&self) -> &ir::Config {
         &self.config
     }
+
+    /// Returns the hash of the original ink! module token stream.
+    ///
+    /// # Note
+    ///
+    /// The hash is computed before any ink! specific items are moved out of
+    /// the module and thus identifies the contract source as written by the
+    /// user. The hash is insensitive to whitespace and regular comments.
+    pub fn source_hash(&self) -> [u8; 32] {
+        self.source_hash
+    }
+
+    /// Returns the fingerprint of the public API of the ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// The fingerprint is the BLAKE-2b 256-bit hash of the sorted set of the
+    /// selectors, names, argument types, return types and mutabilities of all
+    /// ink! constructors and messages. It is thus insensitive to the order in
+    /// which they are defined, their documentation and their implementation
+    /// and can be used to check that the public API did not change across
+    /// versions of an ink! smart contract.
+    ///
+    /// Test-only ink! messages are not part of the public API.
+    pub fn api_fingerprint(&self) -> [u8; 32] {
+        let signatures = ir::abi_diff::callable_signatures(self)
+            .map(|signature| {
+                let (kind, mutability) = match signature.kind() {
+                    ir::AbiItemKind::Constructor => ("constructor", ""),
+                    _ if signature.mutates() => ("message", "w"),
+                    _ => ("message", "r"),
+                };
+                let selector = signature
+                    .selector()
+                    .expect("ink! constructors and messages always have a selector");
+                let [b0, b1, b2, b3] = *selector.as_bytes();
+                let inputs = signature
+                    .inputs()
+                    .iter()
+                    .map(|input| format!("{}, ", input))
+                    .collect::<String>();
+                format!(
+                    "{} 0x{:02X}{:02X}{:02X}{:02X} {}({}) -> {}{}",
+                    kind,
+                    b0,
+                    b1,
+                    b2,
+                    b3,
+                    signature.name(),
+                    inputs,
+                    signature.output().unwrap_or_default(),
+                    mutability,
+                )
+            })
+            .collect::<BTreeSet<_>>();
+        let buffer = signatures.into_iter().collect::<Vec<_>>().join("\n");
+        let mut output = [0x00_u8; 32];
+        blake2b_256(buffer.as_bytes(), &mut output);
+        output
+    }
+
+    /// Returns the difference between the ABI of this ink! smart contract and
+    /// the ABI of the `other` ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// This ink! smart contract is treated as the original and `other` as the
+    /// new version, e.g. when reviewing an upgrade. ink! constructors and
+    /// messages are matched by their composed selectors and ink! events by
+    /// their names. Matching items with differing names, input types, return
+    /// types, mutabilities or payabilities are reported as changed with the
+    /// changes classified by [`ir::AbiChange`].
+    ///
+    /// Test-only ink! messages are not part of the ABI.
+    pub fn abi_diff(&self, other: &Contract) -> ir::AbiDiff {
+        ir::AbiDiff::new(self, other)
+    }
+
+    /// Returns `true` if a top-level item of the ink! module is named `name`.
+    ///
+    /// # Note
+    ///
+    /// This is mainly useful for procedural macros built on top of ink! that
+    /// need to inject their own items into the ink! module without colliding
+    /// with user defined items. Names brought into scope by `use` declarations
+    /// are taken into account while items nested in other items are not.
+    pub fn contains_ident(&self, name: &str) -> bool {
+        self.module().items().iter().any(|item| {
+            match item {
+                ir::Item::Ink(ir::InkItem::Storage(storage)) => storage.ident() == name,
+                ir::Item::Ink(ir::InkItem::Event(event)) => event.ident() == name,
+                ir::Item::Ink(ir::InkItem::ImplBlock(_)) => false,
+                ir::Item::Rust(item) => rust_item_contains_ident(item, name),
+            }
+        })
+    }
+
+    /// Returns the names of the environment functions called by the ink!
+    /// messages and constructors of the ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// This is a best-effort syntactic scan mainly useful for auditing which
+    /// host functions an ink! smart contract might call. Only calls of the
+    /// form `self.env().f(..)` and `Self::env().f(..)` within the bodies of
+    /// ink! messages and constructors are found. Calls made through helper
+    /// functions or other indirections are not taken into account.
+    ///
+    /// The returned names are sorted and deduplicated.
+    pub fn env_calls(&self) -> Vec<String> {
+        use ir::Callable as _;
+        let mut collector = EnvCallCollector::default();
+        for item_impl in self.module().impls() {
+            let messages = item_impl
+                .iter_messages()
+                .map(|message| message.callable().statements());
+            let constructors = item_impl
+                .iter_constructors()
+                .map(|constructor| constructor.callable().statements());
+            for stmt in messages.chain(constructors).flatten() {
+                syn::visit::Visit::visit_stmt(&mut collector, stmt);
+            }
+        }
+        collector.env_calls.into_iter().collect()
+    }
+
+    /// Returns the ink! message dispatched for the given selector if any.
+    ///
+    /// # Note
+    ///
+    /// This is mainly useful for tooling that needs to decode the input of a
+    /// call to the ink! smart contract. The selector is matched against the
+    /// composed selectors of the ink! messages, i.e. after namespaces and the
+    /// configured selector hash have been applied.
+    ///
+    /// ink! messages cannot have wildcard selectors. Use
+    /// [`Contract::constructor_by_selector`] for ink! constructors.
+    pub fn message_by_selector(&self, selector: [u8; 4]) -> Option<&ir::Message> {
+        self.module()
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .find(|message| message.composed_selector().as_bytes() == &selector)
+            .map(|message| message.callable())
+    }
+
+    /// Returns the ink! constructor dispatched for the given selector if any.
+    ///
+    /// # Note
+    ///
+    /// The selector is matched against the composed selectors of the ink!
+    /// constructors. If none of them matches and `include_wildcard` is `true`
+    /// the ink! constructor with the wildcard selector `selector = _` is
+    /// returned if any since it is run for all unknown selectors.
+    pub fn constructor_by_selector(
+        &self,
+        selector: [u8; 4],
+        include_wildcard: bool,
+    ) -> Option<&ir::Constructor> {
+        let constructors = || {
+            self.module()
+                .impls()
+                .flat_map(ir::ItemImpl::iter_constructors)
+        };
+        constructors()
+            .find(|constructor| constructor.composed_selector().as_bytes() == &selector)
+            .or_else(|| {
+                include_wildcard
+                    .then(|| {
+                        constructors()
+                            .find(|constructor| constructor.has_wildcard_selector())
+                    })
+                    .flatten()
+            })
+            .map(|constructor| constructor.callable())
+    }
+
+    /// Returns the ink! trait definitions declared within the ink! module.
+    ///
+    /// # Note
+    ///
+    /// Only traits annotated with `#[ink::trait_definition]` at the top-level
+    /// of the ink! module are found. Use [`ir::InkTrait::selectors`] to query
+    /// the selectors of their ink! constructors and messages.
+    pub fn trait_definitions(&self) -> impl Iterator<Item = &ir::InkTrait> {
+        self.trait_definitions.iter()
+    }
+
+    /// Returns the paths of the types referenced by the fields of the ink!
+    /// storage struct.
+    ///
+    /// # Note
+    ///
+    /// This is a syntactic scan mainly useful for tooling that wants to know
+    /// which types the ink! storage depends upon. The generic arguments of the
+    /// field types are taken into account one level deep, e.g. for a field of
+    /// type `Mapping<AccountId, Balance>` the paths `Mapping`, `AccountId` and
+    /// `Balance` are returned. The returned paths do not carry generic arguments.
+    ///
+    /// If `exclude_primitives` is `true` primitive types such as `bool` or `u32`
+    /// are not part of the result.
+    ///
+    /// The returned paths are deduplicated and in order of their first occurrence.
+    pub fn storage_referenced_types(&self, exclude_primitives: bool) -> Vec<syn::Path> {
+        let mut paths = Vec::new();
+        for field in self.module().storage().fields() {
+            ir::utils::collect_type_paths(&field.ty, 1, &mut paths);
+        }
+        let mut referenced_types = Vec::new();
+        for path in paths {
+            if exclude_primitives && is_primitive_type_path(&path) {
+                continue
+            }
+            if !referenced_types.contains(&path) {
+                referenced_types.push(path);
+            }
+        }
+        referenced_types
+    }
+}
+
+/// Returns the ink! trait definitions declared at the top-level of the ink! module.
+///
+/// # Note
+///
+/// Malformed ink! trait definitions are skipped since they are reported by
+/// the `#[ink::trait_definition]` macro itself once it is expanded.
+fn collect_trait_definitions(module: &ir::ItemMod) -> Vec<ir::InkTrait> {
+    module
+        .items()
+        .iter()
+        .filter_map(|item| {
+            match item {
+                ir::Item::Rust(syn::Item::Trait(item_trait)) => Some(item_trait),
+                _ => None,
+            }
+        })
+        .filter_map(|item_trait| {
+            let (trait_def_attrs, attrs): (Vec<_>, Vec<_>) = item_trait
+                .attrs
+                .iter()
+                .cloned()
+                .partition(is_trait_definition_attr);
+            if trait_def_attrs.is_empty() {
+                return None
+            }
+            let item_trait = syn::ItemTrait {
+                attrs,
+                ..item_trait.clone()
+            };
+            ir::InkTrait::try_from(item_trait).ok()
+        })
+        .collect()
+}
+
+/// Returns `true` if the attribute is `#[ink::trait_definition]` or
+/// `#[ink_lang::trait_definition]`.
+fn is_trait_definition_attr(attr: &syn::Attribute) -> bool {
+    let segments = &attr.path.segments;
+    segments.len() == 2
+        && (segments[0].ident == "ink" || segments[0].ident == "ink_lang")
+        && segments[1].ident == "trait_definition"
+}
+
+/// Returns `true` if the path is `ink::contract` or `ink_lang::contract`.
+fn is_contract_path(path: &syn::Path) -> bool {
+    let segments = &path.segments;
+    segments.len() == 2
+        && (segments[0].ident == "ink" || segments[0].ident == "ink_lang")
+        && segments[1].ident == "contract"
+}
+
+/// Returns the ink! configuration of the attribute if it is `#[ink::contract]`
+/// or `#[ink_lang::contract]`, possibly wrapped in (nested) `cfg_attr`.
+///
+/// Also returns what remains of the attribute once the `#[ink::contract]`
+/// annotation has been removed from it. Only a `cfg_attr` that conditionally
+/// applies further attributes remains, e.g. `#[cfg_attr(test, allow(unused))]`
+/// out of `#[cfg_attr(test, allow(unused), ink::contract)]`.
+///
+/// Returns `None` if the attribute is no `#[ink::contract]` attribute.
+fn contract_attr_config(
+    attr: &syn::Attribute,
+) -> Option<(Result<TokenStream2, syn::Error>, Option<syn::Attribute>)> {
+    /// The condition of a `cfg_attr` and the paths and arguments of the
+    /// attributes it conditionally applies.
+    type CfgAttrArgs = (syn::NestedMeta, Vec<(syn::Path, Option<TokenStream2>)>);
+
+    /// Parses the arguments of `cfg_attr` into its condition and the paths
+    /// and arguments of the conditionally applied attributes.
+    fn parse_cfg_attr(input: syn::parse::ParseStream) -> Result<CfgAttrArgs, syn::Error> {
+        let condition = input.parse::<syn::NestedMeta>()?;
+        let mut attrs = Vec::new();
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break
+            }
+            let path = input.call(syn::Path::parse_mod_style)?;
+            let args = if input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                Some(content.parse::<TokenStream2>()?)
+            } else {
+                None
+            };
+            attrs.push((path, args));
+        }
+        Ok((condition, attrs))
+    }
+    /// Returns the ink! configuration and the remaining tokens of the
+    /// attribute with the given path and arguments.
+    fn find_config(
+        path: &syn::Path,
+        args: Option<TokenStream2>,
+    ) -> Option<(Result<TokenStream2, syn::Error>, Option<TokenStream2>)> {
+        if is_contract_path(path) {
+            return Some((Ok(args.unwrap_or_default()), None))
+        }
+        if !path.is_ident("cfg_attr") {
+            return None
+        }
+        let (condition, mut attrs) =
+            syn::parse::Parser::parse2(parse_cfg_attr, args?).ok()?;
+        let (position, (config, remaining)) =
+            attrs
+                .iter()
+                .enumerate()
+                .find_map(|(position, (path, args))| {
+                    find_config(path, args.clone()).map(|found| (position, found))
+                })?;
+        let remaining_attrs = attrs
+            .drain(..)
+            .enumerate()
+            .filter_map(|(n, (path, args))| {
+                if n != position {
+                    return Some(match args {
+                        Some(args) => quote::quote! { #path(#args) },
+                        None => quote::quote! { #path },
+                    })
+                }
+                remaining.clone()
+            })
+            .collect::<Vec<_>>();
+        if remaining_attrs.is_empty() {
+            return Some((config, None))
+        }
+        Some((
+            config,
+            Some(quote::quote! { cfg_attr(#condition, #( #remaining_attrs ),*) }),
+        ))
+    }
+    if is_contract_path(&attr.path) {
+        if attr.tokens.is_empty() {
+            return Some((Ok(TokenStream2::new()), None))
+        }
+        return Some((attr.parse_args::<TokenStream2>(), None))
+    }
+    if attr.path.is_ident("cfg_attr") {
+        let (config, remaining) =
+            find_config(&attr.path, attr.parse_args::<TokenStream2>().ok())?;
+        let remaining = remaining.map(|remaining| syn::parse_quote!(#[#remaining]));
+        return Some((config, remaining))
+    }
+    None
+}
+
+/// Collects all `#[ink::contract]` modules among the given items.
+///
+/// Descends into inline modules that are not `#[ink::contract]` modules.
+fn collect_contract_modules(items: Vec<syn::Item>, modules: &mut Vec<syn::ItemMod>) {
+    for item in items {
+        if let syn::Item::Mod(item_mod) = item {
+            if item_mod
+                .attrs
+                .iter()
+                .any(|attr| contract_attr_config(attr).is_some())
+            {
+                modules.push(item_mod)
+            } else if let Some((_, items)) = item_mod.content {
+                collect_contract_modules(items, modules)
+            }
+        }
+    }
+}
+
+/// Returns `true` if the given path refers to a Rust primitive type.
+fn is_primitive_type_path(path: &syn::Path) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16",
+        "i32", "i64", "i128", "isize", "f32", "f64",
+    ];
+    path.get_ident()
+        .map(|ident| PRIMITIVES.iter().any(|primitive| ident == primitive))
+        .unwrap_or(false)
+}
+
+/// Collects the names of all methods called on the environment accessor.
+#[derive(Default)]
+struct EnvCallCollector {
+    env_calls: BTreeSet<String>,
+}
+
+impl EnvCallCollector {
+    /// Returns `true` if the expression is either `self.env()` or `Self::env()`.
+    fn is_env_accessor(expr: &syn::Expr) -> bool {
+        match expr {
+            syn::Expr::MethodCall(method_call) => {
+                method_call.method == "env"
+                    && method_call.args.is_empty()
+                    && matches!(
+                        &*method_call.receiver,
+                        syn::Expr::Path(expr_path) if expr_path.path.is_ident("self")
+                    )
+            }
+            syn::Expr::Call(call) => {
+                call.args.is_empty()
+                    && matches!(
+                        &*call.func,
+                        syn::Expr::Path(expr_path)
+                            if expr_path.path.segments.len() == 2
+                                && expr_path.path.segments[0].ident == "Self"
+                                && expr_path.path.segments[1].ident == "env"
+                    )
+            }
+            syn::Expr::Paren(paren) => Self::is_env_accessor(&paren.expr),
+            _ => false,
+        }
+    }
+}
+
+impl<'ast> syn::visit::Visit<'ast> for EnvCallCollector {
+    fn visit_expr_method_call(&mut self, method_call: &'ast syn::ExprMethodCall) {
+        if Self::is_env_accessor(&method_call.receiver) {
+            self.env_calls.insert(method_call.method.to_string());
+        }
+        syn::visit::visit_expr_method_call(self, method_call)
+    }
+}
+
+/// Returns `true` if the given Rust item defines or imports an item named `name`.
+fn rust_item_contains_ident(item: &syn::Item, name: &str) -> bool {
+    fn use_tree_contains_ident(tree: &syn::UseTree, name: &str) -> bool {
+        match tree {
+            syn::UseTree::Path(use_path) => use_tree_contains_ident(&use_path.tree, name),
+            syn::UseTree::Name(use_name) => use_name.ident == name,
+            syn::UseTree::Rename(use_rename) => use_rename.rename == name,
+            syn::UseTree::Glob(_) => false,
+            syn::UseTree::Group(use_group) => {
+                use_group
+                    .items
+                    .iter()
+                    .any(|tree| use_tree_contains_ident(tree, name))
+            }
+        }
+    }
+    let ident = match item {
+        syn::Item::Const(item) => &item.ident,
+        syn::Item::Enum(item) => &item.ident,
+        syn::Item::ExternCrate(item) => {
+            item.rename
+                .as_ref()
+                .map(|(_, rename)| rename)
+                .unwrap_or(&item.ident)
+        }
+        syn::Item::Fn(item) => &item.sig.ident,
+        syn::Item::Macro(item) => {
+            match &item.ident {
+                Some(ident) => ident,
+                None => return false,
+            }
+        }
+        syn::Item::Macro2(item) => &item.ident,
+        syn::Item::Mod(item) => &item.ident,
+        syn::Item::Static(item) => &item.ident,
+        syn::Item::Struct(item) => &item.ident,
+        syn::Item::Trait(item) => &item.ident,
+        syn::Item::TraitAlias(item) => &item.ident,
+        syn::Item::Type(item) => &item.ident,
+        syn::Item::Union(item) => &item.ident,
+        syn::Item::Use(item) => return use_tree_contains_ident(&item.tree, name),
+        _ => return false,
+    };
+    ident == name
+}
+
+/// Writes the given tokens into the buffer in a whitespace insensitive form.
+///
+/// Every token is written in its textual form followed by a single space.
+/// Groups are written as their opening delimiter, their normalized inner
+/// tokens and their closing delimiter.
+fn normalize_tokens(tokens: TokenStream2, buffer: &mut String) {
+    for token in tokens {
+        match token {
+            TokenTree2::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                    proc_macro2::Delimiter::Brace => ("{", "}"),
+                    proc_macro2::Delimiter::Bracket => ("[", "]"),
+                    proc_macro2::Delimiter::None => ("", ""),
+                };
+                buffer.push_str(open);
+                buffer.push(' ');
+                normalize_tokens(group.stream(), buffer);
+                buffer.push_str(close);
+            }
+            TokenTree2::Ident(ident) => buffer.push_str(&ident.to_string()),
+            TokenTree2::Punct(punct) => buffer.push(punct.as_char()),
+            TokenTree2::Literal(literal) => buffer.push_str(&literal.to_string()),
+        }
+        buffer.push(' ');
+    }
+}
+
+/// Computes the BLAKE-2b 256-bit hash of the given ink! module token stream.
+///
+/// # Note
+///
+/// Before hashing the token stream is normalized so that the hash is stable
+/// across reformatting of the original source:
+///
+/// - Every token is written in its textual form separated by a single space.
+/// - Groups are written as their opening delimiter, their normalized inner
+///   tokens and their closing delimiter.
+/// - Punctuation spacing and source spans are ignored.
+///
+/// Since comments are not part of the token stream they do not contribute
+/// to the hash while doc comments do since they are desugared into
+/// `#[doc = "..."]` attributes.
+fn compute_source_hash(ink_module: &TokenStream2) -> [u8; 32] {
+    let mut buffer = String::new();
+    normalize_tokens(ink_module.clone(), &mut buffer);
+    let mut output = [0x00_u8; 32];
+    blake2b_256(buffer.as_bytes(), &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the given source string into a token stream.
+    fn tokens(source: &str) -> TokenStream2 {
+        source.parse::<TokenStream2>().unwrap()
+    }
+
+    #[test]
+    fn source_hash_is_stable_across_reformatting() {
+        let compact = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message)] pub fn get(&self) -> i32 { self.value } } }",
+        );
+        let reformatted = tokens(
+            r#"
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    value : i32
+                }
+
+                impl MyStorage {
+                    // A regular comment does not contribute to the hash.
+                    #[ink(constructor)]
+                    pub fn new()->Self {
+                        Self { value: 0 }
+                    }
+
+                    #[ink(message)]
+                    pub fn get( &self ) -> i32 {
+                        self.value
+                    }
+                }
+            }
+            "#,
+        );
+        assert_eq!(
+            compute_source_hash(&compact),
+            compute_source_hash(&reformatted)
+        );
+    }
+
+    #[test]
+    fn source_hash_differs_for_different_sources() {
+        let fst = tokens("mod my_contract { fn get() -> i32 { 0 } }");
+        let snd = tokens("mod my_contract { fn get() -> i32 { 1 } }");
+        assert_ne!(compute_source_hash(&fst), compute_source_hash(&snd));
+    }
+
+    #[test]
+    fn contract_source_hash_works() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn message(&self) {} } }",
+        );
+        let contract = Contract::new(TokenStream2::new(), ink_module.clone()).unwrap();
+        assert_eq!(contract.source_hash(), compute_source_hash(&ink_module));
+    }
+
+    #[test]
+    fn api_fingerprint_is_stable_across_reordering() {
+        let original = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message)] pub fn get(&self) -> i32 { self.value } \
+             #[ink(message)] pub fn set(&mut self, value: i32) { self.value = value; } } }",
+        );
+        let reordered = tokens(
+            r#"
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage { value: i32 }
+
+                impl MyStorage {
+                    /// Sets the value.
+                    #[ink(message)]
+                    pub fn set(&mut self, new_value: i32) { self.value = new_value }
+
+                    /// Returns the value.
+                    #[ink(message)]
+                    pub fn get( &self ) -> i32 { return self.value }
+
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self { value: 42 } }
+                }
+            }
+            "#,
+        );
+        let changed = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message)] pub fn get(&self) -> i64 { self.value as i64 } \
+             #[ink(message)] pub fn set(&mut self, value: i32) { self.value = value; } } }",
+        );
+        let fingerprint = |ink_module| {
+            Contract::new(TokenStream2::new(), ink_module)
+                .unwrap()
+                .api_fingerprint()
+        };
+        assert_eq!(fingerprint(original.clone()), fingerprint(reordered));
+        assert_ne!(fingerprint(original), fingerprint(changed));
+    }
+
+    #[test]
+    fn abi_diff_works() {
+        let original = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             #[ink(event)] pub struct Changed { #[ink(topic)] value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message)] pub fn get(&self) -> i32 { self.value } \
+             #[ink(message, selector = \"0x00000001\")] pub fn set(&mut self, value: i32) { self.value = value; } } }",
+        );
+        let upgraded = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             #[ink(event)] pub struct Changed { #[ink(topic)] value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message)] pub fn get(&self) -> i32 { self.value } \
+             #[ink(message)] pub fn reset(&mut self) { self.value = 0; } \
+             #[ink(message, selector = \"0x00000001\")] pub fn set(&mut self, value: i32) { self.value = value; } } }",
+        );
+        let original = Contract::new(TokenStream2::new(), original).unwrap();
+        let upgraded = Contract::new(TokenStream2::new(), upgraded).unwrap();
+        assert!(original.abi_diff(&original).is_empty());
+        let diff = original.abi_diff(&upgraded);
+        assert!(diff.removed().is_empty());
+        assert!(diff.changed().is_empty());
+        assert_eq!(diff.added().len(), 1);
+        let added = &diff.added()[0];
+        assert_eq!(added.kind(), ir::AbiItemKind::Message);
+        assert_eq!(added.name(), "reset");
+        assert!(added.mutates());
+        assert!(diff.is_compatible());
+        let diff = upgraded.abi_diff(&original);
+        assert_eq!(diff.removed().len(), 1);
+        assert_eq!(diff.removed()[0].name(), "reset");
+        assert!(!diff.is_compatible());
+    }
+
+    #[test]
+    fn abi_diff_classifies_changes() {
+        let original = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message, selector = \"0x00000001\")] pub fn get(&self) -> i32 { self.value } } }",
+        );
+        let changed = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: i32 } \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self { value: 0 } } \
+             #[ink(message, selector = \"0x00000001\")] pub fn value(&self) -> i64 { self.value as i64 } } }",
+        );
+        let original = Contract::new(TokenStream2::new(), original).unwrap();
+        let changed = Contract::new(TokenStream2::new(), changed).unwrap();
+        let diff = original.abi_diff(&changed);
+        assert!(diff.added().is_empty());
+        assert!(diff.removed().is_empty());
+        assert_eq!(diff.changed().len(), 1);
+        let modification = &diff.changed()[0];
+        assert_eq!(modification.before().output(), Some("i32"));
+        assert_eq!(modification.after().output(), Some("i64"));
+        assert_eq!(
+            modification.changes(),
+            &[ir::AbiChange::Renamed, ir::AbiChange::OutputChanged],
+        );
+    }
+
+    #[test]
+    fn from_file_works() {
+        let file: syn::File = syn::parse_quote! {
+            use ink_lang as ink;
+
+            pub struct Unrelated;
+
+            mod other {
+                fn helper() {}
+            }
+
+            #[ink::contract(upgradeable = true)]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self {} }
+
+                    #[ink(message)]
+                    pub fn message(&self) {}
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {}
+        };
+        let contract = Contract::from_file(file).unwrap();
+        assert_eq!(contract.module_ident(), "my_contract");
+        assert!(contract.config().is_upgradeable());
+    }
+
+    #[test]
+    fn from_file_nested_works() {
+        let file: syn::File = syn::parse_quote! {
+            mod outer {
+                #[ink_lang::contract]
+                mod my_contract {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self { Self {} }
+
+                        #[ink(message)]
+                        pub fn message(&self) {}
+                    }
+                }
+            }
+        };
+        let contract = Contract::from_file(file).unwrap();
+        assert_eq!(contract.module_ident(), "my_contract");
+    }
+
+    #[test]
+    fn from_file_cfg_attr_works() {
+        let file: syn::File = syn::parse_quote! {
+            #[cfg_attr(feature = "contract", ink::contract(upgradeable = true))]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self {} }
+
+                    #[ink(message)]
+                    pub fn message(&self) {}
+                }
+            }
+        };
+        let contract = Contract::from_file(file).unwrap();
+        assert_eq!(contract.module_ident(), "my_contract");
+        assert!(contract.config().is_upgradeable());
+    }
+
+    #[test]
+    fn from_file_nested_cfg_attr_works() {
+        let file: syn::File = syn::parse_quote! {
+            #[cfg_attr(
+                not(feature = "std"),
+                allow(dead_code),
+                cfg_attr(feature = "contract", ink_lang::contract)
+            )]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self {} }
+
+                    #[ink(message)]
+                    pub fn message(&self) {}
+                }
+            }
+
+            #[cfg_attr(test, allow(unused))]
+            mod unrelated {}
+        };
+        let contract = Contract::from_file(file).unwrap();
+        assert_eq!(contract.module_ident(), "my_contract");
+        let expected: syn::Attribute = syn::parse_quote! {
+            #[cfg_attr(not(feature = "std"), allow(dead_code))]
+        };
+        assert_eq!(contract.module().attrs(), &[expected]);
+    }
+
+    #[test]
+    fn from_file_cfg_attr_keeps_other_attributes() {
+        let file: syn::File = syn::parse_quote! {
+            #[cfg_attr(feature = "contract", ink::contract, allow(unused))]
+            mod my_contract {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn new() -> Self { Self {} }
+
+                    #[ink(message)]
+                    pub fn message(&self) {}
+                }
+            }
+        };
+        let contract = Contract::from_file(file).unwrap();
+        let expected: syn::Attribute = syn::parse_quote! {
+            #[cfg_attr(feature = "contract", allow(unused))]
+        };
+        assert_eq!(contract.module().attrs(), &[expected]);
+    }
+
+    #[test]
+    fn from_file_without_contract_fails() {
+        let file: syn::File = syn::parse_quote! {
+            mod my_contract {}
+        };
+        assert_eq!(
+            Contract::from_file(file)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+            Err("encountered no #[ink::contract] module in the file".to_string()),
+        );
+    }
+
+    #[test]
+    fn from_file_with_multiple_contracts_fails() {
+        let file: syn::File = syn::parse_quote! {
+            #[ink::contract]
+            mod fst {}
+
+            #[ink::contract]
+            mod snd {}
+        };
+        assert_eq!(
+            Contract::from_file(file)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+            Err("encountered multiple #[ink::contract] modules in the file".to_string()),
+        );
+    }
+
+    #[test]
+    fn empty_module_fails() {
+        for config in &["", "upgradeable = true, explicit_selectors = true"] {
+            assert_eq!(
+                Contract::new(tokens(config), tokens("mod my_contract {}"))
+                    .map(|_| ())
+                    .map_err(|error| error.to_string()),
+                Err("contract module contains no items; expected at least one #[ink(storage)] struct".to_string()),
+            );
+        }
+    }
+
+    #[test]
+    fn missing_constructor_fails() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(message)] pub fn message(&self) {} } }",
+        );
+        assert_eq!(
+            Contract::new(TokenStream2::new(), ink_module)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+            Err("missing ink! constructor for `MyStorage`, a contract without any #[ink(constructor)] can never be instantiated".to_string()),
+        );
+    }
+
+    #[test]
+    fn allow_no_constructor_works() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(message)] pub fn message(&self) {} } }",
+        );
+        assert!(Contract::new(tokens("allow_no_constructor = true"), ink_module).is_ok());
+    }
+
+    #[test]
+    fn upgradeable_adds_set_code_message() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn message(&self) {} } }",
+        );
+        let contract = Contract::new(tokens("upgradeable = true"), ink_module).unwrap();
+        let messages = contract
+            .module()
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .map(|message| ir::Callable::ident(&message).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["message", "set_code"]);
+    }
+
+    #[test]
+    fn upgradeable_overlapping_selector_fails() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message, selector = \"0x694FB50F\")] pub fn upgrade(&self) {} } }",
+        );
+        assert_eq!(
+            Contract::new(tokens("upgradeable = true"), ink_module)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+            Err("encountered ink! message with the same selector (= [69, 4F, B5, 0F]) as the \
+                 `set_code` ink! message generated for upgradeable ink! smart contracts"
+                .to_string()),
+        );
+    }
+
+    #[test]
+    fn migration_stubs_add_migrate_message() {
+        let ink_module = |version: &str| {
+            tokens(&format!(
+                "mod my_contract {{ #[ink(storage{})] pub struct MyStorage {{}} \
+                 impl MyStorage {{ #[ink(constructor)] pub fn new() -> Self {{ Self {{}} }} \
+                 #[ink(message)] pub fn message(&self) {{}} }} }}",
+                version,
+            ))
+        };
+        let messages = |config: &str, version: &str| {
+            let contract = Contract::new(tokens(config), ink_module(version)).unwrap();
+            contract
+                .module()
+                .impls()
+                .flat_map(ir::ItemImpl::iter_messages)
+                .map(|message| ir::Callable::ident(&message).to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            messages("migration_stubs = true", ", version = 2"),
+            vec!["message", "migrate"]
+        );
+        assert_eq!(messages("migration_stubs = true", ""), vec!["message"]);
+        assert_eq!(messages("", ", version = 2"), vec!["message"]);
+        let contract = Contract::new(
+            tokens("migration_stubs = true"),
+            ink_module(", version = 2"),
+        )
+        .unwrap();
+        let migrate = contract
+            .message_by_selector(*ir::Selector::new(b"migrate").as_bytes())
+            .unwrap();
+        let statements = ir::Callable::statements(migrate);
+        let body = quote::quote! { #( #statements )* }.to_string();
+        assert!(body.contains("for step in from_version .. 2u32"));
+        assert!(body.contains("0u32 => self . migrate_from_v0 ()"));
+        assert!(body.contains("1u32 => self . migrate_from_v1 ()"));
+        assert!(!body.contains("migrate_from_v2"));
+    }
+
+    #[test]
+    fn explicit_selectors_works() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor, selector = \"0x00000001\")] pub fn new() -> Self { Self {} } \
+             #[ink(message, selector = \"0x00000002\")] pub fn message(&self) {} } }",
+        );
+        assert!(Contract::new(tokens("explicit_selectors = true"), ink_module).is_ok());
+    }
+
+    #[test]
+    fn explicit_selectors_missing_selector_fails() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor, selector = \"0x00000001\")] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn get(&self) {} \
+             #[ink(message)] pub fn set(&mut self) {} } }",
+        );
+        let errors = Contract::new(tokens("explicit_selectors = true"), ink_module)
+            .map(|_| ())
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "missing explicit selector for ink! message `get` as required by `explicit_selectors = true`",
+                "missing explicit selector for ink! message `set` as required by `explicit_selectors = true`",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_metadata_types_works() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage { value: Vec<u8> } \
+             #[ink(event)] pub struct Event { value: (u32, [bool; 2]) } \
+             impl MyStorage { #[ink(constructor)] pub fn new(value: Vec<u8>) -> Self { Self { value } } \
+             #[ink(message)] pub fn get(&self, values: &[u8]) -> Option<&'static str> { None } \
+             #[ink(message)] pub fn get_macro(&self) -> Option<my_type!()> { None } } }",
+        );
+        assert!(
+            Contract::new(tokens("validate_metadata_types = true"), ink_module).is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_metadata_types_unregistrable_type_fails() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn callback(&self) -> Vec<fn(u32) -> bool> { Vec::new() } } }",
+        );
+        assert!(
+            Contract::new(TokenStream2::new(), ink_module.clone()).is_ok(),
+            "types are not validated by default",
+        );
+        assert_eq!(
+            Contract::new(tokens("validate_metadata_types = true"), ink_module)
+                .map(|_| ())
+                .map_err(|error| error.to_string()),
+            Err(
+                "type `Vec < fn (u32) -> bool >` of ink! message `callback` cannot be \
+                 registered in the metadata type registry since function pointers do not \
+                 implement `scale_info::TypeInfo`"
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn derive_debug_with_manual_debug_fails() {
+        let ink_module = |items: &str| {
+            tokens(&format!(
+                "mod my_contract {{ {} \
+                 impl MyStorage {{ #[ink(constructor)] pub fn new() -> Self {{ Self {{}} }} \
+                 #[ink(message)] pub fn get(&self) {{}} }} }}",
+                items
+            ))
+        };
+        let derive_debug = |items: &str| {
+            Contract::new(tokens("derive_debug = true"), ink_module(items))
+                .map(|_| ())
+                .map_err(|error| error.to_string())
+        };
+        let storage = "#[ink(storage)] pub struct MyStorage {}";
+        assert_eq!(derive_debug(storage), Ok(()));
+        assert_eq!(
+            derive_debug(
+                "#[ink(storage)] #[derive(Default, core::fmt::Debug)] pub struct MyStorage {}"
+            ),
+            Err("encountered `Debug` derive on the ink! storage struct `MyStorage` \
+                 which conflicts with `derive_debug = true`"
+                .to_string()),
+        );
+        assert_eq!(
+            derive_debug(
+                "#[ink(storage)] #[cfg_attr(test, derive(Debug))] pub struct MyStorage {}"
+            ),
+            Err("encountered `Debug` derive on the ink! storage struct `MyStorage` \
+                 which conflicts with `derive_debug = true`"
+                .to_string()),
+        );
+        assert_eq!(
+            derive_debug(&format!(
+                "{} #[ink(event)] pub struct Event {{}} \
+                 impl fmt::Debug for Event {{}}",
+                storage
+            )),
+            Err(
+                "encountered `Debug` implementation for the ink! event struct `Event` \
+                 which conflicts with `derive_debug = true`"
+                    .to_string()
+            ),
+        );
+        assert_eq!(
+            derive_debug(
+                "#[ink(storage)] pub type Storage = MyStorage; \
+                 pub struct MyStorage {} \
+                 impl Debug for Storage {}"
+            ),
+            Err(
+                "encountered `Debug` implementation for the ink! storage struct \
+                 `MyStorage` which conflicts with `derive_debug = true`"
+                    .to_string()
+            ),
+        );
+        assert!(Contract::new(
+            TokenStream2::new(),
+            ink_module(
+                "#[ink(storage)] #[derive(Debug)] pub struct MyStorage {} \
+                 impl Debug for MyStorage {}"
+            ),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn message_and_constructor_by_selector_works() {
+        let contract = Contract::new(
+            tokens(""),
+            tokens(
+                "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+                 impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+                 #[ink(constructor, selector = _)] pub fn fallback() -> Self { Self {} } \
+                 #[ink(message)] pub fn get(&self) {} \
+                 #[ink(message, selector = \"0x00000002\")] pub fn set(&mut self) {} } }",
+            ),
+        )
+        .unwrap();
+        let message_ident = |selector| {
+            contract
+                .message_by_selector(selector)
+                .map(|message| ir::Callable::ident(message).to_string())
+        };
+        assert_eq!(
+            message_ident(*ir::Selector::new(b"get").as_bytes()),
+            Some("get".to_string())
+        );
+        assert_eq!(
+            message_ident([0x00, 0x00, 0x00, 0x02]),
+            Some("set".to_string())
+        );
+        assert_eq!(message_ident([0xFF; 4]), None);
+        let constructor_ident = |selector, include_wildcard| {
+            contract
+                .constructor_by_selector(selector, include_wildcard)
+                .map(|constructor| ir::Callable::ident(constructor).to_string())
+        };
+        let new = *ir::Selector::new(b"new").as_bytes();
+        assert_eq!(constructor_ident(new, false), Some("new".to_string()));
+        assert_eq!(constructor_ident(new, true), Some("new".to_string()));
+        assert_eq!(constructor_ident([0xFF; 4], false), None);
+        assert_eq!(
+            constructor_ident([0xFF; 4], true),
+            Some("fallback".to_string())
+        );
+    }
+
+    #[test]
+    fn contract_namespace_seeds_selectors() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn get(&self) {} \
+             #[ink(message, selector = \"0x00000002\")] pub fn set(&mut self) {} } }",
+        );
+        let selectors = |config: &str| {
+            let contract = Contract::new(tokens(config), ink_module.clone()).unwrap();
+            contract
+                .module()
+                .impls()
+                .flat_map(ir::ItemImpl::iter_messages)
+                .map(|message| message.composed_selector())
+                .collect::<Vec<_>>()
+        };
+        let vault = selectors("namespace = \"vault\"");
+        let bank = selectors("namespace = \"bank\"");
+        assert_ne!(vault[0], bank[0]);
+        assert_eq!(vault[0], ir::Selector::new(b"vault::get"));
+        assert_eq!(bank[0], ir::Selector::new(b"bank::get"));
+        assert_eq!(selectors("")[0], ir::Selector::new(b"get"));
+        // Explicitly provided selectors are not affected by the namespace.
+        assert_eq!(vault[1], bank[1]);
+        assert_eq!(vault[1].as_bytes(), &[0x00, 0x00, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn selector_hash_changes_selectors() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn get(&self) {} \
+             #[ink(message, selector = \"0x00000002\")] pub fn set(&mut self) {} } }",
+        );
+        let selectors = |config: &str| {
+            let contract = Contract::new(tokens(config), ink_module.clone()).unwrap();
+            contract
+                .module()
+                .impls()
+                .flat_map(ir::ItemImpl::iter_messages)
+                .map(|message| message.composed_selector())
+                .collect::<Vec<_>>()
+        };
+        let blake2 = selectors("hash = \"blake2_256\"");
+        let keccak = selectors("hash = \"keccak_256\"");
+        assert_ne!(blake2[0], keccak[0]);
+        assert_eq!(blake2[0], ir::Selector::new(b"get"));
+        assert_eq!(selectors("")[0], blake2[0]);
+        assert_eq!(
+            keccak[0],
+            ir::Selector::with_hash(b"get", ir::SelectorHash::Keccak256)
+        );
+        // Explicitly provided selectors are not affected by the hash.
+        assert_eq!(blake2[1], keccak[1]);
+    }
+
+    #[test]
+    fn contains_ident_works() {
+        let ink_module = tokens(
+            "mod my_contract { \
+                 use core::convert::{TryFrom, From as Into2}; \
+                 #[ink(storage)] pub struct MyStorage {} \
+                 #[ink(event)] pub struct MyEvent {} \
+                 const MY_CONST: u32 = 0; \
+                 fn helper() { fn nested() {} } \
+                 impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+                 #[ink(message)] pub fn message(&self) {} } }",
+        );
+        let contract = Contract::new(TokenStream2::new(), ink_module).unwrap();
+        for name in &[
+            "MyStorage",
+            "MyEvent",
+            "MY_CONST",
+            "helper",
+            "TryFrom",
+            "Into2",
+        ] {
+            assert!(contract.contains_ident(name), "missing `{}`", name);
+        }
+        for name in &["my_contract", "nested", "message", "new", "From", "convert"] {
+            assert!(!contract.contains_ident(name), "unexpected `{}`", name);
+        }
+    }
+
+    #[test]
+    fn module_ident_works() {
+        let ink_module = tokens(
+            "mod my_contract { #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } \
+             #[ink(message)] pub fn message(&self) {} } }",
+        );
+        let contract = Contract::new(tokens("ref_name = \"MyRef\""), ink_module).unwrap();
+        assert_eq!(contract.module_ident(), "my_contract");
+    }
+
+    #[test]
+    fn storage_referenced_types_works() {
+        let contract = Contract::new(
+            TokenStream2::new(),
+            tokens(
+                "mod my_contract { \
+                 #[ink(storage)] #[derive(Default)] pub struct MyStorage { \
+                     owner: AccountId, \
+                     balances: Mapping<AccountId, Balance>, \
+                     config: custom::Config, \
+                     counter: u32, \
+                 } \
+                 impl MyStorage { \
+                 #[ink(constructor)] pub fn new() -> Self { Self::default() } \
+                 #[ink(message)] pub fn message(&self) {} } }",
+            ),
+        )
+        .unwrap();
+        let referenced_types = |exclude_primitives| {
+            contract
+                .storage_referenced_types(exclude_primitives)
+                .into_iter()
+                .map(|path| quote::quote!(#path).to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            referenced_types(true),
+            vec!["AccountId", "Mapping", "Balance", "custom :: Config"]
+        );
+        assert_eq!(
+            referenced_types(false),
+            vec!["AccountId", "Mapping", "Balance", "custom :: Config", "u32"]
+        );
+    }
+
+    #[test]
+    fn env_calls_works() {
+        let contract = Contract::new(
+            TokenStream2::new(),
+            tokens(
+                "mod my_contract { #[ink(storage)] pub struct MyStorage { owner: AccountId } \
+                 impl MyStorage { \
+                 #[ink(constructor)] pub fn new() -> Self { Self { owner: Self::env().caller() } } \
+                 #[ink(message)] pub fn pay(&mut self, value: Balance) { \
+                     if self.env().caller() == self.owner { \
+                         self.env().transfer(self.owner, value).unwrap() \
+                     } \
+                 } \
+                 #[ink(message)] pub fn env_free(&self) -> bool { self.helper().caller() } } }",
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            contract.env_calls(),
+            vec!["caller".to_string(), "transfer".to_string()]
+        );
+    }
+
+    #[test]
+    fn trait_definitions_works() {
+        let ink_module = tokens(
+            "mod my_contract { \
+             #[ink::trait_definition] pub trait Flip { #[ink(message)] fn flip(&mut self); } \
+             pub trait NotInk { fn helper(&self); } \
+             #[ink(storage)] pub struct MyStorage {} \
+             impl MyStorage { #[ink(constructor)] pub fn new() -> Self { Self {} } } \
+             #[ink(namespace = \"flipping\")] \
+             impl Flip for MyStorage { #[ink(message)] fn flip(&mut self) {} } }",
+        );
+        let contract = Contract::new(
+            tokens("namespace = \"vault\", hash = \"keccak_256\""),
+            ink_module,
+        )
+        .unwrap();
+        let trait_definitions = contract.trait_definitions().collect::<Vec<_>>();
+        assert_eq!(trait_definitions.len(), 1);
+        assert_eq!(trait_definitions[0].ident(), "Flip");
+        let selectors =
+            trait_definitions[0].selectors(None, None, ir::SelectorHash::Blake2x256);
+        assert_eq!(selectors.len(), 1);
+        assert_eq!(selectors[0].0, "flip");
+        assert_eq!(selectors[0].1, ir::Selector::new(b"Flip::flip"));
+        let item_impl = contract
+            .module()
+            .impls()
+            .find(|item_impl| item_impl.trait_path().is_some())
+            .unwrap();
+        let message = item_impl.iter_messages().next().unwrap();
+        let selectors = trait_definitions[0].selectors(
+            item_impl.contract_namespace(),
+            item_impl.namespace(),
+            item_impl.selector_hash(),
+        );
+        assert_eq!(
+            selectors[0].1,
+            ir::Selector::with_hash(
+                b"vault::flipping::Flip::flip",
+                ir::SelectorHash::Keccak256
+            )
+        );
+        assert_eq!(message.composed_selector(), selectors[0].1);
+    }
 }
@@ -22,6 +22,10 @@ use proc_macro2::{
     Ident,
     Span,
 };
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::spanned::Spanned as _;
 
 /// An ink! event struct definition.
@@ -41,10 +45,21 @@ use syn::spanned::Spanned as _;
 /// }
 /// # }).unwrap();
 /// ```
+///
+/// # Generic Events
+///
+/// Event structs may have generic type parameters, e.g. `pub struct Transfer<T>`.
+/// Since events must be emitted as concrete types, a generic ink! event is
+/// monomorphized for every distinct set of concrete type arguments it is used
+/// with throughout the ink! module, e.g. `Transfer::<MyToken> { .. }`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Event {
     item: syn::ItemStruct,
     pub anonymous: bool,
+    /// The concrete type arguments of all instances of the event.
+    ///
+    /// Non-generic events have exactly one instance without type arguments.
+    instances: Vec<Vec<syn::Type>>,
 }
 
 impl quote::ToTokens for Event {
@@ -93,11 +108,22 @@ impl TryFrom<syn::ItemStruct> for Event {
                 }
             },
         )?;
-        if !item_struct.generics.params.is_empty() {
-            return Err(format_err_spanned!(
-                item_struct.generics.params,
-                "generic ink! event structs are not supported",
-            ))
+        for param in item_struct.generics.params.iter() {
+            match param {
+                syn::GenericParam::Type(_) => (),
+                syn::GenericParam::Lifetime(lifetime) => {
+                    return Err(format_err_spanned!(
+                        lifetime,
+                        "lifetime parameters are not supported for ink! event structs",
+                    ))
+                }
+                syn::GenericParam::Const(const_param) => {
+                    return Err(format_err_spanned!(
+                        const_param,
+                        "const parameters are not supported for ink! event structs",
+                    ))
+                }
+            }
         }
         utils::ensure_pub_visibility("event structs", struct_span, &item_struct.vis)?;
         'repeat: for field in item_struct.fields.iter() {
@@ -106,31 +132,23 @@ impl TryFrom<syn::ItemStruct> for Event {
             if ink_attrs.is_empty() {
                 continue 'repeat
             }
-            let normalized =
-                ir::InkAttribute::from_expanded(ink_attrs).map_err(|err| {
-                    err.into_combine(format_err!(field_span, "at this invocation",))
-                })?;
-            if !matches!(normalized.first().kind(), ir::AttributeArg::Topic) {
-                return Err(format_err!(
-                    field_span,
-                    "first optional ink! attribute of an event field must be #[ink(topic)]",
-                ))
-            }
-            for arg in normalized.args() {
-                if !matches!(arg.kind(), ir::AttributeArg::Topic) {
-                    return Err(format_err!(
-                        arg.span(),
-                        "encountered conflicting ink! attribute for event field",
-                    ))
-                }
-            }
+            ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::EventField)?;
+            ir::InkAttribute::from_expanded(ink_attrs).map_err(|err| {
+                err.into_combine(format_err!(field_span, "at this invocation",))
+            })?;
         }
+        let instances = if item_struct.generics.params.is_empty() {
+            vec![Vec::new()]
+        } else {
+            Vec::new()
+        };
         Ok(Self {
             item: syn::ItemStruct {
                 attrs: other_attrs,
                 ..item_struct
             },
             anonymous: ink_attrs.is_anonymous(),
+            instances,
         })
     }
 }
@@ -141,16 +159,363 @@ impl Event {
         &self.item.ident
     }
 
-    /// Returns an iterator yielding all the `#[ink(topic)]` annotated fields
-    /// of the event struct.
+    /// Returns an iterator yielding all the fields of the event struct.
+    ///
+    /// # Note
+    ///
+    /// The fields are yielded in source order. Event topics are emitted and
+    /// described in the metadata in this order, so off-chain decoders can rely
+    /// on the `#[ink(topic)]` annotated fields appearing as written.
     pub fn fields(&self) -> EventFieldsIter {
         EventFieldsIter::new(self)
     }
 
+    /// Returns the number of fields of the event struct.
+    pub fn field_count(&self) -> usize {
+        self.item.fields.len()
+    }
+
     /// Returns all non-ink! attributes.
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.item.attrs
     }
+
+    /// Returns `true` if the event struct is annotated with `#[doc(hidden)]`.
+    ///
+    /// # Note
+    ///
+    /// Hidden events are emitted as usual but excluded from the contract
+    /// metadata.
+    pub fn is_doc_hidden(&self) -> bool {
+        utils::is_doc_hidden(self.attrs())
+    }
+
+    /// Returns the generics of the event struct.
+    pub fn generics(&self) -> &syn::Generics {
+        &self.item.generics
+    }
+
+    /// Returns `true` if the event struct has generic type parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.item.generics.params.is_empty()
+    }
+
+    /// Returns an iterator yielding all concrete instances of the event.
+    ///
+    /// # Note
+    ///
+    /// Non-generic events always yield exactly one instance. Generic events
+    /// yield one instance per distinct set of concrete type arguments they are
+    /// used with in the ink! module.
+    pub fn instances(&self) -> impl Iterator<Item = EventInstance<'_>> {
+        self.instances.iter().enumerate().map(move |(index, args)| {
+            EventInstance {
+                event: self,
+                index,
+                args,
+            }
+        })
+    }
+
+    /// Resolves the concrete instances of a generic event from the given
+    /// instantiations found in the ink! module.
+    ///
+    /// # Errors
+    ///
+    /// If the generic event is never used with concrete type arguments.
+    pub(crate) fn resolve_instances(
+        &mut self,
+        instantiations: &[(Ident, Vec<syn::Type>)],
+    ) -> Result<(), syn::Error> {
+        if !self.is_generic() {
+            return Ok(())
+        }
+        let len_params = self.item.generics.params.len();
+        let mut instances = Vec::new();
+        let mut seen = Vec::new();
+        for (ident, args) in instantiations {
+            if ident != self.ident() || args.len() != len_params {
+                continue
+            }
+            let key = quote! { #( #args ),* }.to_string();
+            if !seen.contains(&key) {
+                seen.push(key);
+                instances.push(args.clone());
+            }
+        }
+        if instances.is_empty() {
+            return Err(format_err_spanned!(
+                self.item,
+                "generic ink! event `{}` is never instantiated with concrete type arguments, e.g. `{}::<T> {{ .. }}`",
+                self.ident(),
+                self.ident(),
+            ))
+        }
+        self.instances = instances;
+        Ok(())
+    }
+}
+
+/// Collects all paths with concrete type arguments, e.g. `Transfer<MyToken>`
+/// or `Transfer::<MyToken> { .. }`, used in the given items.
+///
+/// # Note
+///
+/// The definitions of ink! event structs themselves are skipped.
+///
+/// Only type arguments that are fully concrete are collected. Paths whose
+/// type arguments refer to generic parameters in scope, to `Self` or to
+/// inferred types, e.g. `Transfer::<T> { .. }` within a generic function or
+/// `Transfer::<_> { .. }`, cannot be monomorphized and are ignored.
+pub(crate) fn collect_instantiations(
+    items: &[syn::Item],
+) -> Vec<(Ident, Vec<syn::Type>)> {
+    struct Collector {
+        instantiations: Vec<(Ident, Vec<syn::Type>)>,
+        /// The generic type parameters currently in scope.
+        generics: Vec<Ident>,
+    }
+
+    impl Collector {
+        /// Visits the item with the given generic type parameters in scope.
+        fn visit_with_generics<F>(&mut self, generics: &syn::Generics, visit: F)
+        where
+            F: FnOnce(&mut Self),
+        {
+            let len_generics = self.generics.len();
+            self.generics
+                .extend(generics.type_params().map(|param| param.ident.clone()));
+            visit(self);
+            self.generics.truncate(len_generics);
+        }
+
+        /// Returns `true` if the type does not depend on any generic parameter
+        /// in scope, `Self` or inferred types.
+        fn is_concrete(&self, ty: &syn::Type) -> bool {
+            struct Concrete<'a> {
+                generics: &'a [Ident],
+                is_concrete: bool,
+            }
+
+            impl<'ast> syn::visit::Visit<'ast> for Concrete<'_> {
+                fn visit_type(&mut self, ty: &'ast syn::Type) {
+                    match ty {
+                        syn::Type::Infer(_) | syn::Type::ImplTrait(_) => {
+                            self.is_concrete = false
+                        }
+                        _ => syn::visit::visit_type(self, ty),
+                    }
+                }
+
+                fn visit_path(&mut self, path: &'ast syn::Path) {
+                    if let Some(segment) = path.segments.first() {
+                        if segment.ident == "Self"
+                            || (path.leading_colon.is_none()
+                                && self.generics.contains(&segment.ident))
+                        {
+                            self.is_concrete = false
+                        }
+                    }
+                    syn::visit::visit_path(self, path)
+                }
+            }
+
+            let mut concrete = Concrete {
+                generics: &self.generics,
+                is_concrete: true,
+            };
+            syn::visit::Visit::visit_type(&mut concrete, ty);
+            concrete.is_concrete
+        }
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for Collector {
+        fn visit_item_struct(&mut self, item_struct: &'ast syn::ItemStruct) {
+            if Event::is_ink_event(item_struct).unwrap_or_default() {
+                return
+            }
+            self.visit_with_generics(&item_struct.generics, |this| {
+                syn::visit::visit_item_struct(this, item_struct)
+            })
+        }
+
+        fn visit_item_enum(&mut self, item_enum: &'ast syn::ItemEnum) {
+            self.visit_with_generics(&item_enum.generics, |this| {
+                syn::visit::visit_item_enum(this, item_enum)
+            })
+        }
+
+        fn visit_item_fn(&mut self, item_fn: &'ast syn::ItemFn) {
+            self.visit_with_generics(&item_fn.sig.generics, |this| {
+                syn::visit::visit_item_fn(this, item_fn)
+            })
+        }
+
+        fn visit_item_impl(&mut self, item_impl: &'ast syn::ItemImpl) {
+            self.visit_with_generics(&item_impl.generics, |this| {
+                syn::visit::visit_item_impl(this, item_impl)
+            })
+        }
+
+        fn visit_impl_item_method(&mut self, method: &'ast syn::ImplItemMethod) {
+            self.visit_with_generics(&method.sig.generics, |this| {
+                syn::visit::visit_impl_item_method(this, method)
+            })
+        }
+
+        fn visit_item_trait(&mut self, item_trait: &'ast syn::ItemTrait) {
+            self.visit_with_generics(&item_trait.generics, |this| {
+                syn::visit::visit_item_trait(this, item_trait)
+            })
+        }
+
+        fn visit_trait_item_method(&mut self, method: &'ast syn::TraitItemMethod) {
+            self.visit_with_generics(&method.sig.generics, |this| {
+                syn::visit::visit_trait_item_method(this, method)
+            })
+        }
+
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if let Some(segment) = path.segments.last() {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    let types = args
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            match arg {
+                                syn::GenericArgument::Type(ty)
+                                    if self.is_concrete(ty) =>
+                                {
+                                    Some(ty.clone())
+                                }
+                                _ => None,
+                            }
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    if let Some(types) = types {
+                        self.instantiations.push((segment.ident.clone(), types));
+                    }
+                }
+            }
+            syn::visit::visit_path(self, path)
+        }
+    }
+
+    let mut collector = Collector {
+        instantiations: Vec::new(),
+        generics: Vec::new(),
+    };
+    for item in items {
+        syn::visit::Visit::visit_item(&mut collector, item);
+    }
+    collector.instantiations
+}
+
+/// A concrete instance of an ink! event.
+///
+/// For non-generic events this is the event itself, for generic events this is
+/// the event monomorphized with a set of concrete type arguments.
+#[derive(Debug, Copy, Clone)]
+pub struct EventInstance<'a> {
+    /// The instantiated event.
+    event: &'a Event,
+    /// The index of the instance within all instances of the event.
+    index: usize,
+    /// The concrete type arguments of the instance.
+    args: &'a [syn::Type],
+}
+
+impl<'a> EventInstance<'a> {
+    /// Returns the instantiated event.
+    pub fn event(self) -> &'a Event {
+        self.event
+    }
+
+    /// Returns the concrete type of the instance, e.g. `Transfer<MyToken>`.
+    pub fn ty(self) -> syn::Type {
+        let ident = self.event.ident();
+        if self.args.is_empty() {
+            return syn::parse_quote! { #ident }
+        }
+        let args = self.args;
+        syn::parse_quote! { #ident< #( #args ),* > }
+    }
+
+    /// Returns the name of the instance, e.g. `"Transfer<MyToken>"`.
+    ///
+    /// # Note
+    ///
+    /// This is used for the event signature topic and metadata so that every
+    /// instance of a generic event is distinguishable.
+    pub fn name(self) -> String {
+        let ident = self.event.ident().to_string();
+        if self.args.is_empty() {
+            return ident
+        }
+        let args = self
+            .args
+            .iter()
+            .map(utils::type_to_string)
+            .collect::<Vec<_>>();
+        format!("{}<{}>", ident, args.join(", "))
+    }
+
+    /// Returns a unique identifier for the instance within the ink! module.
+    ///
+    /// # Note
+    ///
+    /// This is the event identifier for non-generic events.
+    pub fn variant_ident(self) -> Ident {
+        let ident = self.event.ident();
+        if self.args.is_empty() {
+            return ident.clone()
+        }
+        format_ident!("{}_{}", ident, self.index)
+    }
+
+    /// Substitutes the generic type parameters of the event in the given type
+    /// with the concrete type arguments of the instance.
+    pub fn monomorphize(self, ty: &syn::Type) -> syn::Type {
+        struct Substitute<'a> {
+            params: Vec<&'a Ident>,
+            args: &'a [syn::Type],
+        }
+
+        impl syn::visit_mut::VisitMut for Substitute<'_> {
+            fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+                if let syn::Type::Path(type_path) = ty {
+                    if let Some(position) = type_path
+                        .path
+                        .get_ident()
+                        .filter(|_| type_path.qself.is_none())
+                        .and_then(|ident| {
+                            self.params.iter().position(|param| *param == ident)
+                        })
+                    {
+                        *ty = self.args[position].clone();
+                        return
+                    }
+                }
+                syn::visit_mut::visit_type_mut(self, ty)
+            }
+        }
+
+        let mut ty = ty.clone();
+        let params = self
+            .event
+            .generics()
+            .type_params()
+            .map(|param| &param.ident)
+            .collect::<Vec<_>>();
+        syn::visit_mut::VisitMut::visit_type_mut(
+            &mut Substitute {
+                params,
+                args: self.args,
+            },
+            &mut ty,
+        );
+        ty
+    }
 }
 
 /// An event field with a flag indicating if this field is an event topic.
@@ -191,7 +556,7 @@ impl<'a> EventField<'a> {
     }
 }
 
-/// Iterator yielding all `#[ink(topic)]` annotated fields of an event struct.
+/// Iterator yielding all fields of an event struct in source order.
 pub struct EventFieldsIter<'a> {
     iter: syn::punctuated::Iter<'a, syn::Field>,
 }
@@ -239,6 +604,25 @@ mod tests {
         assert!(Event::try_from(item_struct).is_ok());
     }
 
+    #[test]
+    fn field_count_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(event)]
+            pub struct MyEvent {
+                #[ink(topic)]
+                field_1: i32,
+                field_2: bool,
+                field_3: u8,
+            }
+        };
+        assert_eq!(Event::try_from(item_struct).unwrap().field_count(), 3);
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(event)]
+            pub struct EmptyEvent {}
+        };
+        assert_eq!(Event::try_from(item_struct).unwrap().field_count(), 0);
+    }
+
     fn assert_try_from_fails(item_struct: syn::ItemStruct, expected: &str) {
         assert_eq!(
             Event::try_from(item_struct).map_err(|err| err.to_string()),
@@ -258,7 +642,8 @@ mod tests {
                     field_2: bool,
                 }
             },
-            "encountered conflicting ink! attribute argument",
+            "#[ink(storage)] cannot be combined with #[ink(event)]: \
+             the ink! storage struct cannot be an ink! event at the same time",
         )
     }
 
@@ -309,20 +694,136 @@ mod tests {
     }
 
     #[test]
-    fn generic_event_fails() {
+    fn generic_event_works() {
+        let event = <Event as TryFrom<syn::ItemStruct>>::try_from(syn::parse_quote! {
+            #[ink(event)]
+            pub struct GenericEvent<T> {
+                #[ink(topic)]
+                field_1: T,
+                field_2: bool,
+            }
+        })
+        .unwrap();
+        assert!(event.is_generic());
+        assert_eq!(event.instances().count(), 0);
+    }
+
+    #[test]
+    fn lifetime_generic_event_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(event)]
+                pub struct GenericEvent<'a> {
+                    #[ink(topic)]
+                    field_1: &'a i32,
+                    field_2: bool,
+                }
+            },
+            "lifetime parameters are not supported for ink! event structs",
+        )
+    }
+
+    #[test]
+    fn const_generic_event_fails() {
         assert_try_from_fails(
             syn::parse_quote! {
                 #[ink(event)]
-                pub struct GenericEvent<T> {
+                pub struct GenericEvent<const N: usize> {
                     #[ink(topic)]
-                    field_1: T,
+                    field_1: [u8; N],
                     field_2: bool,
                 }
             },
-            "generic ink! event structs are not supported",
+            "const parameters are not supported for ink! event structs",
         )
     }
 
+    #[test]
+    fn generic_event_instances_works() {
+        let mut event =
+            <Event as TryFrom<syn::ItemStruct>>::try_from(syn::parse_quote! {
+                #[ink(event)]
+                pub struct Transfer<T> {
+                    #[ink(topic)]
+                    value: Option<T>,
+                }
+            })
+            .unwrap();
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                fn transfer() {
+                    emit(Transfer::<MyToken> { value: None });
+                    emit(Transfer::<MyToken> { value: None });
+                    let _: Transfer<Vec<u8>> = unimplemented!();
+                    let _: Transfer<u8, u8> = unimplemented!();
+                    let _: Other<u32> = unimplemented!();
+                    let _: Transfer<_> = unimplemented!();
+                    let _: Transfer<Self> = unimplemented!();
+                }
+            },
+            syn::parse_quote! {
+                fn generic_transfer<T>() {
+                    emit(Transfer::<T> { value: None });
+                    emit(Transfer::<Vec<T>> { value: None });
+                }
+            },
+            syn::parse_quote! {
+                impl<U> Sender<U> {
+                    fn send(&self) {
+                        emit(Transfer::<U> { value: None });
+                    }
+                }
+            },
+        ];
+        event
+            .resolve_instances(&collect_instantiations(&items))
+            .unwrap();
+        let field_ty = event.fields().next().unwrap().ty();
+        let instances = event
+            .instances()
+            .map(|instance| {
+                (
+                    instance.name(),
+                    instance.variant_ident().to_string(),
+                    instance.monomorphize(field_ty),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            instances,
+            vec![
+                (
+                    "Transfer<MyToken>".to_string(),
+                    "Transfer_0".to_string(),
+                    syn::parse_quote! { Option<MyToken> },
+                ),
+                (
+                    "Transfer<Vec<u8>>".to_string(),
+                    "Transfer_1".to_string(),
+                    syn::parse_quote! { Option<Vec<u8>> },
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn generic_event_without_instances_fails() {
+        let mut event =
+            <Event as TryFrom<syn::ItemStruct>>::try_from(syn::parse_quote! {
+                #[ink(event)]
+                pub struct Transfer<T> {
+                    value: T,
+                }
+            })
+            .unwrap();
+        assert_eq!(
+            event
+                .resolve_instances(&[])
+                .map_err(|error| error.to_string()),
+            Err("generic ink! event `Transfer` is never instantiated with concrete type arguments, e.g. `Transfer::<T> { .. }`".to_string()),
+        );
+    }
+
     #[test]
     fn non_pub_event_struct() {
         assert_try_from_fails(
@@ -365,7 +866,7 @@ mod tests {
                     field_2: bool,
                 }
             },
-            "first optional ink! attribute of an event field must be #[ink(topic)]",
+            "#[ink(message)] cannot be applied to an event field",
         )
     }
 
@@ -381,7 +882,7 @@ mod tests {
                     field_2: bool,
                 }
             },
-            "encountered conflicting ink! attribute for event field",
+            "#[ink(payable)] cannot be applied to an event field",
         )
     }
 
@@ -478,4 +979,27 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn doc_hidden_event_works() {
+        let event = <Event as TryFrom<syn::ItemStruct>>::try_from(syn::parse_quote! {
+            #[ink(event)]
+            #[doc(hidden)]
+            pub struct MyEvent {
+                field: i32,
+            }
+        })
+        .unwrap();
+        assert!(event.is_doc_hidden());
+        assert!(event.attrs().iter().any(|attr| attr.path.is_ident("doc")));
+        let event = <Event as TryFrom<syn::ItemStruct>>::try_from(syn::parse_quote! {
+            /// Documented and visible.
+            #[ink(event)]
+            pub struct MyEvent {
+                field: i32,
+            }
+        })
+        .unwrap();
+        assert!(!event.is_doc_hidden());
+    }
 }
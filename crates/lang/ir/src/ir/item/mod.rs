@@ -19,12 +19,16 @@ mod storage;
 mod tests;
 
 pub use self::{
-    event::Event,
+    event::{
+        Event,
+        EventInstance,
+    },
     storage::Storage,
 };
 
+pub(crate) use self::event::collect_instantiations;
+
 use crate::{
-    error::ExtError as _,
     ir,
     ir::attrs::Attrs as _,
 };
@@ -62,6 +66,9 @@ impl TryFrom<syn::Item> for Item {
                 if !ir::contains_ink_attributes(&item_struct.attrs) {
                     return Ok(Self::Rust(item_struct.into()))
                 }
+                let (ink_attrs, _) =
+                    ir::partition_attributes(item_struct.attrs.iter().cloned())?;
+                ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::Struct)?;
                 // At this point we know that there must be at least one ink!
                 // attribute. This can be either the ink! storage struct,
                 // an ink! event or an invalid ink! attribute.
@@ -97,20 +104,15 @@ impl TryFrom<syn::Item> for Item {
                     .map(Self::Ink)
             }
             item => {
-                // This is an error if the item contains any unexpected
-                // ink! attributes. Otherwise it is a normal Rust item.
-                if ir::contains_ink_attributes(item.attrs()) {
-                    let (ink_attrs, _) =
-                        ir::partition_attributes(item.attrs().iter().cloned())?;
-                    assert!(!ink_attrs.is_empty());
-                    fn into_err(attr: &ir::InkAttribute) -> syn::Error {
-                        format_err!(attr.span(), "encountered unexpected ink! attribute",)
-                    }
-                    return Err(ink_attrs[1..]
-                        .iter()
-                        .map(into_err)
-                        .fold(into_err(&ink_attrs[0]), |fst, snd| fst.into_combine(snd)))
+                if let syn::Item::Enum(item_enum) = &item {
+                    ir::Storage::ensure_no_storage_enum(item_enum)?;
                 }
+                // This is an error if the item contains any ink! attributes
+                // since none of them are applicable to the remaining kinds of
+                // items. Otherwise it is a normal Rust item.
+                let (ink_attrs, _) =
+                    ir::partition_attributes(item.attrs().iter().cloned())?;
+                ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::of_item(&item))?;
                 Ok(Self::Rust(item))
             }
         }
@@ -13,11 +13,16 @@
 // limitations under the License.
 
 use crate::{
+    error::ExtError as _,
     ir,
     ir::utils,
 };
 use core::convert::TryFrom;
-use proc_macro2::Ident;
+use proc_macro2::{
+    Ident,
+    Span,
+};
+use std::collections::HashMap;
 use syn::spanned::Spanned as _;
 
 /// An ink! storage struct definition.
@@ -45,6 +50,28 @@ use syn::spanned::Spanned as _;
 pub struct Storage {
     /// The underlying `struct` Rust item.
     ast: syn::ItemStruct,
+    /// The explicit storage keys of the fields of the ink! storage struct.
+    ///
+    /// # Note
+    ///
+    /// Yields one entry per field in the order of the fields.
+    storage_keys: Vec<Option<ir::StorageKey>>,
+    /// Flags for every field of the ink! storage struct that are `true` for
+    /// fields flagged with `#[ink(hidden)]`.
+    ///
+    /// # Note
+    ///
+    /// Yields one entry per field in the order of the fields.
+    hidden_fields: Vec<bool>,
+    /// If `true` a `new_zeroed` associated function is generated for the
+    /// ink! storage struct as requested via `#[ink(storage, zeroed)]`.
+    zeroed: bool,
+    /// If `true` ink! does not generate the `StorageLayout` implementation of
+    /// the ink! storage struct as requested via `#[ink(storage, manual_layout)]`.
+    manual_layout: bool,
+    /// The version of the storage layout as declared via
+    /// `#[ink(storage, version = N)]` or `0` if none has been declared.
+    version: u32,
 }
 
 impl quote::ToTokens for Storage {
@@ -75,6 +102,37 @@ impl Storage {
             .expect("missing expected ink! attribute for struct");
         Ok(matches!(attr.first().kind(), ir::AttributeArg::Storage))
     }
+
+    /// Returns an error if the first ink! annotation on the given enum is
+    /// `#[ink(storage)]`.
+    ///
+    /// # Note
+    ///
+    /// The ink! storage defines the root storage layout of an ink! smart contract
+    /// which must be the same throughout its lifetime. Since the layout of an enum
+    /// depends on its currently active variant it cannot be used as ink! storage.
+    ///
+    /// # Errors
+    ///
+    /// If the first found ink! attribute is malformed.
+    pub(super) fn ensure_no_storage_enum(
+        item_enum: &syn::ItemEnum,
+    ) -> Result<(), syn::Error> {
+        if !ir::contains_ink_attributes(&item_enum.attrs) {
+            return Ok(())
+        }
+        let attr = ir::first_ink_attribute(&item_enum.attrs)?
+            .expect("missing expected ink! attribute for enum");
+        if let ir::AttributeArg::Storage = attr.first().kind() {
+            return Err(format_err!(
+                attr.span(),
+                "ink! storage must be a struct: enums cannot be the root storage of \
+                 an ink! smart contract since their storage layout depends on the \
+                 active variant",
+            ))
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<syn::ItemStruct> for Storage {
@@ -82,13 +140,16 @@ impl TryFrom<syn::ItemStruct> for Storage {
 
     fn try_from(item_struct: syn::ItemStruct) -> Result<Self, Self::Error> {
         let struct_span = item_struct.span();
-        let (_ink_attrs, other_attrs) = ir::sanitize_attributes(
+        let (ink_attrs, other_attrs) = ir::sanitize_attributes(
             struct_span,
             item_struct.attrs,
             &ir::AttributeArgKind::Storage,
             |arg| {
                 match arg.kind() {
-                    ir::AttributeArg::Storage => Ok(()),
+                    ir::AttributeArg::Storage
+                    | ir::AttributeArg::Zeroed
+                    | ir::AttributeArg::ManualLayout
+                    | ir::AttributeArg::Version(_) => Ok(()),
                     _ => Err(None),
                 }
             },
@@ -100,16 +161,251 @@ impl TryFrom<syn::ItemStruct> for Storage {
             ))
         }
         utils::ensure_pub_visibility("storage structs", struct_span, &item_struct.vis)?;
+        for (n, field) in item_struct.fields.iter().enumerate() {
+            Self::ensure_storable_field(n, field)?;
+        }
+        let zeroed = ink_attrs.is_zeroed();
+        if zeroed {
+            for (n, field) in item_struct.fields.iter().enumerate() {
+                Self::ensure_zeroable_field(n, field)?;
+            }
+        }
+        let manual_layout = ink_attrs.is_manual_layout();
+        Self::ensure_no_conflicting_derives(&other_attrs, manual_layout)?;
+        let mut fields = item_struct.fields;
+        let (storage_keys, hidden_fields) = Self::extract_field_attributes(&mut fields)?;
+        if manual_layout {
+            if let Some((field, _)) = fields
+                .iter()
+                .zip(&hidden_fields)
+                .find(|(_, hidden)| **hidden)
+            {
+                return Err(format_err_spanned!(
+                    field,
+                    "#[ink(hidden)] has no effect on fields of an ink! storage struct \
+                     flagged with #[ink(storage, manual_layout)] since its `StorageLayout` \
+                     is hand-written",
+                ))
+            }
+        }
         Ok(Self {
             ast: syn::ItemStruct {
                 attrs: other_attrs,
+                fields,
                 ..item_struct
             },
+            storage_keys,
+            hidden_fields,
+            zeroed,
+            manual_layout,
+            version: ink_attrs.version().unwrap_or_default(),
         })
     }
 }
 
 impl Storage {
+    /// Returns an error if the type of the given storage field can obviously
+    /// never be stored in the contract storage.
+    ///
+    /// # Note
+    ///
+    /// This is a best-effort check that only catches types such as function
+    /// pointers or references that never implement the `scale::Encode`,
+    /// `scale::Decode` and `SpreadLayout` traits required for storage fields.
+    /// Its purpose is to report those with a clear error pointing at the field
+    /// instead of an unsatisfied trait bound deep within the generated code.
+    fn ensure_storable_field(n: usize, field: &syn::Field) -> Result<(), syn::Error> {
+        let unstorable = utils::find_nested_type(&field.ty, false, |ty| {
+            match ty {
+                syn::Type::BareFn(_) => Some("function pointers"),
+                syn::Type::Reference(_) => Some("references"),
+                syn::Type::Ptr(_) => Some("raw pointers"),
+                syn::Type::TraitObject(_) => Some("trait objects"),
+                syn::Type::ImplTrait(_) => Some("`impl Trait` types"),
+                syn::Type::Never(_) => Some("the never type"),
+                _ => None,
+            }
+        });
+        if let Some((_, kind)) = unstorable {
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| n.to_string());
+            let ty = &field.ty;
+            return Err(format_err_spanned!(
+                field,
+                "ink! storage field `{}` of type `{}` cannot be stored in the contract storage \
+                 since {} do not implement `scale::Encode`, `scale::Decode` and `SpreadLayout`",
+                field_name,
+                quote::quote!(#ty),
+                kind,
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns the zero value of the given type if it has a well-defined one.
+    ///
+    /// # Note
+    ///
+    /// Only fixed-width integers, `bool` as well as arrays and tuples thereof
+    /// have a well-defined zero value. Other types, e.g. ink! storage data
+    /// structures or type aliases, may have a `Default` implementation but
+    /// ink! cannot tell whether it is equivalent to zero-initialization.
+    fn zero_value(ty: &syn::Type) -> Option<syn::Expr> {
+        const INTEGERS: &[&str] = &[
+            "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+        ];
+        match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                let ident = type_path.path.get_ident()?;
+                if INTEGERS.iter().any(|integer| ident == integer) {
+                    Some(syn::parse_quote! { 0 })
+                } else if ident == "bool" {
+                    Some(syn::parse_quote! { false })
+                } else {
+                    None
+                }
+            }
+            syn::Type::Array(type_array) => {
+                let zero = Self::zero_value(&type_array.elem)?;
+                let len = &type_array.len;
+                Some(syn::parse_quote! { [#zero; #len] })
+            }
+            syn::Type::Tuple(type_tuple) => {
+                let zeros = type_tuple
+                    .elems
+                    .iter()
+                    .map(Self::zero_value)
+                    .collect::<Option<Vec<_>>>()?;
+                Some(syn::parse_quote! { ( #( #zeros, )* ) })
+            }
+            syn::Type::Paren(type_paren) => Self::zero_value(&type_paren.elem),
+            syn::Type::Group(type_group) => Self::zero_value(&type_group.elem),
+            _ => None,
+        }
+    }
+
+    /// Returns an error if the type of the given storage field has no
+    /// well-defined zero value as required by `#[ink(storage, zeroed)]`.
+    fn ensure_zeroable_field(n: usize, field: &syn::Field) -> Result<(), syn::Error> {
+        if Self::zero_value(&field.ty).is_none() {
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| n.to_string());
+            let ty = &field.ty;
+            return Err(format_err_spanned!(
+                field,
+                "ink! storage field `{}` of type `{}` has no well-defined zero value \
+                 required by #[ink(storage, zeroed)], expected integers, `bool` or \
+                 arrays and tuples thereof",
+                field_name,
+                quote::quote!(#ty),
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the ink! storage struct derives traits that are
+    /// already derived for it by ink!.
+    ///
+    /// # Note
+    ///
+    /// ink! derives `SpreadLayout` and `StorageLayout` for the ink! storage
+    /// struct. Deriving them manually as well results in conflicting trait
+    /// implementations that are reported deep within the generated code.
+    /// Derives that are conditionally applied via `cfg_attr` are checked as well.
+    ///
+    /// Deriving `StorageLayout` is allowed for ink! storage structs flagged with
+    /// `#[ink(storage, manual_layout)]` since ink! does not derive it for them.
+    fn ensure_no_conflicting_derives(
+        attrs: &[syn::Attribute],
+        manual_layout: bool,
+    ) -> Result<(), syn::Error> {
+        let generated_derives: &[&str] = if manual_layout {
+            &["SpreadLayout"]
+        } else {
+            &["SpreadLayout", "StorageLayout"]
+        };
+        if let Some(derive) = utils::find_derive(attrs, generated_derives) {
+            return Err(format_err_spanned!(
+                derive,
+                "encountered conflicting derive of `{}` on the ink! storage struct \
+                 which ink! already derives for it",
+                derive
+                    .segments
+                    .last()
+                    .map(|segment| &segment.ident)
+                    .expect("encountered derive without path segments"),
+            ))
+        }
+        Ok(())
+    }
+
+    /// Extracts the explicit storage keys and hidden flags of the given ink!
+    /// storage fields and removes their ink! attributes.
+    ///
+    /// # Errors
+    ///
+    /// - If a storage field has ink! attributes other than `storage_key` or `hidden`.
+    /// - If two storage fields have the same explicit storage key.
+    /// - If a storage field has the explicit storage key `0` which is the root key
+    ///   of the storage fields without explicit storage key.
+    fn extract_field_attributes(
+        fields: &mut syn::Fields,
+    ) -> Result<(Vec<Option<ir::StorageKey>>, Vec<bool>), syn::Error> {
+        let mut storage_keys = Vec::new();
+        let mut hidden_fields = Vec::new();
+        let mut seen = <HashMap<ir::StorageKey, Span>>::new();
+        for field in fields.iter_mut() {
+            let field_span = field.span();
+            let (ink_attrs, other_attrs) = ir::partition_attributes(field.attrs.clone())?;
+            if ink_attrs.is_empty() {
+                storage_keys.push(None);
+                hidden_fields.push(false);
+                continue
+            }
+            ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::StorageField)?;
+            let normalized =
+                ir::InkAttribute::from_expanded(ink_attrs).map_err(|err| {
+                    err.into_combine(format_err!(field_span, "at this invocation",))
+                })?;
+            let storage_key = normalized.storage_key();
+            if let Some(first_span) =
+                storage_key.and_then(|storage_key| seen.insert(storage_key, field_span))
+            {
+                return Err(format_err!(
+                    field_span,
+                    "encountered ink! storage field with the same storage key as another storage field",
+                )
+                .into_combine(format_err!(
+                    first_span,
+                    "first ink! storage field with this storage key here",
+                )))
+            }
+            field.attrs = other_attrs;
+            storage_keys.push(storage_key);
+            hidden_fields.push(normalized.is_hidden());
+        }
+        let has_positional_fields = storage_keys.iter().any(Option::is_none);
+        if let Some(root_key_span) = seen
+            .iter()
+            .find(|(storage_key, _)| storage_key.as_bytes() == &[0x00; 32])
+            .map(|(_, span)| *span)
+            .filter(|_| has_positional_fields)
+        {
+            return Err(format_err!(
+                root_key_span,
+                "encountered ink! storage field with the explicit storage key 0 \
+                 which is the root key of the storage fields without explicit storage key",
+            ))
+        }
+        Ok((storage_keys, hidden_fields))
+    }
+
     /// Returns the non-ink! attributes of the ink! storage struct.
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.ast.attrs
@@ -124,6 +420,149 @@ impl Storage {
     pub fn fields(&self) -> syn::punctuated::Iter<syn::Field> {
         self.ast.fields.iter()
     }
+
+    /// Returns the explicit storage keys of the fields of the storage struct.
+    ///
+    /// Yields one entry per field in the same order as [`Storage::fields`]
+    /// that is `None` for fields without explicit storage key.
+    ///
+    /// # Note
+    ///
+    /// Fields with explicit storage keys are stored under the key specified via
+    /// `#[ink(storage_key = N)]` while all other fields are stored under keys
+    /// derived from their position within the storage struct as usual.
+    pub fn storage_keys(&self) -> &[Option<ir::StorageKey>] {
+        &self.storage_keys
+    }
+
+    /// Returns `true` if any field of the storage struct has an explicit storage key.
+    pub fn has_explicit_storage_keys(&self) -> bool {
+        self.storage_keys.iter().any(Option::is_some)
+    }
+
+    /// Returns the hidden flags of the fields of the storage struct.
+    ///
+    /// Yields one entry per field in the same order as [`Storage::fields`]
+    /// that is `true` for fields flagged with `#[ink(hidden)]`.
+    ///
+    /// # Note
+    ///
+    /// Hidden fields are part of the on-chain storage layout of the storage
+    /// struct but are excluded from the generated metadata.
+    pub fn hidden_fields(&self) -> &[bool] {
+        &self.hidden_fields
+    }
+
+    /// Returns `true` if any field of the storage struct is flagged with `#[ink(hidden)]`.
+    pub fn has_hidden_fields(&self) -> bool {
+        self.hidden_fields.iter().any(|hidden| *hidden)
+    }
+
+    /// Returns `true` if the storage struct has been flagged with
+    /// `#[ink(storage, manual_layout)]`.
+    ///
+    /// # Note
+    ///
+    /// ink! does not generate the `StorageLayout` implementation of such a
+    /// storage struct but expects a hand-written or derived one.
+    /// The storage struct is still the storage root of the ink! smart contract.
+    pub fn has_manual_layout(&self) -> bool {
+        self.manual_layout
+    }
+
+    /// Returns the version of the storage layout of the storage struct.
+    ///
+    /// # Note
+    ///
+    /// The version is declared via `#[ink(storage, version = N)]` and is `0`
+    /// for storage structs without a declared version.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the zero values of the fields of the storage struct if it has
+    /// been flagged with `#[ink(storage, zeroed)]`.
+    ///
+    /// Yields one entry per field in the same order as [`Storage::fields`].
+    ///
+    /// # Note
+    ///
+    /// These are used to generate the `new_zeroed` associated function of the
+    /// storage struct.
+    pub fn zero_values(&self) -> Option<Vec<syn::Expr>> {
+        if !self.zeroed {
+            return None
+        }
+        let zero_values = self
+            .fields()
+            .map(|field| {
+                Self::zero_value(&field.ty)
+                    .expect("encountered unexpected storage field without zero value")
+            })
+            .collect();
+        Some(zero_values)
+    }
+
+    /// Returns an iter yielding all fields of the storage struct declared `pub`.
+    ///
+    /// # Note
+    ///
+    /// Restricted visibilities such as `pub(crate)` are not yielded.
+    pub fn pub_fields(&self) -> impl Iterator<Item = &syn::Field> {
+        self.fields()
+            .filter(|field| matches!(field.vis, syn::Visibility::Public(_)))
+    }
+
+    /// Returns an iter yielding all fields of the storage struct whose type
+    /// contains an interior mutability type such as `RefCell` together with
+    /// the identifier of that type.
+    ///
+    /// # Note
+    ///
+    /// Interior mutability types do not persist meaningfully in the contract
+    /// storage. Since this inspects the type paths syntactically it does not
+    /// see through type aliases.
+    pub fn interior_mutability_fields(
+        &self,
+    ) -> impl Iterator<Item = (&syn::Field, &Ident)> {
+        self.fields().filter_map(|field| {
+            Self::interior_mutability_type(&field.ty).map(|ident| (field, ident))
+        })
+    }
+
+    /// Returns the identifier of the first interior mutability type found
+    /// within the given type if any.
+    fn interior_mutability_type(ty: &syn::Type) -> Option<&Ident> {
+        const INTERIOR_MUTABILITY_TYPES: &[&str] = &[
+            "Cell",
+            "RefCell",
+            "UnsafeCell",
+            "OnceCell",
+            "Mutex",
+            "RwLock",
+        ];
+        struct Finder<'ast> {
+            found: Option<&'ast Ident>,
+        }
+        impl<'ast> syn::visit::Visit<'ast> for Finder<'ast> {
+            fn visit_path_segment(&mut self, segment: &'ast syn::PathSegment) {
+                if self.found.is_some() {
+                    return
+                }
+                if INTERIOR_MUTABILITY_TYPES
+                    .iter()
+                    .any(|name| segment.ident == name)
+                {
+                    self.found = Some(&segment.ident);
+                    return
+                }
+                syn::visit::visit_path_segment(self, segment)
+            }
+        }
+        let mut finder = Finder { found: None };
+        syn::visit::Visit::visit_type(&mut finder, ty);
+        finder.found
+    }
 }
 
 #[cfg(test)]
@@ -149,6 +588,141 @@ mod tests {
         )
     }
 
+    #[test]
+    fn explicit_storage_keys_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                #[ink(storage_key = 0x1234)]
+                field_1: i32,
+                field_2: bool,
+                #[ink(storage_key = 0x5678)]
+                field_3: u8,
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        let mut key_1 = [0x00; 32];
+        key_1[0] = 0x34;
+        key_1[1] = 0x12;
+        let mut key_3 = [0x00; 32];
+        key_3[0] = 0x78;
+        key_3[1] = 0x56;
+        assert!(storage.has_explicit_storage_keys());
+        assert_eq!(
+            storage
+                .storage_keys()
+                .iter()
+                .map(|key| key.map(|key| *key.as_bytes()))
+                .collect::<Vec<_>>(),
+            vec![Some(key_1), None, Some(key_3)],
+        );
+        assert!(storage.fields().all(|field| field.attrs.is_empty()));
+    }
+
+    #[test]
+    fn hidden_fields_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: i32,
+                #[ink(hidden)]
+                field_2: bool,
+                #[ink(hidden, storage_key = 0x1234)]
+                field_3: u8,
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        assert!(storage.has_hidden_fields());
+        assert_eq!(storage.hidden_fields(), &[false, true, true]);
+        assert_eq!(
+            storage
+                .storage_keys()
+                .iter()
+                .filter(|key| key.is_some())
+                .count(),
+            1
+        );
+        assert!(storage.fields().all(|field| field.attrs.is_empty()));
+    }
+
+    #[test]
+    fn pub_fields_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: i32,
+                pub field_2: bool,
+                pub(crate) field_3: u8,
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        let pub_fields = storage
+            .pub_fields()
+            .map(|field| field.ident.as_ref().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(pub_fields, vec!["field_2"]);
+    }
+
+    #[test]
+    fn duplicate_storage_keys_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(storage_key = 0x1234)]
+                    field_1: i32,
+                    #[ink(storage_key = 4660)]
+                    field_2: bool,
+                }
+            },
+            "encountered ink! storage field with the same storage key as another storage field",
+        )
+    }
+
+    #[test]
+    fn root_storage_key_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    field_1: i32,
+                    #[ink(storage_key = 0)]
+                    field_2: bool,
+                }
+            },
+            "encountered ink! storage field with the explicit storage key 0 \
+             which is the root key of the storage fields without explicit storage key",
+        )
+    }
+
+    #[test]
+    fn root_storage_key_without_positional_fields_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                #[ink(storage_key = 0)]
+                field_1: i32,
+                #[ink(storage_key = 0x1234)]
+                field_2: bool,
+            }
+        };
+        assert!(Storage::try_from(item_struct).is_ok());
+    }
+
+    #[test]
+    fn invalid_storage_field_attribute_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(topic)]
+                    field_1: i32,
+                }
+            },
+            "#[ink(topic)] cannot be applied to a storage field",
+        )
+    }
+
     #[test]
     fn conflicting_attributes_fails() {
         assert_try_from_fails(
@@ -160,7 +734,8 @@ mod tests {
                     field_2: bool,
                 }
             },
-            "encountered conflicting ink! attribute argument",
+            "#[ink(event)] cannot be combined with #[ink(storage)]: \
+             the ink! storage struct cannot be an ink! event at the same time",
         )
     }
 
@@ -233,4 +808,258 @@ mod tests {
             "non `pub` ink! storage structs are not supported",
         )
     }
+
+    #[test]
+    fn storage_enum_fails() {
+        let item_enum: syn::ItemEnum = syn::parse_quote! {
+            #[ink(storage)]
+            pub enum S {
+                A { value: i32 },
+                B(bool),
+            }
+        };
+        assert_eq!(
+            Storage::ensure_no_storage_enum(&item_enum).map_err(|err| err.to_string()),
+            Err(
+                "ink! storage must be a struct: enums cannot be the root storage of \
+                 an ink! smart contract since their storage layout depends on the \
+                 active variant"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            <ir::Item as TryFrom<syn::Item>>::try_from(item_enum.into())
+                .map_err(|err| err.to_string()),
+            Err(
+                "ink! storage must be a struct: enums cannot be the root storage of \
+                 an ink! smart contract since their storage layout depends on the \
+                 active variant"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn unstorable_field_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    field_1: i32,
+                    callback: fn(),
+                }
+            },
+            "ink! storage field `callback` of type `fn ()` cannot be stored in the contract storage \
+             since function pointers do not implement `scale::Encode`, `scale::Decode` and `SpreadLayout`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage(i32, [&'static str; 2]);
+            },
+            "ink! storage field `1` of type `[& 'static str ; 2]` cannot be stored in the contract storage \
+             since references do not implement `scale::Encode`, `scale::Decode` and `SpreadLayout`",
+        );
+    }
+
+    #[test]
+    fn conflicting_derive_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                #[derive(Default, ink_storage::traits::StorageLayout)]
+                pub struct MyStorage {
+                    field_1: i32,
+                }
+            },
+            "encountered conflicting derive of `StorageLayout` on the ink! storage struct \
+             which ink! already derives for it",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage)]
+                #[cfg_attr(feature = "std", derive(SpreadLayout))]
+                pub struct MyStorage {
+                    field_1: i32,
+                }
+            },
+            "encountered conflicting derive of `SpreadLayout` on the ink! storage struct \
+             which ink! already derives for it",
+        );
+    }
+
+    #[test]
+    fn manual_layout_derive_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage, manual_layout)]
+            #[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+            pub struct MyStorage {
+                field_1: i32,
+            }
+        };
+        assert!(<ir::Storage as TryFrom<_>>::try_from(item_struct).is_ok());
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage, manual_layout)]
+                #[derive(SpreadLayout)]
+                pub struct MyStorage {
+                    field_1: i32,
+                }
+            },
+            "encountered conflicting derive of `SpreadLayout` on the ink! storage struct \
+             which ink! already derives for it",
+        );
+    }
+
+    #[test]
+    fn non_conflicting_derive_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            #[derive(Default, PartialEq)]
+            pub struct MyStorage {
+                field_1: i32,
+            }
+        };
+        assert!(<ir::Storage as TryFrom<_>>::try_from(item_struct).is_ok());
+    }
+
+    #[test]
+    fn zeroed_works() {
+        use quote::ToTokens as _;
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage, zeroed)]
+            pub struct MyStorage {
+                field_1: u128,
+                field_2: bool,
+                field_3: [u8; 32],
+                field_4: (i32, [bool; 2]),
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        let zero_values = storage
+            .zero_values()
+            .unwrap()
+            .into_iter()
+            .map(|zero_value| zero_value.into_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            zero_values,
+            vec!["0", "false", "[0 ; 32]", "(0 , [false ; 2] ,)"],
+        );
+    }
+
+    #[test]
+    fn zero_values_without_zeroed_is_none() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: u32,
+            }
+        };
+        assert_eq!(Storage::try_from(item_struct).unwrap().zero_values(), None);
+    }
+
+    #[test]
+    fn zeroed_without_zero_value_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage, zeroed)]
+                pub struct MyStorage {
+                    field_1: u32,
+                    field_2: ink_storage::Vec<u32>,
+                }
+            },
+            "ink! storage field `field_2` of type `ink_storage :: Vec < u32 >` has no \
+             well-defined zero value required by #[ink(storage, zeroed)], expected \
+             integers, `bool` or arrays and tuples thereof",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage, zeroed)]
+                pub struct MyStorage {
+                    field_1: (u32, Balance),
+                }
+            },
+            "ink! storage field `field_1` of type `(u32 , Balance)` has no \
+             well-defined zero value required by #[ink(storage, zeroed)], expected \
+             integers, `bool` or arrays and tuples thereof",
+        );
+    }
+
+    #[test]
+    fn manual_layout_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage, manual_layout)]
+            pub struct MyStorage {
+                field_1: i32,
+                #[ink(storage_key = 0x1234)]
+                field_2: bool,
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        assert!(storage.has_manual_layout());
+    }
+
+    #[test]
+    fn version_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage, version = 3)]
+            pub struct MyStorage {
+                field_1: i32,
+            }
+        };
+        assert_eq!(Storage::try_from(item_struct).unwrap().version(), 3);
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: i32,
+            }
+        };
+        assert_eq!(Storage::try_from(item_struct).unwrap().version(), 0);
+    }
+
+    #[test]
+    fn manual_layout_with_hidden_field_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(storage, manual_layout)]
+                pub struct MyStorage {
+                    field_1: i32,
+                    #[ink(hidden)]
+                    field_2: bool,
+                }
+            },
+            "#[ink(hidden)] has no effect on fields of an ink! storage struct \
+             flagged with #[ink(storage, manual_layout)] since its `StorageLayout` \
+             is hand-written",
+        );
+    }
+
+    #[test]
+    fn interior_mutability_fields_works() {
+        let item_struct: syn::ItemStruct = syn::parse_quote! {
+            #[ink(storage)]
+            pub struct MyStorage {
+                field_1: core::cell::RefCell<u32>,
+                field_2: u32,
+                field_3: ink_storage::Vec<Cell<bool>>,
+                field_4: [Mutex<u8>; 2],
+            }
+        };
+        let storage = Storage::try_from(item_struct).unwrap();
+        let actual = storage
+            .interior_mutability_fields()
+            .map(|(field, ty)| {
+                (field.ident.as_ref().unwrap().to_string(), ty.to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                ("field_1".to_string(), "RefCell".to_string()),
+                ("field_3".to_string(), "Cell".to_string()),
+                ("field_4".to_string(), "Mutex".to_string()),
+            ]
+        );
+    }
 }
@@ -81,3 +81,39 @@ fn simple_rust_item_works() {
         )
     }
 }
+
+#[test]
+fn misapplied_ink_attributes_fail() {
+    let misapplied: Vec<(syn::Item, &str)> = vec![
+        (
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub struct MyStorage {}
+            },
+            "#[ink(constructor)] cannot be applied to a struct",
+        ),
+        (
+            syn::parse_quote! {
+                #[ink(payable)]
+                fn free_function() {}
+            },
+            "#[ink(payable)] cannot be applied to a function",
+        ),
+        (
+            syn::parse_quote! {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    #[ink(topic)]
+                    field_1: bool,
+                }
+            },
+            "#[ink(topic)] cannot be applied to a storage field",
+        ),
+    ];
+    for (item, expected_error) in misapplied {
+        assert_eq!(
+            <ir::Item as TryFrom<_>>::try_from(item).map_err(|err| err.to_string()),
+            Err(expected_error.to_string()),
+        );
+    }
+}
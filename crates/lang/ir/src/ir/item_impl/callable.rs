@@ -21,7 +21,6 @@ use proc_macro2::{
     Ident,
     Span,
 };
-use quote::ToTokens as _;
 use syn::spanned::Spanned as _;
 
 /// The kind of externally callable smart contract entity.
@@ -120,10 +119,18 @@ where
         <C as Callable>::visibility(&self.callable)
     }
 
-    fn inputs(&self) -> InputsIter {
+    fn inputs_iter(&self) -> InputsIter {
+        <C as Callable>::inputs_iter(&self.callable)
+    }
+
+    fn inputs(&self) -> &[(Ident, syn::Type)] {
         <C as Callable>::inputs(&self.callable)
     }
 
+    fn input_defaults(&self) -> &[Option<String>] {
+        <C as Callable>::input_defaults(self.callable)
+    }
+
     fn inputs_span(&self) -> Span {
         <C as Callable>::inputs_span(&self.callable)
     }
@@ -166,7 +173,23 @@ pub trait Callable {
     fn visibility(&self) -> Visibility;
 
     /// Returns an iterator yielding all input parameters of the ink! callable.
-    fn inputs(&self) -> InputsIter;
+    fn inputs_iter(&self) -> InputsIter;
+
+    /// Returns the identifiers and types of all input parameters of the ink! callable.
+    ///
+    /// # Note
+    ///
+    /// This does not include the `self` receiver of ink! messages.
+    fn inputs(&self) -> &[(Ident, syn::Type)];
+
+    /// Returns the advisory default values of all input parameters of the ink! callable
+    /// in the same order as [`Callable::inputs`].
+    ///
+    /// # Note
+    ///
+    /// Default values are set via `#[ink(default = value)]` on input parameters and
+    /// are only emitted into the metadata for front-ends to prefill the inputs.
+    fn input_defaults(&self) -> &[Option<String>];
 
     /// Returns the span of the inputs of the ink! callable.
     fn inputs_span(&self) -> Span;
@@ -190,6 +213,8 @@ pub trait Callable {
 /// Then the selector is composed in the following way:
 ///
 /// - If `s` is given we simply return `s`.
+/// - Otherwise if `T` is `None` (inherent impl block) we concatenate `S` and `i`
+///   with `::` as separator or simply take `i` if no namespace is given.
 /// - Otherwise if `T` is not `None` (trait impl block) we concatenate
 ///   `S`, `T` and `i` with `::` as separator if `T` refers to a full-path.
 ///   If `T` refers to a relative path or is just an identifier we only take
@@ -281,6 +306,45 @@ pub trait Callable {
 /// BLAKE2("my_namespace::MyTrait::my_message".to_string().as_bytes())[0..4]
 /// ```
 ///
+/// ## Using a namespace on an inherent implementation block
+///
+/// Given
+///
+/// ```no_compile
+/// #[ink(impl, namespace = "my_namespace")]
+/// impl MyStorage {
+///     #[ink(message)]
+///     fn my_message(&self) {}
+/// }
+/// ```
+///
+/// ... then the selector of `my_message` is composed such as:
+/// ```no_compile
+/// BLAKE2("my_namespace::my_message".to_string().as_bytes())[0..4]
+/// ```
+///
+/// ## Using a contract namespace
+///
+/// Given
+///
+/// ```no_compile
+/// #[ink::contract(namespace = "vault")]
+/// mod my_contract {
+///     impl MyStorage {
+///         #[ink(message)]
+///         fn my_message(&self) {}
+///     }
+/// }
+/// ```
+///
+/// ... then the selector of `my_message` is composed such as:
+/// ```no_compile
+/// BLAKE2("vault::my_message".to_string().as_bytes())[0..4]
+/// ```
+///
+/// The contract namespace prefixes the composition of all of the above
+/// examples except for explicitly provided selectors.
+///
 /// ## Note
 ///
 /// All above examples work similarly for ink! constructors interchangeably.
@@ -304,46 +368,13 @@ where
     if let Some(selector) = callable.user_provided_selector() {
         return *selector
     }
-    let callable_ident = callable.ident().to_string().into_bytes();
-    let namespace_bytes = item_impl
-        .namespace()
-        .map(|namespace| namespace.as_bytes().to_vec())
-        .unwrap_or_default();
-    let separator = &b"::"[..];
-    let joined = match item_impl.trait_path() {
-        None => {
-            // Inherent implementation block:
-            if namespace_bytes.is_empty() {
-                callable_ident
-            } else {
-                [namespace_bytes, callable_ident].join(separator)
-            }
-        }
-        Some(path) => {
-            // Trait implementation block:
-            //
-            // We need to separate between full-path, e.g. `::my::full::Path`
-            // starting with `::` and relative paths for the composition.
-            let path_bytes = if path.leading_colon.is_some() {
-                let mut str_repr = path.to_token_stream().to_string();
-                str_repr.retain(|c| !c.is_whitespace());
-                str_repr.into_bytes()
-            } else {
-                path.segments
-                    .last()
-                    .expect("encountered empty trait path")
-                    .ident
-                    .to_string()
-                    .into_bytes()
-            };
-            if namespace_bytes.is_empty() {
-                [path_bytes, callable_ident].join(separator)
-            } else {
-                [namespace_bytes, path_bytes, callable_ident].join(separator)
-            }
-        }
-    };
-    ir::Selector::new(&joined)
+    ir::Selector::compose(
+        item_impl.contract_namespace(),
+        item_impl.namespace(),
+        item_impl.trait_path(),
+        callable.ident(),
+        item_impl.selector_hash(),
+    )
 }
 
 /// Ensures that common invariants of externally callable ink! entities are met.
@@ -357,7 +388,8 @@ where
 ///  - async (async WebAssembly smart contract calling is not allowed)
 ///  - unsafe (caller provided assertions not yet stable)
 /// - Furthermore this is `true` if the externally callable is defined for a
-///   non default ABI (e.g. `extern "C"`) or doesn't have valid visibility.
+///   non default ABI (e.g. `extern "C"`) or doesn't have valid visibility
+///   or if its where clause cannot be dispatched.
 pub(super) fn ensure_callable_invariants(
     method_item: &syn::ImplItemMethod,
     kind: CallableKind,
@@ -382,6 +414,9 @@ pub(super) fn ensure_callable_invariants(
             kind,
         ))
     }
+    if let Some(where_clause) = &method_item.sig.generics.where_clause {
+        ensure_dispatchable_where_clause(where_clause, kind)?;
+    }
     if method_item.sig.constness.is_some() {
         return Err(format_err_spanned!(
             method_item.sig.constness,
@@ -420,6 +455,261 @@ pub(super) fn ensure_callable_invariants(
     Ok(())
 }
 
+/// Ensures that the where clause of an externally callable ink! entity can be dispatched.
+///
+/// # Note
+///
+/// Since externally callable ink! entities must not be generic their where clause
+/// can only constrain concrete types, e.g. `where Self: Clone`. Those bounds are
+/// kept on the generated method and checked by the Rust compiler.
+///
+/// # Errors
+///
+/// - If the where clause contains an equality constraint.
+/// - If the where clause refers to lifetimes other than `'static` that are not
+///   introduced by a higher-ranked trait bound, e.g. `for<'a>`.
+fn ensure_dispatchable_where_clause(
+    where_clause: &syn::WhereClause,
+    kind: CallableKind,
+) -> Result<(), syn::Error> {
+    #[derive(Default)]
+    struct LifetimeVisitor {
+        /// The lifetimes introduced by the enclosing higher-ranked trait bounds.
+        bound: Vec<Ident>,
+        /// The first lifetime that is neither `'static` nor bound.
+        found: Option<syn::Lifetime>,
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for LifetimeVisitor {
+        fn visit_predicate_type(&mut self, predicate: &'ast syn::PredicateType) {
+            let len = self.bound.len();
+            if let Some(bound_lifetimes) = &predicate.lifetimes {
+                self.bound.extend(
+                    bound_lifetimes
+                        .lifetimes
+                        .iter()
+                        .map(|def| def.lifetime.ident.clone()),
+                );
+            }
+            syn::visit::visit_predicate_type(self, predicate);
+            self.bound.truncate(len);
+        }
+
+        fn visit_trait_bound(&mut self, trait_bound: &'ast syn::TraitBound) {
+            let len = self.bound.len();
+            if let Some(bound_lifetimes) = &trait_bound.lifetimes {
+                self.bound.extend(
+                    bound_lifetimes
+                        .lifetimes
+                        .iter()
+                        .map(|def| def.lifetime.ident.clone()),
+                );
+            }
+            syn::visit::visit_trait_bound(self, trait_bound);
+            self.bound.truncate(len);
+        }
+
+        fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+            if self.found.is_none()
+                && lifetime.ident != "static"
+                && !self.bound.contains(&lifetime.ident)
+            {
+                self.found = Some(lifetime.clone())
+            }
+        }
+    }
+
+    let mut visitor = LifetimeVisitor::default();
+    for predicate in &where_clause.predicates {
+        if let syn::WherePredicate::Eq(predicate_eq) = predicate {
+            return Err(format_err_spanned!(
+                predicate_eq,
+                "equality constraints in where clauses of ink! {}s are not supported",
+                kind,
+            ))
+        }
+        syn::visit::Visit::visit_where_predicate(&mut visitor, predicate);
+    }
+    if let Some(lifetime) = visitor.found {
+        return Err(format_err_spanned!(
+            lifetime,
+            "where clauses of ink! {}s must not refer to lifetimes other than `'static`",
+            kind,
+        ))
+    }
+    Ok(())
+}
+
+/// Resolves the identifiers and types of the input parameters of an externally
+/// callable ink! entity.
+///
+/// # Errors
+///
+/// If an input parameter is not bound to a simple identifier.
+pub(super) fn resolve_args(
+    method_item: &syn::ImplItemMethod,
+    kind: CallableKind,
+) -> Result<Vec<(Ident, syn::Type)>, syn::Error> {
+    method_item
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|fn_arg| {
+            match fn_arg {
+                syn::FnArg::Typed(pat_type) => Some(pat_type),
+                syn::FnArg::Receiver(_) => None,
+            }
+        })
+        .map(|pat_type| {
+            match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => {
+                    Ok((pat_ident.ident.clone(), (*pat_type.ty).clone()))
+                }
+                _ => {
+                    Err(format_err_spanned!(
+                        pat_type.pat,
+                        "ink! {} inputs must be bound to identifiers",
+                        kind,
+                    ))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the advisory default values of the inputs of the ink! message or
+/// constructor and removes their ink! attributes from the method item.
+///
+/// # Errors
+///
+/// - If an input has ink! attributes other than `#[ink(default = value)]`.
+/// - If the literal of a default value does not fit the type of its input.
+pub(super) fn extract_arg_defaults(
+    method_item: &mut syn::ImplItemMethod,
+) -> Result<Vec<Option<String>>, syn::Error> {
+    method_item
+        .sig
+        .inputs
+        .iter_mut()
+        .filter_map(|fn_arg| {
+            match fn_arg {
+                syn::FnArg::Typed(pat_type) => Some(pat_type),
+                syn::FnArg::Receiver(_) => None,
+            }
+        })
+        .map(|pat_type| {
+            if !ir::contains_ink_attributes(&pat_type.attrs) {
+                return Ok(None)
+            }
+            let (ink_attrs, _) = ir::partition_attributes(pat_type.attrs.clone())?;
+            ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::Input)?;
+            let (ink_attrs, other_attrs) = ir::sanitize_attributes(
+                pat_type.span(),
+                pat_type.attrs.clone(),
+                &ir::AttributeArgKind::Default,
+                |arg| {
+                    match arg.kind() {
+                        ir::AttributeArg::Default(_) => Ok(()),
+                        _ => Err(None),
+                    }
+                },
+            )?;
+            let default_lit = ink_attrs.args().find_map(|arg| {
+                match (arg.kind(), &arg.ast) {
+                    (ir::AttributeArg::Default(_), syn::Meta::NameValue(name_value)) => {
+                        Some(&name_value.lit)
+                    }
+                    _ => None,
+                }
+            });
+            if let Some(default_lit) = default_lit {
+                ensure_default_fits_type(default_lit, &pat_type.ty)?;
+            }
+            pat_type.attrs = other_attrs;
+            Ok(ink_attrs.default_value().map(ToString::to_string))
+        })
+        .collect()
+}
+
+/// Ensures that the literal of an advisory default value fits the type of
+/// its input.
+///
+/// # Note
+///
+/// Only inputs of primitive types, `String` and `str` are checked since type
+/// aliases, e.g. `Balance`, cannot be resolved by ink!. Integer literals must
+/// be in range of the integer type of their input.
+///
+/// # Errors
+///
+/// If the kind of the literal does not match the type of the input.
+fn ensure_default_fits_type(lit: &syn::Lit, ty: &syn::Type) -> Result<(), syn::Error> {
+    let ty_ident = match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.get_ident()
+        }
+        syn::Type::Reference(type_ref) => {
+            match &*type_ref.elem {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                    type_path.path.get_ident().filter(|ident| *ident == "str")
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let ty_ident = match ty_ident {
+        Some(ty_ident) => ty_ident.to_string(),
+        None => return Ok(()),
+    };
+    let (expected, fits) = match ty_ident.as_str() {
+        "bool" => ("a `bool`", matches!(lit, syn::Lit::Bool(_))),
+        "String" | "str" => ("a string", matches!(lit, syn::Lit::Str(_))),
+        "f32" | "f64" => ("a float", matches!(lit, syn::Lit::Float(_))),
+        int_ty => {
+            match fits_integer_type(lit, int_ty) {
+                Some(fits) => ("an in range integer", fits),
+                None => return Ok(()),
+            }
+        }
+    };
+    if !fits {
+        return Err(format_err_spanned!(
+            lit,
+            "expected {} literal for the default value of an input of type `{}`",
+            expected,
+            ty_ident,
+        ))
+    }
+    Ok(())
+}
+
+/// Returns `true` if the literal is an integer literal in range of the given
+/// integer type.
+///
+/// Returns `None` if the given type is not a primitive integer type.
+fn fits_integer_type(lit: &syn::Lit, int_ty: &str) -> Option<bool> {
+    let lit_int = match lit {
+        syn::Lit::Int(lit_int) => Some(lit_int),
+        _ => None,
+    };
+    macro_rules! fits {
+        ( $( $int:ident ),* ) => {
+            match int_ty {
+                $(
+                    stringify!($int) => {
+                        Some(lit_int.map_or(false, |lit_int| {
+                            lit_int.base10_parse::<$int>().is_ok()
+                        }))
+                    }
+                )*
+                _ => None,
+            }
+        };
+    }
+    fits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)
+}
+
 /// The visibility of an ink! message or constructor.
 #[derive(Debug, Clone)]
 pub enum Visibility {
@@ -559,6 +849,17 @@ mod tests {
             },
             b"my_message".to_vec(),
         );
+        assert_compose_selector::<ir::Message, _>(
+            syn::parse_quote! {
+                #[ink(impl)]
+                impl MyStorage {}
+            },
+            syn::parse_quote! {
+                #[ink(message)]
+                fn r#type(&self) {}
+            },
+            b"type".to_vec(),
+        );
         assert_compose_selector::<ir::Message, _>(
             syn::parse_quote! {
                 #[ink(impl)]
@@ -581,6 +882,17 @@ mod tests {
             },
             b"::my::full::path::MyTrait::my_message".to_vec(),
         );
+        assert_compose_selector::<ir::Message, _>(
+            syn::parse_quote! {
+                #[ink(impl, namespace = "my_namespace")]
+                impl MyStorage {}
+            },
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) {}
+            },
+            b"my_namespace::my_message".to_vec(),
+        );
         assert_compose_selector::<ir::Message, _>(
             syn::parse_quote! {
                 #[ink(impl, namespace = "my_namespace")]
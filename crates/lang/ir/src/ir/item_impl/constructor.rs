@@ -14,6 +14,8 @@
 
 use super::{
     ensure_callable_invariants,
+    extract_arg_defaults,
+    resolve_args,
     Callable,
     CallableKind,
     InputsIter,
@@ -64,6 +66,10 @@ use syn::spanned::Spanned as _;
 pub struct Constructor {
     /// The underlying Rust method item.
     pub(super) item: syn::ImplItemMethod,
+    /// The identifiers and types of the inputs of the ink! constructor.
+    args: Vec<(Ident, syn::Type)>,
+    /// The advisory default values of the inputs of the ink! constructor.
+    arg_defaults: Vec<Option<String>>,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -71,6 +77,16 @@ pub struct Constructor {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<ir::Selector>,
+    /// The name of the constant the user provided selector is taken from if
+    /// given as `selector = MY_SELECTOR`.
+    ///
+    /// # Note
+    ///
+    /// The constant is resolved by the ink! module which sets the selector.
+    selector_const: Option<Ident>,
+    /// If `true` the ink! constructor has been flagged with `selector = _`
+    /// and is run for all unknown constructor selectors.
+    has_wildcard_selector: bool,
 }
 
 impl quote::ToTokens for Constructor {
@@ -90,14 +106,48 @@ impl Constructor {
         }) if path.is_ident("Self"))
     }
 
-    /// Ensures that the return type of the ink! constructor is `Self`.
+    /// Returns the `T` and `E` types if the given type is a `Result<T, E>`.
+    fn result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+        let type_path = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => type_path,
+            _ => return None,
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Result" {
+            return None
+        }
+        let args = match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+        let mut types = args.iter().map(|arg| {
+            match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        });
+        match (types.next(), types.next(), types.next()) {
+            (Some(Some(ok_ty)), Some(Some(err_ty)), None) => Some((ok_ty, err_ty)),
+            _ => None,
+        }
+    }
+
+    /// Ensures that the return type of the ink! constructor is either `Self`
+    /// or `Result<Self, E>`.
     ///
     /// Returns an appropriate error otherwise.
     ///
+    /// # Note
+    ///
+    /// The enclosing ink! implementation block replaces the name of the ink!
+    /// storage struct in the return type of its ink! constructors with `Self`
+    /// beforehand so that it is accepted as well.
+    ///
     /// # Errors
     ///
-    /// If the ink! constructor does not return `Self` or is missing a return
-    /// type entirely.
+    /// - If the ink! constructor is missing a return type entirely.
+    /// - If the ink! constructor returns any other type, e.g. `u32`, a
+    ///   reference or `Result<u32, E>`.
     fn ensure_valid_return_type(
         method_item: &syn::ImplItemMethod,
     ) -> Result<(), syn::Error> {
@@ -109,10 +159,14 @@ impl Constructor {
                 ))
             }
             syn::ReturnType::Type(_, return_type) => {
-                if !Self::type_is_self_val(return_type.as_ref()) {
+                let ok_ty = Self::result_types(return_type)
+                    .map(|(ok_ty, _)| ok_ty)
+                    .unwrap_or(return_type);
+                if !Self::type_is_self_val(ok_ty) {
                     return Err(format_err_spanned!(
                         return_type,
-                        "ink! constructors must return Self",
+                        "ink! constructors must return Self, Result<Self, E> \
+                         or the ink! storage type",
                     ))
                 }
             }
@@ -155,15 +209,40 @@ impl Constructor {
             &ir::AttributeArgKind::Constructor,
             |arg| {
                 match arg.kind() {
-                    ir::AttributeArg::Constructor | ir::AttributeArg::Selector(_) => {
-                        Ok(())
-                    }
+                    ir::AttributeArg::Constructor
+                    | ir::AttributeArg::Selector(_)
+                    | ir::AttributeArg::SelectorConst(_)
+                    | ir::AttributeArg::WildcardSelector => Ok(()),
                     ir::AttributeArg::Payable => {
                         Err(Some(format_err!(
                             arg.span(),
                             "constructors are implicitly payable"
                         )))
                     }
+                    ir::AttributeArg::TestOnly => {
+                        Err(Some(format_err!(
+                            arg.span(),
+                            "only ink! messages can be flagged as test_only"
+                        )))
+                    }
+                    ir::AttributeArg::RawReturn => {
+                        Err(Some(format_err!(
+                            arg.span(),
+                            "only ink! messages can be flagged as raw_return"
+                        )))
+                    }
+                    ir::AttributeArg::Weight(_) => {
+                        Err(Some(format_err!(
+                            arg.span(),
+                            "only ink! messages can have a weight hint"
+                        )))
+                    }
+                    ir::AttributeArg::Mutates(_) => {
+                        Err(Some(format_err!(
+                            arg.span(),
+                            "only ink! messages can have a mutates override"
+                        )))
+                    }
                     _ => Err(None),
                 }
             },
@@ -174,14 +253,22 @@ impl Constructor {
 impl TryFrom<syn::ImplItemMethod> for Constructor {
     type Error = syn::Error;
 
-    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+    fn try_from(mut method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
         ensure_callable_invariants(&method_item, CallableKind::Constructor)?;
         Self::ensure_valid_return_type(&method_item)?;
         Self::ensure_no_self_receiver(&method_item)?;
+        let arg_defaults = extract_arg_defaults(&mut method_item)?;
+        let args = resolve_args(&method_item, CallableKind::Constructor)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
         let selector = ink_attrs.selector();
+        let selector_const = ink_attrs.selector_const().cloned();
+        let has_wildcard_selector = ink_attrs.has_wildcard_selector();
         Ok(Constructor {
+            args,
+            arg_defaults,
             selector,
+            selector_const,
+            has_wildcard_selector,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -215,10 +302,18 @@ impl Callable for Constructor {
         }
     }
 
-    fn inputs(&self) -> InputsIter {
+    fn inputs_iter(&self) -> InputsIter {
         InputsIter::from(self)
     }
 
+    fn inputs(&self) -> &[(Ident, syn::Type)] {
+        &self.args
+    }
+
+    fn input_defaults(&self) -> &[Option<String>] {
+        &self.arg_defaults
+    }
+
     fn inputs_span(&self) -> Span {
         self.item.sig.inputs.span()
     }
@@ -233,6 +328,50 @@ impl Constructor {
     pub fn attrs(&self) -> &[syn::Attribute] {
         &self.item.attrs
     }
+
+    /// Returns the error type `E` if the ink! constructor is fallible, i.e.
+    /// returns `Result<Self, E>`.
+    ///
+    /// # Note
+    ///
+    /// Fallible ink! constructors revert the instantiation and return the
+    /// encoded error to the caller upon returning `Err`.
+    pub fn error_type(&self) -> Option<&syn::Type> {
+        match &self.item.sig.output {
+            syn::ReturnType::Type(_, return_type) => {
+                Self::result_types(return_type).map(|(_, err_ty)| err_ty)
+            }
+            syn::ReturnType::Default => None,
+        }
+    }
+
+    /// Returns the where clause of the ink! constructor if any.
+    pub fn where_clause(&self) -> Option<&syn::WhereClause> {
+        self.item.sig.generics.where_clause.as_ref()
+    }
+
+    /// Returns the name of the constant the user provided selector is taken
+    /// from if given as `selector = MY_SELECTOR`.
+    pub fn selector_const(&self) -> Option<&Ident> {
+        self.selector_const.as_ref()
+    }
+
+    /// Sets the user provided selector resolved from the selector constant.
+    pub(crate) fn set_selector(&mut self, selector: ir::Selector) {
+        self.selector = Some(selector);
+    }
+
+    /// Returns `true` if the ink! constructor has been flagged with the
+    /// wildcard selector `selector = _`.
+    ///
+    /// # Note
+    ///
+    /// Such an ink! constructor is run whenever the deploy input starts with
+    /// a selector that does not match any ink! constructor. It can still be
+    /// called via its own composed selector.
+    pub fn has_wildcard_selector(&self) -> bool {
+        self.has_wildcard_selector
+    }
 }
 
 #[cfg(test)]
@@ -240,7 +379,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn inputs_works() {
+    fn inputs_iter_works() {
         macro_rules! expected_inputs {
             ( $( $name:ident: $ty:ty ),* ) => {{
                 vec![
@@ -281,7 +420,7 @@ mod tests {
         for (expected_inputs, item_method) in test_inputs {
             let actual_inputs = <ir::Constructor as TryFrom<_>>::try_from(item_method)
                 .unwrap()
-                .inputs()
+                .inputs_iter()
                 .cloned()
                 .map(|pat_type| syn::FnArg::Typed(pat_type))
                 .collect::<Vec<_>>();
@@ -289,6 +428,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn inputs_works() {
+        let test_inputs: Vec<(Vec<(Ident, syn::Type)>, syn::ImplItemMethod)> = vec![
+            (
+                // No inputs:
+                vec![],
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor() -> Self {}
+                },
+            ),
+            (
+                // Some inputs:
+                vec![
+                    (syn::parse_quote! { a }, syn::parse_quote! { i32 }),
+                    (syn::parse_quote! { b }, syn::parse_quote! { [u8; 32] }),
+                ],
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    fn my_constructor(a: i32, mut b: [u8; 32]) -> Self {}
+                },
+            ),
+        ];
+        for (expected_args, item_method) in test_inputs {
+            let actual_args = <ir::Constructor as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .inputs()
+                .to_vec();
+            assert_eq!(actual_args, expected_args);
+        }
+    }
+
+    #[test]
+    fn try_from_non_ident_input_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(constructor)]
+                fn my_constructor(_: i32) -> Self {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                fn my_constructor((a, b): (i32, u64)) -> Self {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_try_from_fails(
+                item_method,
+                "ink! constructor inputs must be bound to identifiers",
+            )
+        }
+    }
+
     #[test]
     fn visibility_works() {
         let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
@@ -342,6 +533,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wildcard_selector_works() {
+        let wildcard: ir::Constructor = <ir::Constructor as TryFrom<
+            syn::ImplItemMethod,
+        >>::try_from(syn::parse_quote! {
+            #[ink(constructor, selector = _)]
+            pub fn fallback(value: u32) -> Self {}
+        })
+        .unwrap();
+        assert!(wildcard.has_wildcard_selector());
+        assert_eq!(wildcard.user_provided_selector(), None);
+        let regular: ir::Constructor = <ir::Constructor as TryFrom<
+            syn::ImplItemMethod,
+        >>::try_from(syn::parse_quote! {
+            #[ink(constructor, selector = 1)]
+            pub fn new() -> Self {}
+        })
+        .unwrap();
+        assert!(!regular.has_wildcard_selector());
+    }
+
     fn assert_try_from_fails(item_method: syn::ImplItemMethod, expected_err: &str) {
         assert_eq!(
             <ir::Constructor as TryFrom<_>>::try_from(item_method)
@@ -384,11 +596,59 @@ mod tests {
             },
             syn::parse_quote! {
                 #[ink(constructor)]
-                pub fn my_constructor() -> Result<Self, ()> {}
+                pub fn my_constructor() -> (Self, u32) {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Vec<Self> {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<u32, Error> {}
+            },
+            syn::parse_quote! {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<Self> {}
             },
         ];
         for item_method in item_methods {
-            assert_try_from_fails(item_method, "ink! constructors must return Self")
+            assert_try_from_fails(
+                item_method,
+                "ink! constructors must return Self, Result<Self, E> \
+                 or the ink! storage type",
+            )
+        }
+    }
+
+    #[test]
+    fn fallible_constructor_works() {
+        let item_methods: Vec<(syn::ImplItemMethod, Option<syn::Type>)> = vec![
+            (
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                },
+                None,
+            ),
+            (
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Result<Self, ()> {}
+                },
+                Some(syn::parse_quote! { () }),
+            ),
+            (
+                syn::parse_quote! {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> core::result::Result<Self, Error> {}
+                },
+                Some(syn::parse_quote! { Error }),
+            ),
+        ];
+        for (item_method, expected_error_type) in item_methods {
+            let constructor = <ir::Constructor as TryFrom<_>>::try_from(item_method)
+                .expect("encountered invalid ink! constructor");
+            assert_eq!(constructor.error_type(), expected_error_type.as_ref());
         }
     }
 
@@ -567,6 +827,26 @@ mod tests {
                 #[ink(payable)]
                 fn my_constructor() -> Self {}
             },
+            // constructor + test_only
+            syn::parse_quote! {
+                #[ink(constructor, test_only)]
+                fn my_constructor() -> Self {}
+            },
+            // constructor + raw_return
+            syn::parse_quote! {
+                #[ink(constructor, raw_return)]
+                fn my_constructor() -> Self {}
+            },
+            // constructor + weight
+            syn::parse_quote! {
+                #[ink(constructor, weight = 1_000)]
+                fn my_constructor() -> Self {}
+            },
+            // constructor + mutates
+            syn::parse_quote! {
+                #[ink(constructor, mutates = false)]
+                fn my_constructor() -> Self {}
+            },
         ];
         for item_method in item_methods {
             assert_try_from_fails(
@@ -17,12 +17,10 @@ use super::{
     Message,
 };
 use crate::{
-    error::ExtError as _,
     ir,
     ir::attrs::Attrs as _,
 };
 use core::convert::TryFrom;
-use syn::spanned::Spanned as _;
 
 /// An item within an ink! implementation block.
 ///
@@ -66,6 +64,9 @@ impl TryFrom<syn::ImplItem> for ImplItem {
                 if !ir::contains_ink_attributes(&method_item.attrs) {
                     return Ok(Self::Other(method_item.into()))
                 }
+                let (ink_attrs, _) =
+                    ir::partition_attributes(method_item.attrs.iter().cloned())?;
+                ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::Method)?;
                 let attr = ir::first_ink_attribute(&method_item.attrs)?
                     .expect("missing expected ink! attribute for struct");
                 match attr.first().kind() {
@@ -87,20 +88,15 @@ impl TryFrom<syn::ImplItem> for ImplItem {
                 }
             }
             other_item => {
-                // This is an error if the impl item contains any unexpected
-                // ink! attributes. Otherwise it is a normal Rust item.
-                if ir::contains_ink_attributes(other_item.attrs()) {
-                    let (ink_attrs, _) =
-                        ir::partition_attributes(other_item.attrs().iter().cloned())?;
-                    assert!(!ink_attrs.is_empty());
-                    fn into_err(attr: &ir::InkAttribute) -> syn::Error {
-                        format_err!(attr.span(), "encountered unexpected ink! attribute",)
-                    }
-                    return Err(ink_attrs[1..]
-                        .iter()
-                        .map(into_err)
-                        .fold(into_err(&ink_attrs[0]), |fst, snd| fst.into_combine(snd)))
-                }
+                // This is an error if the impl item contains any ink! attributes
+                // since none of them are applicable to the remaining kinds of
+                // impl items. Otherwise it is a normal Rust item.
+                let (ink_attrs, _) =
+                    ir::partition_attributes(other_item.attrs().iter().cloned())?;
+                ir::ensure_applicable(
+                    &ink_attrs,
+                    ir::AttributeTarget::of_impl_item(&other_item),
+                )?;
                 Ok(Self::Other(other_item))
             }
         }
@@ -14,6 +14,8 @@
 
 use super::{
     ensure_callable_invariants,
+    extract_arg_defaults,
+    resolve_args,
     Callable,
     CallableKind,
     InputsIter,
@@ -95,8 +97,16 @@ impl Receiver {
 pub struct Message {
     /// The underlying Rust method item.
     pub(super) item: syn::ImplItemMethod,
+    /// The identifiers and types of the inputs of the ink! message.
+    args: Vec<(Ident, syn::Type)>,
+    /// The advisory default values of the inputs of the ink! message.
+    arg_defaults: Vec<Option<String>>,
     /// If the ink! message can receive funds.
     is_payable: bool,
+    /// If the ink! message is only exposed in test builds.
+    is_test_only: bool,
+    /// If the ink! message returns its `Vec<u8>` result as raw bytes.
+    is_raw_return: bool,
     /// An optional user provided selector.
     ///
     /// # Note
@@ -104,6 +114,38 @@ pub struct Message {
     /// This overrides the computed selector, even when using a manual namespace
     /// for the parent implementation block.
     selector: Option<ir::Selector>,
+    /// The name of the constant the user provided selector is taken from if
+    /// given as `selector = MY_SELECTOR`.
+    ///
+    /// # Note
+    ///
+    /// The constant is resolved by the ink! module which sets the selector.
+    selector_const: Option<Ident>,
+    /// The storage field of an ink! getter message if any.
+    ///
+    /// # Note
+    ///
+    /// The body of ink! getter messages is generated from this field.
+    getter: Option<Ident>,
+    /// The optional weight hint of the ink! message.
+    ///
+    /// # Note
+    ///
+    /// This is only emitted into the metadata and purely advisory.
+    weight: Option<u64>,
+    /// The optional standard interface identifier of the ink! message.
+    ///
+    /// # Note
+    ///
+    /// This is only emitted into the metadata and purely advisory.
+    standard: Option<String>,
+    /// The optional mutability override of the ink! message.
+    ///
+    /// # Note
+    ///
+    /// This is only emitted into the metadata and overrides the mutability
+    /// derived from the receiver of the ink! message.
+    mutates: Option<bool>,
 }
 
 impl quote::ToTokens for Message {
@@ -170,6 +212,85 @@ impl Message {
         Ok(())
     }
 
+    /// Ensures that the signature and body of an ink! getter message are valid.
+    ///
+    /// # Errors
+    ///
+    /// - If the ink! getter message has a `&mut self` receiver.
+    /// - If the ink! getter message has inputs besides its receiver.
+    /// - If the ink! getter message has a non-empty body.
+    fn ensure_valid_getter(method_item: &syn::ImplItemMethod) -> Result<(), syn::Error> {
+        let mut fn_args = method_item.sig.inputs.iter();
+        if let Some(syn::FnArg::Receiver(receiver)) = fn_args.next() {
+            if receiver.mutability.is_some() {
+                return Err(format_err!(
+                    receiver,
+                    "ink! getter messages must have a `&self` receiver"
+                ))
+            }
+        }
+        if let Some(input) = fn_args.next() {
+            return Err(format_err!(
+                input,
+                "ink! getter messages must not have inputs besides `&self`"
+            ))
+        }
+        if !method_item.block.stmts.is_empty() {
+            return Err(format_err!(
+                method_item.block,
+                "ink! getter messages must have an empty body"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Ensures that an ink! message flagged as `raw_return` returns `Vec<u8>`.
+    ///
+    /// # Errors
+    ///
+    /// If the ink! message has no return type or a return type other than `Vec<u8>`.
+    fn ensure_valid_raw_return(
+        method_item: &syn::ImplItemMethod,
+    ) -> Result<(), syn::Error> {
+        fn is_byte_vec(ty: &syn::Type) -> bool {
+            let segment = match ty {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                    type_path.path.segments.last()
+                }
+                _ => None,
+            };
+            let args = match segment {
+                Some(segment) if segment.ident == "Vec" => &segment.arguments,
+                _ => return false,
+            };
+            match args {
+                syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                    matches!(
+                        &args.args[0],
+                        syn::GenericArgument::Type(syn::Type::Path(elem))
+                            if elem.qself.is_none() && elem.path.is_ident("u8")
+                    )
+                }
+                _ => false,
+            }
+        }
+        match &method_item.sig.output {
+            syn::ReturnType::Type(_arrow, ret_type) if is_byte_vec(ret_type) => Ok(()),
+            syn::ReturnType::Type(_arrow, ret_type) => {
+                Err(format_err!(
+                    ret_type,
+                    "ink! messages flagged as `raw_return` must return `Vec<u8>`"
+                ))
+            }
+            syn::ReturnType::Default => {
+                Err(format_err!(
+                    method_item.sig,
+                    "ink! messages flagged as `raw_return` must return `Vec<u8>`"
+                ))
+            }
+        }
+    }
+
     /// Sanitizes the attributes for the ink! message.
     ///
     /// Returns a tuple of ink! attributes and non-ink! attributes.
@@ -184,7 +305,20 @@ impl Message {
                 match arg.kind() {
                     ir::AttributeArg::Message
                     | ir::AttributeArg::Payable
-                    | ir::AttributeArg::Selector(_) => Ok(()),
+                    | ir::AttributeArg::TestOnly
+                    | ir::AttributeArg::RawReturn
+                    | ir::AttributeArg::Selector(_)
+                    | ir::AttributeArg::SelectorConst(_)
+                    | ir::AttributeArg::Getter(_)
+                    | ir::AttributeArg::Weight(_)
+                    | ir::AttributeArg::Standard(_)
+                    | ir::AttributeArg::Mutates(_) => Ok(()),
+                    ir::AttributeArg::WildcardSelector => {
+                        Err(Some(format_err!(
+                            arg.span(),
+                            "only ink! constructors can have a wildcard selector"
+                        )))
+                    }
                     _ => Err(None),
                 }
             },
@@ -195,16 +329,40 @@ impl Message {
 impl TryFrom<syn::ImplItemMethod> for Message {
     type Error = syn::Error;
 
-    fn try_from(method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
+    fn try_from(mut method_item: syn::ImplItemMethod) -> Result<Self, Self::Error> {
         ensure_callable_invariants(&method_item, CallableKind::Message)?;
         Self::ensure_receiver_is_self_ref(&method_item)?;
         Self::ensure_not_return_self(&method_item)?;
+        let arg_defaults = extract_arg_defaults(&mut method_item)?;
+        let args = resolve_args(&method_item, CallableKind::Message)?;
         let (ink_attrs, other_attrs) = Self::sanitize_attributes(&method_item)?;
         let is_payable = ink_attrs.is_payable();
+        let is_test_only = ink_attrs.is_test_only();
+        let is_raw_return = ink_attrs.is_raw_return();
         let selector = ink_attrs.selector();
+        let selector_const = ink_attrs.selector_const().cloned();
+        let getter = ink_attrs.getter();
+        let weight = ink_attrs.weight();
+        let standard = ink_attrs.standard().map(ToOwned::to_owned);
+        let mutates = ink_attrs.mutates();
+        if getter.is_some() {
+            Self::ensure_valid_getter(&method_item)?;
+        }
+        if is_raw_return {
+            Self::ensure_valid_raw_return(&method_item)?;
+        }
         Ok(Self {
+            args,
+            arg_defaults,
             is_payable,
+            is_test_only,
+            is_raw_return,
             selector,
+            selector_const,
+            getter,
+            weight,
+            standard,
+            mutates,
             item: syn::ImplItemMethod {
                 attrs: other_attrs,
                 ..method_item
@@ -238,10 +396,18 @@ impl Callable for Message {
         }
     }
 
-    fn inputs(&self) -> InputsIter {
+    fn inputs_iter(&self) -> InputsIter {
         InputsIter::from(self)
     }
 
+    fn inputs(&self) -> &[(Ident, syn::Type)] {
+        &self.args
+    }
+
+    fn input_defaults(&self) -> &[Option<String>] {
+        &self.arg_defaults
+    }
+
     fn inputs_span(&self) -> Span {
         self.item.sig.inputs.span()
     }
@@ -279,6 +445,150 @@ impl Message {
             syn::ReturnType::Type(_, return_type) => Some(return_type),
         }
     }
+
+    /// Returns the where clause of the ink! message if any.
+    pub fn where_clause(&self) -> Option<&syn::WhereClause> {
+        self.item.sig.generics.where_clause.as_ref()
+    }
+
+    /// Returns the name of the constant the user provided selector is taken
+    /// from if given as `selector = MY_SELECTOR`.
+    pub fn selector_const(&self) -> Option<&Ident> {
+        self.selector_const.as_ref()
+    }
+
+    /// Sets the user provided selector resolved from the selector constant.
+    pub(crate) fn set_selector(&mut self, selector: ir::Selector) {
+        self.selector = Some(selector);
+    }
+
+    /// Returns `true` if the ink! message is only exposed in test builds.
+    ///
+    /// # Note
+    ///
+    /// Test-only ink! messages are excluded from the contract dispatch and
+    /// metadata unless compiled with `cfg(test)`.
+    pub fn is_test_only(&self) -> bool {
+        self.is_test_only
+    }
+
+    /// Returns `true` if the ink! message returns its `Vec<u8>` result as raw bytes.
+    ///
+    /// # Note
+    ///
+    /// The result of such ink! messages is returned without the SCALE length
+    /// prefix of `Vec<u8>`.
+    pub fn is_raw_return(&self) -> bool {
+        self.is_raw_return
+    }
+
+    /// Returns the weight hint of the ink! message if any.
+    ///
+    /// # Note
+    ///
+    /// The weight hint is only emitted into the metadata for front-ends to
+    /// estimate fees and does not affect the behavior of the ink! message.
+    pub fn weight(&self) -> Option<u64> {
+        self.weight
+    }
+
+    /// Returns the standard interface identifier of the ink! message if any,
+    /// e.g. `"erc20::transfer"`.
+    ///
+    /// # Note
+    ///
+    /// The identifier is only emitted into the metadata for wallets to
+    /// recognize standard interfaces and does not affect the ink! message.
+    pub fn standard(&self) -> Option<&str> {
+        self.standard.as_deref()
+    }
+
+    /// Returns `true` if the ink! message is advertised as mutating the
+    /// contract storage in the metadata.
+    ///
+    /// # Note
+    ///
+    /// This is derived from the receiver of the ink! message unless it has
+    /// been overridden via `#[ink(mutates = flag)]`, e.g. for `&mut self`
+    /// messages that do not logically mutate the contract storage.
+    pub fn mutates(&self) -> bool {
+        self.mutates.unwrap_or_else(|| self.receiver().is_ref_mut())
+    }
+
+    /// Returns the `#[must_use]` attribute of the ink! message if any.
+    ///
+    /// # Note
+    ///
+    /// The attribute is kept among the non-ink! attributes of the ink! message
+    /// and is propagated to the generated interface and client methods.
+    pub fn must_use(&self) -> Option<&syn::Attribute> {
+        self.attrs()
+            .iter()
+            .find(|attr| attr.path.is_ident("must_use"))
+    }
+
+    /// Returns `true` if the ink! message is annotated with `#[doc(hidden)]`.
+    ///
+    /// # Note
+    ///
+    /// Hidden ink! messages are dispatched as usual but excluded from the
+    /// contract metadata.
+    pub fn is_doc_hidden(&self) -> bool {
+        ir::utils::is_doc_hidden(self.attrs())
+    }
+
+    /// Returns the storage fields read and written by the ink! message.
+    ///
+    /// # Note
+    ///
+    /// This is a best-effort syntactic analysis of the body of the ink! message
+    /// and therefore only approximate. See [`ir::StorageAccess`] for details.
+    pub fn storage_access(&self) -> ir::StorageAccess {
+        let mut access =
+            ir::StorageAccess::from_block(&self.item.block, self.receiver().is_ref_mut());
+        if let Some(field) = self.getter() {
+            access.push_read(field);
+        }
+        access
+    }
+
+    /// Returns the storage field of the ink! message if it is an ink! getter.
+    pub fn getter(&self) -> Option<&Ident> {
+        self.getter.as_ref()
+    }
+
+    /// Resolves the return type of the ink! getter message to the type of
+    /// its storage field.
+    ///
+    /// # Errors
+    ///
+    /// If the ink! getter message already has a return type that does not
+    /// match the type of its storage field.
+    pub(crate) fn resolve_getter_output(
+        &mut self,
+        field_ty: &syn::Type,
+    ) -> Result<(), syn::Error> {
+        debug_assert!(self.getter.is_some());
+        match &self.item.sig.output {
+            syn::ReturnType::Default => {
+                self.item.sig.output = syn::parse_quote! { -> #field_ty };
+            }
+            syn::ReturnType::Type(_, return_type) => {
+                use quote::ToTokens as _;
+                if return_type.to_token_stream().to_string()
+                    != field_ty.to_token_stream().to_string()
+                {
+                    return Err(format_err!(
+                        return_type,
+                        "the return type of an ink! getter message must match the type \
+                         of its storage field `{}`",
+                        field_ty.to_token_stream(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -323,7 +633,7 @@ mod tests {
     }
 
     #[test]
-    fn inputs_works() {
+    fn inputs_iter_works() {
         macro_rules! expected_inputs {
             ( $( $name:ident: $ty:ty ),* ) => {{
                 vec![
@@ -364,7 +674,7 @@ mod tests {
         for (expected_inputs, item_method) in test_inputs {
             let actual_inputs = <ir::Message as TryFrom<_>>::try_from(item_method)
                 .unwrap()
-                .inputs()
+                .inputs_iter()
                 .cloned()
                 .map(|pat_type| syn::FnArg::Typed(pat_type))
                 .collect::<Vec<_>>();
@@ -419,142 +729,738 @@ mod tests {
     }
 
     #[test]
-    fn receiver_works() {
-        let test_inputs: Vec<(Receiver, syn::ImplItemMethod)> = vec![
+    fn is_test_only_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Not test-only.
             (
-                Receiver::Ref,
+                false,
                 syn::parse_quote! {
                     #[ink(message)]
                     fn my_message(&self) {}
                 },
             ),
+            // Normalized ink! attribute.
             (
-                Receiver::RefMut,
+                true,
                 syn::parse_quote! {
-                    #[ink(message, payable)]
-                    fn my_message(&mut self) {}
+                    #[ink(message, test_only)]
+                    pub fn my_message(&self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    #[ink(test_only)]
+                    pub fn my_message(&mut self) {}
                 },
             ),
         ];
-        for (expected_receiver, item_method) in test_inputs {
-            let actual_receiver = <ir::Message as TryFrom<_>>::try_from(item_method)
+        for (expect_test_only, item_method) in test_inputs {
+            let is_test_only = <ir::Message as TryFrom<_>>::try_from(item_method)
                 .unwrap()
-                .receiver();
-            assert_eq!(actual_receiver, expected_receiver);
+                .is_test_only();
+            assert_eq!(is_test_only, expect_test_only);
         }
     }
 
     #[test]
-    fn visibility_works() {
+    fn is_raw_return_works() {
         let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
-            // &self
+            // Not raw return.
             (
                 false,
                 syn::parse_quote! {
                     #[ink(message)]
-                    fn my_message(&self) {}
+                    fn my_message(&self) -> Vec<u8> {}
                 },
             ),
-            // &self + pub
+            // Normalized ink! attribute.
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message, raw_return)]
+                    pub fn my_message(&self) -> Vec<u8> {}
+                },
+            ),
+            // Different ink! attributes and full path.
             (
                 true,
                 syn::parse_quote! {
                     #[ink(message)]
-                    pub fn my_message(&self) {}
+                    #[ink(raw_return)]
+                    pub fn my_message(&mut self) -> ink_prelude::vec::Vec<u8> {}
                 },
             ),
-            // &mut self
+        ];
+        for (expect_raw_return, item_method) in test_inputs {
+            let is_raw_return = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .is_raw_return();
+            assert_eq!(is_raw_return, expect_raw_return);
+        }
+    }
+
+    #[test]
+    fn mutates_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // Derived from the receiver.
             (
                 false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                true,
                 syn::parse_quote! {
                     #[ink(message)]
                     fn my_message(&mut self) {}
                 },
             ),
-            // &mut self + pub
+            // Overridden by the ink! attribute.
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message, mutates = false)]
+                    fn my_message(&mut self) {}
+                },
+            ),
             (
                 true,
                 syn::parse_quote! {
                     #[ink(message)]
-                    pub fn my_message(&mut self) {}
+                    #[ink(mutates = true)]
+                    fn my_message(&self) {}
                 },
             ),
         ];
-        for (is_pub, item_method) in test_inputs {
-            let visibility = <ir::Message as TryFrom<_>>::try_from(item_method)
+        for (expected_mutates, item_method) in test_inputs {
+            let mutates = <ir::Message as TryFrom<_>>::try_from(item_method)
                 .unwrap()
-                .visibility();
-            assert_eq!(visibility.is_pub(), is_pub);
-            assert_eq!(visibility.is_inherited(), !is_pub);
+                .mutates();
+            assert_eq!(mutates, expected_mutates);
         }
     }
 
     #[test]
-    fn try_from_works() {
-        let item_methods: Vec<syn::ImplItemMethod> = vec![
-            // &self
-            syn::parse_quote! {
-                #[ink(message)]
-                fn my_message(&self) {}
-            },
-            // &self + pub
-            syn::parse_quote! {
-                #[ink(message)]
-                pub fn my_message(&self) {}
-            },
-            // &mut self
-            syn::parse_quote! {
-                #[ink(message)]
-                fn my_message(&mut self) {}
-            },
-            // &mut self + pub
-            syn::parse_quote! {
-                #[ink(message)]
-                pub fn my_message(&mut self) {}
-            },
-            // &self + payable
-            syn::parse_quote! {
-                #[ink(message, payable)]
-                fn my_message(&self) {}
-            },
-            // &mut self + payable
-            syn::parse_quote! {
-                #[ink(message, payable)]
-                fn my_message(&mut self) {}
-            },
-            // &self + many inputs + output works
-            syn::parse_quote! {
-                #[ink(message)]
-                fn my_message(&self, input1: i32, input2: i64, input3: u32, input4: u64) -> bool {}
-            },
-            // &mut self + many inputs + output works
-            syn::parse_quote! {
-                #[ink(message)]
-                fn my_message(&mut self, input1: i32, input2: i64, input3: u32, input4: u64) -> bool {}
-            },
+    fn must_use_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) -> i32 {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    #[must_use]
+                    fn my_message(&self) -> i32 {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[must_use = "the result signals success"]
+                    #[ink(message)]
+                    fn my_message(&mut self) -> bool {}
+                },
+            ),
         ];
-        for item_method in item_methods {
-            assert!(<ir::Message as TryFrom<_>>::try_from(item_method).is_ok());
+        for (expect_must_use, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.must_use().is_some(), expect_must_use);
+            assert_eq!(
+                message
+                    .attrs()
+                    .iter()
+                    .filter(|attr| attr.path.is_ident("must_use"))
+                    .count(),
+                expect_must_use as usize,
+            );
         }
     }
 
-    fn assert_try_from_fails(item_method: syn::ImplItemMethod, expected_err: &str) {
-        assert_eq!(
-            <ir::Message as TryFrom<_>>::try_from(item_method)
-                .map_err(|err| err.to_string()),
-            Err(expected_err.to_string()),
-        );
-    }
-
     #[test]
-    fn try_from_generics_fails() {
-        let item_methods: Vec<syn::ImplItemMethod> = vec![
-            syn::parse_quote! {
-                #[ink(message)]
-                fn my_message<T>(&self) {}
-            },
-            syn::parse_quote! {
-                #[ink(message)]
+    fn is_doc_hidden_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            (
+                false,
+                syn::parse_quote! {
+                    /// Visible in the metadata.
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    #[doc(hidden)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                true,
+                syn::parse_quote! {
+                    #[doc(hidden, alias = "internal")]
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            (
+                false,
+                syn::parse_quote! {
+                    #[doc(alias = "hidden")]
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expect_doc_hidden, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.is_doc_hidden(), expect_doc_hidden);
+        }
+    }
+
+    #[test]
+    fn weight_works() {
+        let test_inputs: Vec<(Option<u64>, syn::ImplItemMethod)> = vec![
+            // No weight hint.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some(1_000_000),
+                syn::parse_quote! {
+                    #[ink(message, weight = 1_000_000)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some(42),
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    #[ink(weight = 42)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_weight, item_method) in test_inputs {
+            let weight = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .weight();
+            assert_eq!(weight, expected_weight);
+        }
+    }
+
+    #[test]
+    fn standard_works() {
+        let test_inputs: Vec<(Option<&str>, syn::ImplItemMethod)> = vec![
+            // No standard identifier.
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // Normalized ink! attribute.
+            (
+                Some("erc20::transfer"),
+                syn::parse_quote! {
+                    #[ink(message, standard = "erc20::transfer")]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // Different ink! attributes.
+            (
+                Some("erc721::owner_of"),
+                syn::parse_quote! {
+                    #[ink(message, selector = 1)]
+                    #[ink(standard = "erc721::owner_of")]
+                    fn my_message(&self) {}
+                },
+            ),
+        ];
+        for (expected_standard, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.standard(), expected_standard);
+        }
+    }
+
+    #[test]
+    fn receiver_works() {
+        let test_inputs: Vec<(Receiver, syn::ImplItemMethod)> = vec![
+            (
+                Receiver::Ref,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                Receiver::RefMut,
+                syn::parse_quote! {
+                    #[ink(message, payable)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (expected_receiver, item_method) in test_inputs {
+            let actual_receiver = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .receiver();
+            assert_eq!(actual_receiver, expected_receiver);
+        }
+    }
+
+    #[test]
+    fn inputs_works() {
+        let test_inputs: Vec<(Vec<(Ident, syn::Type)>, syn::ImplItemMethod)> = vec![
+            (
+                // No inputs:
+                vec![],
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                // Some inputs:
+                vec![
+                    (syn::parse_quote! { a }, syn::parse_quote! { i32 }),
+                    (syn::parse_quote! { b }, syn::parse_quote! { [u8; 32] }),
+                ],
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self, a: i32, mut b: [u8; 32]) {}
+                },
+            ),
+        ];
+        for (expected_args, item_method) in test_inputs {
+            let actual_args = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .inputs()
+                .to_vec();
+            assert_eq!(actual_args, expected_args);
+        }
+    }
+
+    #[test]
+    fn input_defaults_works() {
+        let item_method: syn::ImplItemMethod = syn::parse_quote! {
+            #[ink(message)]
+            fn my_message(
+                &self,
+                #[ink(default = 42)] a: i32,
+                b: bool,
+                #[allow(unused)]
+                #[ink(default = "Alice")]
+                c: String,
+            ) {}
+        };
+        let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+        assert_eq!(
+            message.input_defaults(),
+            &[Some("42".to_string()), None, Some("Alice".to_string())],
+        );
+        // The ink! attributes are removed from the inputs while others are kept.
+        let input_attrs = message
+            .inputs_iter()
+            .map(|pat_type| pat_type.attrs.len())
+            .collect::<Vec<_>>();
+        assert_eq!(input_attrs, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn arg_default_of_mismatching_type_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, #[ink(default = "Alice")] a: u32) {}
+            },
+            "expected an in range integer literal for the default value of an input of type `u32`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, #[ink(default = 256)] a: u8) {}
+            },
+            "expected an in range integer literal for the default value of an input of type `u8`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, #[ink(default = 1)] a: bool) {}
+            },
+            "expected a `bool` literal for the default value of an input of type `bool`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, #[ink(default = true)] a: &str) {}
+            },
+            "expected a string literal for the default value of an input of type `str`",
+        );
+    }
+
+    #[test]
+    fn arg_default_of_unknown_type_works() {
+        let item_method: syn::ImplItemMethod = syn::parse_quote! {
+            #[ink(message)]
+            fn my_message(&self, #[ink(default = 1000)] a: Balance) {}
+        };
+        let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+        assert_eq!(message.input_defaults(), &[Some("1000".to_string())]);
+    }
+
+    #[test]
+    fn try_from_invalid_input_attribute_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, #[ink(payable)] a: i32) {}
+            },
+            "#[ink(payable)] cannot be applied to an input",
+        );
+    }
+
+    #[test]
+    fn try_from_non_ident_input_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, _: i32) {}
+            },
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, (a, b): (i32, u64)) {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_try_from_fails(
+                item_method,
+                "ink! message inputs must be bound to identifiers",
+            )
+        }
+    }
+
+    #[test]
+    fn visibility_works() {
+        let test_inputs: Vec<(bool, syn::ImplItemMethod)> = vec![
+            // &self
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            // &self + pub
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                },
+            ),
+            // &mut self
+            (
+                false,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            // &mut self + pub
+            (
+                true,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    pub fn my_message(&mut self) {}
+                },
+            ),
+        ];
+        for (is_pub, item_method) in test_inputs {
+            let visibility = <ir::Message as TryFrom<_>>::try_from(item_method)
+                .unwrap()
+                .visibility();
+            assert_eq!(visibility.is_pub(), is_pub);
+            assert_eq!(visibility.is_inherited(), !is_pub);
+        }
+    }
+
+    #[test]
+    fn try_from_works() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            // &self
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) {}
+            },
+            // &self + pub
+            syn::parse_quote! {
+                #[ink(message)]
+                pub fn my_message(&self) {}
+            },
+            // &mut self
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&mut self) {}
+            },
+            // &mut self + pub
+            syn::parse_quote! {
+                #[ink(message)]
+                pub fn my_message(&mut self) {}
+            },
+            // &self + payable
+            syn::parse_quote! {
+                #[ink(message, payable)]
+                fn my_message(&self) {}
+            },
+            // &mut self + payable
+            syn::parse_quote! {
+                #[ink(message, payable)]
+                fn my_message(&mut self) {}
+            },
+            // &self + many inputs + output works
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self, input1: i32, input2: i64, input3: u32, input4: u64) -> bool {}
+            },
+            // &mut self + many inputs + output works
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&mut self, input1: i32, input2: i64, input3: u32, input4: u64) -> bool {}
+            },
+        ];
+        for item_method in item_methods {
+            assert!(<ir::Message as TryFrom<_>>::try_from(item_method).is_ok());
+        }
+    }
+
+    fn assert_try_from_fails(item_method: syn::ImplItemMethod, expected_err: &str) {
+        assert_eq!(
+            <ir::Message as TryFrom<_>>::try_from(item_method)
+                .map_err(|err| err.to_string()),
+            Err(expected_err.to_string()),
+        );
+    }
+
+    #[test]
+    fn try_from_invalid_raw_return_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(message, raw_return)]
+                fn my_message(&self) {}
+            },
+            syn::parse_quote! {
+                #[ink(message, raw_return)]
+                fn my_message(&self) -> [u8; 32] {}
+            },
+            syn::parse_quote! {
+                #[ink(message, raw_return)]
+                fn my_message(&self) -> Vec<i32> {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_try_from_fails(
+                item_method,
+                "ink! messages flagged as `raw_return` must return `Vec<u8>`",
+            )
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_weight_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(message, weight = 0)]
+                fn my_message(&self) {}
+            },
+            syn::parse_quote! {
+                #[ink(message, weight = -1)]
+                fn my_message(&self) {}
+            },
+            syn::parse_quote! {
+                #[ink(message, weight = "1000")]
+                fn my_message(&self) {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_try_from_fails(
+                item_method,
+                "expected a positive `u64` integer for `weight` argument, e.g. #[ink(weight = 1_000_000)]",
+            )
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_standard_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message, standard = "")]
+                fn my_message(&self) {}
+            },
+            "expected a non-empty identifier for `standard` argument, e.g. #[ink(standard = \"erc20::transfer\")]",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message, standard = 20)]
+                fn my_message(&self) {}
+            },
+            "expected string type for `standard` argument, e.g. #[ink(standard = \"erc20::transfer\")]",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message, standard)]
+                fn my_message(&self) {}
+            },
+            "encountered #[ink(standard)] that is missing its identifier parameter. \
+             Did you mean #[ink(standard = identifier: str)] ?",
+        );
+    }
+
+    #[test]
+    fn try_from_wildcard_selector_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message, selector = _)]
+                fn my_message(&self) {}
+            },
+            "encountered conflicting ink! attribute argument",
+        )
+    }
+
+    #[test]
+    fn try_from_invalid_mutates_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(message, mutates = "false")]
+                fn my_message(&mut self) {}
+            },
+            syn::parse_quote! {
+                #[ink(message, mutates = 0)]
+                fn my_message(&mut self) {}
+            },
+        ];
+        for item_method in item_methods {
+            assert_try_from_fails(
+                item_method,
+                "expected `bool` value type for `flag` in #[ink(mutates = flag)]",
+            )
+        }
+    }
+
+    #[test]
+    fn try_from_cfg_attr_payable_works() {
+        let cfg_attr: syn::Attribute = syn::parse_quote! {
+            #[cfg_attr(feature = "x", allow(dead_code))]
+        };
+        let message =
+            <ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(syn::parse_quote! {
+                #[ink(message)]
+                #[cfg_attr(feature = "x", ink(payable), allow(dead_code))]
+                fn my_message(&self) {}
+            })
+            .unwrap();
+        assert!(message.is_payable());
+        assert_eq!(message.attrs(), &[cfg_attr]);
+    }
+
+    #[test]
+    fn try_from_preserves_cfg_attr() {
+        let cfg_attr: syn::Attribute = syn::parse_quote! {
+            #[cfg_attr(feature = "std", allow(clippy::new_ret_no_self))]
+        };
+        let message =
+            <ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(syn::parse_quote! {
+                #[ink(message, payable)]
+                #[cfg_attr(feature = "std", allow(clippy::new_ret_no_self))]
+                fn my_message(&self) {}
+            })
+            .unwrap();
+        assert!(message.is_payable());
+        assert_eq!(message.attrs(), &[cfg_attr]);
+    }
+
+    #[test]
+    fn where_clause_works() {
+        let message =
+            <ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) -> bool
+                where
+                    Self: Clone,
+                    for<'a> &'a u8: Copy,
+                    Vec<&'static str>: Default,
+                {
+                    true
+                }
+            })
+            .unwrap();
+        let expected: syn::WhereClause = syn::parse_quote! {
+            where
+                Self: Clone,
+                for<'a> &'a u8: Copy,
+                Vec<&'static str>: Default,
+        };
+        assert_eq!(message.where_clause(), Some(&expected));
+        let message =
+            <ir::Message as TryFrom<syn::ImplItemMethod>>::try_from(syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) {}
+            })
+            .unwrap();
+        assert_eq!(message.where_clause(), None);
+    }
+
+    #[test]
+    fn try_from_undispatchable_where_clause_fails() {
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) where &'a Self: Clone {}
+            },
+            "where clauses of ink! messages must not refer to lifetimes other than `'static`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) where 'a: 'static {}
+            },
+            "where clauses of ink! messages must not refer to lifetimes other than `'static`",
+        );
+        assert_try_from_fails(
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message(&self) where Self: for<'a> Fn(&'a u8) -> &'b u8 {}
+            },
+            "where clauses of ink! messages must not refer to lifetimes other than `'static`",
+        );
+    }
+
+    #[test]
+    fn try_from_generics_fails() {
+        let item_methods: Vec<syn::ImplItemMethod> = vec![
+            syn::parse_quote! {
+                #[ink(message)]
+                fn my_message<T>(&self) {}
+            },
+            syn::parse_quote! {
+                #[ink(message)]
                 pub fn my_message<T>(&self) {}
             },
             syn::parse_quote! {
@@ -730,6 +1636,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn getter_works() {
+        let test_inputs: Vec<(Option<Ident>, syn::ImplItemMethod)> = vec![
+            (
+                None,
+                syn::parse_quote! {
+                    #[ink(message)]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                Some(syn::parse_quote! { my_field }),
+                syn::parse_quote! {
+                    #[ink(message, getter = "my_field")]
+                    fn my_message(&self) {}
+                },
+            ),
+            (
+                Some(syn::parse_quote! { my_field }),
+                syn::parse_quote! {
+                    #[ink(message)]
+                    #[ink(getter = "my_field")]
+                    pub fn my_field(&self) -> i32 {}
+                },
+            ),
+        ];
+        for (expected_getter, item_method) in test_inputs {
+            let message = <ir::Message as TryFrom<_>>::try_from(item_method).unwrap();
+            assert_eq!(message.getter().cloned(), expected_getter);
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_getter_fails() {
+        let test_inputs: Vec<(&str, syn::ImplItemMethod)> = vec![
+            (
+                "ink! getter messages must have a `&self` receiver",
+                syn::parse_quote! {
+                    #[ink(message, getter = "my_field")]
+                    fn my_message(&mut self) {}
+                },
+            ),
+            (
+                "ink! getter messages must not have inputs besides `&self`",
+                syn::parse_quote! {
+                    #[ink(message, getter = "my_field")]
+                    fn my_message(&self, input: i32) {}
+                },
+            ),
+            (
+                "ink! getter messages must have an empty body",
+                syn::parse_quote! {
+                    #[ink(message, getter = "my_field")]
+                    fn my_message(&self) -> i32 { 42 }
+                },
+            ),
+        ];
+        for (expected_err, item_method) in test_inputs {
+            assert_try_from_fails(item_method, expected_err)
+        }
+    }
+
     #[test]
     fn conflicting_attributes_fails() {
         let item_methods: Vec<syn::ImplItemMethod> = vec![
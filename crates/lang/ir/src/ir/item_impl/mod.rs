@@ -32,7 +32,11 @@ mod message;
 #[cfg(test)]
 mod tests;
 
-use self::callable::ensure_callable_invariants;
+use self::callable::{
+    ensure_callable_invariants,
+    extract_arg_defaults,
+    resolve_args,
+};
 pub use self::{
     callable::{
         Callable,
@@ -79,7 +83,18 @@ pub struct ItemImpl {
     /// A namespace to disambiguate trait implementation blocks with equal
     /// names. Generally can be used to change computation of message and
     /// constructor selectors of the implementation block.
+    ///
+    /// This applies to inherent implementation blocks as well which allows
+    /// to group their ink! messages and constructors under a common prefix.
     namespace: Option<ir::Namespace>,
+    /// The namespace of the whole ink! smart contract if any.
+    ///
+    /// Seeds the composed selectors of all ink! messages and constructors
+    /// of the implementation block.
+    contract_namespace: Option<ir::Namespace>,
+    /// The hash primitive used to compute the composed selectors of all ink!
+    /// messages and constructors of the implementation block.
+    selector_hash: ir::SelectorHash,
 }
 
 impl quote::ToTokens for ItemImpl {
@@ -208,6 +223,132 @@ impl ItemImpl {
         }
         Ok(false)
     }
+
+    /// Ensures that the implemented trait path does not bind any lifetimes.
+    ///
+    /// # Note
+    ///
+    /// ink! trait definitions may be generic over types which are bound by
+    /// the implementation block, e.g. `impl Token<MyUnit> for MyStorage`,
+    /// but never over lifetimes.
+    fn ensure_no_lifetime_args(trait_path: &syn::Path) -> Result<(), syn::Error> {
+        for segment in &trait_path.segments {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                for arg in &args.args {
+                    if let syn::GenericArgument::Lifetime(lifetime) = arg {
+                        return Err(format_err_spanned!(
+                            lifetime,
+                            "ink! trait implementation blocks must not bind lifetimes",
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the self type of the implementation block in the return types
+    /// of its ink! constructors with `Self`.
+    ///
+    /// # Note
+    ///
+    /// This allows ink! constructors to return the named ink! storage struct,
+    /// e.g. `MyStorage` or `Result<MyStorage, E>`, while everything else only
+    /// has to deal with `Self` and `Result<Self, E>`.
+    fn normalize_constructor_outputs(self_ty: &syn::Type, items: &mut [syn::ImplItem]) {
+        let self_ty = quote::quote!(#self_ty).to_string();
+        let replace_self_ty = |ty: &mut syn::Type| {
+            if quote::quote!(#ty).to_string() == self_ty {
+                let span = ty.span();
+                *ty = syn::parse_quote_spanned!(span=> Self);
+            }
+        };
+        for item in items {
+            let method = match item {
+                syn::ImplItem::Method(method) => method,
+                _ => continue,
+            };
+            let is_constructor = matches!(
+                ir::first_ink_attribute(&method.attrs),
+                Ok(Some(attr)) if matches!(attr.first().kind(), ir::AttributeArg::Constructor)
+            );
+            let return_type = match &mut method.sig.output {
+                syn::ReturnType::Type(_, return_type) if is_constructor => {
+                    return_type.as_mut()
+                }
+                _ => continue,
+            };
+            if let syn::Type::Path(type_path) = return_type {
+                if let Some(segment) = type_path.path.segments.last_mut() {
+                    if let syn::PathArguments::AngleBracketed(args) =
+                        &mut segment.arguments
+                    {
+                        if let Some(syn::GenericArgument::Type(ok_ty)) =
+                            args.args.first_mut()
+                        {
+                            if segment.ident == "Result" {
+                                replace_self_ty(ok_ty);
+                                continue
+                            }
+                        }
+                    }
+                }
+            }
+            replace_self_ty(return_type);
+        }
+    }
+
+    /// Replaces the `Self::Assoc` types in the method signatures of a trait
+    /// implementation block with the fully qualified `<Storage as Trait>::Assoc`.
+    ///
+    /// # Note
+    ///
+    /// The signatures of ink! messages are also used outside of the implementation
+    /// block, e.g. for the dispatch and the metadata, where `Self` does not refer
+    /// to the ink! storage struct. The method bodies are left untouched.
+    fn qualify_self_assoc_types(
+        self_ty: &syn::Type,
+        trait_path: &syn::Path,
+        items: &mut [syn::ImplItem],
+    ) {
+        struct QualifySelf<'a> {
+            self_ty: &'a syn::Type,
+            trait_path: &'a syn::Path,
+        }
+
+        impl syn::visit_mut::VisitMut for QualifySelf<'_> {
+            fn visit_type_path_mut(&mut self, type_path: &mut syn::TypePath) {
+                syn::visit_mut::visit_type_path_mut(self, type_path);
+                let segments = &type_path.path.segments;
+                if type_path.qself.is_some()
+                    || segments.len() < 2
+                    || segments[0].ident != "Self"
+                {
+                    return
+                }
+                let span = segments[0].ident.span();
+                let self_ty = self.self_ty;
+                let trait_path = self.trait_path;
+                let assoc = segments.iter().skip(1);
+                *type_path = syn::parse_quote_spanned!(span=>
+                    <#self_ty as #trait_path>::#(#assoc)::*
+                );
+            }
+        }
+
+        let mut visitor = QualifySelf {
+            self_ty,
+            trait_path,
+        };
+        for item in items {
+            if let syn::ImplItem::Method(method) = item {
+                syn::visit_mut::VisitMut::visit_signature_mut(
+                    &mut visitor,
+                    &mut method.sig,
+                );
+            }
+        }
+    }
 }
 
 impl TryFrom<syn::ItemImpl> for ItemImpl {
@@ -239,6 +380,16 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
                 "generic ink! implementation blocks are not supported",
             ))
         }
+        let mut item_impl = item_impl;
+        if let Some((_, trait_path, _)) = &item_impl.trait_ {
+            Self::ensure_no_lifetime_args(trait_path)?;
+            Self::qualify_self_assoc_types(
+                &item_impl.self_ty,
+                trait_path,
+                &mut item_impl.items,
+            );
+        }
+        Self::normalize_constructor_outputs(&item_impl.self_ty, &mut item_impl.items);
         let impl_items = item_impl
             .items
             .into_iter()
@@ -294,6 +445,7 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
         let (ink_attrs, other_attrs) = ir::partition_attributes(item_impl.attrs)?;
         let mut namespace = None;
         if !ink_attrs.is_empty() {
+            ir::ensure_applicable(&ink_attrs, ir::AttributeTarget::Impl)?;
             let normalized =
                 ir::InkAttribute::from_expanded(ink_attrs).map_err(|err| {
                     err.into_combine(format_err!(impl_block_span, "at this invocation",))
@@ -319,6 +471,8 @@ impl TryFrom<syn::ItemImpl> for ItemImpl {
             brace_token: item_impl.brace_token,
             items: impl_items,
             namespace,
+            contract_namespace: None,
+            selector_hash: ir::SelectorHash::Blake2x256,
         })
     }
 }
@@ -356,6 +510,69 @@ impl ItemImpl {
         self.namespace.as_ref()
     }
 
+    /// Returns the namespace of the ink! smart contract the implementation block
+    /// belongs to if any has been provided.
+    pub fn contract_namespace(&self) -> Option<&ir::Namespace> {
+        self.contract_namespace.as_ref()
+    }
+
+    /// Sets the namespace of the ink! smart contract the implementation block
+    /// belongs to.
+    pub(crate) fn set_contract_namespace(&mut self, namespace: ir::Namespace) {
+        self.contract_namespace = Some(namespace);
+    }
+
+    /// Returns the hash primitive used to compute the composed selectors of
+    /// the ink! messages and constructors of the implementation block.
+    pub fn selector_hash(&self) -> ir::SelectorHash {
+        self.selector_hash
+    }
+
+    /// Sets the hash primitive used to compute the composed selectors of the
+    /// ink! messages and constructors of the implementation block.
+    pub(crate) fn set_selector_hash(&mut self, hash: ir::SelectorHash) {
+        self.selector_hash = hash;
+    }
+
+    /// Sets the selectors of the ink! messages and constructors of the
+    /// implementation block that are given by a constant, e.g. via
+    /// `selector = MY_SELECTOR`, to the selectors resolved by `resolve`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the combined errors of `resolve`.
+    pub(crate) fn resolve_selector_consts<F>(
+        &mut self,
+        mut resolve: F,
+    ) -> Result<(), syn::Error>
+    where
+        F: FnMut(&Ident) -> Result<ir::Selector, syn::Error>,
+    {
+        let mut errors = Vec::new();
+        for item in &mut self.items {
+            let resolved = match item {
+                ir::ImplItem::Message(message) => {
+                    message.selector_const().cloned().map(|ident| {
+                        resolve(&ident).map(|selector| message.set_selector(selector))
+                    })
+                }
+                ir::ImplItem::Constructor(constructor) => {
+                    constructor.selector_const().cloned().map(|ident| {
+                        resolve(&ident).map(|selector| constructor.set_selector(selector))
+                    })
+                }
+                ir::ImplItem::Other(_) => None,
+            };
+            if let Some(Err(error)) = resolved {
+                errors.push(error)
+            }
+        }
+        errors
+            .into_iter()
+            .reduce(syn::Error::into_combine)
+            .map_or(Ok(()), Err)
+    }
+
     /// Returns an iterator yielding the ink! messages of the implementation block.
     pub fn iter_messages(&self) -> IterMessages {
         IterMessages::new(self)
@@ -370,4 +587,9 @@ impl ItemImpl {
     pub fn items(&self) -> &[ir::ImplItem] {
         &self.items
     }
+
+    /// Returns a slice over the exclusive references of the items of the impl.
+    pub(crate) fn items_mut(&mut self) -> &mut [ir::ImplItem] {
+        &mut self.items
+    }
 }
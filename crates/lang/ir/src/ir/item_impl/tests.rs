@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ir;
+use crate::{
+    ir,
+    ir::Callable as _,
+};
 use core::convert::TryFrom;
 
 #[test]
@@ -207,6 +210,75 @@ fn visibility_fails() {
     );
 }
 
+#[test]
+fn constructor_returning_storage_type_works() {
+    let item_impls: Vec<syn::ItemImpl> = vec![
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> MyStorage {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyTrait for MyStorage {
+                #[ink(constructor)]
+                fn my_constructor() -> MyStorage {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<MyStorage, Error> {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<Self, Error> {}
+            }
+        },
+    ];
+    for item_impl in item_impls {
+        assert!(<ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl).is_ok())
+    }
+}
+
+#[test]
+fn constructor_returning_other_type_fails() {
+    let item_impls: Vec<syn::ItemImpl> = vec![
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> u32 {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> OtherStorage {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Result<u32, Error> {}
+            }
+        },
+        syn::parse_quote! {
+            impl MyStorage {
+                #[ink(constructor)]
+                pub fn my_constructor() -> Option<MyStorage> {}
+            }
+        },
+    ];
+    for item_impl in item_impls {
+        assert_try_from_item_impl_fails(
+            item_impl,
+            "ink! constructors must return Self, Result<Self, E> or the ink! storage type",
+        )
+    }
+}
+
 #[test]
 fn try_from_works() {
     let item_impls: Vec<syn::ItemImpl> = vec![
@@ -230,12 +302,88 @@ fn try_from_works() {
                 fn my_message(&self) {}
             }
         },
+        syn::parse_quote! {
+            impl Token<MyUnit> for MyStorage {
+                #[ink(message)]
+                fn transfer(&mut self, amount: MyUnit) {}
+            }
+        },
     ];
     for item_impl in item_impls {
         assert!(<ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(item_impl).is_ok())
     }
 }
 
+#[test]
+fn generic_trait_impl_works() {
+    let impl_block: ir::ItemImpl =
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            impl Token<MyUnit> for MyStorage {
+                #[ink(message)]
+                fn transfer(&mut self, amount: MyUnit) {}
+            }
+        })
+        .unwrap();
+    // The type arguments bound by the implementation block do not take part
+    // in the selectors so that they match the ones of the trait definition.
+    assert_eq!(
+        impl_block.trait_ident().map(ToString::to_string),
+        Some("Token".into())
+    );
+    let message = impl_block.iter_messages().next().unwrap();
+    assert_eq!(
+        message.composed_selector(),
+        ir::Selector::new("Token::transfer".as_bytes()),
+    );
+}
+
+#[test]
+fn trait_impl_self_assoc_types_are_qualified() {
+    let impl_block: ir::ItemImpl =
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            impl Token<MyUnit> for MyStorage {
+                type Amount = u64;
+
+                #[ink(message)]
+                fn transfer(&mut self, amount: Self::Amount) -> Vec<Self::Amount> {
+                    let _: Self::Amount = amount;
+                    Vec::new()
+                }
+            }
+        })
+        .unwrap();
+    let message = impl_block.iter_messages().next().unwrap();
+    let expected: syn::Type = syn::parse_quote! { <MyStorage as Token<MyUnit> >::Amount };
+    assert_eq!(
+        message
+            .inputs_iter()
+            .map(|input| &*input.ty)
+            .collect::<Vec<_>>(),
+        vec![&expected],
+    );
+    let expected_output: syn::Type = syn::parse_quote! { Vec<#expected> };
+    assert_eq!(message.output(), Some(&expected_output));
+    // The message body still refers to `Self`.
+    let expected_body: syn::Block = syn::parse_quote! {{
+        let _: Self::Amount = amount;
+        Vec::new()
+    }};
+    assert_eq!(message.statements(), &expected_body.stmts[..]);
+}
+
+#[test]
+fn trait_impl_binding_lifetimes_fails() {
+    assert_try_from_item_impl_fails(
+        syn::parse_quote! {
+            impl Token<'static, MyUnit> for MyStorage {
+                #[ink(message)]
+                fn transfer(&mut self, amount: MyUnit) {}
+            }
+        },
+        "ink! trait implementation blocks must not bind lifetimes",
+    );
+}
+
 #[test]
 fn namespace_works() {
     let impl_block: ir::ItemImpl =
@@ -254,3 +402,48 @@ fn namespace_works() {
         ))
     )
 }
+
+#[test]
+fn namespaced_inherent_impl_works() {
+    let impl_block: ir::ItemImpl =
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            #[ink(impl, namespace = "admin")]
+            impl MyStorage {
+                #[ink(message)]
+                pub fn set_owner(&mut self) {}
+
+                #[ink(message, selector = "0xDEADBEEF")]
+                pub fn owner(&self) {}
+
+                fn helper(&self) {}
+            }
+        })
+        .unwrap();
+    assert!(impl_block.trait_path().is_none());
+    assert_eq!(
+        impl_block.namespace(),
+        Some(&ir::Namespace::from(b"admin".to_vec()))
+    );
+    let selectors = impl_block
+        .iter_messages()
+        .map(|message| message.composed_selector())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        selectors,
+        vec![
+            ir::Selector::new(b"admin::set_owner"),
+            ir::Selector::from_bytes([0xDE, 0xAD, 0xBE, 0xEF]),
+        ]
+    );
+    // An `#[ink(impl)]` inherent implementation block without any ink! messages
+    // or constructors is still recognized as such.
+    assert!(
+        <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(syn::parse_quote! {
+            #[ink(impl, namespace = "admin")]
+            impl MyStorage {
+                fn helper(&self) {}
+            }
+        })
+        .is_ok()
+    );
+}
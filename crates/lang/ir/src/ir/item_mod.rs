@@ -21,8 +21,14 @@ use proc_macro2::{
     Ident,
     Span,
 };
-use quote::TokenStreamExt as _;
-use std::collections::HashMap;
+use quote::{
+    format_ident,
+    TokenStreamExt as _,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 use syn::{
     spanned::Spanned,
     token,
@@ -93,6 +99,8 @@ pub struct ItemMod {
     ident: Ident,
     brace: token::Brace,
     items: Vec<ir::Item>,
+    /// The identifiers of the `#[ink(storage)]` type aliases of the storage struct.
+    storage_aliases: Vec<Ident>,
 }
 
 impl ItemMod {
@@ -121,46 +129,269 @@ impl ItemMod {
         Ok(())
     }
 
-    /// Ensures that the given slice of items contains at least one ink! message.
-    fn ensure_contains_message(
-        module_span: Span,
+    /// Ensures that neither the fields of the ink! storage struct nor the inputs
+    /// and outputs of ink! messages and constructors use non-`'static` lifetimes.
+    ///
+    /// # Note
+    ///
+    /// All contract data is decoded from its input or loaded from the contract
+    /// storage and therefore can never borrow from anything. The only exception
+    /// are slice inputs `&[T]` which are decoded as owned `Vec<T>`.
+    fn ensure_no_non_static_lifetimes(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use ir::{
+            utils::ensure_no_non_static_lifetimes,
+            Callable as _,
+        };
+        /// Returns the type that needs to be checked for the given input type.
+        fn input_ty(ty: &syn::Type) -> &syn::Type {
+            match ty {
+                syn::Type::Reference(reference)
+                    if reference.lifetime.is_none() && reference.mutability.is_none() =>
+                {
+                    match &*reference.elem {
+                        syn::Type::Slice(slice) => &slice.elem,
+                        _ => ty,
+                    }
+                }
+                _ => ty,
+            }
+        }
+        for item in items {
+            match item {
+                ir::Item::Ink(ir::InkItem::Storage(storage)) => {
+                    for field in storage.fields() {
+                        ensure_no_non_static_lifetimes(&field.ty, "ink! storage fields")?;
+                    }
+                }
+                ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => {
+                    for constructor in item_impl.iter_constructors() {
+                        for (_, ty) in constructor.inputs() {
+                            ensure_no_non_static_lifetimes(
+                                input_ty(ty),
+                                "ink! constructor parameters",
+                            )?;
+                        }
+                    }
+                    for message in item_impl.iter_messages() {
+                        for (_, ty) in message.inputs() {
+                            ensure_no_non_static_lifetimes(
+                                input_ty(ty),
+                                "ink! message parameters",
+                            )?;
+                        }
+                        if let Some(output) = message.callable().output() {
+                            ensure_no_non_static_lifetimes(
+                                output,
+                                "ink! message return types",
+                            )?;
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that the ink! storage struct does not directly or indirectly
+    /// contain itself or any other recursive type defined within the ink! module.
+    ///
+    /// # Note
+    ///
+    /// Recursive storage types can never have a finite storage layout and
+    /// would otherwise lead to infinite recursion when generating their layout.
+    ///
+    /// Only types contained by value are followed, i.e. plain type paths, tuples
+    /// and arrays. The generic arguments of types such as `Box<T>`, `Vec<T>` or
+    /// `Option<T>` are not followed since they usually introduce an indirection
+    /// that breaks the cycle.
+    fn ensure_no_recursive_storage_types(items: &[ir::Item]) -> Result<(), syn::Error> {
+        let mut types = <HashMap<&Ident, (Span, Vec<&syn::Type>)>>::new();
+        let mut storage_ident = None;
+        for item in items {
+            match item {
+                ir::Item::Ink(ir::InkItem::Storage(storage)) => {
+                    storage_ident = Some(storage.ident());
+                    let field_types = storage.fields().map(|field| &field.ty).collect();
+                    types.insert(storage.ident(), (storage.span(), field_types));
+                }
+                ir::Item::Rust(syn::Item::Struct(item_struct)) => {
+                    let field_types =
+                        item_struct.fields.iter().map(|field| &field.ty).collect();
+                    types.insert(&item_struct.ident, (item_struct.span(), field_types));
+                }
+                ir::Item::Rust(syn::Item::Enum(item_enum)) => {
+                    let field_types = item_enum
+                        .variants
+                        .iter()
+                        .flat_map(|variant| variant.fields.iter())
+                        .map(|field| &field.ty)
+                        .collect();
+                    types.insert(&item_enum.ident, (item_enum.span(), field_types));
+                }
+                _ => (),
+            }
+        }
+        fn visit<'a>(
+            ident: &'a Ident,
+            types: &HashMap<&'a Ident, (Span, Vec<&'a syn::Type>)>,
+            stack: &mut Vec<&'a Ident>,
+            visited: &mut HashSet<&'a Ident>,
+        ) -> Result<(), syn::Error> {
+            if let Some(position) = stack.iter().position(|seen| *seen == ident) {
+                let cycle = stack[position..]
+                    .iter()
+                    .chain(core::iter::once(&ident))
+                    .map(|ident| format!("`{}`", ident))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                return Err(format_err!(
+                    types[ident].0,
+                    "encountered recursive storage type {} which cannot have a finite storage layout",
+                    cycle,
+                ))
+            }
+            if visited.contains(ident) {
+                return Ok(())
+            }
+            stack.push(ident);
+            for ty in &types[ident].1 {
+                let mut paths = Vec::new();
+                ir::utils::collect_type_paths(ty, 0, &mut paths);
+                for path in paths {
+                    let next = if path.is_ident("Self") {
+                        Some(ident)
+                    } else {
+                        path.get_ident()
+                            .and_then(|next| types.get_key_value(next))
+                            .map(|(next, _)| *next)
+                    };
+                    if let Some(next) = next {
+                        visit(next, types, stack, visited)?;
+                    }
+                }
+            }
+            stack.pop();
+            visited.insert(ident);
+            Ok(())
+        }
+        match storage_ident {
+            Some(storage_ident) => {
+                visit(storage_ident, &types, &mut Vec::new(), &mut HashSet::new())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Ensures that no field of the ink! storage struct has an uninhabited type.
+    ///
+    /// # Note
+    ///
+    /// This is a best-effort check that only knows about the types defined within
+    /// the ink! module. Those are uninhabited if they are enums without variants,
+    /// e.g. `enum Never {}`, or structs, tuples and enums whose values would all have
+    /// to contain an uninhabited type. An ink! storage struct with a field of such
+    /// a type can never be constructed.
+    fn ensure_no_uninhabited_storage_fields(
         items: &[ir::Item],
     ) -> Result<(), syn::Error> {
-        let found_message = items
-            .iter()
-            .filter_map(|item| {
-                match item {
-                    ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => {
-                        Some(item_impl.iter_messages())
+        /// Returns `true` if the type is known to be uninhabited.
+        fn is_uninhabited(ty: &syn::Type, uninhabited: &HashSet<&Ident>) -> bool {
+            match ty {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                    type_path
+                        .path
+                        .get_ident()
+                        .map(|ident| uninhabited.contains(ident))
+                        .unwrap_or_default()
+                }
+                syn::Type::Tuple(tuple) => {
+                    tuple
+                        .elems
+                        .iter()
+                        .any(|elem| is_uninhabited(elem, uninhabited))
+                }
+                syn::Type::Paren(paren) => is_uninhabited(&paren.elem, uninhabited),
+                syn::Type::Group(group) => is_uninhabited(&group.elem, uninhabited),
+                _ => false,
+            }
+        }
+        let mut uninhabited = HashSet::new();
+        loop {
+            let known = uninhabited.len();
+            for item in items {
+                let ident = match item {
+                    ir::Item::Rust(syn::Item::Enum(item_enum))
+                        if item_enum.variants.iter().all(|variant| {
+                            variant
+                                .fields
+                                .iter()
+                                .any(|field| is_uninhabited(&field.ty, &uninhabited))
+                        }) =>
+                    {
+                        &item_enum.ident
                     }
-                    _ => None,
+                    ir::Item::Rust(syn::Item::Struct(item_struct))
+                        if item_struct
+                            .fields
+                            .iter()
+                            .any(|field| is_uninhabited(&field.ty, &uninhabited)) =>
+                    {
+                        &item_struct.ident
+                    }
+                    _ => continue,
+                };
+                uninhabited.insert(ident);
+            }
+            if uninhabited.len() == known {
+                break
+            }
+        }
+        let storage = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .find_map(ir::InkItem::filter_map_storage_item);
+        if let Some(storage) = storage {
+            for (index, field) in storage.fields().enumerate() {
+                if is_uninhabited(&field.ty, &uninhabited) {
+                    let field_name = field
+                        .ident
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| index.to_string());
+                    let ty = &field.ty;
+                    return Err(format_err_spanned!(
+                        field.ty,
+                        "the ink! storage struct `{}` can never be constructed since \
+                         the type `{}` of its field `{}` is uninhabited",
+                        storage.ident(),
+                        quote::quote!(#ty),
+                        field_name,
+                    ))
                 }
-            })
-            .any(|mut messages| messages.next().is_some());
-        if !found_message {
-            return Err(format_err!(module_span, "missing ink! message"))
+            }
         }
         Ok(())
     }
 
-    /// Ensures that the given slice of items contains at least one ink! constructor.
-    fn ensure_contains_constructor(
+    /// Ensures that the given slice of items contains at least one ink! message.
+    fn ensure_contains_message(
         module_span: Span,
         items: &[ir::Item],
     ) -> Result<(), syn::Error> {
-        let found_constructor = items
+        let found_message = items
             .iter()
             .filter_map(|item| {
                 match item {
                     ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => {
-                        Some(item_impl.iter_constructors())
+                        Some(item_impl.iter_messages())
                     }
                     _ => None,
                 }
             })
-            .any(|mut constructors| constructors.next().is_some());
-        if !found_constructor {
-            return Err(format_err!(module_span, "missing ink! constructor"))
+            .any(|mut messages| messages.next().is_some());
+        if !found_message {
+            return Err(format_err!(module_span, "missing ink! message"))
         }
         Ok(())
     }
@@ -239,6 +470,445 @@ impl ItemMod {
         }
         Ok(())
     }
+
+    /// Ensures that no two ink! messages or constructors share the same name.
+    ///
+    /// # Note
+    ///
+    /// Only inherent implementation blocks are checked since ink! messages of
+    /// different trait implementations are allowed to share the same name.
+    /// ink! messages and constructors are checked against each other since they
+    /// both end up as associated functions of the same ink! storage struct.
+    fn ensure_no_duplicate_callable_names(items: &[ir::Item]) -> Result<(), syn::Error> {
+        let mut callables = <HashMap<Ident, (Span, &str)>>::new();
+        for item_impl in items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .filter(|item_impl| item_impl.trait_path().is_none())
+        {
+            use crate::error::ExtError as _;
+            use ir::Callable as _;
+            use std::collections::hash_map::Entry;
+            let messages = item_impl
+                .iter_messages()
+                .map(|message| (message.ident().clone(), message.span(), "message"));
+            let constructors = item_impl.iter_constructors().map(|constructor| {
+                (
+                    constructor.ident().clone(),
+                    constructor.span(),
+                    "constructor",
+                )
+            });
+            for (ident, span, kind) in messages.chain(constructors) {
+                match callables.entry(ident) {
+                    Entry::Occupied(duplicate) => {
+                        let (first_span, first_kind) = *duplicate.get();
+                        return Err(format_err!(
+                            span,
+                            "encountered ink! {} with the same name `{}` as another ink! {}",
+                            kind,
+                            duplicate.key(),
+                            first_kind,
+                        )
+                        .into_combine(format_err!(
+                            first_span,
+                            "first ink! {} named `{}` here",
+                            first_kind,
+                            duplicate.key(),
+                        )))
+                    }
+                    Entry::Vacant(vacant) => {
+                        vacant.insert((span, kind));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that no two ink! events share the same name.
+    ///
+    /// # Note
+    ///
+    /// The event signature topics and the metadata entries of ink! events are
+    /// derived from their names and therefore would collide.
+    fn ensure_no_duplicate_event_names(items: &[ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        use std::collections::hash_map::Entry;
+        let mut events = <HashMap<String, Span>>::new();
+        for event in items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_event_item)
+        {
+            let name = event.ident().to_string();
+            match events.entry(name) {
+                Entry::Occupied(duplicate) => {
+                    return Err(format_err!(
+                        event.ident().span(),
+                        "encountered ink! event with the same name `{}` as another ink! event",
+                        duplicate.key(),
+                    )
+                    .into_combine(format_err!(
+                        *duplicate.get(),
+                        "first ink! event named `{}` here",
+                        duplicate.key(),
+                    )))
+                }
+                Entry::Vacant(vacant) => {
+                    vacant.insert(event.ident().span());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that at most one ink! constructor has been flagged with the
+    /// wildcard selector `selector = _`.
+    fn ensure_at_most_one_wildcard_constructor(
+        items: &[ir::Item],
+    ) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let mut wildcards = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .filter_map(ir::InkItem::filter_map_impl_block)
+            .flat_map(ir::ItemImpl::iter_constructors)
+            .filter(|constructor| constructor.callable().has_wildcard_selector());
+        if let (Some(first), Some(second)) = (wildcards.next(), wildcards.next()) {
+            return Err(format_err!(
+                second.span(),
+                "encountered multiple ink! constructors with a wildcard selector",
+            )
+            .into_combine(format_err!(
+                first.span(),
+                "first ink! constructor with a wildcard selector here",
+            )))
+        }
+        Ok(())
+    }
+
+    /// Resolves an ink! storage type alias, e.g. `#[ink(storage)] type Storage = MyStorage;`,
+    /// to the aliased struct.
+    ///
+    /// The ink! attributes of the type alias are moved to the aliased struct which
+    /// is then treated as the ink! storage struct. The type alias itself is kept as
+    /// a normal Rust item.
+    ///
+    /// # Errors
+    ///
+    /// - If the type alias is generic.
+    /// - If the type alias does not refer to a struct defined at the root of the ink! module.
+    /// - If the aliased struct already has ink! attributes.
+    ///
+    /// Returns the identifiers of all ink! storage type aliases.
+    fn resolve_storage_alias(items: &mut [syn::Item]) -> Result<Vec<Ident>, syn::Error> {
+        let mut aliases = Vec::new();
+        for item in items.iter_mut() {
+            if let syn::Item::Type(item_type) = item {
+                let is_storage_alias = ir::first_ink_attribute(&item_type.attrs)?
+                    .map(|ink_attr| {
+                        matches!(ink_attr.first().kind(), ir::AttributeArg::Storage)
+                    })
+                    .unwrap_or_default();
+                if !is_storage_alias {
+                    continue
+                }
+                if !item_type.generics.params.is_empty() {
+                    return Err(format_err_spanned!(
+                        item_type.generics,
+                        "generic ink! storage type aliases are not supported",
+                    ))
+                }
+                let (ink_attrs, other_attrs) = item_type
+                    .attrs
+                    .drain(..)
+                    .partition(|attr| ir::contains_ink_attributes(Some(attr)));
+                item_type.attrs = other_attrs;
+                aliases.push((
+                    item_type.ident.clone(),
+                    (*item_type.ty).clone(),
+                    ink_attrs,
+                ));
+            }
+        }
+        let mut storage_aliases = Vec::new();
+        for (alias, ty, ink_attrs) in aliases {
+            let aliased = match &ty {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                    let path = &type_path.path;
+                    match path.segments.len() {
+                        1 => path.get_ident(),
+                        2 if path.segments[0].ident == "self"
+                            && path.segments[1].arguments.is_empty() =>
+                        {
+                            Some(&path.segments[1].ident)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            let item_struct = aliased.and_then(|aliased| {
+                items.iter_mut().find_map(|item| {
+                    match item {
+                        syn::Item::Struct(item_struct)
+                            if &item_struct.ident == aliased =>
+                        {
+                            Some(item_struct)
+                        }
+                        _ => None,
+                    }
+                })
+            });
+            let item_struct = match item_struct {
+                Some(item_struct) => item_struct,
+                None => {
+                    return Err(format_err_spanned!(
+                    ty,
+                    "ink! storage type alias `{}` must refer to a struct defined at the \
+                         root of the ink! module",
+                    alias,
+                ))
+                }
+            };
+            if ir::contains_ink_attributes(&item_struct.attrs) {
+                return Err(format_err_spanned!(
+                    item_struct,
+                    "encountered ink! attributes on struct `{}` that is aliased by the \
+                     ink! storage type alias `{}`",
+                    item_struct.ident,
+                    alias,
+                ))
+            }
+            item_struct.attrs.extend(ink_attrs);
+            storage_aliases.push(alias);
+        }
+        Ok(storage_aliases)
+    }
+
+    /// Returns `true` if the given type refers to the ink! storage struct either
+    /// directly or through one of its ink! storage type aliases.
+    ///
+    /// # Note
+    ///
+    /// Only plain identifiers and `self::` paths are considered since the ink!
+    /// storage struct and its type aliases are defined at the root of the ink! module.
+    fn is_storage_type(
+        ty: &syn::Type,
+        storage_ident: &Ident,
+        storage_aliases: &[Ident],
+    ) -> bool {
+        let ident = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                let path = &type_path.path;
+                match path.segments.len() {
+                    1 => path.get_ident(),
+                    2 if path.segments[0].ident == "self"
+                        && path.segments[1].arguments.is_empty() =>
+                    {
+                        Some(&path.segments[1].ident)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match ident {
+            Some(ident) => ident == storage_ident || storage_aliases.contains(ident),
+            None => false,
+        }
+    }
+
+    /// Resolves the selectors of all ink! messages and constructors that are
+    /// given by a constant, e.g. via `#[ink(selector = MY_SELECTOR)]`.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no constant of the given name at the root of the ink! module.
+    /// - If the constant is not of type `u32` or `[u8; 4]`.
+    /// - If the constant is not initialized with an integer literal or an
+    ///   array of four integer literals respectively.
+    fn resolve_selector_consts(items: &mut [ir::Item]) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        let consts = items
+            .iter()
+            .filter_map(|item| {
+                match item {
+                    ir::Item::Rust(syn::Item::Const(item_const)) => {
+                        Some((item_const.ident.clone(), item_const.clone()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<HashMap<_, _>>();
+        let resolve = |ident: &Ident| {
+            let item_const = consts.get(ident).ok_or_else(|| {
+                format_err!(
+                    ident,
+                    "encountered unknown constant `{}` for ink! selector, expected a \
+                     constant defined at the root of the ink! module",
+                    ident,
+                )
+            })?;
+            selector_const_value(item_const)
+        };
+        items
+            .iter_mut()
+            .filter_map(|item| {
+                match item {
+                    ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => {
+                        item_impl.resolve_selector_consts(resolve).err()
+                    }
+                    _ => None,
+                }
+            })
+            .reduce(syn::Error::into_combine)
+            .map_or(Ok(()), Err)
+    }
+
+    /// Resolves the storage fields of all ink! getter messages.
+    ///
+    /// The return type of every ink! getter message is set to the type of its
+    /// storage field if it has not been provided by the user.
+    ///
+    /// # Errors
+    ///
+    /// - If an ink! getter refers to a field that does not exist on the ink! storage struct.
+    /// - If the return type of an ink! getter does not match the type of its field.
+    /// - If an ink! getter conflicts with another inherent method of the same name.
+    fn resolve_getters(
+        items: &mut [ir::Item],
+        storage_aliases: &[Ident],
+    ) -> Result<(), syn::Error> {
+        use crate::{
+            error::ExtError as _,
+            ir::Callable as _,
+        };
+        let storage = items
+            .iter()
+            .filter_map(ir::Item::map_ink_item)
+            .find_map(ir::InkItem::filter_map_storage_item)
+            .expect("encountered ink! module without a storage struct");
+        let storage_ident = storage.ident().clone();
+        let fields = storage
+            .fields()
+            .filter_map(|field| {
+                field.ident.clone().map(|ident| (ident, field.ty.clone()))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut methods = Vec::new();
+        let mut getters = Vec::new();
+        for item in items.iter() {
+            let impl_items: Box<dyn Iterator<Item = (&Ident, bool)>> = match item {
+                ir::Item::Ink(ir::InkItem::ImplBlock(item_impl))
+                    if item_impl.trait_path().is_none() =>
+                {
+                    Box::new(item_impl.items().iter().filter_map(|impl_item| {
+                        match impl_item {
+                            ir::ImplItem::Message(message) => {
+                                Some((message.ident(), message.getter().is_some()))
+                            }
+                            ir::ImplItem::Constructor(constructor) => {
+                                Some((constructor.ident(), false))
+                            }
+                            ir::ImplItem::Other(syn::ImplItem::Method(method)) => {
+                                Some((&method.sig.ident, false))
+                            }
+                            _ => None,
+                        }
+                    }))
+                }
+                ir::Item::Rust(syn::Item::Impl(item_impl))
+                    if item_impl.trait_.is_none()
+                        && Self::is_storage_type(
+                            &item_impl.self_ty,
+                            &storage_ident,
+                            storage_aliases,
+                        ) =>
+                {
+                    Box::new(item_impl.items.iter().filter_map(|impl_item| {
+                        match impl_item {
+                            syn::ImplItem::Method(method) => {
+                                Some((&method.sig.ident, false))
+                            }
+                            _ => None,
+                        }
+                    }))
+                }
+                _ => continue,
+            };
+            for (ident, is_getter) in impl_items {
+                if is_getter {
+                    getters.push(ident);
+                }
+                methods.push(ident);
+            }
+        }
+        for getter in getters {
+            if let Some(conflicting) = methods
+                .iter()
+                .find(|method| **method == getter && !core::ptr::eq(**method, getter))
+            {
+                return Err(format_err!(
+                    getter,
+                    "encountered ink! getter message `{}` that conflicts with an existing method of the same name",
+                    getter,
+                )
+                .into_combine(format_err!(conflicting, "conflicting method here")))
+            }
+        }
+        for message in items
+            .iter_mut()
+            .filter_map(|item| {
+                match item {
+                    ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) => Some(item_impl),
+                    _ => None,
+                }
+            })
+            .flat_map(|item_impl| item_impl.items_mut().iter_mut())
+            .filter_map(|impl_item| {
+                match impl_item {
+                    ir::ImplItem::Message(message) => Some(message),
+                    _ => None,
+                }
+            })
+        {
+            let field = match message.getter() {
+                Some(field) => field,
+                None => continue,
+            };
+            let field_ty = fields.get(field).ok_or_else(|| {
+                format_err!(
+                    field,
+                    "encountered ink! getter for unknown storage field `{}` of `{}`",
+                    field,
+                    storage_ident,
+                )
+            })?;
+            message.resolve_getter_output(field_ty)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the concrete instances of all generic ink! events from the
+    /// instantiations found throughout the ink! module.
+    ///
+    /// # Errors
+    ///
+    /// If a generic ink! event is never instantiated with concrete type arguments.
+    fn resolve_event_instances(
+        items: &mut [ir::Item],
+        instantiations: &[(Ident, Vec<syn::Type>)],
+    ) -> Result<(), syn::Error> {
+        for item in items.iter_mut() {
+            if let ir::Item::Ink(ir::InkItem::Event(event)) = item {
+                event.resolve_instances(instantiations)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<syn::ItemMod> for ItemMod {
@@ -247,7 +917,7 @@ impl TryFrom<syn::ItemMod> for ItemMod {
     fn try_from(module: syn::ItemMod) -> Result<Self, Self::Error> {
         let module_span = module.span();
         idents_lint::ensure_no_ink_identifiers(&module)?;
-        let (brace, items) = match module.content {
+        let (brace, mut items) = match module.content {
             Some((brace, items)) => (brace, items),
             None => {
                 return Err(format_err_spanned!(
@@ -270,14 +940,30 @@ impl TryFrom<syn::ItemMod> for ItemMod {
             }
             return Err(error)
         }
-        let items = items
+        if items.is_empty() {
+            return Err(format_err!(
+                module_span,
+                "contract module contains no items; expected at least one #[ink(storage)] struct",
+            ))
+        }
+        let storage_aliases = Self::resolve_storage_alias(&mut items)?;
+        let instantiations = ir::item::collect_instantiations(&items);
+        let mut items = items
             .into_iter()
             .map(<ir::Item as TryFrom<syn::Item>>::try_from)
             .collect::<Result<Vec<_>, syn::Error>>()?;
         Self::ensure_storage_struct_quantity(module_span, &items)?;
+        Self::ensure_no_recursive_storage_types(&items)?;
+        Self::ensure_no_uninhabited_storage_fields(&items)?;
+        Self::ensure_no_non_static_lifetimes(&items)?;
+        Self::resolve_getters(&mut items, &storage_aliases)?;
+        Self::resolve_selector_consts(&mut items)?;
+        Self::resolve_event_instances(&mut items, &instantiations)?;
         Self::ensure_contains_message(module_span, &items)?;
-        Self::ensure_contains_constructor(module_span, &items)?;
+        Self::ensure_no_duplicate_callable_names(&items)?;
+        Self::ensure_no_duplicate_event_names(&items)?;
         Self::ensure_no_overlapping_selectors(&items)?;
+        Self::ensure_at_most_one_wildcard_constructor(&items)?;
         Ok(Self {
             attrs: other_attrs,
             vis: module.vis,
@@ -285,6 +971,7 @@ impl TryFrom<syn::ItemMod> for ItemMod {
             ident: module.ident,
             brace,
             items,
+            storage_aliases,
         })
     }
 }
@@ -345,42 +1032,460 @@ impl ItemMod {
         storage
     }
 
-    /// Returns all (ink! and non-ink! specific) item definitions of the ink! inline module.
-    pub fn items(&self) -> &[ir::Item] {
-        self.items.as_slice()
+    /// Ensures that the ink! module contains at least one ink! constructor.
+    ///
+    /// # Note
+    ///
+    /// This is not checked upon construction of the ink! module since it
+    /// depends on the ink! configuration whether it is allowed to have no ink!
+    /// constructors, e.g. for library contracts that are only used as
+    /// dependencies.
+    ///
+    /// # Errors
+    ///
+    /// If the ink! module does not contain any ink! constructor. The error
+    /// points at the ink! storage struct.
+    pub(crate) fn ensure_contains_constructor(&self) -> Result<(), syn::Error> {
+        let found_constructor = self
+            .impls()
+            .any(|item_impl| item_impl.iter_constructors().next().is_some());
+        if !found_constructor {
+            let storage = self.storage();
+            return Err(format_err!(
+                storage.span(),
+                "missing ink! constructor for `{}`, a contract without any #[ink(constructor)] can never be instantiated",
+                storage.ident(),
+            ))
+        }
+        Ok(())
     }
 
-    /// Returns an iterator yielding all ink! implementation blocks.
+    /// Ensures that all types of the ink! storage struct, ink! events, ink!
+    /// constructors and ink! messages can be registered in the metadata type
+    /// registry.
     ///
     /// # Note
     ///
-    /// An ink! implementation block can be either an inherent `impl` block
-    /// directly defined for the contract's storage struct if it includes at
-    /// least one `#[ink(message)]` or `#[ink(constructor)]` annotation, e.g.:
+    /// This is only checked for ink! smart contracts configured with
+    /// `validate_metadata_types = true`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the types obviously does not implement `scale_info::TypeInfo`.
+    /// The error names the type and the ink! item using it.
+    pub(crate) fn ensure_registrable_types(&self) -> Result<(), syn::Error> {
+        use ir::{
+            utils::ensure_registrable_type,
+            Callable as _,
+        };
+        let storage = self.storage();
+        for (n, field) in storage.fields().enumerate() {
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| n.to_string());
+            ensure_registrable_type(
+                &field.ty,
+                &format!(
+                    "field `{}` of ink! storage struct `{}`",
+                    field_name,
+                    storage.ident()
+                ),
+            )?;
+        }
+        for event in self.events() {
+            for field in event.fields() {
+                ensure_registrable_type(
+                    field.ty(),
+                    &format!("ink! event `{}`", event.ident()),
+                )?;
+            }
+        }
+        for item_impl in self.impls() {
+            for constructor in item_impl.iter_constructors() {
+                let item = format!("ink! constructor `{}`", constructor.ident());
+                for (_, ty) in constructor.inputs() {
+                    ensure_registrable_type(ty, &item)?;
+                }
+            }
+            for message in item_impl.iter_messages() {
+                let item = format!("ink! message `{}`", message.ident());
+                for (_, ty) in message.inputs() {
+                    ensure_registrable_type(ty, &item)?;
+                }
+                if let Some(output) = message.output() {
+                    ensure_registrable_type(output, &item)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that neither the ink! storage struct nor any ink! event struct
+    /// already implements `Debug`.
+    ///
+    /// # Note
+    ///
+    /// This is only checked for ink! smart contracts configured with
+    /// `derive_debug = true` which derives `Debug` for those structs.
+    ///
+    /// # Errors
+    ///
+    /// If any of those structs derives `Debug` itself or has a `Debug`
+    /// implementation at the top-level of the ink! module.
+    pub(crate) fn ensure_no_manual_debug_impls(&self) -> Result<(), syn::Error> {
+        let storage = self.storage();
+        let structs = core::iter::once((storage.ident(), storage.attrs(), "storage"))
+            .chain(
+                self.events()
+                    .map(|event| (event.ident(), event.attrs(), "event")),
+            );
+        for (ident, attrs, kind) in structs {
+            if let Some(derive) = ir::utils::find_derive(attrs, &["Debug"]) {
+                return Err(format_err_spanned!(
+                    derive,
+                    "encountered `Debug` derive on the ink! {} struct `{}` \
+                     which conflicts with `derive_debug = true`",
+                    kind,
+                    ident,
+                ))
+            }
+            if let Some(item_impl) = self.find_trait_impl(ident, "Debug") {
+                return Err(format_err_spanned!(
+                    item_impl,
+                    "encountered `Debug` implementation for the ink! {} struct `{}` \
+                     which conflicts with `derive_debug = true`",
+                    kind,
+                    ident,
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first implementation of the given trait for the type with
+    /// the given identifier at the top-level of the ink! module if any.
+    ///
+    /// # Note
+    ///
+    /// Traits are matched by the last segment of their path. Implementations
+    /// for the ink! storage struct are also found through its ink! storage
+    /// type aliases.
+    fn find_trait_impl(
+        &self,
+        self_ident: &Ident,
+        trait_name: &str,
+    ) -> Option<&syn::ItemImpl> {
+        let storage_ident = self.storage().ident();
+        self.items().iter().find_map(|item| {
+            let item_impl = match item {
+                ir::Item::Rust(syn::Item::Impl(item_impl)) => item_impl,
+                _ => return None,
+            };
+            let implements_trait = item_impl
+                .trait_
+                .as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .map(|segment| segment.ident == trait_name)
+                .unwrap_or_default();
+            let is_self_ty = if self_ident == storage_ident {
+                Self::is_storage_type(
+                    &item_impl.self_ty,
+                    storage_ident,
+                    &self.storage_aliases,
+                )
+            } else {
+                match &*item_impl.self_ty {
+                    syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                        type_path
+                            .path
+                            .segments
+                            .last()
+                            .map(|segment| segment.ident == *self_ident)
+                            .unwrap_or_default()
+                    }
+                    _ => false,
+                }
+            };
+            if implements_trait && is_self_ty {
+                Some(item_impl)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Ensures that all ink! messages and constructors specify their selectors
+    /// explicitly via `#[ink(selector = ..)]`.
+    ///
+    /// # Note
+    ///
+    /// This is only checked for ink! smart contracts configured with
+    /// `explicit_selectors = true`.
+    ///
+    /// # Errors
+    ///
+    /// Lists every ink! message and constructor that is missing an explicit
+    /// selector.
+    pub(crate) fn ensure_explicit_selectors(&self) -> Result<(), syn::Error> {
+        use crate::error::ExtError as _;
+        use ir::Callable as _;
+        let mut missing = self.impls().flat_map(|item_impl| {
+            let messages = item_impl
+                .iter_messages()
+                .filter(|message| message.user_provided_selector().is_none())
+                .map(|message| (message.ident().clone(), message.span(), "message"));
+            let constructors = item_impl
+                .iter_constructors()
+                .filter(|constructor| {
+                    constructor.user_provided_selector().is_none()
+                        && !constructor.callable().has_wildcard_selector()
+                })
+                .map(|constructor| {
+                    (
+                        constructor.ident().clone(),
+                        constructor.span(),
+                        "constructor",
+                    )
+                });
+            messages.chain(constructors)
+        });
+        let error = |(ident, span, kind): (Ident, Span, &str)| {
+            format_err!(
+                span,
+                "missing explicit selector for ink! {} `{}` as required by `explicit_selectors = true`",
+                kind,
+                ident,
+            )
+        };
+        match missing.next() {
+            Some(first) => {
+                Err(missing.fold(error(first), |acc, next| acc.into_combine(error(next))))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Seeds the composed selectors of all ink! messages and constructors with
+    /// the given namespace of the ink! smart contract.
+    ///
+    /// # Note
+    ///
+    /// This is only applied for ink! smart contracts configured with
+    /// `namespace = "..."`. Explicitly provided selectors are not affected.
+    ///
+    /// # Errors
+    ///
+    /// If the namespaced selectors of some ink! messages or constructors overlap.
+    pub(crate) fn set_contract_namespace(
+        &mut self,
+        namespace: &ir::Namespace,
+    ) -> Result<(), syn::Error> {
+        for item in &mut self.items {
+            if let ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) = item {
+                item_impl.set_contract_namespace(namespace.clone());
+            }
+        }
+        Self::ensure_no_overlapping_selectors(&self.items)
+    }
+
+    /// Computes the composed selectors of all ink! messages and constructors
+    /// with the given hash primitive.
+    ///
+    /// # Note
+    ///
+    /// This is only applied for ink! smart contracts configured with
+    /// `hash = "..."`. Explicitly provided selectors are not affected.
+    ///
+    /// # Errors
+    ///
+    /// If the selectors of some ink! messages or constructors overlap.
+    pub(crate) fn set_selector_hash(
+        &mut self,
+        hash: ir::SelectorHash,
+    ) -> Result<(), syn::Error> {
+        for item in &mut self.items {
+            if let ir::Item::Ink(ir::InkItem::ImplBlock(item_impl)) = item {
+                item_impl.set_selector_hash(hash);
+            }
+        }
+        Self::ensure_no_overlapping_selectors(&self.items)
+    }
+
+    /// Adds the `set_code` ink! message of `upgradeable` ink! smart contracts.
+    ///
+    /// # Note
+    ///
+    /// The generated ink! message replaces the code of the ink! smart contract
+    /// with the code uploaded under the given code hash. It is guarded by the
+    /// `ink_lang::Upgradeable` implementation of the ink! storage struct which
+    /// must be provided by the user.
+    ///
+    /// # Errors
+    ///
+    /// If a user defined ink! message has the same selector as the generated
+    /// `set_code` ink! message.
+    pub(crate) fn add_set_code_message(&mut self) -> Result<(), syn::Error> {
+        let storage_ident = self.storage().ident();
+        let item_impl = <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(
+            syn::parse_quote! {
+                impl #storage_ident {
+                    /// Replaces the code of the contract with the code uploaded
+                    /// under the given code hash.
+                    #[ink(message)]
+                    pub fn set_code(&mut self, code_hash: Hash) {
+                        if !<Self as ::ink_lang::Upgradeable>::can_set_code(self) {
+                            ::core::panic!("caller is not allowed to set the code of the contract")
+                        }
+                        ::ink_env::set_code_hash::<Environment>(code_hash)
+                            .expect("failed to set the code hash of the contract")
+                    }
+                }
+            },
+        )?;
+        self.push_generated_message(
+            item_impl,
+            "set_code",
+            "upgradeable ink! smart contracts",
+        )
+    }
+
+    /// Adds the `migrate` ink! message stub of ink! smart contracts with a
+    /// versioned ink! storage struct.
+    ///
+    /// # Note
+    ///
+    /// Nothing is added if the version of the ink! storage struct is `0`.
+    ///
+    /// The generated ink! message runs the `migrate_from_v{N}(&mut self)` methods
+    /// that must be provided by the user for every prior version `N` in order,
+    /// starting at the given prior version of the storage up to the current version.
+    /// Migrations from the current or later versions are rejected. It is guarded by
+    /// the `ink_lang::Migrate` implementation of the ink! storage struct which must
+    /// be provided by the user as well.
+    ///
+    /// The version of the stored contract storage is not persisted by ink!.
+    ///
+    /// # Errors
+    ///
+    /// If a user defined ink! message has the same selector as the generated
+    /// `migrate` ink! message.
+    pub(crate) fn add_migrate_message(&mut self) -> Result<(), syn::Error> {
+        let storage = self.storage();
+        let version = storage.version();
+        if version == 0 {
+            return Ok(())
+        }
+        let storage_ident = storage.ident();
+        let prior_versions = 0..version;
+        let migrations = prior_versions
+            .clone()
+            .map(|prior_version| format_ident!("migrate_from_v{}", prior_version));
+        let item_impl = <ir::ItemImpl as TryFrom<syn::ItemImpl>>::try_from(
+            syn::parse_quote! {
+                impl #storage_ident {
+                    /// Migrates the storage of the contract from the given prior
+                    /// version to the current version.
+                    #[ink(message)]
+                    pub fn migrate(&mut self, from_version: u32) {
+                        if !<Self as ::ink_lang::Migrate>::can_migrate(self) {
+                            ::core::panic!("caller is not allowed to migrate the storage of the contract")
+                        }
+                        if from_version >= #version {
+                            ::core::panic!(
+                                "cannot migrate the storage of the contract from version {}",
+                                from_version,
+                            )
+                        }
+                        for step in from_version..#version {
+                            match step {
+                                #( #prior_versions => self.#migrations(), )*
+                                _ => ::core::unreachable!(),
+                            }
+                        }
+                    }
+                }
+            },
+        )?;
+        self.push_generated_message(
+            item_impl,
+            "migrate",
+            "versioned ink! storage structs",
+        )
+    }
+
+    /// Adds the implementation block of an ink! message generated by ink!.
+    ///
+    /// # Errors
+    ///
+    /// If a user defined ink! message has the same selector as the generated
+    /// ink! message named `name`.
+    fn push_generated_message(
+        &mut self,
+        item_impl: ir::ItemImpl,
+        name: &str,
+        generated_for: &str,
+    ) -> Result<(), syn::Error> {
+        let selector = item_impl
+            .iter_messages()
+            .next()
+            .map(|message| message.composed_selector())
+            .expect("missing generated ink! message");
+        if let Some(message) = self
+            .impls()
+            .flat_map(ir::ItemImpl::iter_messages)
+            .find(|message| message.composed_selector() == selector)
+        {
+            return Err(format_err!(
+                message.callable().span(),
+                "encountered ink! message with the same selector (= {:02X?}) as the \
+                 `{}` ink! message generated for {}",
+                selector.as_bytes(),
+                name,
+                generated_for,
+            ))
+        }
+        self.items.push(ir::Item::Ink(item_impl.into()));
+        Ok(())
+    }
+
+    /// Returns all (ink! and non-ink! specific) item definitions of the ink! inline module.
+    pub fn items(&self) -> &[ir::Item] {
+        self.items.as_slice()
+    }
+
+    /// Returns an iterator yielding all ink! implementation blocks.
+    ///
+    /// # Note
+    ///
+    /// An ink! implementation block can be either an inherent `impl` block
+    /// directly defined for the contract's storage struct if it includes at
+    /// least one `#[ink(message)]` or `#[ink(constructor)]` annotation, e.g.:
     ///
     /// ```
-    /// # use core::convert::TryFrom;
-    /// # use ink_lang_ir as ir;
-    /// # <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
-    /// # mod my_module {
-    /// # #[ink(storage)]
-    /// # pub struct MyStorage {
-    /// #     /* storage fields */
-    /// # }
-    /// #
-    /// impl MyStorage {
-    /// #   #[ink(constructor)]
-    /// #   pub fn my_constructor() -> Self {
-    /// #       /* constructor implementation */
-    /// #   }
-    /// #
-    ///     #[ink(message)]
-    ///     pub fn my_message(&self) {
-    ///         /* message implementation */
-    ///     }
-    /// }
-    /// # }}).unwrap();
-    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use ink_lang_ir as ir;
+    /// # <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+    /// # mod my_module {
+    /// # #[ink(storage)]
+    /// # pub struct MyStorage {
+    /// #     /* storage fields */
+    /// # }
+    /// #
+    /// impl MyStorage {
+    /// #   #[ink(constructor)]
+    /// #   pub fn my_constructor() -> Self {
+    /// #       /* constructor implementation */
+    /// #   }
+    /// #
+    ///     #[ink(message)]
+    ///     pub fn my_message(&self) {
+    ///         /* message implementation */
+    ///     }
+    /// }
+    /// # }}).unwrap();
+    /// ```
     ///
     /// Also an implementation block can be defined as a trait implementation
     /// for the ink! storage struct using the `#[ink(impl)]` annotation even
@@ -535,6 +1640,92 @@ impl<'a> Iterator for IterItemImpls<'a> {
     }
 }
 
+/// Returns the selector the given constant evaluates to.
+///
+/// # Note
+///
+/// Constants of type `u32` are interpreted as big-endian bytes the same way as
+/// integer selectors, e.g. `#[ink(selector = 0xCAFEBABE)]`.
+///
+/// # Errors
+///
+/// - If the constant is not of type `u32` or `[u8; 4]`.
+/// - If the constant is not initialized with an integer literal or an array of
+///   four integer literals respectively.
+fn selector_const_value(item_const: &syn::ItemConst) -> Result<ir::Selector, syn::Error> {
+    let ident = &item_const.ident;
+    match (&*item_const.ty, &*item_const.expr) {
+        (syn::Type::Path(type_path), expr) if type_path.path.is_ident("u32") => {
+            match expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                }) => {
+                    lit_int
+                        .base10_parse::<u32>()
+                        .map(|selector| ir::Selector::from_bytes(selector.to_be_bytes()))
+                }
+                _ => {
+                    Err(format_err_spanned!(
+                        expr,
+                        "the constant `{}` used as ink! selector must be initialized \
+                         with an integer literal",
+                        ident,
+                    ))
+                }
+            }
+        }
+        (syn::Type::Array(type_array), expr) if is_selector_bytes_type(type_array) => {
+            let bytes = match expr {
+                syn::Expr::Array(expr_array) if expr_array.elems.len() == 4 => {
+                    expr_array
+                        .elems
+                        .iter()
+                        .map(|elem| {
+                            match elem {
+                                syn::Expr::Lit(syn::ExprLit {
+                                    lit: syn::Lit::Int(lit_int),
+                                    ..
+                                }) => lit_int.base10_parse::<u8>().ok(),
+                                _ => None,
+                            }
+                        })
+                        .collect::<Option<Vec<_>>>()
+                }
+                _ => None,
+            };
+            match bytes.as_deref() {
+                Some(&[b0, b1, b2, b3]) => Ok(ir::Selector::from_bytes([b0, b1, b2, b3])),
+                _ => {
+                    Err(format_err_spanned!(
+                        expr,
+                        "the constant `{}` used as ink! selector must be initialized \
+                         with an array of four integer literals",
+                        ident,
+                    ))
+                }
+            }
+        }
+        (ty, _) => {
+            Err(format_err_spanned!(
+            ty,
+            "the constant `{}` used as ink! selector must be of type `u32` or `[u8; 4]`",
+            ident,
+        ))
+        }
+    }
+}
+
+/// Returns `true` if the given array type is `[u8; 4]`.
+fn is_selector_bytes_type(type_array: &syn::TypeArray) -> bool {
+    let is_u8 = matches!(&*type_array.elem, syn::Type::Path(type_path) if type_path.path.is_ident("u8"));
+    let is_len_4 = matches!(&type_array.len, syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit_int),
+        ..
+    }) if lit_int.base10_digits() == "4");
+    is_u8 && is_len_4
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -615,80 +1806,165 @@ mod tests {
     }
 
     #[test]
-    fn multiple_storage_struct_fails() {
+    fn empty_module_fails() {
         assert_fail(
             syn::parse_quote! {
-                mod my_module {
-                    #[ink(storage)]
-                    pub struct MyFirstStorage {}
-                    #[ink(storage)]
-                    pub struct MySecondStorage {}
-                    impl MyFirstStorage {
-                        #[ink(constructor)]
-                        pub fn my_constructor() -> Self {}
-                        #[ink(message)]
-                        pub fn my_message(&self) {}
-                    }
-                }
+                mod my_module {}
             },
-            "encountered multiple ink! storage structs, expected exactly one",
+            "contract module contains no items; expected at least one #[ink(storage)] struct",
         )
     }
 
     #[test]
-    fn missing_constructor_fails() {
+    fn manual_layout_without_impl_in_module_works() {
+        // The `StorageLayout` implementation may as well be derived or be
+        // provided outside of the ink! module.
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage, manual_layout)]
+                #[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+                pub struct MyStorage {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        assert!(<ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).is_ok())
+    }
+
+    #[test]
+    fn recursive_storage_types_fails() {
         assert_fail(
             syn::parse_quote! {
                 mod my_module {
                     #[ink(storage)]
-                    pub struct MyStorage {}
+                    pub struct MyStorage {
+                        first: First,
+                    }
+
+                    pub struct First {
+                        value: i32,
+                        second: (bool, Second),
+                    }
+
+                    pub enum Second {
+                        Leaf,
+                        Node([First; 2]),
+                    }
 
                     impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
                         #[ink(message)]
                         pub fn my_message(&self) {}
                     }
                 }
             },
-            "missing ink! constructor",
+            "encountered recursive storage type `First` -> `Second` -> `First` \
+             which cannot have a finite storage layout",
         )
     }
 
     #[test]
-    fn missing_message_fails() {
+    fn self_recursive_storage_fails() {
         assert_fail(
             syn::parse_quote! {
                 mod my_module {
                     #[ink(storage)]
-                    pub struct MyStorage {}
+                    pub struct MyStorage {
+                        inner: (Self, i32),
+                    }
 
                     impl MyStorage {
                         #[ink(constructor)]
                         pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
                     }
                 }
             },
-            "missing ink! message",
+            "encountered recursive storage type `MyStorage` -> `MyStorage` \
+             which cannot have a finite storage layout",
         )
     }
 
     #[test]
-    fn invalid_out_of_line_module_fails() {
-        assert_fail(
-            syn::parse_quote! {
-                mod my_module;
-            },
-            "out-of-line ink! modules are not supported, use `#[ink::contract] mod name { ... }`",
-        )
+    fn indirectly_recursive_storage_types_works() {
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    first: First,
+                    inner: Option<Box<MyStorage>>,
+                }
+
+                pub struct First {
+                    value: i32,
+                    second: Option<Second>,
+                }
+
+                pub enum Second {
+                    Leaf,
+                    Node(Vec<First>),
+                }
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        assert!(<ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).is_ok());
     }
 
     #[test]
-    fn conflicting_attributes_fails() {
+    fn non_recursive_shared_storage_types_works() {
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    first: Shared,
+                    second: (Shared, Wrapper),
+                }
+
+                pub struct Wrapper {
+                    shared: Shared,
+                }
+
+                pub struct Shared {
+                    value: i32,
+                }
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        assert!(<ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).is_ok());
+    }
+
+    #[test]
+    fn uninhabited_storage_field_fails() {
         assert_fail(
             syn::parse_quote! {
-                #[ink(namespace = "my_namespace")]
                 mod my_module {
                     #[ink(storage)]
-                    pub struct MyStorage {}
+                    pub struct MyStorage {
+                        value: i32,
+                        never: Never,
+                    }
+
+                    pub enum Never {}
+
                     impl MyStorage {
                         #[ink(constructor)]
                         pub fn my_constructor() -> Self {}
@@ -697,33 +1973,569 @@ mod tests {
                     }
                 }
             },
-            "encountered invalid ink! attributes on ink! module",
+            "the ink! storage struct `MyStorage` can never be constructed since \
+             the type `Never` of its field `never` is uninhabited",
         )
     }
 
     #[test]
-    fn overlapping_messages_fails() {
+    fn indirectly_uninhabited_storage_field_fails() {
         assert_fail(
             syn::parse_quote! {
                 mod my_module {
                     #[ink(storage)]
-                    pub struct MyStorage {}
+                    pub struct MyStorage {
+                        state: (i32, State),
+                    }
 
-                    impl MyStorage {
-                        #[ink(constructor)]
-                        pub fn my_constructor() -> Self {}
+                    pub enum State {
+                        Locked(Never),
+                        Closed(Wrapper),
+                    }
 
-                        #[ink(message, selector = "0xDEADBEEF")]
-                        pub fn my_message_1(&self) {}
+                    pub struct Wrapper {
+                        never: Never,
                     }
 
+                    pub enum Never {}
+
                     impl MyStorage {
-                        #[ink(message, selector = "0xDEADBEEF")]
-                        pub fn my_message_2(&self) {}
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
                     }
                 }
             },
-            "encountered ink! messages with overlapping selectors (= [DE, AD, BE, EF])\n\
+            "the ink! storage struct `MyStorage` can never be constructed since \
+             the type `(i32 , State)` of its field `state` is uninhabited",
+        )
+    }
+
+    #[test]
+    fn inhabited_storage_field_of_empty_enum_works() {
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    maybe: Option<Never>,
+                    state: State,
+                }
+
+                pub enum State {
+                    Locked(Never),
+                    Open,
+                }
+
+                pub enum Never {}
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        assert!(<ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).is_ok());
+    }
+
+    #[test]
+    fn macro_items_are_preserved() {
+        let macro_rules: syn::Item = syn::parse_quote! {
+            macro_rules! double {
+                ( $value:expr ) => { $value * 2 };
+            }
+        };
+        let invocation: syn::Item = syn::parse_quote! {
+            double!(struct Helper;);
+        };
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #macro_rules
+
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                #invocation
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).unwrap();
+        let rust_items = item_mod
+            .items()
+            .iter()
+            .filter_map(ir::Item::map_rust_item)
+            .collect::<Vec<_>>();
+        assert_eq!(rust_items, vec![&macro_rules, &invocation]);
+    }
+
+    #[test]
+    fn non_static_lifetime_in_storage_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: Option<&'a i32>,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered non-`'static` lifetime `'a` in ink! storage fields \
+             which must own all of their data",
+        )
+    }
+
+    #[test]
+    fn non_static_lifetime_in_param_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self, value: Cow<'a, str>) {}
+                    }
+                }
+            },
+            "encountered non-`'static` lifetime `'a` in ink! message parameters \
+             which must own all of their data",
+        );
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor(value: &i32) -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered non-`'static` reference with elided lifetime \
+             in ink! constructor parameters which must own all of their data",
+        );
+    }
+
+    #[test]
+    fn non_static_lifetime_in_return_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        value: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) -> &i32 {}
+                    }
+                }
+            },
+            "encountered non-`'static` reference with elided lifetime \
+             in ink! message return types which must own all of their data",
+        )
+    }
+
+    #[test]
+    fn static_lifetimes_and_slice_inputs_work() {
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {
+                    names: Vec<&'static str>,
+                }
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor(values: &[u32]) -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self, values: &[Vec<u8>]) -> &'static str {}
+                }
+            }
+        };
+        assert!(<ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod).is_ok());
+    }
+
+    #[test]
+    fn multiple_storage_struct_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyFirstStorage {}
+                    #[ink(storage)]
+                    pub struct MySecondStorage {}
+                    impl MyFirstStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered multiple ink! storage structs, expected exactly one",
+        )
+    }
+
+    #[test]
+    fn missing_message_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                    }
+                }
+            },
+            "missing ink! message",
+        )
+    }
+
+    #[test]
+    fn invalid_out_of_line_module_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module;
+            },
+            "out-of-line ink! modules are not supported, use `#[ink::contract] mod name { ... }`",
+        )
+    }
+
+    #[test]
+    fn conflicting_attributes_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                #[ink(namespace = "my_namespace")]
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered invalid ink! attributes on ink! module",
+        )
+    }
+
+    #[test]
+    fn duplicate_message_names_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn get(&self) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(message, selector = "0xDEADBEEF")]
+                        pub fn get(&self) {}
+                    }
+                }
+            },
+            "encountered ink! message with the same name `get` as another ink! message",
+        );
+    }
+
+    #[test]
+    fn duplicate_message_and_constructor_names_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn new() -> Self {}
+
+                        #[ink(message)]
+                        pub fn new(&self) {}
+                    }
+                }
+            },
+            "encountered ink! constructor with the same name `new` as another ink! message",
+        );
+    }
+
+    #[test]
+    fn storage_alias_is_resolved() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub type Storage = self::MyStorage;
+
+                    pub struct MyStorage {
+                        value: bool,
+                    }
+
+                    impl Storage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            })
+            .unwrap();
+        assert_eq!(item_mod.storage().ident(), "MyStorage");
+        assert_eq!(item_mod.storage().fields().count(), 1);
+        let alias = item_mod
+            .items()
+            .iter()
+            .find_map(|item| {
+                match item.map_rust_item() {
+                    Some(syn::Item::Type(item_type)) => Some(item_type),
+                    _ => None,
+                }
+            })
+            .unwrap();
+        assert!(alias.attrs.is_empty());
+    }
+
+    #[test]
+    fn storage_alias_to_unknown_struct_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub type Storage = other::MyStorage;
+
+                    impl Storage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "ink! storage type alias `Storage` must refer to a struct defined at the \
+             root of the ink! module",
+        );
+    }
+
+    #[test]
+    fn storage_alias_to_ink_struct_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub type Storage = MyStorage;
+
+                    #[ink(event)]
+                    pub struct MyStorage {}
+
+                    impl Storage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered ink! attributes on struct `MyStorage` that is aliased by the \
+             ink! storage type alias `Storage`",
+        );
+    }
+
+    #[test]
+    fn selector_const_is_resolved() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    const CONSTRUCTOR_SELECTOR: [u8; 4] = [0xCA, 0xFE, 0xBA, 0xBE];
+                    const MESSAGE_SELECTOR: u32 = 0xDEADBEEF;
+
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor, selector = CONSTRUCTOR_SELECTOR)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = self::MESSAGE_SELECTOR)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            })
+            .unwrap();
+        let item_impl = item_mod.impls().next().unwrap();
+        let constructor = item_impl.iter_constructors().next().unwrap();
+        let message = item_impl.iter_messages().next().unwrap();
+        assert_eq!(
+            constructor.composed_selector().as_bytes(),
+            &[0xCA, 0xFE, 0xBA, 0xBE]
+        );
+        assert_eq!(
+            message.composed_selector().as_bytes(),
+            &[0xDE, 0xAD, 0xBE, 0xEF]
+        );
+    }
+
+    #[test]
+    fn selector_const_unknown_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = MY_SELECTOR)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered unknown constant `MY_SELECTOR` for ink! selector, expected a \
+             constant defined at the root of the ink! module",
+        );
+    }
+
+    #[test]
+    fn selector_const_invalid_type_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    const MY_SELECTOR: u64 = 0xDEADBEEF;
+
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = MY_SELECTOR)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "the constant `MY_SELECTOR` used as ink! selector must be of type `u32` or `[u8; 4]`",
+        );
+    }
+
+    #[test]
+    fn selector_const_non_literal_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    const MY_SELECTOR: u32 = compute_selector();
+
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = MY_SELECTOR)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "the constant `MY_SELECTOR` used as ink! selector must be initialized \
+             with an integer literal",
+        );
+    }
+
+    #[test]
+    fn overlapping_selector_consts_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    const MY_SELECTOR: u32 = 0xDEADBEEF;
+
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = MY_SELECTOR)]
+                        pub fn my_message_1(&self) {}
+
+                        #[ink(message, selector = "0xDEADBEEF")]
+                        pub fn my_message_2(&self) {}
+                    }
+                }
+            },
+            "encountered ink! messages with overlapping selectors (= [DE, AD, BE, EF])\n\
+                hint: use #[ink(selector = \"0x...\")] on the callable or \
+                #[ink(namespace = \"...\")] on the implementation block to \
+                disambiguate overlapping selectors.",
+        );
+    }
+
+    #[test]
+    fn overlapping_messages_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, selector = "0xDEADBEEF")]
+                        pub fn my_message_1(&self) {}
+                    }
+
+                    impl MyStorage {
+                        #[ink(message, selector = "0xDEADBEEF")]
+                        pub fn my_message_2(&self) {}
+                    }
+                }
+            },
+            "encountered ink! messages with overlapping selectors (= [DE, AD, BE, EF])\n\
                 hint: use #[ink(selector = \"0x...\")] on the callable or \
                 #[ink(namespace = \"...\")] on the implementation block to \
                 disambiguate overlapping selectors.",
@@ -759,6 +2571,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multiple_wildcard_constructors_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {}
+
+                    impl MyStorage {
+                        #[ink(constructor, selector = _)]
+                        pub fn fallback_1() -> Self {}
+
+                        #[ink(constructor, selector = _)]
+                        pub fn fallback_2() -> Self {}
+
+                        #[ink(message)]
+                        pub fn my_message(&self) {}
+                    }
+                }
+            },
+            "encountered multiple ink! constructors with a wildcard selector",
+        );
+    }
+
+    #[test]
+    fn duplicate_event_names_fails() {
+        let item_mod: syn::ItemMod = syn::parse_quote! {
+            mod my_module {
+                #[ink(storage)]
+                pub struct MyStorage {}
+
+                #[ink(event)]
+                pub struct Transfer {
+                    value: i32,
+                }
+
+                #[ink(event)]
+                pub struct Transfer {
+                    #[ink(topic)]
+                    value: i32,
+                }
+
+                impl MyStorage {
+                    #[ink(constructor)]
+                    pub fn my_constructor() -> Self {}
+                    #[ink(message)]
+                    pub fn my_message(&self) {}
+                }
+            }
+        };
+        let errors = <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(item_mod)
+            .map(|_| ())
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "encountered ink! event with the same name `Transfer` as another ink! event",
+                "first ink! event named `Transfer` here",
+            ],
+        );
+    }
+
     #[test]
     fn overlapping_trait_impls_fails() {
         assert_fail(
@@ -835,4 +2712,138 @@ mod tests {
             .is_ok()
         );
     }
+
+    #[test]
+    fn getter_output_is_resolved() {
+        let item_mod =
+            <ir::ItemMod as TryFrom<syn::ItemMod>>::try_from(syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        my_field: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, getter = "my_field")]
+                        pub fn my_field(&self) {}
+                    }
+                }
+            })
+            .unwrap();
+        let message = item_mod
+            .impls()
+            .flat_map(|item_impl| item_impl.iter_messages())
+            .next()
+            .unwrap();
+        let expected_output: syn::Type = syn::parse_quote! { i32 };
+        assert_eq!(message.callable().output(), Some(&expected_output));
+    }
+
+    #[test]
+    fn getter_unknown_field_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        my_field: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, getter = "other_field")]
+                        pub fn other_field(&self) -> i32 {}
+                    }
+                }
+            },
+            "encountered ink! getter for unknown storage field `other_field` of `MyStorage`",
+        );
+    }
+
+    #[test]
+    fn getter_mismatching_output_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        my_field: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, getter = "my_field")]
+                        pub fn my_field(&self) -> bool {}
+                    }
+                }
+            },
+            "the return type of an ink! getter message must match the type \
+             of its storage field `i32`",
+        );
+    }
+
+    #[test]
+    fn getter_conflicting_method_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub struct MyStorage {
+                        my_field: i32,
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        pub fn my_field(&self) -> i32 {
+                            self.my_field
+                        }
+
+                        #[ink(message, getter = "my_field")]
+                        pub fn my_field(&self) -> i32 {}
+                    }
+                }
+            },
+            "encountered ink! getter message `my_field` that conflicts with an existing method of the same name",
+        );
+    }
+
+    #[test]
+    fn getter_conflicting_method_of_storage_alias_fails() {
+        assert_fail(
+            syn::parse_quote! {
+                mod my_module {
+                    #[ink(storage)]
+                    pub type Storage = MyStorage;
+
+                    pub struct MyStorage {
+                        my_field: i32,
+                    }
+
+                    impl Storage {
+                        pub fn my_field(&self) -> i32 {
+                            self.my_field
+                        }
+                    }
+
+                    impl MyStorage {
+                        #[ink(constructor)]
+                        pub fn my_constructor() -> Self {}
+
+                        #[ink(message, getter = "my_field")]
+                        pub fn my_field(&self) -> i32 {}
+                    }
+                }
+            },
+            "encountered ink! getter message `my_field` that conflicts with an existing method of the same name",
+        );
+    }
 }
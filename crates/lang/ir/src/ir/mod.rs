@@ -14,6 +14,8 @@
 
 #![allow(dead_code)]
 
+mod abi_diff;
+mod applicability;
 mod attrs;
 mod blake2;
 mod chain_extension;
@@ -24,35 +26,59 @@ mod ink_test;
 mod item;
 mod item_impl;
 mod item_mod;
+mod keccak;
 mod selector;
+mod storage_access;
 mod trait_def;
 pub mod utils;
 
 #[cfg(test)]
 use self::attrs::Attribute;
 
-use self::attrs::{
-    contains_ink_attributes,
-    first_ink_attribute,
-    partition_attributes,
-    sanitize_attributes,
-    AttributeArg,
-    AttributeArgKind,
-    AttributeFrag,
-    InkAttribute,
+use self::{
+    applicability::{
+        ensure_applicable,
+        ensure_valid_combinations,
+        AttributeTarget,
+    },
+    attrs::{
+        contains_ink_attributes,
+        first_ink_attribute,
+        partition_attributes,
+        sanitize_attributes,
+        AttributeArg,
+        AttributeArgKind,
+        AttributeFrag,
+        InkAttribute,
+    },
 };
 pub use self::{
-    attrs::Namespace,
+    abi_diff::{
+        AbiChange,
+        AbiDiff,
+        AbiItemKind,
+        AbiModification,
+        AbiSignature,
+    },
+    attrs::{
+        Namespace,
+        StorageKey,
+    },
     chain_extension::{
         ChainExtension,
         ChainExtensionMethod,
         ExtensionId,
     },
-    config::Config,
+    config::{
+        Allocator,
+        Config,
+        DispatchStrategy,
+    },
     contract::Contract,
     ink_test::InkTest,
     item::{
         Event,
+        EventInstance,
         InkItem,
         Item,
         Storage,
@@ -76,7 +102,11 @@ pub use self::{
         IterEvents,
         IterItemImpls,
     },
-    selector::Selector,
+    selector::{
+        Selector,
+        SelectorHash,
+    },
+    storage_access::StorageAccess,
     trait_def::{
         InkTrait,
         InkTraitConstructor,
@@ -12,7 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::blake2::blake2b_256;
+use super::{
+    blake2::blake2b_256,
+    keccak::keccak_256,
+};
+use crate::ir;
+use proc_macro2::Ident;
+use quote::ToTokens as _;
+use syn::ext::IdentExt as _;
+
+/// The hash primitives that can be used to compute selectors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SelectorHash {
+    /// The BLAKE-2 256-bit hash which is the default.
+    Blake2x256,
+    /// The KECCAK 256-bit hash.
+    Keccak256,
+}
+
+impl SelectorHash {
+    /// The names of all supported selector hashes as used in the ink! config.
+    pub(crate) const NAMES: &'static [&'static str] = &["blake2_256", "keccak_256"];
+
+    /// Returns the selector hash for the given name if it is supported.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "blake2_256" => Some(Self::Blake2x256),
+            "keccak_256" => Some(Self::Keccak256),
+            _ => None,
+        }
+    }
+}
 
 /// A function selector.
 ///
@@ -32,11 +62,63 @@ impl Selector {
 
     /// Computes the BLAKE-2 256-bit based selector from the given input bytes.
     pub fn new(input: &[u8]) -> Self {
+        Self::with_hash(input, SelectorHash::Blake2x256)
+    }
+
+    /// Computes the selector from the given input bytes using the given hash.
+    pub fn with_hash(input: &[u8], hash: SelectorHash) -> Self {
         let mut output = [0; 32];
-        blake2b_256(input, &mut output);
+        match hash {
+            SelectorHash::Blake2x256 => blake2b_256(input, &mut output),
+            SelectorHash::Keccak256 => keccak_256(input, &mut output),
+        }
         Self::from_bytes([output[0], output[1], output[2], output[3]])
     }
 
+    /// Composes the selector of an ink! message or constructor from its identifier
+    /// using the given hash.
+    ///
+    /// # Note
+    ///
+    /// The identifier is prefixed with the trait path of its ink! trait implementation
+    /// block, the namespace of its implementation block and the namespace of its ink!
+    /// smart contract if any, e.g. `contract_ns::impl_ns::MyTrait::my_message`.
+    /// Raw identifiers such as `r#type` are composed without their `r#` prefix.
+    pub fn compose(
+        contract_namespace: Option<&ir::Namespace>,
+        namespace: Option<&ir::Namespace>,
+        trait_path: Option<&syn::Path>,
+        ident: &Ident,
+        hash: SelectorHash,
+    ) -> Self {
+        let ident_bytes = ident.unraw().to_string().into_bytes();
+        let trait_bytes = trait_path.map(|path| {
+            // We need to separate between full-path, e.g. `::my::full::Path`
+            // starting with `::` and relative paths for the composition.
+            if path.leading_colon.is_some() {
+                let mut str_repr = path.to_token_stream().to_string();
+                str_repr.retain(|c| !c.is_whitespace());
+                str_repr.into_bytes()
+            } else {
+                path.segments
+                    .last()
+                    .expect("encountered empty trait path")
+                    .ident
+                    .to_string()
+                    .into_bytes()
+            }
+        });
+        let joined = contract_namespace
+            .map(|namespace| namespace.as_bytes().to_vec())
+            .into_iter()
+            .chain(namespace.map(|namespace| namespace.as_bytes().to_vec()))
+            .chain(trait_bytes)
+            .chain(Some(ident_bytes))
+            .collect::<Vec<_>>()
+            .join(&b"::"[..]);
+        Self::with_hash(&joined, hash)
+    }
+
     /// Returns the underlying four bytes.
     pub fn as_bytes(&self) -> &[u8; 4] {
         &self.bytes
@@ -53,3 +135,22 @@ impl From<[u8; 4]> for Selector {
         Self::from_bytes(bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_hash_works() {
+        // The first four bytes of the KECCAK 256-bit hash of `transfer(address,uint256)`.
+        assert_eq!(
+            Selector::with_hash(b"transfer(address,uint256)", SelectorHash::Keccak256)
+                .as_bytes(),
+            &[0xA9, 0x05, 0x9C, 0xBB],
+        );
+        assert_eq!(
+            Selector::with_hash(b"get", SelectorHash::Blake2x256),
+            Selector::new(b"get"),
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use std::collections::BTreeSet;
+use syn::ext::IdentExt as _;
+
+/// The storage fields read and written by an ink! message.
+///
+/// # Note
+///
+/// This is computed by a best-effort syntactic analysis of the `self.<field>`
+/// expressions found in the body of the ink! message and therefore only
+/// approximate:
+///
+/// - Assignments to a field, e.g. `self.a = x`, `self.a.b = x` or
+///   `self.a[i] = x`, count as writes.
+/// - Compound assignments, e.g. `self.a += x`, and `&mut self.a` borrows count
+///   as both reads and writes.
+/// - Method calls on a field, e.g. `self.a.insert(x)`, count as reads and, for
+///   `&mut self` ink! messages, conservatively also as writes.
+/// - All other field expressions count as reads, including those found in the
+///   tokens of macro invocations.
+///
+/// Accesses performed indirectly via calls to other methods, e.g.
+/// `self.helper()`, are not tracked.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StorageAccess {
+    /// The names of the read storage fields.
+    reads: BTreeSet<String>,
+    /// The names of the written storage fields.
+    writes: BTreeSet<String>,
+}
+
+impl StorageAccess {
+    /// Analyses the given body of an ink! message.
+    ///
+    /// Method calls on fields are treated as writes if `mutable` is `true`.
+    pub(crate) fn from_block(block: &syn::Block, mutable: bool) -> Self {
+        let mut visitor = private::AccessVisitor {
+            access: Default::default(),
+            mutable,
+        };
+        syn::visit::Visit::visit_block(&mut visitor, block);
+        visitor.access
+    }
+
+    /// Records a read of the given storage field.
+    pub(crate) fn push_read(&mut self, field: &Ident) {
+        self.reads.insert(field.unraw().to_string());
+    }
+
+    /// Records a write of the given storage field.
+    pub(crate) fn push_write(&mut self, field: &Ident) {
+        self.writes.insert(field.unraw().to_string());
+    }
+
+    /// Returns the names of the read storage fields in alphabetical order.
+    pub fn reads(&self) -> impl Iterator<Item = &str> {
+        self.reads.iter().map(String::as_str)
+    }
+
+    /// Returns the names of the written storage fields in alphabetical order.
+    pub fn writes(&self) -> impl Iterator<Item = &str> {
+        self.writes.iter().map(String::as_str)
+    }
+
+    /// Records reads for all `self.<field>` token sequences in the given tokens.
+    fn push_token_reads(&mut self, tokens: TokenStream2) {
+        let tokens = tokens.into_iter().collect::<Vec<_>>();
+        for (index, token) in tokens.iter().enumerate() {
+            match (token, tokens.get(index + 1), tokens.get(index + 2)) {
+                (
+                    TokenTree::Ident(this),
+                    Some(TokenTree::Punct(dot)),
+                    Some(TokenTree::Ident(field)),
+                ) if this == "self" && dot.as_char() == '.' => self.push_read(field),
+                (TokenTree::Group(group), _, _) => self.push_token_reads(group.stream()),
+                _ => (),
+            }
+        }
+    }
+}
+
+mod private {
+    use super::StorageAccess;
+    use proc_macro2::Ident;
+
+    /// Visitor collecting the storage fields accessed via `self.<field>`.
+    pub struct AccessVisitor {
+        pub access: StorageAccess,
+        pub mutable: bool,
+    }
+
+    /// Returns the storage field of the place expression if it is based on
+    /// `self.<field>`, e.g. `self.a`, `self.a.b` or `self.a[i]`.
+    fn base_field(expr: &syn::Expr) -> Option<&Ident> {
+        match expr {
+            syn::Expr::Field(expr_field) => {
+                match (&*expr_field.base, &expr_field.member) {
+                    (syn::Expr::Path(path), syn::Member::Named(field))
+                        if path.path.is_ident("self") =>
+                    {
+                        Some(field)
+                    }
+                    (base, _) => base_field(base),
+                }
+            }
+            syn::Expr::Index(expr_index) => base_field(&expr_index.expr),
+            syn::Expr::Paren(expr_paren) => base_field(&expr_paren.expr),
+            _ => None,
+        }
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for AccessVisitor {
+        fn visit_expr_assign(&mut self, expr: &'ast syn::ExprAssign) {
+            match base_field(&expr.left) {
+                Some(field) => self.access.push_write(field),
+                None => self.visit_expr(&expr.left),
+            }
+            self.visit_expr(&expr.right);
+        }
+
+        fn visit_expr_assign_op(&mut self, expr: &'ast syn::ExprAssignOp) {
+            if let Some(field) = base_field(&expr.left) {
+                self.access.push_write(field);
+            }
+            self.visit_expr(&expr.left);
+            self.visit_expr(&expr.right);
+        }
+
+        fn visit_expr_reference(&mut self, expr: &'ast syn::ExprReference) {
+            if expr.mutability.is_some() {
+                if let Some(field) = base_field(&expr.expr) {
+                    self.access.push_write(field);
+                }
+            }
+            self.visit_expr(&expr.expr);
+        }
+
+        fn visit_expr_method_call(&mut self, expr: &'ast syn::ExprMethodCall) {
+            if self.mutable {
+                if let Some(field) = base_field(&expr.receiver) {
+                    self.access.push_write(field);
+                }
+            }
+            syn::visit::visit_expr_method_call(self, expr);
+        }
+
+        fn visit_expr_field(&mut self, expr: &'ast syn::ExprField) {
+            match (&*expr.base, &expr.member) {
+                (syn::Expr::Path(path), syn::Member::Named(field))
+                    if path.path.is_ident("self") =>
+                {
+                    self.access.push_read(field)
+                }
+                _ => syn::visit::visit_expr_field(self, expr),
+            }
+        }
+
+        fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+            self.access.push_token_reads(mac.tokens.clone());
+        }
+
+        fn visit_item(&mut self, _item: &'ast syn::Item) {
+            // Nested items cannot refer to the `self` of the ink! message.
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_access(block: syn::Block, mutable: bool, reads: &[&str], writes: &[&str]) {
+        let access = StorageAccess::from_block(&block, mutable);
+        assert_eq!(access.reads().collect::<Vec<_>>(), reads);
+        assert_eq!(access.writes().collect::<Vec<_>>(), writes);
+    }
+
+    #[test]
+    fn read_one_write_other_works() {
+        assert_access(
+            syn::parse_quote! {{
+                self.value = self.step;
+            }},
+            true,
+            &["step"],
+            &["value"],
+        );
+    }
+
+    #[test]
+    fn compound_and_nested_places_work() {
+        assert_access(
+            syn::parse_quote! {{
+                self.count += 1;
+                self.point.x = 0;
+                self.items[0] = self.fallback.clone();
+                let _ = &mut self.cache;
+            }},
+            false,
+            &["cache", "count", "fallback"],
+            &["cache", "count", "items", "point"],
+        );
+    }
+
+    #[test]
+    fn method_calls_work() {
+        let block: syn::Block = syn::parse_quote! {{
+            self.balances.insert(owner, self.total.get() + 1);
+            self.helper();
+        }};
+        assert_access(block.clone(), false, &["balances", "total"], &[]);
+        assert_access(block, true, &["balances", "total"], &["balances", "total"]);
+    }
+
+    #[test]
+    fn macros_and_nested_items_work() {
+        assert_access(
+            syn::parse_quote! {{
+                ink_env::debug_println!("value = {}", (self.value));
+                fn helper(other: &Other) -> u32 {
+                    other.ignored
+                }
+                other.ignored
+            }},
+            false,
+            &["value"],
+            &[],
+        );
+    }
+}
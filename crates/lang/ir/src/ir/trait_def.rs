@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    ast,
     ir,
     ir::idents_lint,
 };
@@ -31,6 +32,10 @@ use syn::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct InkTrait {
     item: syn::ItemTrait,
+    /// The namespace prefixing the selectors of the call builder if any.
+    namespace: Option<ir::Namespace>,
+    /// The hash primitive used to compute the selectors of the call builder.
+    selector_hash: ir::SelectorHash,
 }
 
 impl TryFrom<syn::ItemTrait> for InkTrait {
@@ -40,7 +45,11 @@ impl TryFrom<syn::ItemTrait> for InkTrait {
         idents_lint::ensure_no_ink_identifiers(&item_trait)?;
         Self::analyse_properties(&item_trait)?;
         Self::analyse_items(&item_trait)?;
-        Ok(Self { item: item_trait })
+        Ok(Self {
+            item: item_trait,
+            namespace: None,
+            selector_hash: ir::SelectorHash::Blake2x256,
+        })
     }
 }
 
@@ -276,15 +285,136 @@ impl<'a> InkTraitMessage<'a> {
 
 impl InkTrait {
     /// Returns `Ok` if the trait matches all requirements for an ink! trait definition.
+    ///
+    /// # Note
+    ///
+    /// The supported attribute arguments are `namespace = "..."` and `hash = "..."`
+    /// which determine the selectors of the call builder of the ink! trait definition.
     pub fn new(attr: TokenStream2, input: TokenStream2) -> Result<Self> {
-        if !attr.is_empty() {
-            return Err(format_err_spanned!(
-                attr,
-                "unexpected attribute input for ink! trait definition"
-            ))
-        }
+        let args = syn::parse2::<ast::AttributeArgs>(attr)?;
         let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
-        InkTrait::try_from(item_trait)
+        let mut ink_trait = InkTrait::try_from(item_trait)?;
+        ink_trait.parse_args(args)?;
+        Ok(ink_trait)
+    }
+
+    /// Parses the `namespace` and `hash` arguments of the ink! trait definition.
+    ///
+    /// # Errors
+    ///
+    /// If an argument is duplicated, malformed or unknown.
+    fn parse_args(&mut self, args: ast::AttributeArgs) -> Result<()> {
+        use crate::error::ExtError as _;
+        let mut namespace: Option<ast::MetaNameValue> = None;
+        let mut hash: Option<ast::MetaNameValue> = None;
+        for arg in args {
+            let (name, first) = if arg.name.is_ident("namespace") {
+                ("namespace", &mut namespace)
+            } else if arg.name.is_ident("hash") {
+                ("hash", &mut hash)
+            } else {
+                return Err(format_err_spanned!(
+                    arg,
+                    "unexpected attribute input for ink! trait definition"
+                ))
+            };
+            if let Some(fst) = first {
+                return Err(format_err_spanned!(
+                    arg,
+                    "encountered duplicate ink! `{}` trait definition argument",
+                    name,
+                )
+                .into_combine(format_err_spanned!(
+                    fst,
+                    "first `{}` trait definition argument here",
+                    name,
+                )))
+            }
+            let lit_str = match &arg.value {
+                ast::PathOrLit::Lit(syn::Lit::Str(lit_str)) => lit_str,
+                _ => {
+                    return Err(format_err_spanned!(
+                    arg,
+                    "expected a string literal for `{}` ink! trait definition argument",
+                    name,
+                ))
+                }
+            };
+            if name == "namespace" {
+                let bytes = lit_str.value().into_bytes();
+                if bytes.is_empty() {
+                    return Err(format_err_spanned!(
+                        lit_str,
+                        "expected a non-empty string literal for `namespace` ink! trait \
+                         definition argument",
+                    ))
+                }
+                self.namespace = Some(ir::Namespace::from(bytes));
+            } else {
+                self.selector_hash = ir::SelectorHash::from_name(&lit_str.value())
+                    .ok_or_else(|| {
+                        format_err_spanned!(
+                            lit_str,
+                            "unsupported ink! selector hash {:?}, supported hashes are: {}",
+                            lit_str.value(),
+                            ir::SelectorHash::NAMES.join(", "),
+                        )
+                    })?;
+            }
+            *first = Some(arg);
+        }
+        Ok(())
+    }
+
+    /// Returns the ink! messages of the call builder of the ink! trait definition
+    /// together with their selectors.
+    ///
+    /// # Note
+    ///
+    /// The selectors are composed as for an ink! trait implementation block of an
+    /// ink! smart contract with the `namespace` and `hash` of the ink! trait definition.
+    /// The `namespace` stands for the namespace of the ink! smart contract followed by
+    /// the one of its implementation block, e.g. `namespace = "vault::flipping"` for
+    /// `#[ink::contract(namespace = "vault")]` and `#[ink(namespace = "flipping")]`.
+    ///
+    /// Generic ink! trait definitions have no call builder. ink! messages whose inputs
+    /// or output refer to `Self`, e.g. to one of the associated types of the trait, are
+    /// skipped since their types are chosen by the called ink! smart contract.
+    pub fn call_builder_messages(&self) -> Vec<(InkTraitMessage, ir::Selector)> {
+        if !self.item.generics.params.is_empty() {
+            return Vec::new()
+        }
+        struct SelfTypeVisitor {
+            found: bool,
+        }
+
+        impl<'ast> syn::visit::Visit<'ast> for SelfTypeVisitor {
+            fn visit_path(&mut self, path: &'ast syn::Path) {
+                match path.segments.first() {
+                    Some(segment) if segment.ident == "Self" => self.found = true,
+                    _ => syn::visit::visit_path(self, path),
+                }
+            }
+        }
+
+        let refers_to_self = |message: &InkTraitMessage| {
+            let mut visitor = SelfTypeVisitor { found: false };
+            for input in &message.sig().inputs {
+                if let syn::FnArg::Typed(pat_type) = input {
+                    syn::visit::Visit::visit_type(&mut visitor, &pat_type.ty);
+                }
+            }
+            syn::visit::Visit::visit_return_type(&mut visitor, &message.sig().output);
+            visitor.found
+        };
+        self.iter_items()
+            .zip(self.selectors(self.namespace.as_ref(), None, self.selector_hash))
+            .filter_map(|(item, (_, selector))| {
+                item.filter_map_message()
+                    .filter(|message| !refers_to_self(message))
+                    .map(|message| (message, selector))
+            })
+            .collect()
     }
 
     /// Returns span of the ink! trait definition.
@@ -302,18 +432,88 @@ impl InkTrait {
         &self.item.ident
     }
 
+    /// Returns the generics of the ink! trait definition.
+    ///
+    /// # Note
+    ///
+    /// ink! trait definitions may only be generic over types which are bound
+    /// by the `impl` blocks of the implementing ink! smart contracts.
+    pub fn generics(&self) -> &syn::Generics {
+        &self.item.generics
+    }
+
+    /// Returns an iterator yielding the associated constants of the ink! trait definition.
+    pub fn iter_consts(&self) -> impl Iterator<Item = &syn::TraitItemConst> {
+        self.item.items.iter().filter_map(|item| {
+            match item {
+                syn::TraitItem::Const(const_item) => Some(const_item),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns an iterator yielding the associated types of the ink! trait definition.
+    ///
+    /// # Note
+    ///
+    /// Every implementer of the ink! trait must define all of them.
+    pub fn iter_types(&self) -> impl Iterator<Item = &syn::TraitItemType> {
+        self.item.items.iter().filter_map(|item| {
+            match item {
+                syn::TraitItem::Type(type_item) => Some(type_item),
+                _ => None,
+            }
+        })
+    }
+
     /// Returns an iterator yielding the ink! specific items of the ink! trait definition.
     pub fn iter_items(&self) -> IterInkTraitItems {
         IterInkTraitItems::new(self)
     }
 
+    /// Returns the identifiers and selectors of all ink! constructors and
+    /// ink! messages of the ink! trait definition in order of their declaration.
+    ///
+    /// # Note
+    ///
+    /// The selectors are composed the same way as for the ink! trait implementation
+    /// blocks of an ink! smart contract with the given contract namespace, namespace
+    /// of the implementation block and selector hash, e.g. `MyTrait::my_message`
+    /// without any namespace.
+    pub fn selectors(
+        &self,
+        contract_namespace: Option<&ir::Namespace>,
+        namespace: Option<&ir::Namespace>,
+        hash: ir::SelectorHash,
+    ) -> Vec<(&Ident, ir::Selector)> {
+        let trait_path = syn::Path::from(self.ident().clone());
+        self.iter_items()
+            .map(|item| {
+                match item {
+                    InkTraitItem::Constructor(constructor) => &constructor.item.sig.ident,
+                    InkTraitItem::Message(message) => &message.item.sig.ident,
+                }
+            })
+            .map(|ident| {
+                let selector = ir::Selector::compose(
+                    contract_namespace,
+                    namespace,
+                    Some(&trait_path),
+                    ident,
+                    hash,
+                );
+                (ident, selector)
+            })
+            .collect()
+    }
+
     /// Analyses the properties of the ink! trait definition.
     ///
     /// # Errors
     ///
     /// - If the trait has been defined as `unsafe`.
     /// - If the trait is an automatically implemented trait (`auto trait`).
-    /// - If the trait is generic over some set of types.
+    /// - If the trait is generic over lifetimes or constants.
     /// - If the trait's visibility is not public (`pub`).
     fn analyse_properties(item_trait: &syn::ItemTrait) -> Result<()> {
         if let Some(unsafety) = &item_trait.unsafety {
@@ -328,11 +528,22 @@ impl InkTrait {
                 "ink! trait definitions cannot be automatically implemented traits"
             ))
         }
-        if !item_trait.generics.params.is_empty() {
-            return Err(format_err_spanned!(
-                item_trait.generics.params,
-                "ink! trait definitions must not be generic"
-            ))
+        for param in &item_trait.generics.params {
+            match param {
+                syn::GenericParam::Type(_) => (),
+                syn::GenericParam::Lifetime(lifetime) => {
+                    return Err(format_err_spanned!(
+                        lifetime,
+                        "ink! trait definitions must not be generic over lifetimes"
+                    ))
+                }
+                syn::GenericParam::Const(const_param) => {
+                    return Err(format_err_spanned!(
+                        const_param,
+                        "ink! trait definitions must not be generic over constants"
+                    ))
+                }
+            }
         }
         if !matches!(item_trait.vis, syn::Visibility::Public(_)) {
             return Err(format_err_spanned!(
@@ -354,26 +565,29 @@ impl InkTrait {
     /// # Errors
     ///
     /// - If the trait contains an unsupported trait item such as
-    ///     - associated constants (`const`)
-    ///     - associated types (`type`)
     ///     - macros definitions or usages
     ///     - unknown token sequences (verbatims)
     ///     - methods with default implementations
     /// - If the trait contains methods which do not respect the ink! trait definition requirements:
     ///     - All trait methods need to be declared as either `#[ink(message)]` or `#[ink(constructor)]`
     ///       and need to respect their respective rules.
+    /// - If the trait contains associated constants without a concrete type.
+    /// - If the trait contains associated types that are generic, have a default
+    ///   or whose bounds refer to lifetimes.
     ///
     /// # Note
     ///
-    /// Associated types and constants might be allowed in the future.
+    /// Methods with default implementations cannot be supported by the current
+    /// design: the `#[ink::contract]` macro only sees the implementation block of
+    /// an ink! trait and therefore cannot know about messages that an implementer
+    /// omits in favour of a default implementation, so it could neither compute
+    /// their selectors nor dispatch to them. Also ink! trait messages return an
+    /// associated output type that is only defined by the implementer.
     fn analyse_items(item_trait: &syn::ItemTrait) -> Result<()> {
         for trait_item in &item_trait.items {
             match trait_item {
                 syn::TraitItem::Const(const_trait_item) => {
-                    return Err(format_err_spanned!(
-                        const_trait_item,
-                        "associated constants in ink! trait definitions are not supported, yet"
-                    ))
+                    Self::analyse_const(const_trait_item)?;
                 }
                 syn::TraitItem::Macro(macro_trait_item) => {
                     return Err(format_err_spanned!(
@@ -382,10 +596,7 @@ impl InkTrait {
                     ))
                 }
                 syn::TraitItem::Type(type_trait_item) => {
-                    return Err(format_err_spanned!(
-                    type_trait_item,
-                    "associated types in ink! trait definitions are not supported, yet"
-                ))
+                    Self::analyse_type(type_trait_item)?;
                 }
                 syn::TraitItem::Verbatim(verbatim) => {
                     return Err(format_err_spanned!(
@@ -407,16 +618,132 @@ impl InkTrait {
         Ok(())
     }
 
+    /// Analyses an associated constant of the ink! trait definition.
+    ///
+    /// # Errors
+    ///
+    /// - If the associated constant has ink! attributes.
+    /// - If the type of the associated constant is not concrete, e.g. if it
+    ///   refers to `Self`, is inferred (`_`) or is an `impl Trait` type.
+    fn analyse_const(const_item: &syn::TraitItemConst) -> Result<()> {
+        if ir::contains_ink_attributes(&const_item.attrs) {
+            return Err(format_err_spanned!(
+                const_item,
+                "ink! attributes are not supported on associated constants of ink! trait definitions"
+            ))
+        }
+        #[derive(Default)]
+        struct ConcreteTypeVisitor {
+            found: Option<Span>,
+        }
+
+        impl<'ast> syn::visit::Visit<'ast> for ConcreteTypeVisitor {
+            fn visit_type(&mut self, ty: &'ast syn::Type) {
+                if self.found.is_some() {
+                    return
+                }
+                match ty {
+                    syn::Type::Infer(_) | syn::Type::ImplTrait(_) => {
+                        self.found = Some(ty.span())
+                    }
+                    _ => syn::visit::visit_type(self, ty),
+                }
+            }
+
+            fn visit_ident(&mut self, ident: &'ast Ident) {
+                if self.found.is_none() && ident == "Self" {
+                    self.found = Some(ident.span())
+                }
+            }
+        }
+
+        let mut visitor = ConcreteTypeVisitor::default();
+        syn::visit::Visit::visit_type(&mut visitor, &const_item.ty);
+        if let Some(span) = visitor.found {
+            return Err(format_err!(
+                span,
+                "associated constants in ink! trait definitions must have a concrete type"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Analyses an associated type of the ink! trait definition.
+    ///
+    /// # Errors
+    ///
+    /// - If the associated type has ink! attributes.
+    /// - If the associated type is generic or has a default.
+    /// - If the bounds of the associated type refer to lifetimes.
+    fn analyse_type(type_item: &syn::TraitItemType) -> Result<()> {
+        if ir::contains_ink_attributes(&type_item.attrs) {
+            return Err(format_err_spanned!(
+                type_item,
+                "ink! attributes are not supported on associated types of ink! trait definitions"
+            ))
+        }
+        if !type_item.generics.params.is_empty()
+            || type_item.generics.where_clause.is_some()
+        {
+            return Err(format_err_spanned!(
+                type_item.generics,
+                "generic associated types in ink! trait definitions are not supported"
+            ))
+        }
+        if let Some((_, default)) = &type_item.default {
+            return Err(format_err_spanned!(
+                default,
+                "associated types in ink! trait definitions cannot have a default"
+            ))
+        }
+        #[derive(Default)]
+        struct LifetimeVisitor {
+            found: Option<Span>,
+        }
+
+        impl<'ast> syn::visit::Visit<'ast> for LifetimeVisitor {
+            fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+                if self.found.is_none() {
+                    self.found = Some(lifetime.span())
+                }
+            }
+        }
+
+        let mut visitor = LifetimeVisitor::default();
+        for bound in &type_item.bounds {
+            syn::visit::Visit::visit_type_param_bound(&mut visitor, bound);
+        }
+        if let Some(span) = visitor.found {
+            return Err(format_err!(
+                span,
+                "the bounds of associated types in ink! trait definitions must not refer to lifetimes"
+            ))
+        }
+        Ok(())
+    }
+
     /// Analyses an ink! method that can be either an ink! message or constructor.
     ///
     /// # Errors
     ///
+    /// - If the method is neither flagged as ink! message nor as ink! constructor.
     /// - If the method declared as `unsafe`, `const` or `async`.
     /// - If the method has some explicit API.
     /// - If the method is variadic or has generic parameters.
     /// - If the method does not respect the properties of either an
     ///   ink! message or ink! constructor.
     fn analyse_methods(method: &syn::TraitItemMethod) -> Result<()> {
+        let ink_attr = match ir::first_ink_attribute(&method.attrs)? {
+            Some(ink_attr) => ink_attr,
+            None => {
+                return Err(format_err_spanned!(
+                    method.sig,
+                    "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method `{}`: \
+                     ink! trait definitions must only contain ink! messages and constructors",
+                    method.sig.ident,
+                ))
+            }
+        };
         if let Some(default_impl) = &method.default {
             return Err(format_err_spanned!(
                 default_impl,
@@ -459,30 +786,19 @@ impl InkTrait {
                 "generic ink! trait methods are not supported"
             ))
         }
-        match ir::first_ink_attribute(&method.attrs) {
-            Ok(Some(ink_attr)) => {
-                match ink_attr.first().kind() {
-                    ir::AttributeArg::Message => {
-                        Self::analyse_message(method)?;
-                    }
-                    ir::AttributeArg::Constructor => {
-                        Self::analyse_constructor(method)?;
-                    }
-                    _unsupported => {
-                        return Err(format_err_spanned!(
-                            method,
-                            "encountered unsupported ink! attribute for ink! trait method",
-                        ))
-                    }
-                }
+        match ink_attr.first().kind() {
+            ir::AttributeArg::Message => {
+                Self::analyse_message(method)?;
             }
-            Ok(None) => {
+            ir::AttributeArg::Constructor => {
+                Self::analyse_constructor(method)?;
+            }
+            _unsupported => {
                 return Err(format_err_spanned!(
                     method,
-                    "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method"
+                    "encountered unsupported ink! attribute for ink! trait method",
                 ))
             }
-            Err(err) => return Err(err),
         }
         Ok(())
     }
@@ -625,10 +941,22 @@ mod tests {
     }
 
     #[test]
-    fn generic_trait_def_is_denied() {
+    fn generic_trait_def_over_lifetimes_is_denied() {
+        assert_ink_trait_eq_err!(
+            error: "ink! trait definitions must not be generic over lifetimes",
+            pub trait MyTrait<'a> {}
+        );
         assert_ink_trait_eq_err!(
-            error: "ink! trait definitions must not be generic",
-            pub trait MyTrait<T> {}
+            error: "ink! trait definitions must not be generic over lifetimes",
+            pub trait MyTrait<T, 'a> {}
+        );
+    }
+
+    #[test]
+    fn generic_trait_def_over_constants_is_denied() {
+        assert_ink_trait_eq_err!(
+            error: "ink! trait definitions must not be generic over constants",
+            pub trait MyTrait<const N: usize> {}
         );
     }
 
@@ -641,23 +969,71 @@ mod tests {
     }
 
     #[test]
-    fn trait_def_containing_const_item_is_denied() {
+    fn trait_def_containing_non_concrete_const_item_is_denied() {
+        assert_ink_trait_eq_err!(
+            error: "associated constants in ink! trait definitions must have a concrete type",
+            pub trait MyTrait {
+                const T: Self;
+            }
+        );
         assert_ink_trait_eq_err!(
-            error: "associated constants in ink! trait definitions are not supported, yet",
+            error: "associated constants in ink! trait definitions must have a concrete type",
             pub trait MyTrait {
+                const T: [Option<Self>; 2];
+            }
+        );
+        assert_ink_trait_eq_err!(
+            error: "associated constants in ink! trait definitions must have a concrete type",
+            pub trait MyTrait {
+                const T: _;
+            }
+        );
+    }
+
+    #[test]
+    fn trait_def_containing_const_item_with_ink_attribute_is_denied() {
+        assert_ink_trait_eq_err!(
+            error: "ink! attributes are not supported on associated constants of ink! trait definitions",
+            pub trait MyTrait {
+                #[ink(message)]
                 const T: i32;
             }
         );
     }
 
     #[test]
-    fn trait_def_containing_associated_type_is_denied() {
+    fn trait_def_containing_invalid_associated_type_is_denied() {
         assert_ink_trait_eq_err!(
-            error: "associated types in ink! trait definitions are not supported, yet",
+            error: "ink! attributes are not supported on associated types of ink! trait definitions",
             pub trait MyTrait {
+                #[ink(message)]
                 type Type;
             }
         );
+        assert_ink_trait_eq_err!(
+            error: "generic associated types in ink! trait definitions are not supported",
+            pub trait MyTrait {
+                type Type<T>;
+            }
+        );
+        assert_ink_trait_eq_err!(
+            error: "associated types in ink! trait definitions cannot have a default",
+            pub trait MyTrait {
+                type Type = u128;
+            }
+        );
+        assert_ink_trait_eq_err!(
+            error: "the bounds of associated types in ink! trait definitions must not refer to lifetimes",
+            pub trait MyTrait {
+                type Type: Into<u128> + 'static;
+            }
+        );
+        assert_ink_trait_eq_err!(
+            error: "the bounds of associated types in ink! trait definitions must not refer to lifetimes",
+            pub trait MyTrait {
+                type Type: for<'a> From<&'a u128>;
+            }
+        );
     }
 
     #[test]
@@ -673,23 +1049,41 @@ mod tests {
     #[test]
     fn trait_def_containing_non_flagged_method_is_denied() {
         assert_ink_trait_eq_err!(
-            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method",
+            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method \
+                    `non_flagged_1`: ink! trait definitions must only contain ink! messages \
+                    and constructors",
             pub trait MyTrait {
                 fn non_flagged_1(&self);
             }
         );
         assert_ink_trait_eq_err!(
-            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method",
+            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method \
+                    `non_flagged_2`: ink! trait definitions must only contain ink! messages \
+                    and constructors",
             pub trait MyTrait {
                 fn non_flagged_2(&mut self);
             }
         );
         assert_ink_trait_eq_err!(
-            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method",
+            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method \
+                    `non_flagged_3`: ink! trait definitions must only contain ink! messages \
+                    and constructors",
             pub trait MyTrait {
                 fn non_flagged_3() -> Self;
             }
         );
+        assert_ink_trait_eq_err!(
+            error: "missing #[ink(message)] or #[ink(constructor)] flags on ink! trait method \
+                    `helper`: ink! trait definitions must only contain ink! messages \
+                    and constructors",
+            pub trait MyTrait {
+                #[ink(message)]
+                fn message(&self);
+                fn helper(&self) -> bool {
+                    true
+                }
+            }
+        );
     }
 
     #[test]
@@ -925,7 +1319,8 @@ mod tests {
             }
         );
         assert_ink_trait_eq_err!(
-            error: "encountered conflicting ink! attribute argument",
+            error: "#[ink(message)] cannot be combined with #[ink(constructor)]: \
+                    an ink! message cannot be an ink! constructor at the same time",
             pub trait MyTrait {
                 #[ink(constructor)]
                 #[ink(message)]
@@ -953,7 +1348,8 @@ mod tests {
             }
         );
         assert_ink_trait_eq_err!(
-            error: "encountered conflicting ink! attribute argument",
+            error: "#[ink(constructor)] cannot be combined with #[ink(message)]: \
+                    an ink! message cannot be an ink! constructor at the same time",
             pub trait MyTrait {
                 #[ink(message)]
                 #[ink(constructor)]
@@ -987,6 +1383,68 @@ mod tests {
         )
     }
 
+    #[test]
+    fn generic_trait_def_is_ok() {
+        let ink_trait =
+            <InkTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+                pub trait Token<Unit: Copy, Id> {
+                    #[ink(message)]
+                    fn balance_of(&self, owner: Id) -> Unit;
+                    #[ink(message)]
+                    fn transfer(&mut self, to: Id, amount: Unit);
+                }
+            })
+            .unwrap();
+        let params = ink_trait
+            .generics()
+            .type_params()
+            .map(|param| param.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(params, vec!["Unit".to_string(), "Id".to_string()]);
+    }
+
+    #[test]
+    fn iter_consts_works() {
+        let ink_trait =
+            <InkTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+                pub trait MyTrait {
+                    const DECIMALS: u8;
+                    #[ink(message)]
+                    fn message(&self);
+                    const SYMBOL: &'static str = "INK";
+                }
+            })
+            .unwrap();
+        let actual = ink_trait
+            .iter_consts()
+            .map(|const_item| const_item.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec!["DECIMALS".to_string(), "SYMBOL".to_string()]);
+        // Associated constants are no ink! trait items.
+        assert_eq!(ink_trait.iter_items().count(), 1);
+    }
+
+    #[test]
+    fn iter_types_works() {
+        let ink_trait =
+            <InkTrait as TryFrom<syn::ItemTrait>>::try_from(syn::parse_quote! {
+                pub trait MyTrait {
+                    type Balance: Into<u128>;
+                    #[ink(message)]
+                    fn balance(&self) -> Self::Balance;
+                    type Id;
+                }
+            })
+            .unwrap();
+        let actual = ink_trait
+            .iter_types()
+            .map(|type_item| type_item.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec!["Balance".to_string(), "Id".to_string()]);
+        // Associated types are no ink! trait items.
+        assert_eq!(ink_trait.iter_items().count(), 1);
+    }
+
     #[test]
     fn iter_constructors_works() {
         let ink_trait =
@@ -1113,4 +1571,118 @@ mod tests {
             "__ink_trait::MyTrait::constructor_1:0,constructor_2:2",
         );
     }
+
+    #[test]
+    fn call_builder_messages_works() {
+        let call_builder_messages = |attr: TokenStream2, input: TokenStream2| {
+            InkTrait::new(attr, input)
+                .unwrap()
+                .call_builder_messages()
+                .into_iter()
+                .map(|(message, selector)| (message.sig().ident.to_string(), selector))
+                .collect::<Vec<_>>()
+        };
+        let input = quote::quote! {
+            pub trait MyTrait {
+                type Amount: Copy;
+                #[ink(constructor)]
+                fn new() -> Self;
+                #[ink(message)]
+                fn get(&self) -> i32;
+                #[ink(message)]
+                fn amount(&self) -> Self::Amount;
+            }
+        };
+        assert_eq!(
+            call_builder_messages(quote::quote! {}, input.clone()),
+            vec![("get".to_string(), ir::Selector::new(b"MyTrait::get"))],
+        );
+        assert_eq!(
+            call_builder_messages(
+                quote::quote! { namespace = "vault::flipping", hash = "keccak_256" },
+                input,
+            ),
+            vec![(
+                "get".to_string(),
+                ir::Selector::with_hash(
+                    b"vault::flipping::MyTrait::get",
+                    ir::SelectorHash::Keccak256
+                )
+            )],
+        );
+        assert_eq!(
+            call_builder_messages(
+                quote::quote! {},
+                quote::quote! {
+                    pub trait MyTrait<T> {
+                        #[ink(message)]
+                        fn get(&self) -> T;
+                    }
+                },
+            ),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn invalid_trait_definition_args_fails() {
+        let assert_new_fails = |attr: TokenStream2, expected: &str| {
+            let input = quote::quote! {
+                pub trait MyTrait {
+                    #[ink(message)]
+                    fn get(&self) -> i32;
+                }
+            };
+            assert_eq!(
+                InkTrait::new(attr, input).map_err(|err| err.to_string()),
+                Err(expected.to_string()),
+            )
+        };
+        assert_new_fails(
+            quote::quote! { call_builder = true },
+            "unexpected attribute input for ink! trait definition",
+        );
+        assert_new_fails(
+            quote::quote! { namespace = true },
+            "expected a string literal for `namespace` ink! trait definition argument",
+        );
+        assert_new_fails(
+            quote::quote! { namespace = "" },
+            "expected a non-empty string literal for `namespace` ink! trait \
+             definition argument",
+        );
+        assert_new_fails(
+            quote::quote! { hash = "sha_256" },
+            "unsupported ink! selector hash \"sha_256\", supported hashes are: \
+             blake2_256, keccak_256",
+        );
+        assert_new_fails(
+            quote::quote! { hash = "blake2_256", hash = "keccak_256" },
+            "encountered duplicate ink! `hash` trait definition argument",
+        );
+    }
+
+    #[test]
+    fn selectors_works() {
+        let ink_trait = ink_trait! {
+            pub trait MyTrait {
+                #[ink(constructor)]
+                fn new() -> Self;
+                #[ink(message)]
+                fn r#get(&self) -> i32;
+            }
+        };
+        let selectors = ink_trait
+            .selectors(None, None, ir::SelectorHash::Blake2x256)
+            .into_iter()
+            .map(|(ident, selector)| (ident.to_string(), selector))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selectors,
+            vec![
+                ("new".to_string(), ir::Selector::new(b"MyTrait::new")),
+                ("r#get".to_string(), ir::Selector::new(b"MyTrait::get")),
+            ]
+        );
+    }
 }
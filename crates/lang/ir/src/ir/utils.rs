@@ -42,3 +42,283 @@ pub fn ensure_pub_visibility(
     }
     Ok(())
 }
+
+/// Collects the paths of the given type into `paths`.
+///
+/// Descends `depth` levels into the generic arguments of the type paths.
+pub(crate) fn collect_type_paths(
+    ty: &syn::Type,
+    depth: usize,
+    paths: &mut Vec<syn::Path>,
+) {
+    match ty {
+        syn::Type::Path(type_path) => {
+            let mut path = type_path.path.clone();
+            for segment in path.segments.iter_mut() {
+                segment.arguments = syn::PathArguments::None;
+            }
+            paths.push(path);
+            if depth == 0 {
+                return
+            }
+            for segment in &type_path.path.segments {
+                if let syn::PathArguments::AngleBracketed(generics) = &segment.arguments {
+                    for arg in &generics.args {
+                        if let syn::GenericArgument::Type(ty) = arg {
+                            collect_type_paths(ty, depth - 1, paths)
+                        }
+                    }
+                }
+            }
+        }
+        syn::Type::Array(array) => collect_type_paths(&array.elem, depth, paths),
+        syn::Type::Group(group) => collect_type_paths(&group.elem, depth, paths),
+        syn::Type::Paren(paren) => collect_type_paths(&paren.elem, depth, paths),
+        syn::Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                collect_type_paths(elem, depth, paths)
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Returns the given type as compact string, e.g. `Vec<u8>` or `&'static str`.
+///
+/// # Note
+///
+/// Whitespace is only kept in between two identifiers or keywords. This is the
+/// textual form of types used for the signatures of ink! items.
+pub(crate) fn type_to_string(ty: &syn::Type) -> String {
+    let tokens = quote::quote! { #ty }.to_string();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut result = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let prev_is_word =
+                matches!(result.chars().last(), Some(prev) if is_word(prev));
+            let next_is_word = matches!(chars.peek(), Some(&next) if is_word(next));
+            if !(prev_is_word && next_is_word) {
+                continue
+            }
+        }
+        result.push(c)
+    }
+    result
+}
+
+/// Ensures that the given type does not use any non-`'static` lifetime.
+///
+/// # Note
+///
+/// The `position` parameter is given to improve the resulting error message.
+/// It denotes where the type is used, e.g. `"ink! storage fields"`.
+///
+/// # Errors
+///
+/// - If the type uses an explicit lifetime other than `'static`, e.g. `&'a T`.
+/// - If the type uses a reference with an elided lifetime, e.g. `&T`.
+pub(crate) fn ensure_no_non_static_lifetimes(
+    ty: &syn::Type,
+    position: &str,
+) -> Result<(), syn::Error> {
+    /// Finds the first non-`'static` lifetime within a type.
+    #[derive(Default)]
+    struct LifetimeVisitor {
+        found: Option<(Span, String)>,
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for LifetimeVisitor {
+        fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+            if self.found.is_none() && lifetime.ident != "static" {
+                self.found = Some((lifetime.span(), format!("lifetime `{}`", lifetime)));
+            }
+        }
+
+        fn visit_type_reference(&mut self, reference: &'ast syn::TypeReference) {
+            if self.found.is_none() && reference.lifetime.is_none() {
+                self.found =
+                    Some((reference.span(), "reference with elided lifetime".into()));
+            }
+            syn::visit::visit_type_reference(self, reference)
+        }
+    }
+
+    let mut visitor = LifetimeVisitor::default();
+    syn::visit::Visit::visit_type(&mut visitor, ty);
+    match visitor.found {
+        Some((span, found)) => {
+            Err(format_err!(
+                span,
+                "encountered non-`'static` {} in {} which must own all of their data",
+                found,
+                position,
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Returns the first type nested within the given type for which `classify`
+/// yields a kind, together with that kind.
+///
+/// # Note
+///
+/// Descends into arrays, slices, tuples, references and parenthesized types.
+/// Generic arguments of type paths, e.g. the `T` in `Vec<T>`, are only visited
+/// if `visit_generic_args` is `true`.
+pub(crate) fn find_nested_type<F>(
+    ty: &syn::Type,
+    visit_generic_args: bool,
+    classify: F,
+) -> Option<(&syn::Type, &'static str)>
+where
+    F: Fn(&syn::Type) -> Option<&'static str>,
+{
+    /// Finds the first type within a type that has been classified.
+    struct TypeVisitor<'a, F> {
+        visit_generic_args: bool,
+        classify: F,
+        found: Option<(&'a syn::Type, &'static str)>,
+    }
+
+    impl<'a, F> syn::visit::Visit<'a> for TypeVisitor<'a, F>
+    where
+        F: Fn(&syn::Type) -> Option<&'static str>,
+    {
+        fn visit_type(&mut self, ty: &'a syn::Type) {
+            if self.found.is_some() {
+                return
+            }
+            match (self.classify)(ty) {
+                Some(kind) => self.found = Some((ty, kind)),
+                None if !self.visit_generic_args && matches!(ty, syn::Type::Path(_)) => {}
+                None => syn::visit::visit_type(self, ty),
+            }
+        }
+    }
+
+    let mut visitor = TypeVisitor {
+        visit_generic_args,
+        classify,
+        found: None,
+    };
+    syn::visit::Visit::visit_type(&mut visitor, ty);
+    visitor.found
+}
+
+/// Ensures that the given type can be registered in the metadata type registry.
+///
+/// # Note
+///
+/// This is a best-effort syntactic check that finds types such as function
+/// pointers or trait objects that never implement `scale_info::TypeInfo`,
+/// also when nested within other types, e.g. `Vec<fn()>`. Its purpose is to
+/// report those with a clear error naming the type and the ink! item using it
+/// instead of an unsatisfied trait bound deep within the generated metadata.
+/// Type macros are accepted since the type they expand to is unknown.
+///
+/// The `item` parameter is given to improve the resulting error message.
+/// It denotes the ink! item using the type, e.g. ``"ink! message `get`"``.
+pub(crate) fn ensure_registrable_type(
+    ty: &syn::Type,
+    item: &str,
+) -> Result<(), syn::Error> {
+    let unregistrable = find_nested_type(ty, true, |ty| {
+        match ty {
+            syn::Type::BareFn(_) => Some("function pointers"),
+            syn::Type::Ptr(_) => Some("raw pointers"),
+            syn::Type::TraitObject(_) => Some("trait objects"),
+            syn::Type::ImplTrait(_) => Some("`impl Trait` types"),
+            syn::Type::Infer(_) => Some("inferred types"),
+            _ => None,
+        }
+    });
+    match unregistrable {
+        Some((found, kind)) => {
+            Err(format_err!(
+                found.span(),
+                "type `{}` of {} cannot be registered in the metadata type registry \
+                 since {} do not implement `scale_info::TypeInfo`",
+                quote::quote!(#ty),
+                item,
+                kind,
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Returns `true` if the given attributes contain `#[doc(hidden)]`.
+///
+/// # Note
+///
+/// ink! items hidden this way are still dispatched but excluded from the
+/// contract metadata.
+pub(crate) fn is_doc_hidden(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| {
+            match meta {
+                syn::Meta::List(list) => {
+                    list.nested.iter().any(|nested| {
+                        matches!(
+                            nested,
+                            syn::NestedMeta::Meta(syn::Meta::Path(path))
+                                if path.is_ident("hidden")
+                        )
+                    })
+                }
+                _ => false,
+            }
+        })
+}
+
+/// Returns the path of the first derive of one of the given traits in the
+/// given attributes if any.
+///
+/// # Note
+///
+/// Derived traits are matched by the last segment of their path. Derives that
+/// are conditionally applied via `cfg_attr` are found as well.
+pub(crate) fn find_derive(
+    attrs: &[syn::Attribute],
+    traits: &[&str],
+) -> Option<syn::Path> {
+    fn find_in_meta(meta: &syn::Meta, traits: &[&str]) -> Option<syn::Path> {
+        let meta_list = match meta {
+            syn::Meta::List(meta_list) => meta_list,
+            _ => return None,
+        };
+        let nested = meta_list.nested.iter().filter_map(|nested| {
+            match nested {
+                syn::NestedMeta::Meta(meta) => Some(meta),
+                syn::NestedMeta::Lit(_) => None,
+            }
+        });
+        if meta_list.path.is_ident("cfg_attr") {
+            // The first nested meta is the `cfg` predicate which we skip.
+            nested.skip(1).find_map(|meta| find_in_meta(meta, traits))
+        } else if meta_list.path.is_ident("derive") {
+            nested
+                .map(syn::Meta::path)
+                .find(|path| {
+                    path.segments
+                        .last()
+                        .map(|segment| traits.iter().any(|name| segment.ident == name))
+                        .unwrap_or_default()
+                })
+                .cloned()
+        } else {
+            None
+        }
+    }
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive") || attr.path.is_ident("cfg_attr"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .find_map(|meta| find_in_meta(&meta, traits))
+}
@@ -33,37 +33,55 @@ mod error;
 mod ast;
 mod ir;
 
-pub use self::ir::{
-    Callable,
-    CallableKind,
-    CallableWithSelector,
-    ChainExtension,
-    ChainExtensionMethod,
-    Config,
-    Constructor,
-    Contract,
-    Event,
-    ExtensionId,
-    ImplItem,
-    InkItem,
-    InkTest,
-    InkTrait,
-    InkTraitConstructor,
-    InkTraitItem,
-    InkTraitMessage,
-    InputsIter,
-    Item,
-    ItemImpl,
-    ItemMod,
-    IterConstructors,
-    IterEvents,
-    IterInkTraitItems,
-    IterItemImpls,
-    IterMessages,
-    Message,
-    Namespace,
-    Receiver,
-    Selector,
-    Storage,
-    Visibility,
+pub use self::{
+    ast::{
+        AttributeArgs,
+        MetaNameValue,
+        PathOrLit,
+    },
+    ir::{
+        AbiChange,
+        AbiDiff,
+        AbiItemKind,
+        AbiModification,
+        AbiSignature,
+        Allocator,
+        Callable,
+        CallableKind,
+        CallableWithSelector,
+        ChainExtension,
+        ChainExtensionMethod,
+        Config,
+        Constructor,
+        Contract,
+        DispatchStrategy,
+        Event,
+        EventInstance,
+        ExtensionId,
+        ImplItem,
+        InkItem,
+        InkTest,
+        InkTrait,
+        InkTraitConstructor,
+        InkTraitItem,
+        InkTraitMessage,
+        InputsIter,
+        Item,
+        ItemImpl,
+        ItemMod,
+        IterConstructors,
+        IterEvents,
+        IterInkTraitItems,
+        IterItemImpls,
+        IterMessages,
+        Message,
+        Namespace,
+        Receiver,
+        Selector,
+        SelectorHash,
+        Storage,
+        StorageAccess,
+        StorageKey,
+        Visibility,
+    },
 };
@@ -129,6 +129,32 @@ use proc_macro::TokenStream;
 ///
 ///     **Default value:** Depends on the crate feature propagation of `Cargo.toml`.
 ///
+/// - `ref_name: String`
+///
+///     Tells the ink! code generator under which name to re-export the storage type of
+///     the smart contract when it is compiled as dependency of another ink! smart contract.
+///     Dependent smart contracts can then refer to the contract via this re-export,
+///     e.g. `my_contract::MyStorageRef`, instead of reaching into its module.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(compile_as_dependency = true, ref_name = "MyContractRef")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** The name of the storage struct suffixed with `Ref`.
+///
 /// - `env: impl Environment`
 ///
 ///     Tells the ink! code generator which environment to use for the ink! smart contract.
@@ -139,40 +165,805 @@ use proc_macro::TokenStream;
 ///     that it exposes to the ink! smart contract and the mirrored types used in the runtime
 ///     must be aligned with respect to SCALE encoding and semantics.
 ///
+///     Within the ink! module the names `AccountId`, `Balance`, `Hash`, `Timestamp` and
+///     `BlockNumber` refer to the respective types of the configured environment. So the
+///     inputs of a message such as `fn pay(&mut self, to: AccountId, amount: Balance)`
+///     use the types of the custom `Environment` implementation.
+///
+///     The generated metadata contains an `environment` section listing the names
+///     of these types together with their encoded widths in bytes where known.
+///
+///     **Usage Example:**
+///
+///     Given a custom `Environment` implementation:
+///     ```
+///     pub struct MyEnvironment;
+///
+///     impl ink_env::Environment for MyEnvironment {
+///         const MAX_EVENT_TOPICS: usize = 3;
+///         type AccountId = u64;
+///         type Balance = u128;
+///         type Hash = [u8; 32];
+///         type Timestamp = u64;
+///         type BlockNumber = u32;
+///         type ChainExtension = ::ink_env::NoChainExtension;
+///     }
+///     ```
+///     A user might implement their ink! smart contract using the above custom `Environment`
+///     implementation as demonstrated below:
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(env = MyEnvironment)]
+///     mod my_contract {
+///         # pub struct MyEnvironment;
+///         #
+///         # impl ink_env::Environment for MyEnvironment {
+///         #     const MAX_EVENT_TOPICS: usize = 3;
+///         #     type AccountId = u64;
+///         #     type Balance = u128;
+///         #     type Hash = [u8; 32];
+///         #     type Timestamp = u64;
+///         #     type BlockNumber = u32;
+///         #     type ChainExtension = ::ink_env::NoChainExtension;
+///         # }
+///         #
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
+///
+/// - `source_hash: bool`
+///
+///     Tells the ink! code generator to include a hash of the original contract
+///     module in the generated metadata so that deployed contracts can be matched
+///     to their source.
+///
+///     The hash is the BLAKE-2b 256-bit hash of the module's tokens, each written
+///     in its textual form and separated by a single space. Therefore it is stable
+///     across reformatting and changes to regular comments, while any change to
+///     the actual tokens, including doc comments, yields a different hash.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(source_hash = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `allow_no_constructor: bool`
+///
+///     Tells the ink! code generator to allow the smart contract to have no
+///     `#[ink(constructor)]` at all. Such a contract can never be instantiated
+///     which is why this is an error by default. This is mainly useful for library
+///     contracts that are only ever used as dependencies of other smart contracts.
+///     The metadata of smart contracts without constructors has an empty set of
+///     constructors.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(allow_no_constructor = true)]
+///     mod my_library {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `max_input_size: usize`
+///
+///     Tells the ink! code generator to reject contract inputs that are larger than
+///     the given amount of bytes. The size of the input is checked before it is decoded
+///     for dispatching to the ink! constructors and messages.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(max_input_size = 1024)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** Unbounded.
+///
+/// - `upgradeable: bool`
+///
+///     Tells the ink! code generator to generate a `set_code(&mut self, code_hash: Hash)`
+///     ink! message that replaces the code of the smart contract with the code uploaded
+///     under the given code hash. The storage of the smart contract is kept as is.
+///
+///     The generated message is guarded by the `ink_lang::Upgradeable` trait which
+///     must be implemented for the ink! storage struct in order to decide who is
+///     allowed to replace the code of the smart contract.
+///     It is an error to define another ink! message with the same selector.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(upgradeable = true)]
+///     mod my_contract {
+///         #[ink(storage)]
+///         pub struct MyStorage {
+///             owner: AccountId,
+///         }
+///
+///         impl ink_lang::Upgradeable for MyStorage {
+///             fn can_set_code(&self) -> bool {
+///                 use ink_lang::Env as _;
+///                 self.env().caller() == self.owner
+///             }
+///         }
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage { owner: Self::env().caller() } }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `emit_interface: bool`
+///
+///     Tells the ink! code generator to generate a trait named after the ink! storage
+///     struct suffixed with `Interface` that comprises the signatures of all ink!
+///     messages of the smart contract. The ink! storage struct implements this trait.
+///
+///     This allows external code to be generic over anything providing the same
+///     ink! messages as the smart contract. Trait messages whose name clashes with
+///     another ink! message are prefixed with the snake case name of their trait.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(emit_interface = true)]
+///     mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper { value: bool }
+///         impl Flipper {
+///             # #[ink(constructor)]
+///             # pub fn new() -> Self { Flipper { value: false } }
+///             #[ink(message)]
+///             pub fn flip(&mut self) {
+///                 self.value = !self.value;
+///             }
+///         }
+///     }
+///
+///     fn flip<T: flipper::FlipperInterface>(flipper: &mut T) {
+///         flipper.flip();
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `metadata_version: u32`
+///
+///     Tells the ink! code generator which version of the metadata schema to generate.
+///     Supported versions are `0`, the original metadata schema, and `1` which
+///     additionally carries its version in the generated metadata.
+///     It is an error to specify any other version.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(metadata_version = 1)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `0`
+///
+/// - `explicit_selectors: bool`
+///
+///     Tells the ink! code generator to require an explicit `#[ink(selector = ..)]`
+///     on every ink! message and constructor instead of deriving selectors from
+///     their names. This prevents accidental changes to the contract ABI upon
+///     renaming an ink! message or constructor.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(explicit_selectors = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor, selector = "0xCAFEBABE")]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message, selector = "0xDEADBEEF")]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `namespace: String`
+///
+///     Tells the ink! code generator to seed the composed selectors of all ink! messages
+///     and constructors with the given namespace, e.g. `vault::my_message` instead of
+///     `my_message`. This disambiguates the selectors of multiple ink! smart contracts
+///     that are generated into the same artifact. Explicitly provided selectors are not
+///     affected by the namespace.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(namespace = "vault")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No namespace.
+///
+/// - `inline_helpers: bool`
+///
+///     Tells the ink! code generator to mark small generated helper functions, such as
+///     the bodies of ink! getter messages and the dispatch helpers, with `#[inline]`.
+///
+///     Inlining saves the overhead of function calls and thus may lower the gas costs
+///     of calling the ink! smart contract. However, it may also increase the size of the
+///     resulting Wasm binary since the inlined code might be duplicated at every call site.
+///     Contract writers are encouraged to measure both the binary size and gas costs of
+///     their smart contract before enabling this.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(inline_helpers = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage { value: bool }
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage { value: false } }
+///         #     #[ink(message, getter = "value")]
+///         #     pub fn value(&self) -> bool {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `license: String`
+///
+///     Tells the ink! code generator to include the given SPDX license identifier of the
+///     ink! smart contract in its generated metadata, e.g. for registries that want to
+///     display the license of a contract. The license must be a non-empty string.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(license = "Apache-2.0")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No license.
+///
+/// - `max_event_fields: usize`
+///
+///     Tells the ink! code generator to emit a compile-time warning for every ink! event
+///     that has more fields than the given threshold. Events with many fields are
+///     expensive to encode and are often better split up into multiple events.
+///     This is purely advisory and never rejects a contract.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(max_event_fields = 8)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** No limit.
+///
+/// - `allocator: String`
+///
+///     Tells the ink! code generator which global Wasm heap memory allocator the ink!
+///     smart contract uses. Supported allocators are:
+///
+///     - `"wee_alloc"`: Optimized for a small code size while still freeing memory.
+///     - `"bump"`: Never frees memory which results in an even smaller code size.
+///       This is well suited for contracts that allocate little memory per call.
+///
+///     The global allocator is registered by `ink_allocator` so that it is shared by
+///     the contract and all of its dependencies. The `"bump"` allocator is registered
+///     if the `bump-allocator` feature of `ink_env` is enabled. Wasm builds of the
+///     contract fail to compile if the registered allocator does not match this config.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(allocator = "bump")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `"wee_alloc"`
+///
+/// - `warn_pub_storage: bool`
+///
+///     Tells the ink! code generator to emit a compile-time warning for every field of
+///     the ink! storage struct that is declared `pub`. Such fields leak the internal
+///     state of the contract to other Rust code and should rather be exposed via
+///     accessor ink! messages. This is purely advisory and never rejects a contract.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(warn_pub_storage = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `warn_interior_mutability: bool`
+///
+///     Tells the ink! code generator to emit a compile-time warning for every field of
+///     the ink! storage struct whose type contains an interior mutability type such as
+///     `Cell`, `RefCell` or `Mutex`. Those do not persist meaningfully in the contract
+///     storage; store the value directly and mutate it in `&mut self` ink! messages
+///     instead. The types are matched by name so type aliases are not seen through.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(warn_interior_mutability = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `hash: String`
+///
+///     Tells the ink! code generator which hash primitive to use for computing the
+///     selectors of all ink! messages and constructors, e.g. in order to match the
+///     hashing used by some runtime. Supported hashes are `"blake2_256"` and
+///     `"keccak_256"`. Explicitly provided selectors are not affected.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(hash = "keccak_256")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `"blake2_256"`
+///
+/// - `dispatch: String`
+///
+///     Tells the ink! code generator how to dispatch on the selectors of incoming
+///     calls to ink! messages and constructors. Supported strategies are `"match"`
+///     which matches on all selectors and `"binary_search"` which sorts all selectors
+///     at compile time and performs a binary search over them. The latter scales
+///     better for ink! smart contracts with many ink! messages.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(dispatch = "binary_search")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `"match"`
+///
+/// - `storage_access_hints: bool`
+///
+///     Tells the ink! code generator to emit the storage fields read and written by
+///     every ink! message into the metadata, e.g. for tools computing worst-case gas.
+///     The fields are found by a syntactic analysis of `self.<field>` expressions in
+///     the body of each ink! message and are therefore only approximate. Accesses
+///     via calls to other methods are not tracked.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(storage_access_hints = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `embed_build_info: bool`
+///
+///     Tells the ink! code generator to embed the versions of the Rust compiler and
+///     of ink! that built the ink! smart contract into a `buildInfo` section of the
+///     metadata so that registries can record the provenance of contracts.
+///     Both versions are captured when the ink! smart contract is compiled.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(embed_build_info = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `expose_selectors: bool`
+///
+///     Tells the ink! code generator to generate a `SELECTORS` associated constant
+///     of type `&[[u8; 4]]` for the ink! storage struct that lists the selectors
+///     of all ink! messages sorted in ascending order. Contracts may use it to
+///     expose their own set of selectors on-chain for introspection.
+///     Test-only ink! messages are not part of the listed selectors.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(expose_selectors = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `validate_metadata_types: bool`
+///
+///     Tells the ink! code generator to reject types of the ink! storage struct,
+///     ink! events, ink! constructors and ink! messages that can never be registered
+///     in the metadata type registry, e.g. function pointers, raw pointers or trait
+///     objects. The error names the offending type and the ink! item using it
+///     instead of reporting an unsatisfied `scale_info::TypeInfo` bound late within
+///     the generated metadata code.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(validate_metadata_types = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `entry_call: String`
+///
+///     Tells the ink! code generator to export the entry point that dispatches
+///     calls to ink! messages under the given name instead of `call`.
+///
+///     This is useful for chains whose contracts module expects differently named
+///     exports. The name must be a valid identifier and must differ from the name
+///     of the `deploy` entry point.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(entry_call = "exec")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `"call"`
+///
+/// - `entry_deploy: String`
+///
+///     Tells the ink! code generator to export the entry point that dispatches
+///     calls to ink! constructors under the given name instead of `deploy`.
+///
+///     The name must be a valid identifier and must differ from the name of the
+///     `call` entry point.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(entry_deploy = "init")]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `"deploy"`
+///
+/// - `derive_debug: bool`
+///
+///     Tells the ink! code generator to derive `Debug` for the ink! storage struct
+///     and all ink! event structs in test and `std` builds.
+///
+///     This is useful for integration tests that want to print or compare contract
+///     state and emitted events. The ink! storage struct and ink! event structs must
+///     not already implement `Debug` themselves.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(derive_debug = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `migration_stubs: bool`
+///
+///     Tells the ink! code generator to generate a `migrate(&mut self, from_version: u32)`
+///     ink! message stub if the ink! storage struct has been flagged with
+///     `#[ink(storage, version = N)]` where `N` is greater than `0`.
+///
+///     The generated message runs the `migrate_from_v{K}(&mut self)` methods that must be
+///     defined for every prior version `K` below `N` in order, starting at the given prior
+///     version, e.g. `migrate(0)` runs `migrate_from_v0` followed by `migrate_from_v1` for
+///     `N = 2`. Other versions are rejected. This is only scaffolding: the migrations
+///     themselves must be written by hand.
+///
+///     ink! does not persist the version of the stored contract storage. The caller must
+///     pass the version the storage is currently at and the contract is responsible for
+///     not running a migration twice, e.g. by storing the version in a field and checking
+///     it in `Migrate::can_migrate`.
+///
+///     The generated message is guarded by the `ink_lang::Migrate` trait which must be
+///     implemented for the ink! storage struct in order to decide who is allowed to migrate
+///     the storage of the smart contract.
+///     It is an error to define another ink! message with the same selector.
+///
+///     **Usage Example:**
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract(migration_stubs = true)]
+///     mod my_contract {
+///         #[ink(storage, version = 2)]
+///         pub struct MyStorage {
+///             owner: AccountId,
+///         }
+///
+///         impl ink_lang::Migrate for MyStorage {
+///             fn can_migrate(&self) -> bool {
+///                 use ink_lang::Env as _;
+///                 self.env().caller() == self.owner
+///             }
+///         }
+///
+///         impl MyStorage {
+///             fn migrate_from_v0(&mut self) { /* ... */ }
+///             fn migrate_from_v1(&mut self) { /* ... */ }
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage { owner: Self::env().caller() } }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         }
+///         // ...
+///     }
+///     ```
+///
+///     **Default value:** `false`
+///
+/// - `no_panic_dispatch: bool`
+///
+///     Tells the ink! code generator to catch panics of ink! messages during dispatch.
+///
+///     A caught panic makes the dispatch return an error which is encoded in the
+///     returned dispatch code instead of trapping the contract execution. The changes
+///     of a panicking `&mut self` message are not written back to the storage.
+///
+///     **Limitations:** Panics can only be caught for contracts compiled with the `std`
+///     feature and unwinding panics, e.g. in off-chain tests. Since Wasm builds abort on
+///     panics a contract using this flag fails to compile without the `std` feature.
+///     Aborts, e.g. due to a failed memory allocation, are never caught.
+///
 ///     **Usage Example:**
-///
-///     Given a custom `Environment` implementation:
 ///     ```
-///     pub struct MyEnvironment;
-///
-///     impl ink_env::Environment for MyEnvironment {
-///         const MAX_EVENT_TOPICS: usize = 3;
-///         type AccountId = u64;
-///         type Balance = u128;
-///         type Hash = [u8; 32];
-///         type Timestamp = u64;
-///         type BlockNumber = u32;
-///         type ChainExtension = ::ink_env::NoChainExtension;
+///     # use ink_lang as ink;
+///     #[ink::contract(no_panic_dispatch = true)]
+///     mod my_contract {
+///         # #[ink(storage)]
+///         # pub struct MyStorage;
+///         # impl MyStorage {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { MyStorage {} }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///         // ...
 ///     }
 ///     ```
-///     A user might implement their ink! smart contract using the above custom `Environment`
-///     implementation as demonstrated below:
+///
+///     **Default value:** `false`
+///
+/// - `expose_call_info: bool`
+///
+///     Tells the ink! code generator to generate a `CALL_INFO` associated constant
+///     of type `&[(u32, &str, &str)]` for the ink! storage struct. It maps the selector
+///     of every ink! message, given as big-endian `u32`, to its signature, e.g.
+///     `"transfer(AccountId,Balance)"`, and its return type, e.g. `"bool"` or `"()"`.
+///     This is a lightweight companion to the full contract metadata, e.g. for block
+///     explorers. Test-only ink! messages are not part of the table.
+///
+///     **Usage Example:**
 ///     ```
 ///     # use ink_lang as ink;
-///     #[ink::contract(env = MyEnvironment)]
+///     #[ink::contract(expose_call_info = true)]
 ///     mod my_contract {
-///         # pub struct MyEnvironment;
-///         #
-///         # impl ink_env::Environment for MyEnvironment {
-///         #     const MAX_EVENT_TOPICS: usize = 3;
-///         #     type AccountId = u64;
-///         #     type Balance = u128;
-///         #     type Hash = [u8; 32];
-///         #     type Timestamp = u64;
-///         #     type BlockNumber = u32;
-///         #     type ChainExtension = ::ink_env::NoChainExtension;
-///         # }
-///         #
 ///         # #[ink(storage)]
 ///         # pub struct MyStorage;
 ///         # impl MyStorage {
@@ -185,7 +976,7 @@ use proc_macro::TokenStream;
 ///     }
 ///     ```
 ///
-///     **Default value:** `DefaultEnvironment` defined in `ink_env` crate.
+///     **Default value:** `false`
 ///
 /// ## Anaylsis
 ///
@@ -221,11 +1012,105 @@ use proc_macro::TokenStream;
 ///     }
 ///     ```
 ///
-/// - There must be at least one `#[ink(constructor)]` defined method.
+///     Fields of the storage struct can be pinned to an explicit storage key via
+///     `#[ink(storage_key = N)]`. Pinned fields are stored starting at their key and
+///     do not shift the keys of the other fields. Explicit storage keys must be unique
+///     and fit into 256 bits. They must also lie outside of the keys used by the other
+///     fields which start at the root key `0` and span their accumulated footprint.
+///
+///     Fields flagged with `#[ink(hidden)]` are excluded from the generated metadata
+///     while still being part of the on-chain storage layout, e.g. for internal
+///     bookkeeping that is not meant to be public.
+///
+///     **Example:**
+///
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract]
+///     mod flipper {
+///         #[ink(storage)]
+///         pub struct Flipper {
+///             #[ink(storage_key = 0x1234)]
+///             value: bool,
+///         }
+///         # impl Flipper {
+///         #     #[ink(constructor)]
+///         #     pub fn construct() -> Self { Flipper { value: false } }
+///         #     #[ink(message)]
+///         #     pub fn message(&self) {}
+///         # }
+///     }
+///     ```
+///
+///     Storage structs whose fields all have a well-defined zero value, i.e. integers,
+///     `bool` or arrays and tuples thereof, can be flagged with `#[ink(storage, zeroed)]`
+///     in order to generate a `new_zeroed` associated function returning the storage
+///     with all of its fields set to zero.
+///
+///     **Example:**
+///
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract]
+///     mod counter {
+///         #[ink(storage, zeroed)]
+///         pub struct Counter {
+///             value: u64,
+///             history: [u32; 8],
+///         }
+///
+///         impl Counter {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self::new_zeroed()
+///             }
+///             # #[ink(message)]
+///             # pub fn message(&self) {}
+///         }
+///     }
+///     ```
+///
+///     The version of the storage layout can be declared via
+///     `#[ink(storage, version = N)]`. Storage structs without a declared version
+///     have version `0`. See the `migration_stubs` configuration for how the version
+///     is used.
+///
+///     The `#[ink(storage)]` attribute may also be put on a type alias referring to
+///     a struct defined at the root of the ink! module. The aliased struct is then
+///     used as the storage struct and must not have ink! attributes on its own.
+///
+///     ```
+///     # use ink_lang as ink;
+///     #[ink::contract]
+///     mod flipper {
+///         #[ink(storage)]
+///         pub type Storage = Flipper;
+///
+///         pub struct Flipper {
+///             value: bool,
+///         }
+///
+///         impl Storage {
+///             #[ink(constructor)]
+///             pub fn new() -> Self {
+///                 Self { value: false }
+///             }
+///             # #[ink(message)]
+///             # pub fn message(&self) {}
+///         }
+///     }
+///     ```
+///
+/// - There must be at least one `#[ink(constructor)]` defined method unless
+///   `allow_no_constructor = true` has been configured.
 ///
 ///     Methods flagged with `#[ink(constructor)]` are special in that they are dispatchable
 ///     upon contract instantiation. A contract may define multiple such constructors which
 ///     allow users of the contract to instantiate a contract in multiple different ways.
+
+///     Constructors return `Self` or, if they are fallible, `Result<Self, E>` where `E`
+///     implements `scale::Encode`. Returning `Err` reverts the instantiation and returns
+///     the encoded error to the caller.
 ///
 ///     **Example:**
 ///
@@ -263,6 +1148,9 @@ use proc_macro::TokenStream;
 ///
 ///     - An ink! message with a `&self` receiver may only read state whereas an ink! message
 ///       with a `&mut self` receiver may mutate the contract's storage.
+///     - ink! messages must not be generic but may have a where clause constraining
+///       concrete types, e.g. `where Self: Clone`. The where clause must not refer to
+///       lifetimes other than `'static` except for those of higher-ranked trait bounds.
 ///
 ///     **Example:**
 ///
@@ -342,7 +1230,10 @@ use proc_macro::TokenStream;
 ///     These selectors are mainly used to drive the contract's dispatch upon calling it.
 ///
 ///     An ink! smart contract author can control the selector of an ink! message or ink!
-///     constructor using the `selector` flag. An example is shown below:
+///     constructor using the `selector` flag. The selector is either given as a string of
+///     four hex encoded bytes or as a `u32` integer literal in decimal, hexadecimal or
+///     binary form which is interpreted as big-endian bytes, e.g. `selector = 0xCAFEBABE`
+///     or `selector = 10` for `[0x00, 0x00, 0x00, 0x0A]`. An example is shown below:
 ///
 ///     ```
 ///     # use ink_lang as ink;
@@ -371,10 +1262,298 @@ use proc_macro::TokenStream;
 ///         pub fn get(&self) -> bool {
 ///             self.value
 ///         }
+///
+///         /// Returns the current value negated.
+///         #[ink(message, selector = 0xC0DECAFE)] // Integer literals work as well.
+///         pub fn get_negated(&self) -> bool {
+///             !self.value
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Fallback constructor:**
+///
+///     At most one ink! constructor may be flagged with the wildcard selector
+///     `selector = _`. Instantiations with an unknown selector are then dispatched
+///     to it instead of being rejected and the remaining input is decoded as its
+///     arguments. The wildcard constructor can still be called with its own
+///     selector. ink! messages must not use the wildcard selector.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         /// Handles all instantiations with an unknown selector.
+///         #[ink(constructor, selector = _)]
+///         pub fn fallback(initial_value: bool) -> Self {
+///             Flipper { value: initial_value }
+///         }
+///         # /// Returns the current value.
+///         # #[ink(message)]
+///         # pub fn get(&self) -> bool {
+///         #     self.value
+///         # }
+///     }
+///     # }
+///     ```
+///
+///     **Selectors given by a constant:**
+///
+///     The selector may also be given by the name of a constant defined at the root
+///     of the ink! module, e.g. `selector = MY_SELECTOR` or `selector = self::MY_SELECTOR`.
+///     The constant must be of type `u32` or `[u8; 4]` and initialized with an integer
+///     literal or an array of four integer literals respectively. Constants of type `u32`
+///     are interpreted as big-endian bytes the same way as integer literal selectors.
+///     Since ink! reads the selector from the literal while expanding the ink! module,
+///     constants computed by other expressions or defined outside of the ink! module
+///     are not supported.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///     /// The selector of the `get` message.
+///     pub const GET_SELECTOR: u32 = 0xC0DECAFE;
+///
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: initial_value }
+///         # }
+///         /// Returns the current value.
+///         #[ink(message, selector = GET_SELECTOR)]
+///         pub fn get(&self) -> bool {
+///             self.value
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Getter messages:**
+///
+///     ink! messages that simply return the value of a storage field can be
+///     generated using the `getter` flag. The flagged message must have an empty
+///     body, a `&self` receiver and no further inputs. Its return type may be
+///     omitted in which case it is set to the type of the storage field.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         /// Returns the current value.
+///         #[ink(message, getter = "value")]
+///         pub fn get(&self) -> bool {}
+///     }
+///     # }
+///     ```
+///
+///     **Test-only messages:**
+///
+///     ink! messages flagged as `test_only` are only dispatched and included in the
+///     contract metadata when compiled with `cfg(test)`. This is useful for helper
+///     messages that seed or inspect the contract's state in unit tests.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod flipper {
+///         # #[ink(storage)]
+///         # pub struct Flipper {
+///         #     value: bool,
+///         # }
+///     impl Flipper {
+///         # #[ink(constructor)]
+///         # pub fn new(initial_value: bool) -> Self {
+///         #     Flipper { value: false }
+///         # }
+///         # #[ink(message)]
+///         # pub fn get(&self) -> bool {
+///         #     self.value
+///         # }
+///         /// Sets the current value in unit tests.
+///         #[ink(message, test_only)]
+///         pub fn set(&mut self, new_value: bool) {
+///             self.value = new_value;
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Raw return messages:**
+///
+///     ink! messages flagged as `raw_return` return their `Vec<u8>` result as raw bytes
+///     without the SCALE length prefix. It is an error to flag ink! messages that do
+///     not return `Vec<u8>` as `raw_return`.
+///
+///     Note that the contract metadata still describes the return type as `Vec<u8>`,
+///     so callers have to be aware that the result is not length prefixed.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod raw_return {
+///         # use ink_prelude::vec::Vec;
+///         # #[ink(storage)]
+///         # pub struct RawReturn {
+///         #     bytes: Vec<u8>,
+///         # }
+///     impl RawReturn {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     RawReturn { bytes: Vec::new() }
+///         # }
+///         /// Returns the stored bytes as they are.
+///         #[ink(message, raw_return)]
+///         pub fn bytes(&self) -> Vec<u8> {
+///             self.bytes.clone()
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Weight hints:**
+///
+///     ink! messages can provide a static weight hint via `weight = N` with a positive
+///     `u64` integer `N`. The hint is emitted as the `weight` field of the message in the
+///     contract metadata so that front-ends can estimate fees. It is purely advisory and
+///     does not affect the behavior of the ink! message.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod weighted {
+///         # #[ink(storage)]
+///         # pub struct Weighted {}
+///     impl Weighted {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Weighted {}
+///         # }
+///         /// Does some expensive work.
+///         #[ink(message, weight = 1_000_000)]
+///         pub fn work(&self) {}
+///     }
+///     # }
+///     ```
+///
+///     **Standard interface tags:**
+///
+///     ink! messages can be tagged as the entry of a standard interface via
+///     `standard = "identifier"`, e.g. `standard = "erc20::transfer"`. The tag is emitted
+///     as the `standard` field of the message in the contract metadata so that wallets
+///     can recognize standard interfaces. It does not affect the ink! message.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod token {
+///         # #[ink(storage)]
+///         # pub struct Token {}
+///     impl Token {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Token {}
+///         # }
+///         /// Transfers `value` tokens to `to`.
+///         #[ink(message, standard = "erc20::transfer")]
+///         pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
+///             # let _ = (to, value);
+///             true
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Mutability overrides:**
+///
+///     Whether an ink! message is advertised as mutating the contract storage in the
+///     contract metadata is derived from its receiver. Messages that take `&mut self`
+///     only for borrowing reasons can override this via `mutates = false`. The override
+///     only affects the metadata and not the behavior of the ink! message.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod cached {
+///         # #[ink(storage)]
+///         # pub struct Cached { value: u32 }
+///     impl Cached {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Cached { value: 0 }
+///         # }
+///         /// Returns the value without logically changing the contract state.
+///         #[ink(message, mutates = false)]
+///         pub fn get(&mut self) -> u32 {
+///             self.value
+///         }
+///     }
+///     # }
+///     ```
+///
+///     **Default values:**
+///
+///     Inputs of ink! messages and constructors can provide a default value via
+///     `#[ink(default = value)]` with a string, integer, float or bool literal. The
+///     default value is emitted as the `default` field of the input in the contract
+///     metadata so that front-ends can prefill it. It is purely advisory and callers
+///     still have to provide all inputs. The literal must fit the type of the input
+///     if it is a primitive type, `String` or `str`, e.g. an in range integer literal
+///     for an input of type `u8`.
+///
+///     ```
+///     # use ink_lang as ink;
+///     # #[ink::contract]
+///     # mod counter {
+///         # #[ink(storage)]
+///         # pub struct Counter { value: u32 }
+///     impl Counter {
+///         # #[ink(constructor)]
+///         # pub fn new() -> Self {
+///         #     Counter { value: 0 }
+///         # }
+///         /// Increments the value by the given amount.
+///         #[ink(message)]
+///         pub fn inc_by(&mut self, #[ink(default = 1)] by: u32) {
+///             self.value += by;
+///         }
 ///     }
 ///     # }
 ///     ```
 ///
+/// - ink! attributes wrapped in `#[cfg_attr(...)]` are applied unconditionally.
+///
+///     Procedural macros cannot evaluate `cfg` predicates, so ink! cannot know whether
+///     an ink! attribute wrapped in `cfg_attr` applies or not. Therefore ink! applies
+///     such attributes, e.g. `#[cfg_attr(feature = "x", ink(payable))]`, as if the `cfg`
+///     predicate holds. The `cfg_attr` attribute is preserved for all its other wrapped
+///     attributes which stay conditional as usual.
+///
+/// - ink! attributes without arguments may also be written in the path form,
+///   e.g. `#[ink::message]` instead of `#[ink(message)]`.
+///
+///     Both forms are treated equivalently, however, the ink! attributes of a single
+///     item must consistently use either of the two forms.
+///
 /// ## Interacting with the Contract Executor
 ///
 /// The `ink_env` crate provides facitilies to interact with the contract executor that
@@ -470,6 +1649,17 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// **Generic events:**
+///
+/// Event structs may have generic type parameters. Lifetime and const parameters
+/// are not supported. Every generic event is monomorphized for each set of concrete
+/// type arguments it is explicitly used with in the ink! module, e.g.
+/// `Transfer::<MyToken> { .. }`. The signature topic and metadata of each instance
+/// incorporate its concrete type arguments, e.g. `Transfer<MyToken>`.
+/// Uses with type arguments that are not concrete, e.g. `Transfer::<T> { .. }`
+/// within a generic function, do not yield an instance. Every such type argument
+/// must therefore also be used concretely somewhere in the ink! module.
+///
 /// ## Example: Flipper
 ///
 /// The below code shows the complete implementation of the so-called Flipper
@@ -587,6 +1777,49 @@ pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// # Associated Constants
+///
+/// ink! trait definitions may declare associated constants of a concrete type,
+/// e.g. `const DECIMALS: u8;`, that the implementing ink! smart contracts provide
+/// in their ink! trait implementation blocks just like for any other Rust trait.
+///
+/// # Associated Types
+///
+/// ink! trait definitions may also declare associated types with bounds, e.g.
+/// `type Amount: Into<u128>;`, that the implementing ink! smart contracts define
+/// in their ink! trait implementation blocks. Generic associated types, defaults
+/// and bounds referring to lifetimes, e.g. `type Amount: 'static;`, are not supported.
+/// The signatures of ink! messages may use them, e.g. `-> Self::Amount`, which the
+/// ink! trait implementation blocks of the contract resolve to the fully qualified
+/// `<MyStorage as MyTrait>::Amount` for the dispatch and the metadata.
+///
+/// # Call Builders
+///
+/// ink! trait definitions compiled with the `ink-as-dependency` crate feature, e.g. by
+/// a contract depending on the crate defining the trait, also generate a call builder
+/// named after the trait suffixed with `CallBuilder`, e.g. `Erc20CallBuilder`. It builds
+/// calls to the ink! messages of any contract implementing the trait using the selectors
+/// of the trait messages, e.g.
+/// `Erc20CallBuilder::<DefaultEnvironment>::new(account_id).total_supply()`.
+/// This allows to call contracts by depending only on the trait definition.
+///
+/// The selectors are composed as for a trait implementation block without a namespace
+/// in a contract using the default selector hash. Contracts implementing the trait with
+/// a namespace or another selector hash are called through
+/// `#[ink::trait_definition(namespace = "vault::flipping", hash = "keccak_256")]` where
+/// the `namespace` is the namespace of the contract followed by the namespace of its
+/// implementation block. Call builders are not generated for generic trait definitions
+/// and do not provide messages whose inputs or output refer to `Self`.
+///
+/// # Generic Trait Definitions
+///
+/// ink! trait definitions may be generic over types, e.g. `pub trait Token<Unit>`.
+/// The type parameters are bound by the implementing ink! smart contract, e.g.
+/// `impl Token<Cents> for Coin`, so that the messages of the implementation use
+/// the concrete types in their metadata. The type arguments do not take part in
+/// the selectors which are still composed as `Token::message`.
+/// Lifetime and const parameters are not supported.
 #[proc_macro_attribute]
 pub fn trait_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
     trait_def::analyze(attr.into(), item.into()).into()
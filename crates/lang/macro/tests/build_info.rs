@@ -0,0 +1,49 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(embed_build_info = true)]
+mod flipper {
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn build_info_is_embedded_into_metadata() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let build_info = metadata.spec().build_info().unwrap();
+    assert!(build_info.rustc_version().starts_with("rustc "));
+    assert!(!build_info.ink_version().is_empty());
+}
@@ -0,0 +1,72 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(expose_call_info = true)]
+mod registry {
+    #[ink(storage)]
+    pub struct Registry {
+        value: bool,
+    }
+
+    impl Registry {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message, selector = 0xCAFEBABE)]
+        pub fn set(&mut self, value: bool) {
+            self.value = value;
+        }
+
+        #[ink(message, selector = 0x0000BEEF)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn call_info_matches_messages() {
+    assert_eq!(
+        registry::Registry::CALL_INFO,
+        &[
+            (0xCAFEBABE, "set(bool)", "()"),
+            (0x0000BEEF, "get()", "bool")
+        ]
+    );
+    let metadata = unsafe { __ink_generate_metadata() };
+    let selectors = metadata
+        .spec()
+        .messages()
+        .iter()
+        .map(|message| {
+            let mut selector = [0x00; 4];
+            selector.copy_from_slice(message.selector().to_bytes());
+            u32::from_be_bytes(selector)
+        })
+        .collect::<Vec<_>>();
+    let call_info_selectors = registry::Registry::CALL_INFO
+        .iter()
+        .map(|(selector, _, _)| *selector)
+        .collect::<Vec<_>>();
+    assert_eq!(call_info_selectors, selectors);
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "client")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod token {
+    #[ink(storage)]
+    pub struct Token {
+        total_supply: Balance,
+    }
+
+    impl Token {
+        /// Creates a new token with the given total supply.
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            Self { total_supply }
+        }
+
+        /// Returns the total supply of the token.
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        /// Transfers `value` tokens to `to`.
+        #[ink(message, selector = "0xCAFEBABE")]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
+            let _ = (to, value);
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scale::Encode as _;
+
+        #[test]
+        fn client_encodes_transfer_payload() {
+            let to = AccountId::from([0x01; 32]);
+            let call = TokenClient.transfer(to, 1000);
+            let expected = [
+                &[0xCA, 0xFE, 0xBA, 0xBE][..],
+                &to.encode()[..],
+                &1000u128.encode()[..],
+            ]
+            .concat();
+            assert_eq!(call.input(), &expected[..]);
+        }
+
+        #[test]
+        fn client_decodes_message_results() {
+            let call = TokenClient.total_supply();
+            assert_eq!(&call.input()[4..], &[] as &[u8]);
+            assert_eq!(call.decode_output(&42u128.encode()), Ok(42));
+            let mut trailing = 42u128.encode();
+            trailing.push(0x00);
+            assert!(call.decode_output(&trailing).is_err());
+            assert!(TokenClient
+                .transfer(AccountId::from([0x01; 32]), 1)
+                .decode_output(&true.encode())
+                .unwrap());
+        }
+    }
+}
@@ -26,6 +26,20 @@ fn compile_tests() {
     t.pass("tests/ui/pass/08-static-env.rs");
     t.pass("tests/ui/pass/09-derive-for-storage.rs");
     t.pass("tests/ui/pass/10-alias-storage-struct-impl.rs");
+    t.pass("tests/ui/pass/11-getter-message.rs");
+    t.pass("tests/ui/pass/12-test-only-message.rs");
+    t.pass("tests/ui/pass/13-allow-no-constructor.rs");
+    t.pass("tests/ui/pass/14-inner-module-docs.rs");
+    t.pass("tests/ui/pass/15-max-input-size.rs");
+    t.pass("tests/ui/pass/16-dependency-ref.rs");
+    t.pass("tests/ui/pass/17-dependency-ref-name.rs");
+    t.pass("tests/ui/pass/18-emit-interface.rs");
+    t.pass("tests/ui/pass/19-raw-return.rs");
+    t.pass("tests/ui/pass/20-slice-input.rs");
+    t.pass("tests/ui/pass/21-path-form-attributes.rs");
+    t.pass("tests/ui/pass/22-no-panic-dispatch.rs");
+    t.pass("tests/ui/pass/23-storage-alias.rs");
+    t.pass("tests/ui/pass/24-fallible-constructor.rs");
 
     t.compile_fail("tests/ui/fail/C-00-constructor-self-ref.rs");
     t.compile_fail("tests/ui/fail/C-01-constructor-self-mut.rs");
@@ -48,6 +62,8 @@ fn compile_tests() {
     t.compile_fail("tests/ui/fail/M-03-message-returns-self.rs");
     t.compile_fail("tests/ui/fail/M-04-message-returns-non-codec.rs");
     t.compile_fail("tests/ui/fail/M-05-message-invalid-selector.rs");
+    t.compile_fail("tests/ui/fail/M-06-getter-conflicting-method.rs");
+    t.compile_fail("tests/ui/fail/M-07-getter-unknown-field.rs");
     t.compile_fail("tests/ui/fail/M-10-method-unknown-ink-marker.rs");
 
     t.compile_fail("tests/ui/fail/S-01-missing-storage-struct.rs");
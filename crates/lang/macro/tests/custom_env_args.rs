@@ -0,0 +1,115 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+/// An account identifier that is only 20 bytes wide.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ShortAccountId([u8; 20]);
+
+/// An environment that uses `ShortAccountId` as its `AccountId` and `u64` as
+/// its `Balance` type.
+pub enum ShortAccountEnvironment {}
+
+impl ink_env::Environment for ShortAccountEnvironment {
+    const MAX_EVENT_TOPICS: usize = 4;
+    type AccountId = ShortAccountId;
+    type Balance = u64;
+    type Hash = <ink_env::DefaultEnvironment as ink_env::Environment>::Hash;
+    type Timestamp = <ink_env::DefaultEnvironment as ink_env::Environment>::Timestamp;
+    type BlockNumber = <ink_env::DefaultEnvironment as ink_env::Environment>::BlockNumber;
+    type ChainExtension = ink_env::NoChainExtension;
+}
+
+#[ink::contract(env = crate::ShortAccountEnvironment)]
+mod payments {
+    #[ink(storage)]
+    pub struct Payments {
+        total: Balance,
+    }
+
+    impl Payments {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { total: 0 }
+        }
+
+        #[ink(message)]
+        pub fn pay(&mut self, to: AccountId, amount: Balance) -> AccountId {
+            self.total += amount;
+            to
+        }
+
+        #[ink(message)]
+        pub fn total(&self) -> Balance {
+            self.total
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::ShortAccountId;
+
+        #[test]
+        fn message_inputs_use_types_of_environment() {
+            // The environment types of the message inputs must resolve to the
+            // types of the configured environment instead of the default one.
+            let pay: fn(&mut Payments, ShortAccountId, u64) -> ShortAccountId =
+                Payments::pay;
+            let mut payments = Payments::new();
+            let to = ShortAccountId([0x01; 20]);
+            assert_eq!(pay(&mut payments, to, 5), to);
+            assert_eq!(payments.total(), 5);
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn message_inputs_keep_environment_display_names() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let args = metadata.spec().messages()[0]
+        .args()
+        .iter()
+        .map(|arg| {
+            (
+                arg.name().clone(),
+                arg.ty().display_name().segments().to_vec(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        args,
+        vec![
+            ("to".to_string(), vec!["AccountId".to_string()]),
+            ("amount".to_string(), vec!["Balance".to_string()]),
+        ],
+    );
+}
+
+#[test]
+fn environment_metadata_uses_widths_of_environment() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let environment = metadata.spec().environment().unwrap();
+    assert_eq!(environment.account_id().size(), Some(20));
+    assert_eq!(environment.balance().size(), Some(8));
+}
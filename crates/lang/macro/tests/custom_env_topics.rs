@@ -0,0 +1,108 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+/// A hash type that is only half as wide as the default `Hash`.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    scale::Encode,
+    scale::Decode,
+)]
+pub struct ShortHash([u8; 16]);
+
+impl AsRef<[u8]> for ShortHash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl AsMut<[u8]> for ShortHash {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0[..]
+    }
+}
+
+impl ink_env::Clear for ShortHash {
+    fn is_clear(&self) -> bool {
+        self.0.iter().all(|&byte| byte == 0x00)
+    }
+
+    fn clear() -> Self {
+        Self([0x00; 16])
+    }
+}
+
+/// An environment that uses `ShortHash` as its `Hash` type.
+pub enum ShortHashEnvironment {}
+
+impl ink_env::Environment for ShortHashEnvironment {
+    const MAX_EVENT_TOPICS: usize = 4;
+    type AccountId = <ink_env::DefaultEnvironment as ink_env::Environment>::AccountId;
+    type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+    type Hash = ShortHash;
+    type Timestamp = <ink_env::DefaultEnvironment as ink_env::Environment>::Timestamp;
+    type BlockNumber = <ink_env::DefaultEnvironment as ink_env::Environment>::BlockNumber;
+    type ChainExtension = ink_env::NoChainExtension;
+}
+
+#[ink::contract(env = crate::ShortHashEnvironment)]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    /// Exemplary event
+    #[ink(event)]
+    pub struct MyEvent {
+        #[ink(topic)]
+        v0: Option<AccountId>,
+        #[ink(topic)]
+        v1: Balance,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            MyContract {}
+        }
+
+        /// Emits a `MyEvent`.
+        #[ink(message)]
+        pub fn emit_my_event(&self) {
+            Self::env().emit_event(MyEvent { v0: None, v1: 0 });
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn environment_metadata_does_not_require_type_info() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let hash = metadata.spec().environment().unwrap().hash();
+    assert_eq!(hash.name(), "custom_env_topics::ShortHash");
+    assert_eq!(hash.size(), Some(16));
+}
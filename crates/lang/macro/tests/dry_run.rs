@@ -0,0 +1,82 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "dry-run")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod token {
+    #[ink(storage)]
+    pub struct Token {
+        total_supply: Balance,
+    }
+
+    impl Token {
+        /// Creates a new token with the given total supply.
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            Self { total_supply }
+        }
+
+        /// Returns the total supply of the token.
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        /// Transfers `value` tokens to `to`.
+        #[ink(message, selector = "0xCAFEBABE")]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
+            let _ = (to, value);
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scale::Encode as _;
+
+        #[test]
+        fn dry_run_stubs_exist_per_message() {
+            let to = AccountId::from([0x01; 32]);
+            assert_eq!(
+                TokenClient.transfer_dry_run(to, 1000).input(),
+                TokenClient.transfer(to, 1000).input(),
+            );
+            assert_eq!(
+                TokenClient.total_supply_dry_run().input(),
+                TokenClient.total_supply().input(),
+            );
+        }
+
+        #[test]
+        fn dry_run_decodes_result_and_revert_flag() {
+            let committed = TokenClient
+                .total_supply_dry_run()
+                .decode_output(0, &42u128.encode())
+                .unwrap();
+            assert_eq!(committed.result(), &42);
+            assert!(!committed.is_reverted());
+            let reverted = TokenClient
+                .transfer_dry_run(AccountId::from([0x01; 32]), 1)
+                .decode_output(1, &false.encode())
+                .unwrap();
+            assert!(reverted.is_reverted());
+            assert!(!reverted.into_result());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "event-filters")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod token {
+    #[ink(storage)]
+    pub struct Token {}
+
+    /// Emitted whenever tokens are transferred.
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    /// Emitted whenever tokens are burned.
+    #[ink(event, anonymous)]
+    pub struct Burn {
+        #[ink(topic)]
+        from: AccountId,
+        value: Balance,
+    }
+
+    impl Token {
+        /// Creates a new token.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        /// Transfers `value` tokens from `from` to `to`.
+        #[ink(message)]
+        pub fn transfer(&self, from: AccountId, to: AccountId, value: Balance) {
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+        }
+
+        /// Burns `value` tokens of `from`.
+        #[ink(message)]
+        pub fn burn(&self, from: AccountId, value: Balance) {
+            self.env().emit_event(Burn { from, value });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::test::EmittedEvent;
+        use ink_lang as ink;
+
+        /// Returns the raw topics of the last emitted event.
+        fn last_event_topics() -> Vec<Vec<u8>> {
+            ink_env::test::recorded_events()
+                .collect::<Vec<EmittedEvent>>()
+                .last()
+                .expect("missing emitted event")
+                .topics
+                .iter()
+                .map(|topic| {
+                    topic
+                        .encoded_bytes()
+                        .expect("encoded bytes must exist")
+                        .to_vec()
+                })
+                .collect()
+        }
+
+        /// Returns the raw bytes of the given filter topics.
+        fn filter_topics(topics: &[Option<Hash>]) -> Vec<Option<Vec<u8>>> {
+            topics
+                .iter()
+                .map(|topic| topic.as_ref().map(|topic| topic.as_ref().to_vec()))
+                .collect()
+        }
+
+        #[ink::test]
+        fn transfer_filter_by_from_matches_emitted_topics() {
+            let from = AccountId::from([0x01; 32]);
+            let to = AccountId::from([0x02; 32]);
+            Token::new().transfer(from, to, 100);
+            let emitted = last_event_topics();
+            let filter = TransferFilter::new().from(&Some(from));
+            assert_eq!(
+                filter_topics(filter.topics()),
+                vec![Some(emitted[0].clone()), Some(emitted[1].clone()), None],
+            );
+            // Filters on other values do not match the emitted topic.
+            let other = TransferFilter::new().from(&Some(to));
+            assert_ne!(filter_topics(other.topics())[1], Some(emitted[1].clone()));
+        }
+
+        #[ink::test]
+        fn anonymous_filter_has_no_signature_topic() {
+            let from = AccountId::from([0x01; 32]);
+            Token::new().burn(from, 100);
+            let emitted = last_event_topics();
+            assert_eq!(filter_topics(BurnFilter::new().topics()), vec![None],);
+            assert_eq!(
+                filter_topics(BurnFilter::new().from(&from).into_filter().topics()),
+                vec![Some(emitted[0].clone())],
+            );
+        }
+    }
+}
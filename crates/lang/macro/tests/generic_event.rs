@@ -0,0 +1,95 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    /// Exemplary token type.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MyToken(u8);
+
+    /// Exemplary generic event.
+    #[ink(event)]
+    pub struct Transfer<T> {
+        #[ink(topic)]
+        token: T,
+        value: Balance,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            MyContract {}
+        }
+
+        /// Emits a `Transfer<MyToken>`.
+        #[ink(message)]
+        pub fn transfer_my_token(&self, value: Balance) {
+            Self::env().emit_event(Transfer::<MyToken> {
+                token: MyToken(1),
+                value,
+            });
+        }
+
+        /// Emits a `Transfer<u8>`.
+        #[ink(message)]
+        pub fn transfer_u8(&self, value: Balance) {
+            Self::env().emit_event(Transfer::<u8> { token: 1, value });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::test::EmittedEvent;
+        use ink_lang as ink;
+        use scale::Decode as _;
+
+        #[ink::test]
+        fn generic_event_works() {
+            // given
+            let my_contract = MyContract::new();
+
+            // when
+            my_contract.transfer_my_token(100);
+            my_contract.transfer_u8(100);
+
+            // then
+            let emitted_events =
+                ink_env::test::recorded_events().collect::<Vec<EmittedEvent>>();
+            assert_eq!(emitted_events.len(), 2);
+            let transfer = <Transfer<MyToken>>::decode(&mut &emitted_events[0].data[1..])
+                .expect("encountered invalid `Transfer<MyToken>` event data");
+            assert_eq!(transfer.token, MyToken(1));
+            assert_eq!(transfer.value, 100);
+            // the signature topic depends on the concrete instantiation
+            let signature_topics = emitted_events
+                .iter()
+                .map(|event| {
+                    event.topics[0]
+                        .encoded_bytes()
+                        .expect("encoded bytes must exist")
+                })
+                .collect::<Vec<_>>();
+            assert_ne!(signature_topics[0], signature_topics[1]);
+        }
+    }
+}
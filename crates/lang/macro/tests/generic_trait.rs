@@ -0,0 +1,96 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait Token<Unit> {
+    /// Returns the total supply of the token.
+    #[ink(message)]
+    fn total_supply(&self) -> Unit;
+
+    /// Mints the given amount of tokens.
+    #[ink(message)]
+    fn mint(&mut self, amount: Unit);
+}
+
+#[ink::contract]
+mod coin {
+    use super::Token;
+
+    /// The unit the coin is accounted in.
+    pub type Cents = u64;
+
+    #[ink(storage)]
+    pub struct Coin {
+        total_supply: Cents,
+    }
+
+    impl Coin {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { total_supply: 0 }
+        }
+    }
+
+    impl Token<Cents> for Coin {
+        #[ink(message)]
+        fn total_supply(&self) -> Cents {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn mint(&mut self, amount: Cents) {
+            self.total_supply += amount;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn generic_trait_is_implemented() {
+            let mut coin = Coin::new();
+            <Coin as Token<Cents>>::mint(&mut coin, 42);
+            assert_eq!(<Coin as Token<Cents>>::total_supply(&coin), 42);
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn generic_trait_messages_use_bound_types() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let messages = metadata.spec().messages();
+    let total_supply = &messages[0];
+    assert_eq!(
+        total_supply.name(),
+        &["Token".to_string(), "total_supply".to_string()],
+    );
+    assert_eq!(
+        total_supply.selector().to_bytes(),
+        ink_lang_ir::Selector::new(b"Token::total_supply").as_bytes(),
+    );
+    let mint = &messages[1];
+    assert_eq!(
+        mint.args()[0].ty().display_name().segments(),
+        &["Cents".to_string()],
+    );
+}
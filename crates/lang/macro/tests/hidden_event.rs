@@ -0,0 +1,80 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod hidden_event {
+    #[ink(storage)]
+    pub struct HiddenEvent {}
+
+    /// Internal event excluded from the metadata.
+    #[ink(event)]
+    #[doc(hidden)]
+    pub struct Internal {
+        value: u8,
+    }
+
+    /// Public event declared after the hidden one.
+    #[ink(event)]
+    pub struct Public {
+        pub value: u32,
+    }
+
+    impl HiddenEvent {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn emit_internal(&self) {
+            self.env().emit_event(Internal { value: 1 });
+        }
+
+        #[ink(message)]
+        pub fn emit_public(&self) {
+            self.env().emit_event(Public { value: 42 });
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[ink::test]
+fn events_after_hidden_events_decode_through_metadata() {
+    use hidden_event::{
+        HiddenEvent,
+        Public,
+    };
+    use scale::Decode as _;
+
+    let contract = HiddenEvent::new();
+    contract.emit_public();
+    let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+    assert_eq!(emitted_events.len(), 1);
+    let data = &mut &emitted_events[0].data[..];
+    let variant_index = u8::decode(data).unwrap() as usize;
+    let metadata = unsafe { __ink_generate_metadata() };
+    let events = metadata.spec().events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[variant_index].name(), "Public");
+    let event = Public::decode(data).unwrap();
+    assert!(data.is_empty());
+    assert_eq!(event.value, 42);
+}
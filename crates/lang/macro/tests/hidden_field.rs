@@ -0,0 +1,110 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod bookkeeping {
+    #[ink(storage)]
+    pub struct Bookkeeping {
+        value: i32,
+        #[ink(hidden)]
+        nonce: u64,
+        flag: bool,
+    }
+
+    impl Bookkeeping {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                value: 42,
+                nonce: 7,
+                flag: true,
+            }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> (i32, u64, bool) {
+            (self.value, self.nonce, self.flag)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use ink_metadata::layout::{
+            Layout,
+            LayoutKey,
+        };
+        use ink_primitives::{
+            Key,
+            KeyPtr,
+        };
+        use ink_storage::traits::{
+            pull_spread_root,
+            push_spread_root,
+            SpreadLayout,
+            StorageLayout,
+        };
+
+        fn key(n: u8) -> Key {
+            let mut bytes = [0x00; 32];
+            bytes[0] = n;
+            Key::from(bytes)
+        }
+
+        #[ink::test]
+        fn hidden_field_is_part_of_the_layout() {
+            assert_eq!(<Bookkeeping as SpreadLayout>::FOOTPRINT, 3);
+            push_spread_root(&Bookkeeping::new(), &key(0));
+            assert_eq!(ink_env::get_contract_storage::<u64>(&key(1)), Ok(Some(7)));
+            assert_eq!(
+                ink_env::get_contract_storage::<bool>(&key(2)),
+                Ok(Some(true))
+            );
+            let pulled = pull_spread_root::<Bookkeeping>(&key(0));
+            assert_eq!(pulled.get(), (42, 7, true));
+        }
+
+        #[test]
+        fn hidden_field_is_absent_from_metadata() {
+            let layout =
+                <Bookkeeping as StorageLayout>::layout(&mut KeyPtr::from(key(0)));
+            let struct_layout = match layout {
+                Layout::Struct(struct_layout) => struct_layout,
+                _ => panic!("expected struct layout for the storage struct"),
+            };
+            let fields = struct_layout
+                .fields()
+                .iter()
+                .map(|field| {
+                    let key = match field.layout() {
+                        Layout::Cell(cell) => cell.key(),
+                        _ => panic!("expected cell layout for primitive storage field"),
+                    };
+                    (field.name().copied(), key)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                fields,
+                vec![
+                    (Some("value"), &LayoutKey::from(key(0))),
+                    (Some("flag"), &LayoutKey::from(key(2))),
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(max_input_size = 8)]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: u32,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            MyContract { value: 0 }
+        }
+
+        /// Sets the current value.
+        #[ink(message, selector = "0x00000001")]
+        pub fn set(&mut self, new_value: u32) {
+            self.value = new_value;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            call::Selector,
+            test::CallData,
+            DefaultEnvironment,
+        };
+        use ink_lang as ink;
+        use ink_lang::{
+            BoundedInput,
+            DispatchError,
+            DispatchMode,
+            DispatchUsingMode,
+        };
+        use scale::Decode as _;
+
+        /// The selector followed by the encoded `u32` input.
+        type Input = ([u8; 4], u32);
+
+        #[test]
+        fn input_within_bounds_is_accepted() {
+            let input = [0x01, 0x02, 0x03, 0x04, 0x2A, 0x00, 0x00, 0x00];
+            let decoded = <BoundedInput<Input, 8>>::decode(&mut &input[..])
+                .map(BoundedInput::into_inner);
+            assert_eq!(decoded.ok(), Some(([0x01, 0x02, 0x03, 0x04], 42)));
+        }
+
+        #[test]
+        fn oversized_input_is_rejected() {
+            let input = [0x01, 0x02, 0x03, 0x04, 0x2A, 0x00, 0x00, 0x00, 0xFF];
+            assert_eq!(
+                <BoundedInput<Input, 8>>::decode(&mut &input[..])
+                    .map(BoundedInput::into_inner)
+                    .map_err(|error| error.to_string()),
+                Err("input exceeds the maximum input size".to_string()),
+            );
+        }
+
+        /// Dispatches the given call data in the given mode.
+        fn dispatch(
+            mode: DispatchMode,
+            call_data: CallData,
+        ) -> Result<(), DispatchError> {
+            ink_env::test::push_execution_context::<DefaultEnvironment>(
+                [0x01; 32].into(),
+                [0x07; 32].into(),
+                1_000_000,
+                0,
+                call_data,
+            );
+            let result = <MyContract as DispatchUsingMode>::dispatch_using_mode(mode);
+            ink_env::test::pop_execution_context();
+            result
+        }
+
+        #[ink::test]
+        fn oversized_call_data_is_rejected_by_dispatch() {
+            let constructor = CallData::new(Selector::new([0x00; 4]));
+            assert!(dispatch(DispatchMode::Instantiate, constructor).is_ok());
+            let mut set = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x01]));
+            set.push_arg(&42_u32);
+            assert!(dispatch(DispatchMode::Call, set.clone()).is_ok());
+            // One byte more than the maximum input size of 8 bytes.
+            set.push_arg(&0xFF_u8);
+            assert!(matches!(
+                dispatch(DispatchMode::Call, set),
+                Err(DispatchError::CouldNotReadInput)
+            ));
+        }
+    }
+}
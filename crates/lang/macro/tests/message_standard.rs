@@ -0,0 +1,78 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod token {
+    #[ink(storage)]
+    pub struct Token {
+        total_supply: Balance,
+    }
+
+    impl Token {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            Self { total_supply }
+        }
+
+        /// Returns the total supply of the token.
+        #[ink(message, standard = "erc20::total_supply")]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        /// Transfers `value` tokens to `to`.
+        #[ink(message, standard = "erc20::transfer")]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
+            let _ = (to, value);
+            true
+        }
+
+        /// Not part of any standard interface.
+        #[ink(message)]
+        pub fn burn(&mut self, value: Balance) {
+            self.total_supply -= value;
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn standard_tags_are_emitted_into_metadata() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let standards = metadata
+        .spec()
+        .messages()
+        .iter()
+        .map(|message| {
+            (
+                message.name().last().unwrap().as_str(),
+                message.standard().map(String::as_str),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        standards,
+        vec![
+            ("total_supply", Some("erc20::total_supply")),
+            ("transfer", Some("erc20::transfer")),
+            ("burn", None),
+        ],
+    );
+}
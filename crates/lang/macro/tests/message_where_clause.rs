@@ -0,0 +1,65 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod flipper {
+    #[ink(storage)]
+    #[derive(Clone)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new() -> Self
+        where
+            Self: Clone,
+        {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self)
+        where
+            Self: Clone,
+            for<'a> &'a bool: core::ops::Not<Output = bool>,
+        {
+            self.value = !&self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool
+        where
+            Self: Clone,
+        {
+            self.clone().value
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn messages_with_where_clause_work() {
+            let mut flipper = Flipper::new();
+            assert!(!flipper.get());
+            flipper.flip();
+            assert!(flipper.get());
+        }
+    }
+}
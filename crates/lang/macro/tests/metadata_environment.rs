@@ -0,0 +1,67 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod flipper {
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn environment_is_emitted_with_default_widths() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let environment = metadata.spec().environment().unwrap();
+    let env_types = [
+        environment.account_id(),
+        environment.balance(),
+        environment.hash(),
+        environment.block_number(),
+        environment.timestamp(),
+    ]
+    .iter()
+    .map(|env_type| (env_type.name().as_str(), env_type.size()))
+    .collect::<Vec<_>>();
+    assert_eq!(
+        env_types,
+        vec![
+            ("ink_env::types::AccountId", Some(32)),
+            ("u128", Some(16)),
+            ("ink_env::types::Hash", Some(32)),
+            ("u32", Some(4)),
+            ("u64", Some(8)),
+        ],
+    );
+}
@@ -0,0 +1,98 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(migration_stubs = true)]
+mod versioned {
+    #[ink(storage, version = 2)]
+    pub struct Versioned {
+        owner: AccountId,
+        /// Records the migrations that have been run as decimal digits.
+        migrations: u32,
+    }
+
+    impl ink_lang::Migrate for Versioned {
+        fn can_migrate(&self) -> bool {
+            use ink_lang::Env as _;
+            self.env().caller() == self.owner
+        }
+    }
+
+    impl Versioned {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                migrations: 0,
+            }
+        }
+
+        #[ink(message)]
+        pub fn migrations(&self) -> u32 {
+            self.migrations
+        }
+
+        fn migrate_from_v0(&mut self) {
+            self.migrations = self.migrations * 10 + 1;
+        }
+
+        fn migrate_from_v1(&mut self) {
+            self.migrations = self.migrations * 10 + 2;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        #[ink::test]
+        fn migrate_runs_all_later_migrations_in_order() {
+            let mut versioned = Versioned::new();
+            versioned.migrate(0);
+            assert_eq!(versioned.migrations(), 12);
+        }
+
+        #[ink::test]
+        fn migrate_skips_earlier_migrations() {
+            let mut versioned = Versioned::new();
+            versioned.migrate(1);
+            assert_eq!(versioned.migrations(), 2);
+        }
+
+        #[ink::test]
+        #[should_panic(
+            expected = "cannot migrate the storage of the contract from version 2"
+        )]
+        fn migrate_from_current_version_fails() {
+            let mut versioned = Versioned::new();
+            versioned.migrate(2);
+        }
+
+        #[ink::test]
+        #[should_panic(
+            expected = "caller is not allowed to migrate the storage of the contract"
+        )]
+        fn non_owner_cannot_migrate() {
+            let mut versioned = Versioned {
+                owner: AccountId::from([0xFF; 32]),
+                migrations: 0,
+            };
+            versioned.migrate(0);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod counter {
+    /// Defines a getter returning the given storage field.
+    macro_rules! getter {
+        ( $name:ident, $field:ident, $ty:ty ) => {
+            impl Counter {
+                pub fn $name(&self) -> $ty {
+                    self.$field
+                }
+            }
+        };
+    }
+
+    /// Returns the given value doubled.
+    macro_rules! double {
+        ( $value:expr ) => {
+            $value * 2
+        };
+    }
+
+    #[ink(storage)]
+    pub struct Counter {
+        value: u32,
+    }
+
+    getter!(current, value, u32);
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new(value: u32) -> Self {
+            Self {
+                value: double!(value),
+            }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            double!(self.current())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn module_macros_are_preserved() {
+            let counter = Counter::new(1);
+            assert_eq!(counter.current(), 2);
+            assert_eq!(counter.get(), 4);
+        }
+    }
+}
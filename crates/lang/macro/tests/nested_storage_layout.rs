@@ -0,0 +1,111 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod bank {
+    use ink_storage::{
+        collections::HashMap as StorageHashMap,
+        traits::SpreadLayout,
+    };
+
+    /// The balances of all accounts of the bank.
+    #[derive(Debug, SpreadLayout)]
+    #[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+    pub struct Balances {
+        total: Balance,
+        accounts: StorageHashMap<AccountId, Balance>,
+    }
+
+    #[ink(storage)]
+    pub struct Bank {
+        owner: AccountId,
+        balances: Balances,
+    }
+
+    impl Bank {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                balances: Balances {
+                    total: 0,
+                    accounts: Default::default(),
+                },
+            }
+        }
+
+        #[ink(message)]
+        pub fn total(&self) -> Balance {
+            self.balances.total
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_metadata::layout::{
+            Layout,
+            LayoutKey,
+        };
+        use ink_primitives::{
+            Key,
+            KeyPtr,
+        };
+        use ink_storage::traits::StorageLayout;
+
+        fn key(n: u8) -> Key {
+            let mut bytes = [0x00; 32];
+            bytes[0] = n;
+            Key::from(bytes)
+        }
+
+        fn fields(layout: &Layout) -> Vec<(Option<&'static str>, &Layout)> {
+            match layout {
+                Layout::Struct(struct_layout) => {
+                    struct_layout
+                        .fields()
+                        .iter()
+                        .map(|field| (field.name().copied(), field.layout()))
+                        .collect()
+                }
+                _ => panic!("expected struct layout"),
+            }
+        }
+
+        #[test]
+        fn sub_struct_layout_is_nested() {
+            let layout = <Bank as StorageLayout>::layout(&mut KeyPtr::from(key(0)));
+            let bank = fields(&layout);
+            assert_eq!(
+                bank.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+                vec![Some("owner"), Some("balances")],
+            );
+            let balances = fields(bank[1].1);
+            assert_eq!(
+                balances.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+                vec![Some("total"), Some("accounts")],
+            );
+            match balances[0].1 {
+                Layout::Cell(cell) => assert_eq!(cell.key(), &LayoutKey::from(key(1))),
+                _ => panic!("expected cell layout for `total`"),
+            }
+            // The storage hash map itself is laid out as a struct of its parts.
+            assert!(matches!(balances[1].1, Layout::Struct(_)));
+        }
+    }
+}
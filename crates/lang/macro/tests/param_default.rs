@@ -0,0 +1,72 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod counter {
+    #[ink(storage)]
+    pub struct Counter {
+        value: u32,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new(#[ink(default = 0)] init_value: u32) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn inc_by(&mut self, #[ink(default = 1)] by: u32, wrapping: bool) {
+            self.value = match wrapping {
+                true => self.value.wrapping_add(by),
+                false => self.value + by,
+            };
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn param_defaults_are_emitted_into_metadata() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let spec = metadata.spec();
+    let defaults =
+        |args: &[ink_metadata::MessageParamSpec<scale_info::form::PortableForm>]| {
+            args.iter()
+                .map(|arg| (arg.name().clone(), arg.default().cloned()))
+                .collect::<Vec<_>>()
+        };
+    assert_eq!(
+        defaults(spec.constructors()[0].args()),
+        vec![("init_value".to_string(), Some("0".to_string()))],
+    );
+    assert_eq!(
+        defaults(spec.messages()[0].args()),
+        vec![
+            ("by".to_string(), Some("1".to_string())),
+            ("wrapping".to_string(), None),
+        ],
+    );
+}
@@ -0,0 +1,110 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod raw_return {
+    use ink_prelude::vec::Vec;
+
+    #[ink(storage)]
+    pub struct RawReturn {
+        bytes: Vec<u8>,
+    }
+
+    impl RawReturn {
+        /// Creates a new `RawReturn` instance storing the given bytes.
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new(bytes: Vec<u8>) -> Self {
+            Self { bytes }
+        }
+
+        /// Returns the stored bytes without SCALE length prefix.
+        #[ink(message, raw_return, selector = "0x00000001")]
+        pub fn raw_bytes(&self) -> Vec<u8> {
+            self.bytes.clone()
+        }
+
+        /// Returns the SCALE encoded stored bytes.
+        #[ink(message, selector = "0x00000002")]
+        pub fn bytes(&self) -> Vec<u8> {
+            self.bytes.clone()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            call::Selector,
+            test::CallData,
+            DefaultEnvironment,
+        };
+        use ink_lang as ink;
+        use ink_lang::{
+            DispatchMode,
+            DispatchUsingMode,
+        };
+        use scale::Encode as _;
+
+        /// Dispatches the call data in the given mode and returns the bytes
+        /// returned to the caller if any.
+        fn dispatch(mode: DispatchMode, call_data: CallData) -> Option<Vec<u8>> {
+            ink_env::test::push_execution_context::<DefaultEnvironment>(
+                [0x01; 32].into(),
+                [0x07; 32].into(),
+                1_000_000,
+                0,
+                call_data,
+            );
+            let returned = ink_env::test::catch_return_value(|| {
+                <RawReturn as DispatchUsingMode>::dispatch_using_mode(mode)
+                    .expect("encountered dispatch error")
+            });
+            ink_env::test::pop_execution_context();
+            returned.map(|returned| {
+                assert!(!returned.reverted);
+                returned.data
+            })
+        }
+
+        /// Instantiates the contract storing the given bytes.
+        fn instantiate(bytes: Vec<u8>) {
+            let mut call_data = CallData::new(Selector::new([0x00; 4]));
+            call_data.push_arg(&bytes);
+            assert_eq!(dispatch(DispatchMode::Instantiate, call_data), None);
+        }
+
+        #[ink::test]
+        fn raw_return_bytes_are_unmodified() {
+            let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+            instantiate(bytes.clone());
+            let call_data = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x01]));
+            assert_eq!(dispatch(DispatchMode::Call, call_data), Some(bytes));
+        }
+
+        #[ink::test]
+        fn non_raw_return_bytes_are_length_prefixed() {
+            let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+            instantiate(bytes.clone());
+            let call_data = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x02]));
+            assert_eq!(
+                dispatch(DispatchMode::Call, call_data),
+                Some(bytes.encode())
+            );
+        }
+    }
+}
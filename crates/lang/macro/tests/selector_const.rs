@@ -0,0 +1,58 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod registry {
+    /// The selector of the `new` constructor.
+    pub const NEW_SELECTOR: [u8; 4] = [0xCA, 0xFE, 0xBA, 0xBE];
+    /// The selector of the `get` message.
+    pub const GET_SELECTOR: u32 = 0xDEADBEEF;
+
+    #[ink(storage)]
+    pub struct Registry {
+        value: bool,
+    }
+
+    impl Registry {
+        #[ink(constructor, selector = NEW_SELECTOR)]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message, selector = self::GET_SELECTOR)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn selector_consts_are_used() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let constructor = &metadata.spec().constructors()[0];
+    let message = &metadata.spec().messages()[0];
+    assert_eq!(constructor.selector().to_bytes(), &registry::NEW_SELECTOR);
+    assert_eq!(
+        message.selector().to_bytes(),
+        &registry::GET_SELECTOR.to_be_bytes()
+    );
+}
@@ -0,0 +1,129 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod slice_input {
+    #[ink(storage)]
+    pub struct SliceInput {
+        sum: u32,
+    }
+
+    impl SliceInput {
+        /// Creates a new `SliceInput` instance with the sum of the given values.
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new(init_values: &[u32]) -> Self {
+            Self {
+                sum: init_values.iter().sum(),
+            }
+        }
+
+        /// Adds the given values to the sum.
+        #[ink(message, selector = "0x00000001")]
+        pub fn add(&mut self, values: &[u32]) {
+            self.sum += values.iter().sum::<u32>();
+        }
+
+        /// Returns `true` if the given value is contained in the given values.
+        #[ink(message, selector = "0x00000002")]
+        pub fn contains(&self, values: &[u32], value: u32) -> bool {
+            values.contains(&value)
+        }
+
+        /// Returns the current sum.
+        #[ink(message, selector = "0x00000003")]
+        pub fn sum(&self) -> u32 {
+            self.sum
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            call::Selector,
+            test::CallData,
+            DefaultEnvironment,
+        };
+        use ink_lang as ink;
+        use ink_lang::{
+            DispatchMode,
+            DispatchUsingMode,
+        };
+        use scale::Decode as _;
+
+        /// Dispatches the call data in the given mode and returns the bytes
+        /// returned to the caller if any.
+        fn dispatch(mode: DispatchMode, call_data: CallData) -> Option<Vec<u8>> {
+            ink_env::test::push_execution_context::<DefaultEnvironment>(
+                [0x01; 32].into(),
+                [0x07; 32].into(),
+                1_000_000,
+                0,
+                call_data,
+            );
+            let returned = ink_env::test::catch_return_value(|| {
+                <SliceInput as DispatchUsingMode>::dispatch_using_mode(mode)
+                    .expect("encountered dispatch error")
+            });
+            ink_env::test::pop_execution_context();
+            returned.map(|returned| {
+                assert!(!returned.reverted);
+                returned.data
+            })
+        }
+
+        /// Returns the current sum of the dispatched contract.
+        fn sum() -> u32 {
+            let call_data = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x03]));
+            let returned =
+                dispatch(DispatchMode::Call, call_data).expect("missing returned sum");
+            u32::decode(&mut &returned[..]).expect("could not decode sum")
+        }
+
+        #[ink::test]
+        fn slice_inputs_are_decoded_from_encoded_vec() {
+            let mut new = CallData::new(Selector::new([0x00; 4]));
+            new.push_arg(&vec![1_u32, 2, 3]);
+            assert_eq!(dispatch(DispatchMode::Instantiate, new), None);
+            assert_eq!(sum(), 6);
+
+            let mut add = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x01]));
+            add.push_arg(&vec![10_u32, 20]);
+            assert_eq!(dispatch(DispatchMode::Call, add), None);
+            assert_eq!(sum(), 36);
+        }
+
+        #[ink::test]
+        fn slice_input_is_followed_by_other_inputs() {
+            let mut new = CallData::new(Selector::new([0x00; 4]));
+            new.push_arg(&Vec::<u32>::new());
+            assert_eq!(dispatch(DispatchMode::Instantiate, new), None);
+            let contains = |value: u32| {
+                let mut call_data =
+                    CallData::new(Selector::new([0x00, 0x00, 0x00, 0x02]));
+                call_data.push_arg(&vec![1_u32, 2, 3]);
+                call_data.push_arg(&value);
+                let returned = dispatch(DispatchMode::Call, call_data)
+                    .expect("missing returned value");
+                bool::decode(&mut &returned[..]).expect("could not decode bool")
+            };
+            assert!(contains(2));
+            assert!(!contains(4));
+        }
+    }
+}
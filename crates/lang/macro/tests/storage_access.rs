@@ -0,0 +1,58 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(storage_access_hints = true)]
+mod stepper {
+    #[ink(storage)]
+    pub struct Stepper {
+        value: u32,
+        step: u32,
+    }
+
+    impl Stepper {
+        #[ink(constructor)]
+        pub fn new(step: u32) -> Self {
+            Self { value: 0, step }
+        }
+
+        #[ink(message)]
+        pub fn advance(&mut self) {
+            self.value = self.step;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[test]
+fn storage_access_is_emitted_into_metadata() {
+    let metadata = unsafe { __ink_generate_metadata() };
+    let messages = metadata.spec().messages();
+    let advance = messages[0].storage_access().unwrap();
+    assert_eq!(advance.reads(), &["step".to_string()]);
+    assert_eq!(advance.writes(), &["value".to_string()]);
+    let get = messages[1].storage_access().unwrap();
+    assert_eq!(get.reads(), &["value".to_string()]);
+    assert!(get.writes().is_empty());
+}
@@ -0,0 +1,88 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod pinned {
+    #[ink(storage)]
+    pub struct Pinned {
+        #[ink(storage_key = 0x1234)]
+        first: i32,
+        value: bool,
+        #[ink(storage_key = 0x5678)]
+        second: u8,
+    }
+
+    impl Pinned {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                first: 42,
+                value: true,
+                second: 7,
+            }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> (i32, bool, u8) {
+            (self.first, self.value, self.second)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use ink_primitives::Key;
+        use ink_storage::traits::{
+            pull_spread_root,
+            push_spread_root,
+            SpreadLayout,
+        };
+
+        fn key(low: u8, high: u8) -> Key {
+            let mut bytes = [0x00; 32];
+            bytes[0] = low;
+            bytes[1] = high;
+            Key::from(bytes)
+        }
+
+        #[test]
+        fn pinned_fields_do_not_contribute_to_footprint() {
+            assert_eq!(<Pinned as SpreadLayout>::FOOTPRINT, 1);
+        }
+
+        #[ink::test]
+        fn pinned_fields_are_stored_under_their_keys() {
+            let root_key = Key::from([0x00; 32]);
+            push_spread_root(&Pinned::new(), &root_key);
+            assert_eq!(
+                ink_env::get_contract_storage::<i32>(&key(0x34, 0x12)),
+                Ok(Some(42))
+            );
+            assert_eq!(
+                ink_env::get_contract_storage::<u8>(&key(0x78, 0x56)),
+                Ok(Some(7))
+            );
+            assert_eq!(
+                ink_env::get_contract_storage::<bool>(&root_key),
+                Ok(Some(true))
+            );
+            let pulled = pull_spread_root::<Pinned>(&root_key);
+            assert_eq!(pulled.get(), (42, true, 7));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::{
+    call::Selector,
+    test::CallData,
+    DefaultEnvironment,
+};
+use ink_lang as ink;
+use ink_lang::{
+    DispatchMode,
+    DispatchUsingMode,
+};
+
+#[ink::contract]
+mod match_dispatch {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: i32,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            MyContract { value: 0 }
+        }
+
+        /// Seeds the current value for testing purposes.
+        #[ink(message, test_only, selector = "0x00000001")]
+        pub fn seed(&mut self, new_value: i32) {
+            self.value = new_value;
+        }
+
+        /// Returns the current value.
+        #[ink(message, selector = "0x00000002")]
+        pub fn get(&self) -> i32 {
+            self.value
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        #[ink::test]
+        fn test_only_message_works() {
+            let mut my_contract = MyContract::new();
+            assert_eq!(my_contract.get(), 0);
+            my_contract.seed(42);
+            assert_eq!(my_contract.get(), 42);
+        }
+    }
+}
+
+#[ink::contract(dispatch = "binary_search")]
+mod binary_search_dispatch {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: i32,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            MyContract { value: 0 }
+        }
+
+        /// Seeds the current value for testing purposes.
+        #[ink(message, test_only, selector = "0x00000001")]
+        pub fn seed(&mut self, new_value: i32) {
+            self.value = new_value;
+        }
+
+        /// Returns the current value.
+        #[ink(message, selector = "0x00000002")]
+        pub fn get(&self) -> i32 {
+            self.value
+        }
+    }
+}
+
+/// Dispatches the given call data in the given mode to the contract `C` and
+/// returns the bytes returned to the caller if any.
+fn dispatch<C>(mode: DispatchMode, call_data: CallData) -> Option<Vec<u8>>
+where
+    C: DispatchUsingMode,
+{
+    ink_env::test::push_execution_context::<DefaultEnvironment>(
+        [0x01; 32].into(),
+        [0x07; 32].into(),
+        1_000_000,
+        0,
+        call_data,
+    );
+    let returned = ink_env::test::catch_return_value(|| {
+        <C as DispatchUsingMode>::dispatch_using_mode(mode)
+            .expect("encountered dispatch error")
+    });
+    ink_env::test::pop_execution_context();
+    returned.map(|returned| {
+        assert!(!returned.reverted);
+        returned.data
+    })
+}
+
+/// Instantiates the contract `C`, seeds its value through its test-only ink!
+/// message and checks the seeded value.
+fn dispatch_test_only_message<C>()
+where
+    C: DispatchUsingMode,
+{
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let new = CallData::new(Selector::new([0x00; 4]));
+        assert_eq!(dispatch::<C>(DispatchMode::Instantiate, new), None);
+        let mut seed = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x01]));
+        seed.push_arg(&42_i32);
+        assert_eq!(dispatch::<C>(DispatchMode::Call, seed), None);
+        let get = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x02]));
+        assert_eq!(
+            dispatch::<C>(DispatchMode::Call, get),
+            Some(scale::Encode::encode(&42_i32)),
+        );
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_only_message_is_dispatched_in_test_builds() {
+    dispatch_test_only_message::<match_dispatch::MyContract>();
+}
+
+#[test]
+fn test_only_message_is_dispatched_in_test_builds_using_binary_search() {
+    dispatch_test_only_message::<binary_search_dispatch::MyContract>();
+}
@@ -0,0 +1,99 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {}
+
+    /// Exemplary event with topics on a subset of its fields.
+    #[ink(event)]
+    pub struct MyEvent {
+        #[ink(topic)]
+        first: u8,
+        second: u32,
+        #[ink(topic)]
+        third: bool,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            MyContract {}
+        }
+
+        /// Emits a `MyEvent`.
+        #[ink(message)]
+        pub fn emit_my_event(&self) {
+            Self::env().emit_event(MyEvent {
+                first: 1,
+                second: 2,
+                third: true,
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            test::EmittedEvent,
+            topics::PrefixedValue,
+        };
+        use ink_lang as ink;
+        use scale::Encode as _;
+
+        /// Returns the expected topic for the given prefixed value.
+        ///
+        /// The values used in this test are small enough to not be hashed.
+        fn expected_topic<T: scale::Encode>(prefix: &[u8], value: &T) -> [u8; 32] {
+            let encoded = PrefixedValue { prefix, value }.encode();
+            assert!(encoded.len() <= 32);
+            let mut topic = [0x00; 32];
+            topic[..encoded.len()].copy_from_slice(&encoded);
+            topic
+        }
+
+        #[ink::test]
+        fn event_topics_are_in_source_order() {
+            // given
+            let my_contract = MyContract::new();
+
+            // when
+            MyContract::emit_my_event(&my_contract);
+
+            // then
+            let emitted_events =
+                ink_env::test::recorded_events().collect::<Vec<EmittedEvent>>();
+            let encoded_topics: std::vec::Vec<&[u8]> = emitted_events[0]
+                .topics
+                .iter()
+                .map(|topic| topic.encoded_bytes().expect("encoded bytes must exist"))
+                .collect();
+            assert_eq!(
+                encoded_topics,
+                vec![
+                    &expected_topic(b"", b"MyContract::MyEvent")[..],
+                    &expected_topic(b"MyContract::MyEvent::first", &1u8)[..],
+                    &expected_topic(b"MyContract::MyEvent::third", &true)[..],
+                ]
+            );
+        }
+    }
+}
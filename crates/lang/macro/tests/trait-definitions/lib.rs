@@ -0,0 +1,45 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ink! trait definitions used as a dependency by the `trait_call_builder` test
+//! so that their call builders are generated.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::{
+    DefaultEnvironment,
+    Environment,
+};
+use ink_lang as ink;
+
+pub type AccountId = <DefaultEnvironment as Environment>::AccountId;
+pub type Balance = <DefaultEnvironment as Environment>::Balance;
+
+#[ink::trait_definition]
+pub trait Vault {
+    /// Deposits the given amount for the given account.
+    #[ink(message)]
+    fn deposit(&mut self, owner: AccountId, amount: Balance) -> bool;
+
+    /// Returns the deposited amount of the given account.
+    #[ink(message)]
+    fn deposited(&self, owner: AccountId) -> Balance;
+}
+
+#[ink::trait_definition(namespace = "bank::flipping", hash = "keccak_256")]
+pub trait Flip {
+    /// Flips the current value.
+    #[ink(message)]
+    fn flip(&mut self);
+}
@@ -0,0 +1,85 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait TokenInfo {
+    /// The type in which the token amounts are stored.
+    type Amount: Into<u128>;
+
+    /// Returns the total supply of the token.
+    #[ink(message)]
+    fn supply(&self) -> Self::Amount;
+
+    /// Increases the total supply of the token by the given amount.
+    #[ink(message)]
+    fn mint(&mut self, amount: Self::Amount);
+}
+
+#[ink::contract]
+mod token {
+    use super::TokenInfo;
+
+    #[ink(storage)]
+    pub struct Token {
+        total_supply: u64,
+    }
+
+    impl Token {
+        /// Creates a new token with the given total supply.
+        #[ink(constructor)]
+        pub fn new(total_supply: u64) -> Self {
+            Self { total_supply }
+        }
+    }
+
+    impl TokenInfo for Token {
+        type Amount = u64;
+
+        #[ink(message)]
+        fn supply(&self) -> Self::Amount {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn mint(&mut self, amount: Self::Amount) {
+            self.total_supply += amount;
+        }
+    }
+}
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink_metadata::InkProject;
+}
+
+#[ink::test]
+fn trait_assoc_types_in_signatures_work() {
+    use token::Token;
+
+    let mut token = Token::new(40);
+    let amount: <Token as TokenInfo>::Amount = 2;
+    token.mint(amount);
+    assert_eq!(token.supply().into(), 42u128);
+    let metadata = unsafe { __ink_generate_metadata() };
+    let messages = metadata
+        .spec()
+        .messages()
+        .iter()
+        .map(|message| message.name().join("::"))
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["TokenInfo::supply", "TokenInfo::mint"]);
+}
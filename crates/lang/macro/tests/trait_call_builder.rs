@@ -0,0 +1,93 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::{
+    call::{
+        build_call,
+        utils::ReturnType,
+        ExecutionInput,
+        Selector,
+    },
+    hash::{
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+        Keccak256,
+    },
+    DefaultEnvironment,
+};
+use trait_definitions::{
+    AccountId,
+    Balance,
+    FlipCallBuilder,
+    VaultCallBuilder,
+};
+
+/// Returns the selector composed from the given input using the hash `H`.
+fn selector<H>(input: &[u8]) -> Selector
+where
+    H: CryptoHash + HashOutput<Type = [u8; 32]>,
+{
+    let mut output = [0x00; 32];
+    ink_env::hash_bytes::<H>(input, &mut output);
+    Selector::new([output[0], output[1], output[2], output[3]])
+}
+
+#[test]
+fn trait_call_builder_encodes_calls_by_trait_selectors() {
+    let callee = AccountId::from([0x01; 32]);
+    let owner = AccountId::from([0x02; 32]);
+    let builder = VaultCallBuilder::<DefaultEnvironment>::new(callee);
+    assert_eq!(builder.account_id(), &callee);
+    let params = builder.deposit(owner, 100).gas_limit(5000).params();
+    let expected = build_call::<DefaultEnvironment>()
+        .callee(callee)
+        .gas_limit(5000)
+        .exec_input(
+            ExecutionInput::new(selector::<Blake2x256>(b"Vault::deposit"))
+                .push_arg(owner)
+                .push_arg(100 as Balance),
+        )
+        .returns::<ReturnType<bool>>()
+        .params();
+    assert_eq!(format!("{:?}", params), format!("{:?}", expected));
+    let params = builder.deposited(owner).params();
+    let expected = build_call::<DefaultEnvironment>()
+        .callee(callee)
+        .exec_input(
+            ExecutionInput::new(selector::<Blake2x256>(b"Vault::deposited"))
+                .push_arg(owner),
+        )
+        .returns::<ReturnType<Balance>>()
+        .params();
+    assert_eq!(format!("{:?}", params), format!("{:?}", expected));
+}
+
+#[test]
+fn trait_call_builder_uses_namespace_and_hash() {
+    let callee = AccountId::from([0x01; 32]);
+    let params = FlipCallBuilder::<DefaultEnvironment>::new(callee)
+        .flip()
+        .params();
+    let expected = build_call::<DefaultEnvironment>()
+        .callee(callee)
+        .exec_input(ExecutionInput::new(selector::<Keccak256>(
+            b"bank::flipping::Flip::flip",
+        )))
+        .returns::<()>()
+        .params();
+    assert_eq!(format!("{:?}", params), format!("{:?}", expected));
+}
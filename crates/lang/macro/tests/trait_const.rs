@@ -0,0 +1,65 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::trait_definition]
+pub trait TokenInfo {
+    /// The number of decimals of the token.
+    const DECIMALS: u8;
+
+    /// Returns the total supply of the token scaled by its decimals.
+    #[ink(message)]
+    fn scaled_supply(&self) -> u128;
+}
+
+#[ink::contract]
+mod token {
+    use super::TokenInfo;
+
+    #[ink(storage)]
+    pub struct Token {
+        total_supply: Balance,
+    }
+
+    impl Token {
+        /// Creates a new token with the given total supply.
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            Self { total_supply }
+        }
+    }
+
+    impl TokenInfo for Token {
+        const DECIMALS: u8 = 2;
+
+        #[ink(message)]
+        fn scaled_supply(&self) -> u128 {
+            self.total_supply * 10u128.pow(Self::DECIMALS as u32)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn trait_const_is_implemented() {
+            assert_eq!(<Token as TokenInfo>::DECIMALS, 2);
+            assert_eq!(Token::new(42).scaled_supply(), 4200);
+        }
+    }
+}
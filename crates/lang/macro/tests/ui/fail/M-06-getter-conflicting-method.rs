@@ -0,0 +1,27 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod getter_conflicting_method {
+    #[ink(storage)]
+    pub struct GetterConflictingMethod {
+        value: bool,
+    }
+
+    impl GetterConflictingMethod {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message, getter = "value")]
+        pub fn value(&self) -> bool {}
+    }
+
+    impl GetterConflictingMethod {
+        pub fn value(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
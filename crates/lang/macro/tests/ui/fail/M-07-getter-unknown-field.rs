@@ -0,0 +1,21 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod getter_unknown_field {
+    #[ink(storage)]
+    pub struct GetterUnknownField {
+        value: bool,
+    }
+
+    impl GetterUnknownField {
+        #[ink(constructor)]
+        pub fn constructor() -> Self {
+            Self { value: false }
+        }
+
+        #[ink(message, getter = "balance")]
+        pub fn balance(&self) {}
+    }
+}
+
+fn main() {}
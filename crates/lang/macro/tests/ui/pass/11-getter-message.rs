@@ -0,0 +1,32 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod getter_message {
+    #[ink(storage)]
+    pub struct GetterMessage {
+        balance: Balance,
+        owner: AccountId,
+    }
+
+    impl GetterMessage {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                balance: 0,
+                owner: Self::env().caller(),
+            }
+        }
+
+        #[ink(message, getter = "balance")]
+        pub fn balance(&self) -> Balance {}
+
+        #[ink(message, getter = "owner")]
+        pub fn owner(&self) {}
+    }
+
+    fn _ensure_getter_outputs(getter_message: &GetterMessage) -> (Balance, AccountId) {
+        (getter_message.balance(), getter_message.owner())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,104 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod match_dispatch {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: i32,
+    }
+
+    impl MyContract {
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, test_only, selector = "0x00000001")]
+        pub fn seed(&mut self, new_value: i32) {
+            self.value = new_value;
+        }
+
+        #[ink(message, selector = "0x00000002")]
+        pub fn inc(&mut self) {
+            self.value += 1;
+        }
+    }
+}
+
+#[ink::contract(dispatch = "binary_search")]
+mod binary_search_dispatch {
+    #[ink(storage)]
+    pub struct MyContract {
+        value: i32,
+    }
+
+    impl MyContract {
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message, test_only, selector = "0x00000001")]
+        pub fn seed(&mut self, new_value: i32) {
+            self.value = new_value;
+        }
+
+        #[ink(message, selector = "0x00000002")]
+        pub fn inc(&mut self) {
+            self.value += 1;
+        }
+    }
+}
+
+use ink_env::{
+    call::Selector,
+    test::CallData,
+    DefaultEnvironment,
+};
+use ink_lang::{
+    DispatchError,
+    DispatchMode,
+    DispatchUsingMode,
+};
+
+fn dispatch<C>(mode: DispatchMode, call_data: CallData) -> Result<(), DispatchError>
+where
+    C: DispatchUsingMode,
+{
+    ink_env::test::push_execution_context::<DefaultEnvironment>(
+        [0x01; 32].into(),
+        [0x07; 32].into(),
+        1_000_000,
+        0,
+        call_data,
+    );
+    let result = <C as DispatchUsingMode>::dispatch_using_mode(mode);
+    ink_env::test::pop_execution_context();
+    result
+}
+
+fn dispatch_without_test_only_message<C>()
+where
+    C: DispatchUsingMode,
+{
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let constructor = CallData::new(Selector::new([0x00; 4]));
+        assert!(dispatch::<C>(DispatchMode::Instantiate, constructor).is_ok());
+        let inc = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x02]));
+        assert!(dispatch::<C>(DispatchMode::Call, inc).is_ok());
+        // Test-only ink! messages are not dispatchable outside of test builds.
+        let mut seed = CallData::new(Selector::new([0x00, 0x00, 0x00, 0x01]));
+        seed.push_arg(&42_i32);
+        assert!(matches!(
+            dispatch::<C>(DispatchMode::Call, seed),
+            Err(DispatchError::CouldNotReadInput)
+        ));
+        Ok(())
+    })
+    .unwrap()
+}
+
+fn main() {
+    dispatch_without_test_only_message::<match_dispatch::MyContract>();
+    dispatch_without_test_only_message::<binary_search_dispatch::MyContract>();
+}
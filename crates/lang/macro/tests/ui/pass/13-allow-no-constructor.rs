@@ -0,0 +1,14 @@
+use ink_lang as ink;
+
+#[ink::contract(allow_no_constructor = true)]
+mod library {
+    #[ink(storage)]
+    pub struct Library {}
+
+    impl Library {
+        #[ink(message)]
+        pub fn noop(&self) {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use ink_lang as ink;
+
+/// Outer documentation of the contract.
+#[ink::contract]
+mod inner_module_docs {
+    //! Inner documentation of the contract.
+    //!
+    //! Spanning multiple lines.
+
+    #[ink(storage)]
+    pub struct InnerModuleDocs {}
+
+    impl InnerModuleDocs {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn noop(&self) {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use ink_lang as ink;
+
+#[ink::contract(max_input_size = 64)]
+mod max_input_size {
+    #[ink(storage)]
+    pub struct MaxInputSize {
+        value: i32,
+    }
+
+    impl MaxInputSize {
+        #[ink(constructor)]
+        pub fn new(init_value: i32) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, new_value: i32) {
+            self.value = new_value;
+        }
+    }
+}
+
+fn main() {}
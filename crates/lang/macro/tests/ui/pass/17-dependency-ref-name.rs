@@ -0,0 +1,25 @@
+use ink_lang as ink;
+
+#[ink::contract(compile_as_dependency = true, ref_name = "MyFlipper")]
+mod flipper {
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {
+    let _: Option<MyFlipper> = None;
+}
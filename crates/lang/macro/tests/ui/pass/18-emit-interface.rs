@@ -0,0 +1,38 @@
+use ink_lang as ink;
+
+#[ink::contract(emit_interface = true)]
+mod flipper {
+    #[ink(storage)]
+    pub struct Flipper {
+        value: bool,
+    }
+
+    impl Flipper {
+        #[ink(constructor)]
+        pub fn new(init_value: bool) -> Self {
+            Self { value: init_value }
+        }
+
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+use flipper::FlipperInterface;
+
+fn flip_twice<T: FlipperInterface>(flipper: &mut T) -> bool {
+    flipper.flip();
+    flipper.flip();
+    flipper.get()
+}
+
+fn main() {
+    let _ = flip_twice::<flipper::Flipper>;
+}
@@ -0,0 +1,28 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod raw_return {
+    use ink_prelude::vec::Vec;
+
+    #[ink(storage)]
+    pub struct RawReturn {}
+
+    impl RawReturn {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message, raw_return)]
+        pub fn raw_bytes(&self) -> Vec<u8> {
+            Vec::new()
+        }
+
+        #[ink(message, raw_return)]
+        pub fn raw_bytes_mut(&mut self, len: u32) -> Vec<u8> {
+            ink_prelude::vec![0x00; len as usize]
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod slice_input {
+    #[ink(storage)]
+    pub struct SliceInput {
+        sum: u32,
+    }
+
+    impl SliceInput {
+        #[ink(constructor)]
+        pub fn new(init_values: &[u32]) -> Self {
+            Self {
+                sum: init_values.iter().sum(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn add(&mut self, values: &[u32]) {
+            self.sum += values.iter().sum::<u32>();
+        }
+
+        #[ink(message)]
+        pub fn contains(&self, values: &[u32], value: u32) -> bool {
+            values.contains(&value)
+        }
+
+        #[ink(message)]
+        pub fn sum(&self) -> u32 {
+            self.sum
+        }
+    }
+}
+
+fn main() {}
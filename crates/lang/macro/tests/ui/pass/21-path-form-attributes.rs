@@ -0,0 +1,36 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod path_form_attributes {
+    #[ink::storage]
+    pub struct PathFormAttributes {
+        value: bool,
+    }
+
+    #[ink::event]
+    pub struct Flipped {
+        #[ink::topic]
+        value: bool,
+    }
+
+    impl PathFormAttributes {
+        #[ink::constructor]
+        pub fn new() -> Self {
+            Self { value: false }
+        }
+
+        #[ink::message]
+        #[ink::payable]
+        pub fn flip(&mut self) {
+            self.value = !self.value;
+            self.env().emit_event(Flipped { value: self.value });
+        }
+
+        #[ink::message]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+use ink_lang as ink;
+
+#[ink::contract(no_panic_dispatch = true)]
+mod guarded {
+    #[ink(storage)]
+    pub struct Guarded {
+        owner: AccountId,
+    }
+
+    impl Guarded {
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new() -> Self {
+            Self {
+                owner: Self::env().caller(),
+            }
+        }
+
+        /// Panics if the caller is not the owner.
+        #[ink(message, selector = "0x00000001")]
+        pub fn only_owner(&self) {
+            assert_eq!(self.env().caller(), self.owner, "caller is not the owner");
+        }
+    }
+}
+
+use guarded::Guarded;
+use ink_env::{
+    call::Selector,
+    test::CallData,
+    DefaultEnvironment,
+};
+use ink_lang::{
+    DispatchError,
+    DispatchMode,
+    DispatchUsingMode,
+};
+
+fn dispatch_as(
+    caller: [u8; 32],
+    selector: [u8; 4],
+    mode: DispatchMode,
+) -> Result<(), DispatchError> {
+    ink_env::test::push_execution_context::<DefaultEnvironment>(
+        caller.into(),
+        [0x07; 32].into(),
+        1_000_000,
+        0,
+        CallData::new(Selector::new(selector)),
+    );
+    let result = <Guarded as DispatchUsingMode>::dispatch_using_mode(mode);
+    ink_env::test::pop_execution_context();
+    result
+}
+
+fn main() {
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let owner = [0x01; 32];
+        assert!(dispatch_as(owner, [0x00; 4], DispatchMode::Instantiate).is_ok());
+        // The panic of the message is returned as an error instead of trapping.
+        assert!(matches!(
+            dispatch_as([0x02; 32], [0x00, 0x00, 0x00, 0x01], DispatchMode::Call),
+            Err(DispatchError::MessagePanicked)
+        ));
+        assert!(dispatch_as(owner, [0x00, 0x00, 0x00, 0x01], DispatchMode::Call).is_ok());
+        Ok(())
+    })
+    .unwrap()
+}
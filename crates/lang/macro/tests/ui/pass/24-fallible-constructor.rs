@@ -0,0 +1,74 @@
+use ink_lang as ink;
+
+#[ink::contract]
+mod fallible {
+    #[ink(storage)]
+    pub struct Fallible {
+        value: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode)]
+    pub enum Error {
+        ZeroValue,
+    }
+
+    impl Fallible {
+        #[ink(constructor, selector = "0x00000000")]
+        pub fn new(value: u32) -> Result<Self, Error> {
+            if value == 0 {
+                return Err(Error::ZeroValue)
+            }
+            Ok(Self { value })
+        }
+
+        #[ink(constructor)]
+        pub fn default() -> Fallible {
+            Self { value: 1 }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+use fallible::{
+    Error,
+    Fallible,
+};
+use ink_env::{
+    call::Selector,
+    test::CallData,
+    DefaultEnvironment,
+};
+use ink_lang::{
+    DispatchMode,
+    DispatchUsingMode,
+};
+
+fn main() {
+    assert_eq!(
+        Fallible::new(0).map(|fallible| fallible.get()),
+        Err(Error::ZeroValue)
+    );
+    assert_eq!(Fallible::new(42).map(|fallible| fallible.get()), Ok(42));
+    ink_env::test::run_test::<DefaultEnvironment, _>(|_| {
+        let mut call_data = CallData::new(Selector::new([0x00; 4]));
+        call_data.push_arg(&42_u32);
+        ink_env::test::push_execution_context::<DefaultEnvironment>(
+            [0x01; 32].into(),
+            [0x07; 32].into(),
+            1_000_000,
+            0,
+            call_data,
+        );
+        let result = <Fallible as DispatchUsingMode>::dispatch_using_mode(
+            DispatchMode::Instantiate,
+        );
+        ink_env::test::pop_execution_context();
+        assert!(result.is_ok());
+        Ok(())
+    })
+    .unwrap()
+}
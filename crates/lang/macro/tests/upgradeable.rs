@@ -0,0 +1,86 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract(upgradeable = true)]
+mod my_contract {
+    #[ink(storage)]
+    pub struct MyContract {
+        owner: AccountId,
+    }
+
+    impl MyContract {
+        /// Creates a new `MyContract` instance owned by the caller.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            MyContract {
+                owner: Self::env().caller(),
+            }
+        }
+
+        /// Returns the owner of the contract.
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
+    }
+
+    impl ink_lang::Upgradeable for MyContract {
+        fn can_set_code(&self) -> bool {
+            use ink_lang::Env as _;
+            self.env().caller() == self.owner
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        fn contract_code_hash() -> Option<Hash> {
+            let account_id =
+                ink_env::test::get_current_contract_account_id::<Environment>()
+                    .expect("cannot get contract account id");
+            ink_env::test::get_contract_code_hash::<Environment>(account_id)
+                .expect("cannot get contract code hash")
+        }
+
+        #[ink::test]
+        fn owner_can_set_code() {
+            // given
+            let mut my_contract = MyContract::new();
+            assert_eq!(contract_code_hash(), None);
+
+            // when
+            my_contract.set_code(Hash::from([0x42; 32]));
+
+            // then
+            assert_eq!(contract_code_hash(), Some(Hash::from([0x42; 32])));
+        }
+
+        #[ink::test]
+        #[should_panic(
+            expected = "caller is not allowed to set the code of the contract"
+        )]
+        fn non_owner_cannot_set_code() {
+            let mut my_contract = MyContract {
+                owner: AccountId::from([0xFF; 32]),
+            };
+            my_contract.set_code(Hash::from([0x42; 32]));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod fallback {
+    #[ink(storage)]
+    pub struct Fallback {
+        value: u32,
+    }
+
+    impl Fallback {
+        /// Creates a new contract with the default value.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        /// Handles all deployments with an unknown selector.
+        #[ink(constructor, selector = _)]
+        pub fn fallback(value: u32) -> Self {
+            Self { value }
+        }
+
+        /// Returns the stored value.
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        #[test]
+        fn fallback_is_still_a_regular_constructor() {
+            assert_eq!(Fallback::fallback(42).get(), 42);
+        }
+    }
+}
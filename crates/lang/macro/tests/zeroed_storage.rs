@@ -0,0 +1,55 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod tally {
+    #[ink(storage, zeroed)]
+    pub struct Tally {
+        count: u64,
+        total: u128,
+        delta: i32,
+        enabled: bool,
+        buckets: [u32; 4],
+        range: (u8, i16),
+    }
+
+    impl Tally {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::new_zeroed()
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> (u64, u128, i32, bool) {
+            (self.count, self.total, self.delta, self.enabled)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        #[ink::test]
+        fn new_zeroed_works() {
+            let tally = Tally::new();
+            assert_eq!(tally.get(), (0, 0, 0, false));
+            assert_eq!(tally.buckets, [0; 4]);
+            assert_eq!(tally.range, (0, 0));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::marker::PhantomData;
+use ink_env::ReturnFlags;
+use ink_prelude::vec::Vec;
+
+/// An encoded call to an ink! message together with the type of its result.
+///
+/// # Note
+///
+/// Returned by the client stubs that ink! generates for ink! smart contracts
+/// when the `client` crate feature is enabled. It is agnostic over the way
+/// the call is actually submitted: users send the encoded [`ClientCall::input`]
+/// to the ink! smart contract and decode the returned bytes via
+/// [`ClientCall::decode_output`].
+pub struct ClientCall<R> {
+    /// The selector of the called ink! message followed by its encoded inputs.
+    input: Vec<u8>,
+    /// The type of the result of the called ink! message.
+    output: PhantomData<fn() -> R>,
+}
+
+impl<R> ClientCall<R> {
+    /// Creates a new client call for the ink! message with the given selector
+    /// and encodes the given inputs.
+    #[doc(hidden)]
+    pub fn new<Args>(selector: [u8; 4], args: &Args) -> Self
+    where
+        Args: scale::Encode,
+    {
+        let mut input = Vec::with_capacity(4 + args.size_hint());
+        input.extend_from_slice(&selector);
+        args.encode_to(&mut input);
+        Self {
+            input,
+            output: PhantomData,
+        }
+    }
+
+    /// Returns the selector of the called ink! message followed by its encoded inputs.
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    /// Returns the owned encoded input of the call.
+    pub fn into_input(self) -> Vec<u8> {
+        self.input
+    }
+}
+
+impl<R> ClientCall<R>
+where
+    R: scale::Decode,
+{
+    /// Decodes the bytes returned by the called ink! message into its result.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes do not represent an encoded result of the ink! message or
+    /// if there are bytes left over after decoding it.
+    pub fn decode_output(&self, output: &[u8]) -> Result<R, scale::Error> {
+        <R as scale::DecodeAll>::decode_all(output)
+    }
+}
+
+/// An encoded dry-run call to an ink! message together with the type of its result.
+///
+/// # Note
+///
+/// Returned by the dry-run client stubs that ink! generates for ink! smart
+/// contracts when the `dry-run` crate feature is enabled. The call is meant
+/// to be submitted without committing its state changes, e.g. via the RPC of
+/// a node, in order for front-ends to estimate fees. The returned bytes are
+/// decoded together with the returned flags via [`DryRunCall::decode_output`].
+pub struct DryRunCall<R> {
+    /// The underlying call to the ink! message.
+    call: ClientCall<R>,
+}
+
+impl<R> DryRunCall<R> {
+    /// Creates a new dry-run call from the given call to an ink! message.
+    #[doc(hidden)]
+    pub fn new(call: ClientCall<R>) -> Self {
+        Self { call }
+    }
+
+    /// Returns the selector of the called ink! message followed by its encoded inputs.
+    pub fn input(&self) -> &[u8] {
+        self.call.input()
+    }
+
+    /// Returns the owned encoded input of the call.
+    pub fn into_input(self) -> Vec<u8> {
+        self.call.into_input()
+    }
+}
+
+impl<R> DryRunCall<R>
+where
+    R: scale::Decode,
+{
+    /// Decodes the flags and bytes returned by the dry-run of the called ink!
+    /// message into its result.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes do not represent an encoded result of the ink! message or
+    /// if there are bytes left over after decoding it.
+    pub fn decode_output(
+        &self,
+        flags: u32,
+        output: &[u8],
+    ) -> Result<DryRun<R>, scale::Error> {
+        let result = self.call.decode_output(output)?;
+        Ok(DryRun {
+            result,
+            reverted: ReturnFlags::from_u32(flags).is_reverted(),
+        })
+    }
+}
+
+/// The outcome of the dry-run of an ink! message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRun<R> {
+    /// The decoded result of the ink! message.
+    result: R,
+    /// If the execution of the ink! message would have been reverted.
+    reverted: bool,
+}
+
+impl<R> DryRun<R> {
+    /// Returns a shared reference to the decoded result of the ink! message.
+    pub fn result(&self) -> &R {
+        &self.result
+    }
+
+    /// Returns the decoded result of the ink! message.
+    pub fn into_result(self) -> R {
+        self.result
+    }
+
+    /// Returns `true` if the execution of the ink! message would have been reverted.
+    pub fn is_reverted(&self) -> bool {
+        self.reverted
+    }
+}
@@ -34,3 +34,28 @@ pub enum DispatchMode {
 pub trait DispatchUsingMode {
     fn dispatch_using_mode(mode: DispatchMode) -> Result<(), DispatchError>;
 }
+
+/// Guards the `set_code` message of upgradeable ink! smart contracts.
+///
+/// # Note
+///
+/// Every ink! smart contract configured with `upgradeable = true` must
+/// implement this trait for its ink! storage struct in order to decide
+/// who is allowed to replace the code of the contract.
+pub trait Upgradeable {
+    /// Returns `true` if the caller is allowed to replace the code of the contract.
+    fn can_set_code(&self) -> bool;
+}
+
+/// Guards the `migrate` message of ink! smart contracts with a versioned storage.
+///
+/// # Note
+///
+/// Every ink! smart contract configured with `migration_stubs = true` and a
+/// storage struct flagged with `#[ink(storage, version = N)]` must implement
+/// this trait for its ink! storage struct in order to decide who is allowed
+/// to migrate the storage of the contract.
+pub trait Migrate {
+    /// Returns `true` if the caller is allowed to migrate the storage of the contract.
+    fn can_migrate(&self) -> bool;
+}
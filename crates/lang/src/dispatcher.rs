@@ -14,6 +14,7 @@
 
 use crate::{
     Constructor,
+    ConstructorOutput,
     DispatchError,
     FnOutput,
     FnState,
@@ -28,6 +29,7 @@ use ink_env::{
     Environment,
     ReturnFlags,
 };
+use ink_prelude::vec::Vec;
 use ink_primitives::Key;
 use ink_storage::{
     alloc,
@@ -67,6 +69,83 @@ pub trait Execute {
     fn execute(self) -> Result<()>;
 }
 
+/// Decodes `T` from the contract input only if the input does not exceed
+/// `MAX_INPUT_SIZE` bytes.
+///
+/// # Note
+///
+/// The size of the input is checked before decoding `T` so that oversized
+/// inputs are rejected without decoding any of their contents.
+#[doc(hidden)]
+pub struct BoundedInput<T, const MAX_INPUT_SIZE: usize>(T);
+
+impl<T, const MAX_INPUT_SIZE: usize> BoundedInput<T, MAX_INPUT_SIZE> {
+    /// Returns the decoded input.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MAX_INPUT_SIZE: usize> scale::Decode for BoundedInput<T, MAX_INPUT_SIZE>
+where
+    T: scale::Decode,
+{
+    fn decode<I: scale::Input>(
+        input: &mut I,
+    ) -> core::result::Result<Self, scale::Error> {
+        if let Some(len) = input.remaining_len()? {
+            if len > MAX_INPUT_SIZE {
+                return Err("input exceeds the maximum input size".into())
+            }
+        }
+        <T as scale::Decode>::decode(input).map(Self)
+    }
+}
+
+/// The result of an ink! message flagged as `raw_return`.
+///
+/// # Note
+///
+/// In contrast to `Vec<u8>` the wrapped bytes are encoded as they are without
+/// a SCALE length prefix. This allows ink! messages to return raw bytes.
+#[doc(hidden)]
+pub struct RawReturn(pub Vec<u8>);
+
+impl scale::Decode for RawReturn {
+    fn decode<I: scale::Input>(
+        input: &mut I,
+    ) -> core::result::Result<Self, scale::Error> {
+        let len = input
+            .remaining_len()?
+            .ok_or("cannot decode raw return bytes of unknown length")?;
+        let mut bytes = ink_prelude::vec![0x00; len];
+        input.read(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl scale::Encode for RawReturn {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.0)
+    }
+}
+
+/// The owned type an ink! input of slice type `&[T]` is decoded into.
+///
+/// # Note
+///
+/// Slices cannot be decoded directly, therefore they are decoded as `Vec<T>`
+/// and the ink! message or constructor is called with a borrow of it.
+#[doc(hidden)]
+pub type SliceInput<T> = Vec<T>;
+
 /// Yields `true` if the message accepts payments.
 #[derive(Copy, Clone)]
 #[doc(hidden)]
@@ -91,6 +170,46 @@ impl From<EnablesDynamicStorageAllocator> for bool {
     }
 }
 
+/// Yields `true` if panics of the message are caught during dispatch.
+#[derive(Copy, Clone)]
+#[doc(hidden)]
+pub struct CatchesPanics(pub bool);
+
+impl From<CatchesPanics> for bool {
+    #[inline]
+    fn from(catches_panics: CatchesPanics) -> Self {
+        catches_panics.0
+    }
+}
+
+/// Calls the given message closure and returns its result.
+///
+/// # Note
+///
+/// If panics are caught a panic of the closure is returned as
+/// [`DispatchError::MessagePanicked`] instead of trapping the execution.
+///
+/// Panics can only be caught if the contract is compiled with the `std` feature
+/// and with unwinding panics. Contracts compiled to Wasm use `panic = "abort"`
+/// which is why the `no_panic_dispatch` ink! config rejects builds without the
+/// `std` feature. Aborts, e.g. due to a failed memory allocation, are never caught.
+#[inline]
+#[doc(hidden)]
+pub fn catch_panics<F, R>(catches_panics: CatchesPanics, f: F) -> Result<R>
+where
+    F: FnOnce() -> R,
+{
+    let catches_panics: bool = catches_panics.into();
+    #[cfg(feature = "std")]
+    if catches_panics {
+        return std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+            .map_err(|_| DispatchError::MessagePanicked)
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = catches_panics;
+    Ok(f())
+}
+
 /// Executes the given `&self` message closure.
 ///
 /// # Note
@@ -102,6 +221,7 @@ impl From<EnablesDynamicStorageAllocator> for bool {
 pub fn execute_message<E, M, F>(
     accepts_payments: AcceptsPayments,
     enables_dynamic_storage_allocator: EnablesDynamicStorageAllocator,
+    catches_panics: CatchesPanics,
     f: F,
 ) -> Result<()>
 where
@@ -120,7 +240,7 @@ where
     }
     let root_key = Key::from([0x00; 32]);
     let state = ManuallyDrop::new(pull_spread_root::<<M as FnState>::State>(&root_key));
-    let result = f(&state);
+    let result = catch_panics(catches_panics, || f(&state))?;
     if enables_dynamic_storage_allocator {
         alloc::finalize();
     }
@@ -160,6 +280,7 @@ where
 pub fn execute_message_mut<E, M, F>(
     accepts_payments: AcceptsPayments,
     enables_dynamic_storage_allocator: EnablesDynamicStorageAllocator,
+    catches_panics: CatchesPanics,
     f: F,
 ) -> Result<()>
 where
@@ -179,7 +300,7 @@ where
     let root_key = Key::from([0x00; 32]);
     let mut state =
         ManuallyDrop::new(pull_spread_root::<<M as FnState>::State>(&root_key));
-    let result = f(&mut state);
+    let result = catch_panics(catches_panics, || f(&mut state))?;
     push_spread_root::<<M as FnState>::State>(&state, &root_key);
     if enables_dynamic_storage_allocator {
         alloc::finalize();
@@ -196,6 +317,9 @@ where
 ///
 /// The closure is supposed to already contain all the arguments that the real
 /// constructor message requires and forwards them.
+///
+/// If a fallible constructor returns an error the instantiation is reverted
+/// and the encoded error is returned to the caller.
 #[inline]
 #[doc(hidden)]
 pub fn execute_constructor<C, F>(
@@ -204,14 +328,19 @@ pub fn execute_constructor<C, F>(
 ) -> Result<()>
 where
     C: Constructor,
-    F: FnOnce() -> <C as FnState>::State,
+    F: FnOnce() -> <C as Constructor>::Output,
 {
     let enables_dynamic_storage_allocator: bool =
         enables_dynamic_storage_allocator.into();
     if enables_dynamic_storage_allocator {
         alloc::initialize(ContractPhase::Deploy);
     }
-    let state = ManuallyDrop::new(f());
+    let state = match ConstructorOutput::into_result(f()) {
+        Ok(state) => ManuallyDrop::new(state),
+        Err(error) => {
+            ink_env::return_value(ReturnFlags::default().set_reverted(true), &error)
+        }
+    };
     let root_key = Key::from([0x00; 32]);
     push_spread_root::<<C as FnState>::State>(&state, &root_key);
     if enables_dynamic_storage_allocator {
@@ -280,6 +280,15 @@ where
         )
     }
 
+    /// Replaces the code of the executed smart contract.
+    ///
+    /// # Note
+    ///
+    /// For more details visit: [`ink_env::set_code_hash`]
+    pub fn set_code_hash(self, code_hash: T::Hash) -> Result<()> {
+        ink_env::set_code_hash::<T>(code_hash)
+    }
+
     /// Terminates the existence of a smart contract.
     ///
     /// # Note
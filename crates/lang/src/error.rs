@@ -30,6 +30,7 @@ pub enum DispatchError {
 
     CouldNotReadInput,
     PaidUnpayableMessage,
+    MessagePanicked,
 }
 
 impl DispatchError {
@@ -75,6 +76,7 @@ impl From<DispatchError> for DispatchRetCode {
             DispatchError::InvalidCallParameters => Self(0x06),
             DispatchError::CouldNotReadInput => Self(0x07),
             DispatchError::PaidUnpayableMessage => Self(0x08),
+            DispatchError::MessagePanicked => Self(0x09),
         }
     }
 }
@@ -0,0 +1,102 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ink_env::{
+    topics::topic_hash,
+    Environment,
+};
+use ink_prelude::vec::Vec;
+
+/// A filter matching emitted ink! events by the values of their topics.
+///
+/// # Note
+///
+/// Returned by the event filter builders that ink! generates for all ink!
+/// events when the `event-filters` crate feature is enabled. Off-chain indexers
+/// subscribe with the raw [`EventFilter::topics`] where `None` matches any value
+/// of the topic at the same position. Unless the event is anonymous its first
+/// topic always is its signature topic.
+pub struct EventFilter<E>
+where
+    E: Environment,
+{
+    /// The topics to match in the order they are emitted.
+    topics: Vec<Option<<E as Environment>::Hash>>,
+}
+
+impl<E> EventFilter<E>
+where
+    E: Environment,
+{
+    /// Creates a new event filter for an ink! event with the given amount of
+    /// topics which matches any value for all of them.
+    #[doc(hidden)]
+    pub fn new(len_topics: usize) -> Self {
+        let mut topics = Vec::with_capacity(len_topics);
+        topics.resize_with(len_topics, || None);
+        Self { topics }
+    }
+
+    /// Restricts the topic at the given position to the encoded value.
+    ///
+    /// # Note
+    ///
+    /// The encoded value is turned into the raw topic bytes the same way as
+    /// upon emitting the ink! event.
+    #[doc(hidden)]
+    pub fn with_topic<T>(mut self, index: usize, value: &T) -> Self
+    where
+        T: scale::Encode,
+    {
+        self.topics[index] = Some(topic_hash::<E>(&value.encode()));
+        self
+    }
+
+    /// Returns the topics to match where `None` matches any value.
+    pub fn topics(&self) -> &[Option<<E as Environment>::Hash>] {
+        &self.topics
+    }
+
+    /// Returns the owned topics to match where `None` matches any value.
+    pub fn into_topics(self) -> Vec<Option<<E as Environment>::Hash>> {
+        self.topics
+    }
+}
+
+impl<E> Clone for EventFilter<E>
+where
+    E: Environment,
+{
+    fn clone(&self) -> Self {
+        Self {
+            topics: self.topics.clone(),
+        }
+    }
+}
+
+impl<E> core::fmt::Debug for EventFilter<E>
+where
+    E: Environment,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let topics = self
+            .topics
+            .iter()
+            .map(|topic| topic.as_ref().map(AsRef::<[u8]>::as_ref))
+            .collect::<Vec<_>>();
+        f.debug_struct("EventFilter")
+            .field("topics", &topics)
+            .finish()
+    }
+}
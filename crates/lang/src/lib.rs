@@ -15,11 +15,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod chain_extension;
+mod client;
 mod contract;
 mod cross_calling;
 mod dispatcher;
 mod env_access;
 mod error;
+mod event_filter;
 mod events;
 mod traits;
 
@@ -28,9 +30,16 @@ pub use self::{
         ChainExtensionInstance,
         IsResultType,
     },
+    client::{
+        ClientCall,
+        DryRun,
+        DryRunCall,
+    },
     contract::{
         DispatchMode,
         DispatchUsingMode,
+        Migrate,
+        Upgradeable,
     },
     cross_calling::{
         ForwardCall,
@@ -39,15 +48,20 @@ pub use self::{
         ToAccountId,
     },
     dispatcher::{
+        catch_panics,
         deny_payment,
         execute_constructor,
         execute_message,
         execute_message_mut,
         AcceptsPayments,
+        BoundedInput,
+        CatchesPanics,
         ConstructorDispatcher,
         EnablesDynamicStorageAllocator,
         Execute,
         MessageDispatcher,
+        RawReturn,
+        SliceInput,
     },
     env_access::{
         ContractEnv,
@@ -60,6 +74,7 @@ pub use self::{
         DispatchResult,
         DispatchRetCode,
     },
+    event_filter::EventFilter,
     events::{
         BaseEvent,
         EmitEvent,
@@ -67,6 +82,7 @@ pub use self::{
     traits::{
         CheckedInkTrait,
         Constructor,
+        ConstructorOutput,
         FnInput,
         FnOutput,
         FnSelector,
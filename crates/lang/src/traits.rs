@@ -96,7 +96,44 @@ pub trait FnState {
 /// A dispatchable contract constructor message.
 #[doc(hidden)]
 pub trait Constructor: FnInput + FnSelector + FnState {
-    const CALLABLE: fn(<Self as FnInput>::Input) -> <Self as FnState>::State;
+    /// The return type of the constructor.
+    type Output: ConstructorOutput<<Self as FnState>::State>;
+
+    const CALLABLE: fn(<Self as FnInput>::Input) -> <Self as Constructor>::Output;
+}
+
+/// The return type of dispatchable contract constructors.
+///
+/// Implemented for the storage state `C` of infallible constructors and for
+/// `Result<C, E>` of fallible ones.
+#[doc(hidden)]
+pub trait ConstructorOutput<C> {
+    /// The error type of fallible constructors.
+    type Error: scale::Encode;
+
+    /// Returns the constructed storage state or the error of the constructor.
+    fn into_result(self) -> Result<C, Self::Error>;
+}
+
+impl<C> ConstructorOutput<C> for C {
+    type Error = ();
+
+    #[inline]
+    fn into_result(self) -> Result<C, Self::Error> {
+        Ok(self)
+    }
+}
+
+impl<C, E> ConstructorOutput<C> for Result<C, E>
+where
+    E: scale::Encode,
+{
+    type Error = E;
+
+    #[inline]
+    fn into_result(self) -> Result<C, Self::Error> {
+        self
+    }
 }
 
 /// A `&self` dispatchable contract message.
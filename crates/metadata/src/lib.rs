@@ -21,15 +21,23 @@ extern crate alloc;
 mod tests;
 
 pub mod layout;
+#[cfg(feature = "std")]
+mod schema;
 mod specs;
 mod utils;
 
+#[cfg(feature = "std")]
+pub use self::schema::metadata_json_schema;
+
 pub use self::specs::{
+    BuildInfoSpec,
     ConstructorSpec,
     ConstructorSpecBuilder,
     ContractSpec,
     ContractSpecBuilder,
     DisplayName,
+    EnvTypeSpec,
+    EnvironmentSpec,
     EventParamSpec,
     EventParamSpecBuilder,
     EventSpec,
@@ -40,6 +48,8 @@ pub use self::specs::{
     MessageSpecBuilder,
     ReturnTypeSpec,
     Selector,
+    SourceHash,
+    StorageAccessSpec,
     TypeSpec,
 };
 
@@ -57,9 +67,36 @@ use serde::{
     Serialize,
 };
 
+/// The version of the ink! metadata schema.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetadataVersion {
+    /// The original ink! metadata schema that does not carry its version.
+    #[serde(rename = "0")]
+    V0,
+    /// The ink! metadata schema that explicitly carries its version.
+    #[serde(rename = "1")]
+    V1,
+}
+
+impl Default for MetadataVersion {
+    fn default() -> Self {
+        Self::V0
+    }
+}
+
+impl MetadataVersion {
+    /// Returns `true` if this is the original ink! metadata schema version.
+    fn is_v0(&self) -> bool {
+        matches!(self, Self::V0)
+    }
+}
+
 /// An entire ink! project for metadata file generation purposes.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InkProject {
+    /// The version of the metadata schema.
+    #[serde(default, skip_serializing_if = "MetadataVersion::is_v0")]
+    version: MetadataVersion,
     #[serde(flatten)]
     registry: PortableRegistry,
     #[serde(rename = "storage")]
@@ -77,14 +114,26 @@ impl InkProject {
         let mut registry = Registry::new();
 
         Self {
+            version: Default::default(),
             layout: layout.into().into_portable(&mut registry),
             spec: spec.into().into_portable(&mut registry),
             registry: registry.into(),
         }
     }
+
+    /// Sets the version of the metadata schema.
+    pub fn with_version(mut self, version: MetadataVersion) -> Self {
+        self.version = version;
+        self
+    }
 }
 
 impl InkProject {
+    /// Returns the version of the metadata schema.
+    pub fn version(&self) -> MetadataVersion {
+        self.version
+    }
+
     /// Returns a read-only registry of types in the contract.
     pub fn registry(&self) -> &PortableRegistry {
         &self.registry
@@ -0,0 +1,273 @@
+// Copyright 2018-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::MetadataVersion;
+use serde_json::{
+    json,
+    Value,
+};
+
+/// Returns the JSON Schema describing the shape of the emitted ink! metadata.
+///
+/// # Note
+///
+/// The schema is static and not specific to any ink! smart contract. It covers
+/// all metadata schema versions: unversioned documents follow
+/// [`MetadataVersion::V0`] while versioned documents carry one of the explicit
+/// versions in their `version` field.
+///
+/// The type registry and the storage layout are only described structurally
+/// since their contents are defined by `scale-info` and the storage layout of
+/// the ink! smart contract respectively.
+pub fn metadata_json_schema() -> Value {
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "ink! metadata",
+        "type": "object",
+        "required": ["types", "storage", "spec"],
+        "additionalProperties": false,
+        "properties": {
+            "version": {
+                "description": "The version of the metadata schema. Absent for version 0.",
+                "enum": explicit_versions(),
+            },
+            "types": {
+                "description": "The registry of all types used by the contract.",
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["def"],
+                },
+            },
+            "storage": { "$ref": "#/definitions/layout" },
+            "spec": { "$ref": "#/definitions/contract" },
+        },
+        "definitions": {
+            "docs": {
+                "type": "array",
+                "items": { "type": "string" },
+            },
+            "name": {
+                "type": "array",
+                "items": { "type": "string" },
+                "minItems": 1,
+            },
+            "selector": {
+                "type": "string",
+                "pattern": "^0x[0-9a-fA-F]{8}$",
+            },
+            "hash": {
+                "type": "string",
+                "pattern": "^0x[0-9a-fA-F]{64}$",
+            },
+            "typeId": {
+                "type": "integer",
+                "minimum": 1,
+            },
+            "typeSpec": {
+                "type": "object",
+                "required": ["type", "displayName"],
+                "additionalProperties": false,
+                "properties": {
+                    "type": { "$ref": "#/definitions/typeId" },
+                    "displayName": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                    },
+                },
+            },
+            "messageParam": {
+                "type": "object",
+                "required": ["name", "type"],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "type": "string" },
+                    "type": { "$ref": "#/definitions/typeSpec" },
+                    "default": { "type": "string" },
+                },
+            },
+            "constructor": {
+                "type": "object",
+                "required": ["name", "selector", "args", "docs"],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "$ref": "#/definitions/name" },
+                    "selector": { "$ref": "#/definitions/selector" },
+                    "args": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/messageParam" },
+                    },
+                    "docs": { "$ref": "#/definitions/docs" },
+                },
+            },
+            "message": {
+                "type": "object",
+                "required": [
+                    "name", "selector", "mutates", "payable", "args", "returnType", "docs"
+                ],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "$ref": "#/definitions/name" },
+                    "selector": { "$ref": "#/definitions/selector" },
+                    "mutates": { "type": "boolean" },
+                    "payable": { "type": "boolean" },
+                    "args": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/messageParam" },
+                    },
+                    "returnType": {
+                        "oneOf": [
+                            { "type": "null" },
+                            { "$ref": "#/definitions/typeSpec" },
+                        ],
+                    },
+                    "docs": { "$ref": "#/definitions/docs" },
+                    "weight": {
+                        "type": "integer",
+                        "minimum": 0,
+                    },
+                    "storageAccess": {
+                        "type": "object",
+                        "required": ["reads", "writes"],
+                        "additionalProperties": false,
+                        "properties": {
+                            "reads": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                            },
+                            "writes": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                            },
+                        },
+                    },
+                    "standard": { "type": "string" },
+                },
+            },
+            "eventParam": {
+                "type": "object",
+                "required": ["name", "indexed", "type", "docs"],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "type": "string" },
+                    "indexed": { "type": "boolean" },
+                    "type": { "$ref": "#/definitions/typeSpec" },
+                    "docs": { "$ref": "#/definitions/docs" },
+                },
+            },
+            "event": {
+                "type": "object",
+                "required": ["name", "args", "docs"],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "type": "string" },
+                    "args": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/eventParam" },
+                    },
+                    "docs": { "$ref": "#/definitions/docs" },
+                },
+            },
+            "envType": {
+                "type": "object",
+                "required": ["type"],
+                "additionalProperties": false,
+                "properties": {
+                    "type": { "$ref": "#/definitions/typeSpec" },
+                    "size": {
+                        "type": "integer",
+                        "minimum": 0,
+                    },
+                },
+            },
+            "environment": {
+                "type": "object",
+                "required": ["accountId", "balance", "hash", "blockNumber", "timestamp"],
+                "additionalProperties": false,
+                "properties": {
+                    "accountId": { "$ref": "#/definitions/envType" },
+                    "balance": { "$ref": "#/definitions/envType" },
+                    "hash": { "$ref": "#/definitions/envType" },
+                    "blockNumber": { "$ref": "#/definitions/envType" },
+                    "timestamp": { "$ref": "#/definitions/envType" },
+                },
+            },
+            "buildInfo": {
+                "type": "object",
+                "required": ["rustcVersion", "inkVersion"],
+                "additionalProperties": false,
+                "properties": {
+                    "rustcVersion": { "type": "string" },
+                    "inkVersion": { "type": "string" },
+                },
+            },
+            "contract": {
+                "type": "object",
+                "required": ["constructors", "messages", "events", "docs"],
+                "additionalProperties": false,
+                "properties": {
+                    "constructors": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/constructor" },
+                    },
+                    "messages": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/message" },
+                    },
+                    "events": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/event" },
+                    },
+                    "docs": { "$ref": "#/definitions/docs" },
+                    "sourceHash": { "$ref": "#/definitions/hash" },
+                    "license": { "type": "string" },
+                    "environment": { "$ref": "#/definitions/environment" },
+                    "buildInfo": { "$ref": "#/definitions/buildInfo" },
+                },
+            },
+            "layout": {
+                "description": "A storage layout tagged by its kind.",
+                "type": "object",
+                "minProperties": 1,
+                "maxProperties": 1,
+                "properties": {
+                    "cell": {
+                        "type": "object",
+                        "required": ["key", "ty"],
+                    },
+                    "hash": { "type": "object" },
+                    "array": { "type": "object" },
+                    "struct": {
+                        "type": "object",
+                        "required": ["fields"],
+                    },
+                    "enum": { "type": "object" },
+                },
+                "additionalProperties": false,
+            },
+        },
+    })
+}
+
+/// Returns the values of the `version` field of explicitly versioned ink! metadata.
+fn explicit_versions() -> Vec<Value> {
+    [MetadataVersion::V0, MetadataVersion::V1]
+        .iter()
+        .filter(|version| !version.is_v0())
+        .map(|version| {
+            serde_json::to_value(version)
+                .expect("metadata versions are always serializable")
+        })
+        .collect()
+}
@@ -54,6 +54,22 @@ pub struct ContractSpec<F: Form = MetaForm> {
     events: Vec<EventSpec<F>>,
     /// The contract documentation.
     docs: Vec<F::String>,
+    /// The hash of the original contract source module, if enabled.
+    #[serde(
+        rename = "sourceHash",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    source_hash: Option<SourceHash>,
+    /// The SPDX license identifier of the contract, if provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    license: Option<F::String>,
+    /// The environmental types used by the contract, if provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    environment: Option<EnvironmentSpec<F>>,
+    /// The versions of the tools that built the contract, if embedded.
+    #[serde(rename = "buildInfo", default, skip_serializing_if = "Option::is_none")]
+    build_info: Option<BuildInfoSpec<F>>,
 }
 
 impl IntoPortable for ContractSpec {
@@ -77,6 +93,14 @@ impl IntoPortable for ContractSpec {
                 .map(|event| event.into_portable(registry))
                 .collect::<Vec<_>>(),
             docs: registry.map_into_portable(self.docs),
+            source_hash: self.source_hash,
+            license: self.license.map(|license| license.into_portable(registry)),
+            environment: self
+                .environment
+                .map(|environment| environment.into_portable(registry)),
+            build_info: self
+                .build_info
+                .map(|build_info| build_info.into_portable(registry)),
         }
     }
 }
@@ -104,6 +128,26 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the hash of the original contract source module if any.
+    pub fn source_hash(&self) -> Option<&SourceHash> {
+        self.source_hash.as_ref()
+    }
+
+    /// Returns the SPDX license identifier of the contract if any.
+    pub fn license(&self) -> Option<&F::String> {
+        self.license.as_ref()
+    }
+
+    /// Returns the environmental types used by the contract if any.
+    pub fn environment(&self) -> Option<&EnvironmentSpec<F>> {
+        self.environment.as_ref()
+    }
+
+    /// Returns the versions of the tools that built the contract if embedded.
+    pub fn build_info(&self) -> Option<&BuildInfoSpec<F>> {
+        self.build_info.as_ref()
+    }
 }
 
 /// The message builder is ready to finalize construction.
@@ -181,15 +225,71 @@ impl<S> ContractSpecBuilder<S> {
             ..self
         }
     }
+
+    /// Sets the hash of the original contract source module.
+    pub fn source_hash(self, source_hash: [u8; 32]) -> Self {
+        debug_assert!(self.spec.source_hash.is_none());
+        Self {
+            spec: ContractSpec {
+                source_hash: Some(source_hash.into()),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the SPDX license identifier of the contract specification.
+    pub fn license(self, license: &'static str) -> Self {
+        debug_assert!(self.spec.license.is_none());
+        Self {
+            spec: ContractSpec {
+                license: Some(license),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the environmental types used by the contract.
+    pub fn environment(self, environment: EnvironmentSpec) -> Self {
+        debug_assert!(self.spec.environment.is_none());
+        Self {
+            spec: ContractSpec {
+                environment: Some(environment),
+                ..self.spec
+            },
+            ..self
+        }
+    }
+
+    /// Sets the versions of the Rust compiler and of ink! that built the contract.
+    pub fn build_info(
+        self,
+        rustc_version: &'static str,
+        ink_version: &'static str,
+    ) -> Self {
+        debug_assert!(self.spec.build_info.is_none());
+        Self {
+            spec: ContractSpec {
+                build_info: Some(BuildInfoSpec {
+                    rustc_version,
+                    ink_version,
+                }),
+                ..self.spec
+            },
+            ..self
+        }
+    }
 }
 
 impl ContractSpecBuilder<Valid> {
     /// Finalizes construction of the contract specification.
+    ///
+    /// # Note
+    ///
+    /// The set of constructors may be empty for contracts that opted out of
+    /// having constructors via `allow_no_constructor`.
     pub fn done(self) -> ContractSpec {
-        assert!(
-            !self.spec.constructors.is_empty(),
-            "must have at least one constructor"
-        );
         assert!(
             !self.spec.messages.is_empty(),
             "must have at least one message"
@@ -207,12 +307,190 @@ impl ContractSpec {
                 messages: Vec::new(),
                 events: Vec::new(),
                 docs: Vec::new(),
+                source_hash: None,
+                license: None,
+                environment: None,
+                build_info: None,
             },
             marker: PhantomData,
         }
     }
 }
 
+/// Describes the versions of the tools that built a contract.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct BuildInfoSpec<F: Form = MetaForm> {
+    /// The version of the Rust compiler, e.g. `rustc 1.53.0 (53cb7b09b 2021-06-17)`.
+    rustc_version: F::String,
+    /// The version of ink!, e.g. `3.0.0-rc3`.
+    ink_version: F::String,
+}
+
+impl IntoPortable for BuildInfoSpec {
+    type Output = BuildInfoSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        BuildInfoSpec {
+            rustc_version: self.rustc_version.into_portable(registry),
+            ink_version: self.ink_version.into_portable(registry),
+        }
+    }
+}
+
+impl<F> BuildInfoSpec<F>
+where
+    F: Form,
+{
+    /// Returns the version of the Rust compiler.
+    pub fn rustc_version(&self) -> &F::String {
+        &self.rustc_version
+    }
+
+    /// Returns the version of ink!.
+    pub fn ink_version(&self) -> &F::String {
+        &self.ink_version
+    }
+}
+
+/// Describes the environmental types used by a contract.
+///
+/// Front-ends require these in order to decode the storage and events of
+/// contracts that use a custom environment.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentSpec<F: Form = MetaForm> {
+    /// The type of an account identifier.
+    account_id: EnvTypeSpec<F>,
+    /// The type of balances.
+    balance: EnvTypeSpec<F>,
+    /// The type of hashes.
+    hash: EnvTypeSpec<F>,
+    /// The type of block numbers.
+    block_number: EnvTypeSpec<F>,
+    /// The type of timestamps.
+    timestamp: EnvTypeSpec<F>,
+}
+
+impl IntoPortable for EnvironmentSpec {
+    type Output = EnvironmentSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        EnvironmentSpec {
+            account_id: self.account_id.into_portable(registry),
+            balance: self.balance.into_portable(registry),
+            hash: self.hash.into_portable(registry),
+            block_number: self.block_number.into_portable(registry),
+            timestamp: self.timestamp.into_portable(registry),
+        }
+    }
+}
+
+impl EnvironmentSpec {
+    /// Creates a new environment specification from the given environmental types.
+    pub fn new(
+        account_id: EnvTypeSpec,
+        balance: EnvTypeSpec,
+        hash: EnvTypeSpec,
+        block_number: EnvTypeSpec,
+        timestamp: EnvTypeSpec,
+    ) -> Self {
+        Self {
+            account_id,
+            balance,
+            hash,
+            block_number,
+            timestamp,
+        }
+    }
+}
+
+impl<F> EnvironmentSpec<F>
+where
+    F: Form,
+{
+    /// Returns the type of an account identifier.
+    pub fn account_id(&self) -> &EnvTypeSpec<F> {
+        &self.account_id
+    }
+
+    /// Returns the type of balances.
+    pub fn balance(&self) -> &EnvTypeSpec<F> {
+        &self.balance
+    }
+
+    /// Returns the type of hashes.
+    pub fn hash(&self) -> &EnvTypeSpec<F> {
+        &self.hash
+    }
+
+    /// Returns the type of block numbers.
+    pub fn block_number(&self) -> &EnvTypeSpec<F> {
+        &self.block_number
+    }
+
+    /// Returns the type of timestamps.
+    pub fn timestamp(&self) -> &EnvTypeSpec<F> {
+        &self.timestamp
+    }
+}
+
+/// Describes a single environmental type and its encoded width.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct EnvTypeSpec<F: Form = MetaForm> {
+    /// The full name of the environmental type.
+    name: F::String,
+    /// The width of the encoded type in bytes if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    size: Option<u32>,
+}
+
+impl IntoPortable for EnvTypeSpec {
+    type Output = EnvTypeSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        EnvTypeSpec {
+            name: self.name.into_portable(registry),
+            size: self.size,
+        }
+    }
+}
+
+impl EnvTypeSpec {
+    /// Creates a new environmental type specification of the given type name
+    /// and optional encoded width in bytes.
+    pub fn new(name: &'static str, size: Option<u32>) -> Self {
+        Self { name, size }
+    }
+}
+
+impl<F> EnvTypeSpec<F>
+where
+    F: Form,
+{
+    /// Returns the full name of the environmental type.
+    pub fn name(&self) -> &F::String {
+        &self.name
+    }
+
+    /// Returns the width of the encoded type in bytes if known.
+    pub fn size(&self) -> Option<u32> {
+        self.size
+    }
+}
+
 /// Describes a constructor of a contract.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(bound(
@@ -389,6 +667,22 @@ pub struct MessageSpec<F: Form = MetaForm> {
     return_type: ReturnTypeSpec<F>,
     /// The message documentation.
     docs: Vec<F::String>,
+    /// The optional weight hint of the message.
+    ///
+    /// This is purely advisory and allows front-ends to estimate fees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weight: Option<u64>,
+    /// The optional storage fields read and written by the message.
+    ///
+    /// This is purely advisory and allows tools to estimate worst-case gas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    storage_access: Option<StorageAccessSpec<F>>,
+    /// The optional identifier of the standard interface entry of the message,
+    /// e.g. `"erc20::transfer"`.
+    ///
+    /// This is purely advisory and allows wallets to recognize standard interfaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    standard: Option<F::String>,
 }
 
 /// Type state for builders to tell that some mandatory state has not yet been set
@@ -428,6 +722,9 @@ impl MessageSpec {
                 args: Vec::new(),
                 return_type: ReturnTypeSpec::new(None),
                 docs: Vec::new(),
+                weight: None,
+                storage_access: None,
+                standard: None,
             },
             marker: PhantomData,
         }
@@ -500,6 +797,21 @@ where
     pub fn docs(&self) -> &[F::String] {
         &self.docs
     }
+
+    /// Returns the weight hint of the message if any.
+    pub fn weight(&self) -> Option<u64> {
+        self.weight
+    }
+
+    /// Returns the storage access hints of the message if any.
+    pub fn storage_access(&self) -> Option<&StorageAccessSpec<F>> {
+        self.storage_access.as_ref()
+    }
+
+    /// Returns the identifier of the standard interface entry of the message if any.
+    pub fn standard(&self) -> Option<&F::String> {
+        self.standard.as_ref()
+    }
 }
 
 /// A builder for messages.
@@ -598,6 +910,37 @@ impl<S, M, P, R> MessageSpecBuilder<S, M, P, R> {
         this.spec.docs = docs.into_iter().collect::<Vec<_>>();
         this
     }
+
+    /// Sets the weight hint of the message specification.
+    pub fn weight(self, weight: u64) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.weight.is_none());
+        this.spec.weight = Some(weight);
+        this
+    }
+
+    /// Sets the storage fields read and written by the message specification.
+    pub fn storage_access<RS, WS>(self, reads: RS, writes: WS) -> Self
+    where
+        RS: IntoIterator<Item = &'static str>,
+        WS: IntoIterator<Item = &'static str>,
+    {
+        let mut this = self;
+        debug_assert!(this.spec.storage_access.is_none());
+        this.spec.storage_access = Some(StorageAccessSpec {
+            reads: reads.into_iter().collect::<Vec<_>>(),
+            writes: writes.into_iter().collect::<Vec<_>>(),
+        });
+        this
+    }
+
+    /// Sets the identifier of the standard interface entry of the message specification.
+    pub fn standard(self, standard: &'static str) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.standard.is_none());
+        this.spec.standard = Some(standard);
+        this
+    }
 }
 
 impl
@@ -625,10 +968,61 @@ impl IntoPortable for MessageSpec {
                 .collect::<Vec<_>>(),
             return_type: self.return_type.into_portable(registry),
             docs: registry.map_into_portable(self.docs),
+            weight: self.weight,
+            storage_access: self
+                .storage_access
+                .map(|storage_access| storage_access.into_portable(registry)),
+            standard: self
+                .standard
+                .map(|standard| standard.into_portable(registry)),
+        }
+    }
+}
+
+/// Describes the storage fields read and written by a message.
+///
+/// # Note
+///
+/// This is derived from a syntactic analysis of the message and therefore
+/// only approximate.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "F::Type: Serialize, F::String: Serialize",
+    deserialize = "F::Type: DeserializeOwned, F::String: DeserializeOwned"
+))]
+pub struct StorageAccessSpec<F: Form = MetaForm> {
+    /// The names of the storage fields read by the message.
+    reads: Vec<F::String>,
+    /// The names of the storage fields written by the message.
+    writes: Vec<F::String>,
+}
+
+impl IntoPortable for StorageAccessSpec {
+    type Output = StorageAccessSpec<PortableForm>;
+
+    fn into_portable(self, registry: &mut Registry) -> Self::Output {
+        StorageAccessSpec {
+            reads: registry.map_into_portable(self.reads),
+            writes: registry.map_into_portable(self.writes),
         }
     }
 }
 
+impl<F> StorageAccessSpec<F>
+where
+    F: Form,
+{
+    /// Returns the names of the storage fields read by the message.
+    pub fn reads(&self) -> &[F::String] {
+        &self.reads
+    }
+
+    /// Returns the names of the storage fields written by the message.
+    pub fn writes(&self) -> &[F::String] {
+        &self.writes
+    }
+}
+
 /// Describes an event definition.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(bound(
@@ -758,6 +1152,37 @@ impl Selector {
     }
 }
 
+/// The 32 byte hash of the original source module of a contract.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, derive_more::From)]
+pub struct SourceHash([u8; 32]);
+
+impl serde::Serialize for SourceHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SourceHash {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut arr = [0; 32];
+        serde_hex::deserialize_check_len(d, serde_hex::ExpectedLen::Exact(&mut arr[..]))?;
+        Ok(arr.into())
+    }
+}
+
+impl SourceHash {
+    /// Returns the underlying source hash bytes.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Describes the syntactical name of a type at a given type position.
 ///
 /// This is important when trying to work with type aliases.
@@ -1069,6 +1494,11 @@ pub struct MessageParamSpec<F: Form = MetaForm> {
     /// The type of the parameter.
     #[serde(rename = "type")]
     ty: TypeSpec<F>,
+    /// The optional default value of the parameter.
+    ///
+    /// This is purely advisory and allows front-ends to prefill the parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<F::String>,
 }
 
 impl IntoPortable for MessageParamSpec {
@@ -1078,6 +1508,7 @@ impl IntoPortable for MessageParamSpec {
         MessageParamSpec {
             name: self.name.into_portable(registry),
             ty: self.ty.into_portable(registry),
+            default: self.default.map(|default| default.into_portable(registry)),
         }
     }
 }
@@ -1090,6 +1521,7 @@ impl MessageParamSpec {
                 name,
                 // Uses `()` type by default.
                 ty: TypeSpec::new::<()>(),
+                default: None,
             },
         }
     }
@@ -1108,6 +1540,11 @@ where
     pub fn ty(&self) -> &TypeSpec<F> {
         &self.ty
     }
+
+    /// Returns the default value of the parameter if any.
+    pub fn default(&self) -> Option<&F::String> {
+        self.default.as_ref()
+    }
 }
 
 /// Used to construct a message parameter specification.
@@ -1124,6 +1561,14 @@ impl MessageParamSpecBuilder {
         this
     }
 
+    /// Sets the default value of the message parameter.
+    pub fn default(self, default: &'static str) -> Self {
+        let mut this = self;
+        debug_assert!(this.spec.default.is_none());
+        this.spec.default = Some(default);
+        this
+    }
+
     /// Finishes construction of the message parameter.
     pub fn done(self) -> MessageParamSpec {
         self.spec
@@ -198,3 +198,448 @@ fn trim_docs() {
     );
     assert_eq!(deserialized.docs, compact_spec.docs);
 }
+
+#[test]
+fn spec_contract_source_hash_must_serialize_to_hex() {
+    // given
+    let contract: ContractSpec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_name("new")
+            .selector([94u8, 189u8, 136u8, 214u8])
+            .done()])
+        .messages(vec![MessageSpec::from_name("get")
+            .selector([37u8, 68u8, 74u8, 254u8])
+            .mutates(false)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(None))
+            .done()])
+        .source_hash([0x42; 32])
+        .done();
+    let mut registry = Registry::new();
+    let portable_spec = contract.into_portable(&mut registry);
+
+    // when
+    let json = serde_json::to_value(&portable_spec).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json["sourceHash"],
+        json!("0x4242424242424242424242424242424242424242424242424242424242424242")
+    );
+    assert_eq!(deserialized.source_hash(), portable_spec.source_hash());
+}
+
+#[test]
+fn spec_contract_license_must_serialize() {
+    // given
+    let contract = |license: Option<&'static str>| {
+        let builder = ContractSpec::new()
+            .constructors(vec![ConstructorSpec::from_name("new")
+                .selector([94u8, 189u8, 136u8, 214u8])
+                .done()])
+            .messages(vec![MessageSpec::from_name("get")
+                .selector([37u8, 68u8, 74u8, 254u8])
+                .mutates(false)
+                .payable(false)
+                .returns(ReturnTypeSpec::new(None))
+                .done()]);
+        let builder = match license {
+            Some(license) => builder.license(license),
+            None => builder,
+        };
+        builder.done().into_portable(&mut Registry::new())
+    };
+
+    // when
+    let licensed = contract(Some("Apache-2.0"));
+    let unlicensed = contract(None);
+    let json = serde_json::to_value(&licensed).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(json["license"], json!("Apache-2.0"));
+    assert_eq!(
+        deserialized.license().map(String::as_str),
+        Some("Apache-2.0")
+    );
+    assert!(serde_json::to_value(&unlicensed)
+        .unwrap()
+        .get("license")
+        .is_none());
+}
+
+#[test]
+fn spec_contract_environment_must_serialize() {
+    // given
+    let contract = |environment: Option<EnvironmentSpec>| {
+        let builder = ContractSpec::new()
+            .constructors(vec![ConstructorSpec::from_name("new")
+                .selector([94u8, 189u8, 136u8, 214u8])
+                .done()])
+            .messages(vec![MessageSpec::from_name("get")
+                .selector([37u8, 68u8, 74u8, 254u8])
+                .mutates(false)
+                .payable(false)
+                .returns(ReturnTypeSpec::new(None))
+                .done()]);
+        let builder = match environment {
+            Some(environment) => builder.environment(environment),
+            None => builder,
+        };
+        builder.done().into_portable(&mut Registry::new())
+    };
+    let environment = EnvironmentSpec::new(
+        EnvTypeSpec::new("ink_env::types::AccountId", Some(32)),
+        EnvTypeSpec::new("u128", Some(16)),
+        EnvTypeSpec::new("ink_env::types::Hash", Some(32)),
+        EnvTypeSpec::new("u32", Some(4)),
+        EnvTypeSpec::new("u64", None),
+    );
+
+    // when
+    let with_environment = contract(Some(environment));
+    let without_environment = contract(None);
+    let json = serde_json::to_value(&with_environment).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(json["environment"]["accountId"]["size"], json!(32));
+    assert_eq!(
+        json["environment"]["accountId"]["name"],
+        json!("ink_env::types::AccountId")
+    );
+    assert_eq!(json["environment"]["balance"]["size"], json!(16));
+    assert_eq!(json["environment"]["hash"]["size"], json!(32));
+    assert_eq!(json["environment"]["blockNumber"]["size"], json!(4));
+    assert!(json["environment"]["timestamp"].get("size").is_none());
+    let environment = deserialized.environment().unwrap();
+    assert_eq!(environment.balance().name(), "u128");
+    assert_eq!(environment.balance().size(), Some(16));
+    assert_eq!(environment.timestamp().size(), None);
+    assert!(serde_json::to_value(&without_environment)
+        .unwrap()
+        .get("environment")
+        .is_none());
+}
+
+#[test]
+fn spec_contract_build_info_must_serialize() {
+    // given
+    let contract = |build_info: Option<(&'static str, &'static str)>| {
+        let builder = ContractSpec::new()
+            .constructors(vec![ConstructorSpec::from_name("new")
+                .selector([94u8, 189u8, 136u8, 214u8])
+                .done()])
+            .messages(vec![MessageSpec::from_name("get")
+                .selector([37u8, 68u8, 74u8, 254u8])
+                .mutates(false)
+                .payable(false)
+                .returns(ReturnTypeSpec::new(None))
+                .done()]);
+        let builder = match build_info {
+            Some((rustc_version, ink_version)) => {
+                builder.build_info(rustc_version, ink_version)
+            }
+            None => builder,
+        };
+        builder.done().into_portable(&mut Registry::new())
+    };
+
+    // when
+    let with_build_info = contract(Some(("rustc 1.53.0", "3.0.0-rc3")));
+    let without_build_info = contract(None);
+    let json = serde_json::to_value(&with_build_info).unwrap();
+    let deserialized: ContractSpec<PortableForm> =
+        serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(
+        json["buildInfo"],
+        json!({
+            "rustcVersion": "rustc 1.53.0",
+            "inkVersion": "3.0.0-rc3",
+        })
+    );
+    let build_info = deserialized.build_info().unwrap();
+    assert_eq!(build_info.rustc_version(), "rustc 1.53.0");
+    assert_eq!(build_info.ink_version(), "3.0.0-rc3");
+    assert!(serde_json::to_value(&without_build_info)
+        .unwrap()
+        .get("buildInfo")
+        .is_none());
+}
+
+#[test]
+fn ink_project_version_must_serialize() {
+    // given
+    let new_project = || {
+        let layout = layout::Layout::Cell(layout::CellLayout::new::<i32>(
+            layout::LayoutKey::from([0x00; 32]),
+        ));
+        let contract: ContractSpec = ContractSpec::new()
+            .constructors(vec![ConstructorSpec::from_name("new")
+                .selector([94u8, 189u8, 136u8, 214u8])
+                .done()])
+            .messages(vec![MessageSpec::from_name("get")
+                .selector([37u8, 68u8, 74u8, 254u8])
+                .mutates(false)
+                .payable(false)
+                .returns(ReturnTypeSpec::new(None))
+                .done()])
+            .done();
+        InkProject::new(layout, contract)
+    };
+    let v0_project = new_project();
+    let v1_project = new_project().with_version(MetadataVersion::V1);
+
+    // when
+    let v0_json = serde_json::to_value(&v0_project).unwrap();
+    let v1_json = serde_json::to_value(&v1_project).unwrap();
+    let deserialized: InkProject = serde_json::from_value(v1_json.clone()).unwrap();
+
+    // then
+    assert_eq!(v0_project.version(), MetadataVersion::V0);
+    assert!(v0_json.get("version").is_none());
+    assert_eq!(v1_json["version"], json!("1"));
+    assert_eq!(deserialized.version(), MetadataVersion::V1);
+}
+
+#[test]
+fn ink_project_must_round_trip() {
+    // given
+    let layout = layout::Layout::Cell(layout::CellLayout::new::<i32>(
+        layout::LayoutKey::from([0x00; 32]),
+    ));
+    let contract: ContractSpec = ContractSpec::new()
+        .constructors(vec![ConstructorSpec::from_name("new")
+            .selector([94u8, 189u8, 136u8, 214u8])
+            .args(vec![MessageParamSpec::new("init_value")
+                .of_type(TypeSpec::with_name_str::<i32>("i32"))
+                .done()])
+            .docs(vec!["Creates a new counter."])
+            .done()])
+        .messages(vec![MessageSpec::from_name("get")
+            .selector([37u8, 68u8, 74u8, 254u8])
+            .mutates(false)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<i32>("i32")))
+            .done()])
+        .environment(EnvironmentSpec::new(
+            EnvTypeSpec::new(TypeSpec::with_name_str::<[u8; 32]>("AccountId"), Some(32)),
+            EnvTypeSpec::new(TypeSpec::with_name_str::<u128>("Balance"), Some(16)),
+            EnvTypeSpec::new(TypeSpec::with_name_str::<[u8; 32]>("Hash"), Some(32)),
+            EnvTypeSpec::new(TypeSpec::with_name_str::<u32>("BlockNumber"), Some(4)),
+            EnvTypeSpec::new(TypeSpec::with_name_str::<u64>("Timestamp"), Some(8)),
+        ))
+        .build_info("rustc 1.52.0", "3.0.0-rc3")
+        .done();
+    let project = InkProject::new(layout, contract).with_version(MetadataVersion::V1);
+
+    // when
+    let json = serde_json::to_value(&project).unwrap();
+    let deserialized: InkProject = serde_json::from_value(json.clone()).unwrap();
+
+    // then
+    assert_eq!(deserialized, project);
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), json);
+}
+
+#[test]
+fn spec_message_weight_must_serialize() {
+    // given
+    let new_message = || {
+        MessageSpec::from_name("get")
+            .selector([37u8, 68u8, 74u8, 254u8])
+            .mutates(false)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(None))
+    };
+    let mut registry = Registry::new();
+    let without_weight = new_message().done().into_portable(&mut registry);
+    let with_weight = new_message()
+        .weight(1_000_000)
+        .done()
+        .into_portable(&mut registry);
+
+    // when
+    let without_weight_json = serde_json::to_value(&without_weight).unwrap();
+    let with_weight_json = serde_json::to_value(&with_weight).unwrap();
+    let deserialized: MessageSpec<PortableForm> =
+        serde_json::from_value(with_weight_json.clone()).unwrap();
+
+    // then
+    assert!(without_weight_json.get("weight").is_none());
+    assert_eq!(with_weight_json["weight"], json!(1_000_000));
+    assert_eq!(deserialized.weight(), Some(1_000_000));
+}
+
+#[test]
+fn spec_message_standard_must_serialize() {
+    // given
+    let new_message = || {
+        MessageSpec::from_name("transfer")
+            .selector([132u8, 161u8, 93u8, 161u8])
+            .mutates(true)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(None))
+    };
+    let mut registry = Registry::new();
+    let without_standard = new_message().done().into_portable(&mut registry);
+    let with_standard = new_message()
+        .standard("erc20::transfer")
+        .done()
+        .into_portable(&mut registry);
+
+    // when
+    let without_standard_json = serde_json::to_value(&without_standard).unwrap();
+    let with_standard_json = serde_json::to_value(&with_standard).unwrap();
+    let deserialized: MessageSpec<PortableForm> =
+        serde_json::from_value(with_standard_json.clone()).unwrap();
+
+    // then
+    assert!(without_standard_json.get("standard").is_none());
+    assert_eq!(with_standard_json["standard"], json!("erc20::transfer"));
+    assert_eq!(
+        deserialized.standard().map(String::as_str),
+        Some("erc20::transfer")
+    );
+}
+
+#[test]
+fn spec_message_storage_access_must_serialize() {
+    // given
+    let new_message = || {
+        MessageSpec::from_name("inc")
+            .selector([37u8, 68u8, 74u8, 254u8])
+            .mutates(true)
+            .payable(false)
+            .returns(ReturnTypeSpec::new(None))
+    };
+    let mut registry = Registry::new();
+    let without_access = new_message().done().into_portable(&mut registry);
+    let with_access = new_message()
+        .storage_access(vec!["step"], vec!["value"])
+        .done()
+        .into_portable(&mut registry);
+
+    // when
+    let without_access_json = serde_json::to_value(&without_access).unwrap();
+    let with_access_json = serde_json::to_value(&with_access).unwrap();
+    let deserialized: MessageSpec<PortableForm> =
+        serde_json::from_value(with_access_json.clone()).unwrap();
+
+    // then
+    assert!(without_access_json.get("storageAccess").is_none());
+    assert_eq!(
+        with_access_json["storageAccess"],
+        json!({
+            "reads": ["step"],
+            "writes": ["value"],
+        })
+    );
+    let storage_access = deserialized.storage_access().unwrap();
+    assert_eq!(storage_access.reads(), &["step".to_string()]);
+    assert_eq!(storage_access.writes(), &["value".to_string()]);
+}
+
+#[test]
+fn spec_message_param_default_must_serialize() {
+    // given
+    let new_param = || {
+        MessageParamSpec::new("value").of_type(TypeSpec::with_name_segs::<i32, _>(
+            vec!["i32"].into_iter().map(AsRef::as_ref),
+        ))
+    };
+    let mut registry = Registry::new();
+    let without_default = new_param().done().into_portable(&mut registry);
+    let with_default = new_param().default("0").done().into_portable(&mut registry);
+
+    // when
+    let without_default_json = serde_json::to_value(&without_default).unwrap();
+    let with_default_json = serde_json::to_value(&with_default).unwrap();
+    let deserialized: MessageParamSpec<PortableForm> =
+        serde_json::from_value(with_default_json.clone()).unwrap();
+
+    // then
+    assert!(without_default_json.get("default").is_none());
+    assert_eq!(with_default_json["default"], json!("0"));
+    assert_eq!(deserialized.default().map(String::as_str), Some("0"));
+}
+
+#[test]
+fn ink_project_must_validate_against_json_schema() {
+    // given
+    let new_project = || {
+        let layout = layout::Layout::Struct(layout::StructLayout::new(vec![
+            layout::FieldLayout::new(
+                "value",
+                layout::CellLayout::new::<i32>(layout::LayoutKey::from([0x00; 32])),
+            ),
+        ]));
+        let contract: ContractSpec = ContractSpec::new()
+            .constructors(vec![ConstructorSpec::from_name("new")
+                .selector([94u8, 189u8, 136u8, 214u8])
+                .args(vec![MessageParamSpec::new("init_value")
+                    .of_type(TypeSpec::with_name_str::<i32>("i32"))
+                    .default("0")
+                    .done()])
+                .docs(vec!["Creates a new counter."])
+                .done()])
+            .messages(vec![
+                MessageSpec::from_name("inc")
+                    .selector([231u8, 208u8, 89u8, 15u8])
+                    .mutates(true)
+                    .payable(true)
+                    .returns(ReturnTypeSpec::new(None))
+                    .weight(1_000_000)
+                    .storage_access(vec!["value"], vec!["value"])
+                    .done(),
+                MessageSpec::from_name("get")
+                    .selector([37u8, 68u8, 74u8, 254u8])
+                    .mutates(false)
+                    .payable(false)
+                    .returns(ReturnTypeSpec::new(TypeSpec::with_name_str::<i32>("i32")))
+                    .standard("counter::get")
+                    .done(),
+            ])
+            .events(vec![EventSpec::new("Incremented")
+                .args(vec![EventParamSpec::new("by")
+                    .of_type(TypeSpec::with_name_str::<i32>("i32"))
+                    .indexed(true)
+                    .done()])
+                .docs(vec!["Emitted upon increments."])
+                .done()])
+            .source_hash([0x42; 32])
+            .license("Apache-2.0")
+            .environment(EnvironmentSpec::new(
+                EnvTypeSpec::new(
+                    TypeSpec::with_name_str::<[u8; 32]>("AccountId"),
+                    Some(32),
+                ),
+                EnvTypeSpec::new(TypeSpec::with_name_str::<u128>("Balance"), Some(16)),
+                EnvTypeSpec::new(TypeSpec::with_name_str::<[u8; 32]>("Hash"), Some(32)),
+                EnvTypeSpec::new(TypeSpec::with_name_str::<u32>("BlockNumber"), Some(4)),
+                EnvTypeSpec::new(TypeSpec::with_name_str::<u64>("Timestamp"), Some(8)),
+            ))
+            .build_info("rustc 1.52.0", "3.0.0-rc3")
+            .done();
+        InkProject::new(layout, contract)
+    };
+    let schema = metadata_json_schema();
+    let schema = jsonschema::JSONSchema::compile(&schema).unwrap();
+
+    // when
+    let v0_json = serde_json::to_value(&new_project()).unwrap();
+    let v1_json =
+        serde_json::to_value(&new_project().with_version(MetadataVersion::V1)).unwrap();
+    let mut invalid_json = v1_json.clone();
+    invalid_json["spec"]["messages"][0]["selector"] = json!("0x2a");
+
+    // then
+    assert!(schema.is_valid(&v0_json));
+    assert!(schema.is_valid(&v1_json));
+    assert!(!schema.is_valid(&invalid_json));
+}